@@ -14,9 +14,19 @@
 //! `geometry-nalgebra` feature is enabled.
 
 use arrayvec::ArrayVec;
+use core::array;
+use core::ops::{Add, Mul};
+use core::slice;
 use decorum::cmp::{self, IntrinsicOrd};
-use num::{Bounded, One, Zero};
-use std::ops::{Add, Mul};
+use num::traits::{
+    CheckedAdd, CheckedMul, CheckedSub, Saturating, WrappingAdd, WrappingMul, WrappingSub,
+};
+use num::{Bounded, NumCast, One, Signed, ToPrimitive, Zero};
+use typenum::type_operators::Cmp;
+use typenum::{Greater, U1, U2, U3};
+
+use crate::space::FiniteDimensional;
+use crate::Error;
 
 pub trait Adjunct: Sized {
     type Item;
@@ -28,16 +38,144 @@ pub trait IntoItems: Adjunct {
     fn into_items(self) -> Self::Output;
 }
 
+/// Iteration over borrowed items.
+///
+/// Unlike `IntoItems`, this trait neither consumes `self` nor copies items
+/// into an intermediate collection. This trait is implemented for arrays,
+/// homogeneous tuples, and `nalgebra` types.
+pub trait IterItems: Adjunct {
+    type Iter<'a>: Iterator<Item = &'a Self::Item>
+    where
+        Self: 'a;
+
+    fn iter_items(&self) -> Self::Iter<'_>;
+}
+
+/// Contiguous views of items.
+///
+/// This trait is implemented for types that store their items contiguously in
+/// order, such as arrays and `nalgebra` types. Homogeneous tuples do not
+/// implement this trait, because the layout of tuples is unspecified.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::adjunct::{AsItems, IterItems};
+///
+/// let items = [1, 2, 3];
+/// assert_eq!(&[2, 3], &items.as_items()[1..]);
+/// assert_eq!(Some(&3), (1, 2, 3).iter_items().max());
+/// ```
+pub trait AsItems: IterItems {
+    fn as_items(&self) -> &[Self::Item];
+}
+
 pub trait FromItems: Adjunct {
     fn from_items<I>(items: I) -> Option<Self>
     where
         I: IntoIterator<Item = Self::Item>;
 }
 
+/// Length-checked conversion from items.
+///
+/// Unlike `FromItems`, which ignores any items beyond those that it requires,
+/// this trait requires that the number of items is exactly the number of items
+/// in `Self`. It is implemented for all types that implement `FromItems`.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::adjunct::TryFromItems;
+/// use theon::Error;
+///
+/// assert_eq!(Some([1, 2]), <[i32; 2]>::try_from_items(vec![1, 2]));
+/// assert_eq!(None, <[i32; 2]>::try_from_items(vec![1]));
+/// assert_eq!(None, <[i32; 2]>::try_from_items(vec![1, 2, 3]));
+/// assert_eq!(
+///     Err(Error::TooManyItems),
+///     <[i32; 2]>::from_exact_items(vec![1, 2, 3])
+/// );
+/// ```
+pub trait TryFromItems: FromItems {
+    /// Converts items into `Self`.
+    ///
+    /// Returns `None` if there are too few or too many items.
+    fn try_from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter();
+        let output = Self::from_items(items.by_ref())?;
+        if items.next().is_none() {
+            Some(output)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Converts items into `Self`.
+    ///
+    /// Returns `Error::TooFewItems` or `Error::TooManyItems` if there are too
+    /// few or too many items, respectively. See `try_from_items`.
+    fn from_exact_items<I>(items: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter();
+        let output = Self::from_items(items.by_ref()).ok_or(Error::TooFewItems)?;
+        if items.next().is_none() {
+            Ok(output)
+        }
+        else {
+            Err(Error::TooManyItems)
+        }
+    }
+}
+
+impl<T> TryFromItems for T where T: FromItems {}
+
 pub trait Converged: Adjunct {
     fn converged(value: Self::Item) -> Self;
 }
 
+/// Construction from a function of item indices.
+///
+/// Constructs an adjunct by calling a function with the index of each item.
+/// Indices are given in the same order as `FromItems`. This trait is
+/// implemented for all types that implement `FromItems` and
+/// `FiniteDimensional`. For arrays, use `core::array::from_fn`.
+///
+/// Note that some foreign types provide an inherent `from_fn` function with a
+/// different signature, which takes precedence over this trait.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::adjunct::FromFn;
+///
+/// let weights = [0.5, 0.25, 0.25];
+/// let items: (f64, f64, f64) = FromFn::from_fn(|index| weights[index] * 2.0);
+/// assert_eq!((1.0, 0.5, 0.5), items);
+/// ```
+pub trait FromFn: Adjunct {
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Item;
+}
+
+impl<T> FromFn for T
+where
+    T: FiniteDimensional + FromItems,
+{
+    fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Item,
+    {
+        T::from_items((0..T::dimensions()).map(f)).expect("inconsistent number of items")
+    }
+}
+
 pub trait Map<T = <Self as Adjunct>::Item>: Adjunct {
     type Output: Adjunct<Item = T>;
 
@@ -46,6 +184,174 @@ pub trait Map<T = <Self as Adjunct>::Item>: Adjunct {
         F: FnMut(Self::Item) -> T;
 }
 
+/// In-place mapping of items.
+///
+/// Unlike `Map`, this trait mutates the items of `self` rather than
+/// constructing a new value. This trait is implemented for arrays, homogeneous
+/// tuples, and `nalgebra` types.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::adjunct::MapAssign;
+///
+/// let mut items = [1.0, 2.0];
+/// items.map_assign(|item| *item *= 2.0);
+/// items.zip_map_assign([1.0, 1.0], |a, b| *a -= b);
+/// assert_eq!([1.0, 3.0], items);
+/// ```
+pub trait MapAssign: Adjunct {
+    fn map_assign<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Self::Item);
+
+    /// Mutates each item of `self` with the corresponding item of `other`.
+    fn zip_map_assign<F>(&mut self, other: Self, mut f: F)
+    where
+        Self: IntoItems,
+        F: FnMut(&mut Self::Item, Self::Item),
+    {
+        let mut items = other.into_items().into_iter();
+        self.map_assign(|a| f(a, items.next().unwrap()));
+    }
+}
+
+/// Mapping of items with their indices.
+///
+/// Maps each item with a function that also receives the index of the item.
+/// Indices are given in the same order as `IntoItems` and `FromItems`; for
+/// vectors and points, the index identifies the basis of the component. This
+/// trait is implemented for all types that implement `Map`.
+///
+/// # Examples
+///
+/// Scaling each axis by a different factor:
+///
+/// ```rust
+/// use theon::adjunct::MapWithIndex;
+///
+/// let scale = [2.0, 3.0];
+/// assert_eq!(
+///     [2.0, 6.0],
+///     [1.0, 2.0].map_with_index(|index, item| item * scale[index])
+/// );
+/// ```
+pub trait MapWithIndex<T = <Self as Adjunct>::Item>: Map<T> {
+    fn map_with_index<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(usize, Self::Item) -> T;
+}
+
+impl<T, U> MapWithIndex<U> for T
+where
+    T: Map<U>,
+{
+    fn map_with_index<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(usize, Self::Item) -> U,
+    {
+        let mut index = 0;
+        self.map(|item| {
+            let output = f(index, item);
+            index += 1;
+            output
+        })
+    }
+}
+
+/// Mapping of items by reference.
+///
+/// Unlike `Map`, this trait borrows `self` and its items, so items need not
+/// be cloned. This is useful for items that are expensive to clone, such as
+/// arbitrary precision rationals and intervals. This trait is implemented for
+/// arrays, homogeneous tuples, and `nalgebra` types.
+pub trait MapRef<T = <Self as Adjunct>::Item>: Adjunct {
+    type Output: Adjunct<Item = T>;
+
+    fn map_ref<F>(&self, f: F) -> Self::Output
+    where
+        F: FnMut(&Self::Item) -> T;
+}
+
+/// Fallible mapping of items.
+///
+/// Maps each item with a function that may fail. Mapping stops at the first
+/// failure, which is returned as an error. Functions that produce an `Option`
+/// can be adapted with `Option::ok_or`. This trait is implemented for all
+/// types that implement `IntoItems` and `Map` into a type that implements
+/// `FromItems`.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::adjunct::TryMap;
+///
+/// let parse = |item: &str| item.parse::<i32>();
+/// assert_eq!(Ok([1, 2]), TryMap::try_map(["1", "2"], parse));
+/// assert!(TryMap::try_map(["1", "a"], parse).is_err());
+/// ```
+pub trait TryMap<T = <Self as Adjunct>::Item>: Map<T> {
+    fn try_map<E, F>(self, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(Self::Item) -> Result<T, E>;
+}
+
+impl<T, U> TryMap<U> for T
+where
+    T: IntoItems + Map<U>,
+    <T as Map<U>>::Output: FromItems,
+{
+    fn try_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(Self::Item) -> Result<U, E>,
+    {
+        let mut error = None;
+        let output = <T as Map<U>>::Output::from_items(
+            self.into_items()
+                .into_iter()
+                .map_while(|item| f(item).map_err(|inner| error = Some(inner)).ok())
+                .fuse(),
+        );
+        match error {
+            Some(error) => Err(error),
+            None => Ok(output.expect("inconsistent number of mapped items")),
+        }
+    }
+}
+
+/// Conversion of items between numeric types.
+///
+/// Converts each item via `NumCast`. Conversion fails if any item cannot be
+/// represented in the target type, such as an integer that is out of range or
+/// a `NaN` that is converted into an integer. Conversions between
+/// floating-point types round rather than fail. This trait is implemented for
+/// all types that implement `TryMap`.
+///
+/// Note that some foreign types provide an inherent `cast` function with a
+/// different signature, which takes precedence over this trait.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::adjunct::Cast;
+///
+/// assert_eq!(Some([1.0f32, 2.5]), Cast::cast::<f32>([1.0f64, 2.5]));
+/// assert_eq!(Some((1.0f64, -2.0f64)), (1i32, -2i32).cast::<f64>());
+/// assert_eq!(None, Cast::cast::<u8>([1i32, 256]));
+/// ```
+pub trait Cast: Adjunct {
+    fn cast<T>(self) -> Option<<Self as Map<T>>::Output>
+    where
+        Self: TryMap<T>,
+        Self::Item: ToPrimitive,
+        T: NumCast,
+    {
+        TryMap::try_map(self, |item| T::from(item).ok_or(())).ok()
+    }
+}
+
+impl<T> Cast for T where T: Adjunct {}
+
 // TODO: Consider renaming the `Truncate` and `Extend` traits to `TruncateMap`,
 //       `TruncateInto`, etc., because these traits must support multiple output
 //       types.
@@ -103,6 +409,101 @@ pub trait ZipMap<T = <Self as Adjunct>::Item>: Adjunct {
     }
 }
 
+/// Zips and maps three adjuncts.
+///
+/// This is the three-way analog of `ZipMap`, which is useful for barycentric
+/// combinations, clamping, and fused multiply-add.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::adjunct::ZipMap3;
+///
+/// let (a, b, c) = ([1.0, 0.0], [0.0, 1.0], [1.0, 1.0]);
+/// let centroid = a.zip_map3(b, c, |a, b, c| (a + b + c) / 3.0);
+/// assert_eq!([2.0 / 3.0, 2.0 / 3.0], centroid);
+/// ```
+pub trait ZipMap3<T = <Self as Adjunct>::Item>: Adjunct {
+    type Output: Adjunct<Item = T>;
+
+    fn zip_map3<F>(self, b: Self, c: Self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item, Self::Item) -> T;
+
+    /// Computes `(self * b) + c` for each item.
+    fn per_item_mul_add(self, b: Self, c: Self) -> Self::Output
+    where
+        Self: Adjunct<Item = T>,
+        T: Add<Output = T> + Mul<Output = T>,
+    {
+        self.zip_map3(b, c, |a, b, c| (a * b) + c)
+    }
+}
+
+/// Zips and maps adjuncts with different item types.
+///
+/// Unlike `ZipMap`, the operands of `zip_with` may be of different types with
+/// different items, such as a vector of scalars and a mask of `bool`s. The
+/// output has the same structure as `self`. This trait is implemented for all
+/// types that implement `IntoItems` and `Map` into a type that implements
+/// `FromItems`.
+///
+/// # Examples
+///
+/// Selecting items with a mask:
+///
+/// ```rust
+/// use theon::adjunct::ZipWith;
+///
+/// let mask = [true, false, true];
+/// let selected = [1.0, 2.0, 3.0].zip_with(mask, |x, is_selected| {
+///     if is_selected {
+///         x
+///     }
+///     else {
+///         0.0
+///     }
+/// });
+/// assert_eq!([1.0, 0.0, 3.0], selected);
+/// ```
+pub trait ZipWith<U, T = <Self as Adjunct>::Item>: Adjunct
+where
+    U: Adjunct,
+{
+    type Output: Adjunct<Item = T>;
+
+    /// Zips the items of `self` and `other` and maps them with a function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` has fewer items than `self`.
+    fn zip_with<F>(self, other: U, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, U::Item) -> T;
+}
+
+impl<S, U, T> ZipWith<U, T> for S
+where
+    S: IntoItems + Map<T>,
+    U: IntoItems,
+    <S as Map<T>>::Output: FromItems,
+{
+    type Output = <S as Map<T>>::Output;
+
+    fn zip_with<F>(self, other: U, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, U::Item) -> T,
+    {
+        Self::Output::from_items(
+            self.into_items()
+                .into_iter()
+                .zip(other.into_items())
+                .map(|(a, b)| f(a, b)),
+        )
+        .expect("too few items")
+    }
+}
+
 pub trait Fold: Adjunct {
     fn fold<T, F>(self, seed: T, f: F) -> T
     where
@@ -136,6 +537,54 @@ pub trait Fold: Adjunct {
         self.fold(Bounded::min_value(), cmp::max_or_undefined)
     }
 
+    /// Gets the minimum item.
+    ///
+    /// Returns `None` if there are no items. Undefined items (e.g., `NaN`) are
+    /// propagated: if any item is undefined, then the minimum is undefined.
+    /// Unlike `min_or_undefined`, this does not require `Bounded`.
+    fn min_component(self) -> Option<Self::Item>
+    where
+        Self::Item: IntrinsicOrd,
+    {
+        self.fold(None, |min, item| {
+            Some(min.map_or(item, |min| cmp::min_or_undefined(min, item)))
+        })
+    }
+
+    /// Gets the maximum item.
+    ///
+    /// See `min_component`.
+    fn max_component(self) -> Option<Self::Item>
+    where
+        Self::Item: IntrinsicOrd,
+    {
+        self.fold(None, |max, item| {
+            Some(max.map_or(item, |max| cmp::max_or_undefined(max, item)))
+        })
+    }
+
+    /// Gets the index of the minimum item.
+    ///
+    /// If more than one item is the minimum, then the index of the first such
+    /// item is returned. Returns `None` if there are no items or if any item
+    /// is undefined (e.g., `NaN`).
+    fn min_component_index(self) -> Option<usize>
+    where
+        Self::Item: IntrinsicOrd,
+    {
+        extremum_index(self, |item, min| item < min)
+    }
+
+    /// Gets the index of the maximum item.
+    ///
+    /// See `min_component_index`.
+    fn max_component_index(self) -> Option<usize>
+    where
+        Self::Item: IntrinsicOrd,
+    {
+        extremum_index(self, |item, max| item > max)
+    }
+
     fn any<F>(self, mut f: F) -> bool
     where
         F: FnMut(Self::Item) -> bool,
@@ -165,153 +614,868 @@ pub trait Fold: Adjunct {
     }
 }
 
-// TODO: Use macros to implement these traits for arrays and tuples.
-
-impl<T> Converged for (T, T)
+// Gets the index of the first item for which `f` is `true` when compared to
+// all preceding items. Returns `None` if there are no items or if any item is
+// undefined.
+fn extremum_index<T, F>(items: T, mut f: F) -> Option<usize>
 where
-    T: Clone,
+    T: Fold,
+    T::Item: IntrinsicOrd,
+    F: FnMut(&T::Item, &T::Item) -> bool,
 {
-    fn converged(value: Self::Item) -> Self {
-        (value.clone(), value)
+    let (_, extremum, is_undefined) = items.fold(
+        (0usize, None::<(usize, T::Item)>, false),
+        |(n, extremum, is_undefined), item| {
+            let extremum = match extremum {
+                Some((_, extremum)) if f(&item, &extremum) => Some((n, item)),
+                None => Some((n, item)),
+                extremum => extremum,
+            };
+            (n + 1, extremum, is_undefined || item.is_undefined())
+        },
+    );
+    if is_undefined {
+        None
+    }
+    else {
+        extremum.map(|(index, _)| index)
     }
 }
 
-impl<T> Converged for (T, T, T)
+/// Folding of items by reference.
+///
+/// Unlike `Fold`, this trait borrows `self` and its items, so items need not
+/// be cloned. See `MapRef`.
+pub trait FoldRef: Adjunct {
+    fn fold_ref<T, F>(&self, seed: T, f: F) -> T
+    where
+        F: FnMut(T, &Self::Item) -> T;
+}
+
+/// Per-component operations on adjuncts.
+///
+/// This trait is implemented for all adjuncts that implement `Map` and `ZipMap`
+/// into themselves. Unlike `ZipMap::per_item_min_or_undefined` and similar
+/// functions, comparisons only require `PartialOrd`. If a pair of items is
+/// incomparable (e.g., `NaN`), then `per_component_min` and `per_component_max`
+/// produce the item from `self`.
+pub trait PerComponent: Map<Output = Self> + ZipMap<Output = Self> {
+    fn per_component_min(self, other: Self) -> Self
+    where
+        Self::Item: PartialOrd,
+    {
+        self.zip_map(other, |a, b| {
+            if b < a {
+                b
+            }
+            else {
+                a
+            }
+        })
+    }
+
+    fn per_component_max(self, other: Self) -> Self
+    where
+        Self::Item: PartialOrd,
+    {
+        self.zip_map(other, |a, b| {
+            if b > a {
+                b
+            }
+            else {
+                a
+            }
+        })
+    }
+
+    /// Clamps each component to the interval given by the corresponding
+    /// components of `min` and `max`.
+    fn per_component_clamp(self, min: Self, max: Self) -> Self
+    where
+        Self::Item: PartialOrd,
+    {
+        self.per_component_max(min).per_component_min(max)
+    }
+
+    fn per_component_abs(self) -> Self
+    where
+        Self::Item: Signed,
+    {
+        self.map(|a| a.abs())
+    }
+
+    fn per_component_signum(self) -> Self
+    where
+        Self::Item: Signed,
+    {
+        self.map(|a| a.signum())
+    }
+
+    /// Adds components, returning `None` if any sum overflows.
+    fn checked_add(self, other: Self) -> Option<Self>
+    where
+        Self::Item: CheckedAdd,
+    {
+        checked_zip_map(self, other, |a, b| a.checked_add(b))
+    }
+
+    /// Subtracts components, returning `None` if any difference overflows.
+    fn checked_sub(self, other: Self) -> Option<Self>
+    where
+        Self::Item: CheckedSub,
+    {
+        checked_zip_map(self, other, |a, b| a.checked_sub(b))
+    }
+
+    /// Multiplies components, returning `None` if any product overflows.
+    fn checked_mul(self, other: Self) -> Option<Self>
+    where
+        Self::Item: CheckedMul,
+    {
+        checked_zip_map(self, other, |a, b| a.checked_mul(b))
+    }
+
+    fn saturating_add(self, other: Self) -> Self
+    where
+        Self::Item: Saturating,
+    {
+        self.zip_map(other, |a, b| a.saturating_add(b))
+    }
+
+    fn saturating_sub(self, other: Self) -> Self
+    where
+        Self::Item: Saturating,
+    {
+        self.zip_map(other, |a, b| a.saturating_sub(b))
+    }
+
+    fn wrapping_add(self, other: Self) -> Self
+    where
+        Self::Item: WrappingAdd,
+    {
+        self.zip_map(other, |a, b| a.wrapping_add(&b))
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self
+    where
+        Self::Item: WrappingSub,
+    {
+        self.zip_map(other, |a, b| a.wrapping_sub(&b))
+    }
+
+    fn wrapping_mul(self, other: Self) -> Self
+    where
+        Self::Item: WrappingMul,
+    {
+        self.zip_map(other, |a, b| a.wrapping_mul(&b))
+    }
+}
+
+impl<T> PerComponent for T where T: Map<Output = T> + ZipMap<Output = T> {}
+
+fn checked_zip_map<T, F>(a: T, b: T, mut f: F) -> Option<T>
 where
-    T: Clone,
+    T: ZipMap<Output = T>,
+    F: FnMut(&T::Item, &T::Item) -> Option<T::Item>,
 {
-    fn converged(value: Self::Item) -> Self {
-        (value.clone(), value.clone(), value)
+    let mut is_overflow = false;
+    let output = a.zip_map(b, |a, b| {
+        f(&a, &b).unwrap_or_else(|| {
+            is_overflow = true;
+            a
+        })
+    });
+    if is_overflow {
+        None
+    }
+    else {
+        Some(output)
     }
 }
 
-impl<T> Fold for (T, T) {
-    fn fold<U, F>(self, mut seed: U, mut f: F) -> U
+/// Per-component comparisons of adjuncts.
+///
+/// Comparisons produce a _mask_: an adjunct of `bool`s with the same structure
+/// as the operands. See `Mask`. This trait is implemented for all types that
+/// implement `ZipMap` into `bool`s.
+///
+/// # Examples
+///
+/// Testing if a point is within a box:
+///
+/// ```rust
+/// use theon::adjunct::{Compare, Mask};
+///
+/// let (lower, upper) = ([0.0, 0.0], [1.0, 1.0]);
+/// let point = [0.5, 2.0];
+/// assert_eq!([true, true], lower.cmp_le(point));
+/// assert!(!point.cmp_le(upper).all_true());
+/// ```
+pub trait Compare: ZipMap<bool> {
+    fn cmp_lt(self, other: Self) -> <Self as ZipMap<bool>>::Output
     where
-        F: FnMut(U, Self::Item) -> U,
+        Self::Item: PartialOrd,
     {
-        seed = f(seed, self.0);
-        seed = f(seed, self.1);
-        seed
+        self.zip_map(other, |a, b| a < b)
     }
-}
 
-impl<T> Fold for (T, T, T) {
-    fn fold<U, F>(self, mut seed: U, mut f: F) -> U
+    fn cmp_le(self, other: Self) -> <Self as ZipMap<bool>>::Output
     where
-        F: FnMut(U, Self::Item) -> U,
+        Self::Item: PartialOrd,
     {
-        seed = f(seed, self.0);
-        seed = f(seed, self.1);
-        seed = f(seed, self.2);
-        seed
+        self.zip_map(other, |a, b| a <= b)
+    }
+
+    fn cmp_gt(self, other: Self) -> <Self as ZipMap<bool>>::Output
+    where
+        Self::Item: PartialOrd,
+    {
+        self.zip_map(other, |a, b| a > b)
+    }
+
+    fn cmp_ge(self, other: Self) -> <Self as ZipMap<bool>>::Output
+    where
+        Self::Item: PartialOrd,
+    {
+        self.zip_map(other, |a, b| a >= b)
+    }
+
+    fn cmp_eq(self, other: Self) -> <Self as ZipMap<bool>>::Output
+    where
+        Self::Item: PartialEq,
+    {
+        self.zip_map(other, |a, b| a == b)
+    }
+
+    fn cmp_ne(self, other: Self) -> <Self as ZipMap<bool>>::Output
+    where
+        Self::Item: PartialEq,
+    {
+        self.zip_map(other, |a, b| a != b)
     }
 }
 
-impl<T> FromItems for (T, T) {
-    fn from_items<I>(items: I) -> Option<Self>
+impl<T> Compare for T where T: ZipMap<bool> {}
+
+/// Adjunct of `bool`s.
+///
+/// Masks are typically produced by `Compare` and select between the items of
+/// other adjuncts. This trait is implemented for all types that implement
+/// `Fold` and `IntoItems` with `bool` items.
+pub trait Mask: Fold + IntoItems<Item = bool> {
+    /// Returns `true` if all items are `true`.
+    fn all_true(self) -> bool {
+        self.all(|is| is)
+    }
+
+    /// Returns `true` if any item is `true`.
+    fn any_true(self) -> bool {
+        self.any(|is| is)
+    }
+
+    /// Selects the items of `a` where the mask is `true` and the items of `b`
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mask has fewer items than `a` and `b`.
+    fn select<T>(self, a: T, b: T) -> T
     where
-        I: IntoIterator<Item = Self::Item>,
+        T: FromItems + IntoItems,
     {
-        let mut items = items.into_iter().take(2);
-        match (items.next(), items.next()) {
-            (Some(a), Some(b)) => Some((a, b)),
-            _ => None,
-        }
+        T::from_items(
+            self.into_items()
+                .into_iter()
+                .zip(a.into_items())
+                .zip(b.into_items())
+                .map(|((is, a), b)| {
+                    if is {
+                        a
+                    }
+                    else {
+                        b
+                    }
+                }),
+        )
+        .expect("inconsistent number of items")
     }
 }
 
-impl<T> FromItems for (T, T, T) {
-    fn from_items<I>(items: I) -> Option<Self>
+impl<T> Mask for T where T: Fold + IntoItems<Item = bool> {}
+
+/// Implements a provided swizzling function for `Swizzle`.
+macro_rules! swizzle {
+    ($f:ident, $n:ident, $max:ident => [$($i:literal),+] $(,)?) => {
+        fn $f<T>(self) -> T
+        where
+            Self: FiniteDimensional,
+            Self::Item: Clone,
+            Self::N: Cmp<$max, Output = Greater>,
+            T: FiniteDimensional<N = $n> + FromItems<Item = Self::Item>,
+        {
+            self.swizzle([$($i),+]).expect("inconsistent dimensions")
+        }
+    };
+}
+
+/// Swizzling of low-dimensional adjuncts.
+///
+/// Swizzling selects the items of an adjunct by index in any order and with
+/// repetition, producing another adjunct. Only the first four items of an
+/// adjunct can be selected.
+///
+/// Named functions like `xy` and `zyx` select items by their conventional
+/// names and produce an adjunct with the corresponding dimensionality. These
+/// functions require that `Self` has the named items.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::{Vector2, Vector3};
+/// use theon::adjunct::Swizzle;
+///
+/// let vector = Vector3::new(1.0, 2.0, 3.0);
+/// let reversed: Vector3<f64> = Swizzle::zyx(vector);
+/// let xz: Vector2<f64> = Swizzle::swizzle(vector, [0, 2]).unwrap();
+/// ```
+pub trait Swizzle: IntoItems {
+    /// Selects items by index.
+    ///
+    /// Returns `None` if any index is out of bounds or if the number of
+    /// indices does not match the number of items in `T`.
+    fn swizzle<T, const M: usize>(self, indices: [usize; M]) -> Option<T>
     where
-        I: IntoIterator<Item = Self::Item>,
+        Self::Item: Clone,
+        T: FromItems<Item = Self::Item>,
     {
-        let mut items = items.into_iter().take(3);
-        match (items.next(), items.next(), items.next()) {
-            (Some(a), Some(b), Some(c)) => Some((a, b, c)),
-            _ => None,
+        let items: ArrayVec<[Self::Item; 4]> = self.into_items().into_iter().take(4).collect();
+        if indices.iter().any(|index| *index >= items.len()) {
+            return None;
+        }
+        let mut selected = indices.iter().map(|index| items[*index].clone());
+        let output = T::from_items(selected.by_ref())?;
+        if selected.next().is_none() {
+            Some(output)
+        }
+        else {
+            None
         }
     }
+
+    swizzle!(xy, U2, U1 => [0, 1]);
+    swizzle!(xz, U2, U2 => [0, 2]);
+    swizzle!(yx, U2, U1 => [1, 0]);
+    swizzle!(yz, U2, U2 => [1, 2]);
+    swizzle!(zx, U2, U2 => [2, 0]);
+    swizzle!(zy, U2, U2 => [2, 1]);
+    swizzle!(xyz, U3, U2 => [0, 1, 2]);
+    swizzle!(xzy, U3, U2 => [0, 2, 1]);
+    swizzle!(yxz, U3, U2 => [1, 0, 2]);
+    swizzle!(yzx, U3, U2 => [1, 2, 0]);
+    swizzle!(zxy, U3, U2 => [2, 0, 1]);
+    swizzle!(zyx, U3, U2 => [2, 1, 0]);
 }
 
-impl<T> IntoItems for (T, T) {
-    type Output = ArrayVec<[T; 2]>;
+impl<T> Swizzle for T where T: IntoItems {}
 
-    fn into_items(self) -> Self::Output {
-        ArrayVec::from([self.0, self.1])
-    }
+/// Implements adjunct traits for a homogeneous tuple.
+macro_rules! impl_tuple_adjunct {
+    ($d:literal, ($($t:ident),+), ($($i:tt),+) $(,)?) => {
+        impl<T> Adjunct for ($($t),+) {
+            type Item = T;
+        }
+
+        impl<T> Converged for ($($t),+)
+        where
+            T: Clone,
+        {
+            fn converged(value: Self::Item) -> Self {
+                ($(impl_tuple_adjunct!(@replace $i, value.clone())),+)
+            }
+        }
+
+        impl<T> Fold for ($($t),+) {
+            fn fold<U, F>(self, mut seed: U, mut f: F) -> U
+            where
+                F: FnMut(U, Self::Item) -> U,
+            {
+                $(seed = f(seed, self.$i);)+
+                seed
+            }
+        }
+
+        impl<T> FoldRef for ($($t),+) {
+            fn fold_ref<U, F>(&self, mut seed: U, mut f: F) -> U
+            where
+                F: FnMut(U, &Self::Item) -> U,
+            {
+                $(seed = f(seed, &self.$i);)+
+                seed
+            }
+        }
+
+        impl<T> FromItems for ($($t),+) {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                let mut items = items.into_iter().take($d);
+                Some(($(impl_tuple_adjunct!(@replace $i, items.next()?)),+))
+            }
+        }
+
+        impl<T> IntoItems for ($($t),+) {
+            type Output = ArrayVec<[T; $d]>;
+
+            fn into_items(self) -> Self::Output {
+                ArrayVec::from([$(self.$i),+])
+            }
+        }
+
+        impl<T> IterItems for ($($t),+) {
+            type Iter<'a> = array::IntoIter<&'a T, $d>
+            where
+                T: 'a;
+
+            fn iter_items(&self) -> Self::Iter<'_> {
+                IntoIterator::into_iter([$(&self.$i),+])
+            }
+        }
+
+        impl<T, U> Map<U> for ($($t),+) {
+            type Output = ($(impl_tuple_adjunct!(@replace $t, U)),+);
+
+            fn map<F>(self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> U,
+            {
+                ($(f(self.$i)),+)
+            }
+        }
+
+        impl<T> MapAssign for ($($t),+) {
+            fn map_assign<F>(&mut self, mut f: F)
+            where
+                F: FnMut(&mut Self::Item),
+            {
+                $(f(&mut self.$i);)+
+            }
+        }
+
+        impl<T, U> MapRef<U> for ($($t),+) {
+            type Output = ($(impl_tuple_adjunct!(@replace $t, U)),+);
+
+            fn map_ref<F>(&self, mut f: F) -> Self::Output
+            where
+                F: FnMut(&Self::Item) -> U,
+            {
+                ($(f(&self.$i)),+)
+            }
+        }
+
+        impl<T, U> ZipMap<U> for ($($t),+) {
+            type Output = ($(impl_tuple_adjunct!(@replace $t, U)),+);
+
+            fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> U,
+            {
+                ($(f(self.$i, other.$i)),+)
+            }
+        }
+
+        impl<T, U> ZipMap3<U> for ($($t),+) {
+            type Output = ($(impl_tuple_adjunct!(@replace $t, U)),+);
+
+            fn zip_map3<F>(self, b: Self, c: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item, Self::Item) -> U,
+            {
+                ($(f(self.$i, b.$i, c.$i)),+)
+            }
+        }
+    };
+    (@replace $_x:tt, $($with:tt)+) => {
+        $($with)+
+    };
 }
+impl_tuple_adjunct!(2, (T, T), (0, 1));
+impl_tuple_adjunct!(3, (T, T, T), (0, 1, 2));
+impl_tuple_adjunct!(4, (T, T, T, T), (0, 1, 2, 3));
 
-impl<T> IntoItems for (T, T, T) {
-    type Output = ArrayVec<[T; 3]>;
+/// Implements `Extend` and `Truncate` between a homogeneous tuple and a
+/// homogeneous tuple with one additional item.
+macro_rules! impl_tuple_extend_truncate {
+    (($($t:ident),+), ($($i:tt),+), $n:tt $(,)?) => {
+        impl<T> Extend<($($t),+, T)> for ($($t),+) {
+            fn extend(self, item: Self::Item) -> ($($t),+, T) {
+                ($(self.$i),+, item)
+            }
+        }
 
-    fn into_items(self) -> Self::Output {
-        ArrayVec::from([self.0, self.1, self.2])
+        impl<T> Truncate<($($t),+)> for ($($t),+, T) {
+            fn truncate(self) -> (($($t),+), Self::Item) {
+                (($(self.$i),+), self.$n)
+            }
+        }
+    };
+}
+impl_tuple_extend_truncate!((T, T), (0, 1), 2);
+impl_tuple_extend_truncate!((T, T, T), (0, 1, 2), 3);
+
+impl<T, const N: usize> Adjunct for [T; N] {
+    type Item = T;
+}
+
+impl<T, const N: usize> AsItems for [T; N] {
+    fn as_items(&self) -> &[Self::Item] {
+        self.as_slice()
     }
 }
 
-impl<T, U> Map<U> for (T, T) {
-    type Output = (U, U);
+impl<T, const N: usize> Converged for [T; N]
+where
+    T: Clone,
+{
+    fn converged(value: Self::Item) -> Self {
+        array::from_fn(|_| value.clone())
+    }
+}
 
-    fn map<F>(self, mut f: F) -> Self::Output
+impl<T, const N: usize> Fold for [T; N] {
+    fn fold<U, F>(self, seed: U, f: F) -> U
     where
-        F: FnMut(Self::Item) -> U,
+        F: FnMut(U, Self::Item) -> U,
     {
-        (f(self.0), f(self.1))
+        IntoIterator::into_iter(self).fold(seed, f)
     }
 }
 
-impl<T, U> Map<U> for (T, T, T) {
-    type Output = (U, U, U);
+impl<T, const N: usize> FoldRef for [T; N] {
+    fn fold_ref<U, F>(&self, seed: U, f: F) -> U
+    where
+        F: FnMut(U, &Self::Item) -> U,
+    {
+        self.iter().fold(seed, f)
+    }
+}
 
-    fn map<F>(self, mut f: F) -> Self::Output
+impl<T, const N: usize> FromItems for [T; N] {
+    fn from_items<I>(items: I) -> Option<Self>
     where
-        F: FnMut(Self::Item) -> U,
+        I: IntoIterator<Item = Self::Item>,
     {
-        (f(self.0), f(self.1), f(self.2))
+        let mut items = items.into_iter();
+        let items: [Option<T>; N] = array::from_fn(|_| items.next());
+        if items.iter().all(Option::is_some) {
+            Some(items.map(Option::unwrap))
+        }
+        else {
+            None
+        }
     }
 }
 
-impl<T> Truncate<(T, T)> for (T, T, T) {
-    fn truncate(self) -> ((T, T), Self::Item) {
-        let (a, b, c) = self;
-        ((a, b), c)
+impl<T, const N: usize> IntoItems for [T; N] {
+    type Output = Self;
+
+    fn into_items(self) -> Self::Output {
+        self
     }
 }
 
-impl<T> Extend<(T, T, T)> for (T, T) {
-    fn extend(self, item: Self::Item) -> (T, T, T) {
-        let (a, b) = self;
-        (a, b, item)
+impl<T, const N: usize> IterItems for [T; N] {
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn iter_items(&self) -> Self::Iter<'_> {
+        self.iter()
     }
 }
 
-impl<T> Adjunct for (T, T) {
-    type Item = T;
+impl<T, U, const N: usize> Map<U> for [T; N] {
+    type Output = [U; N];
+
+    fn map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        <[T; N]>::map(self, f)
+    }
 }
 
-impl<T> Adjunct for (T, T, T) {
-    type Item = T;
+impl<T, const N: usize> MapAssign for [T; N] {
+    fn map_assign<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Self::Item),
+    {
+        self.iter_mut().for_each(f)
+    }
 }
 
-impl<T, U> ZipMap<U> for (T, T) {
-    type Output = (U, U);
+impl<T, U, const N: usize> MapRef<U> for [T; N] {
+    type Output = [U; N];
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    fn map_ref<F>(&self, mut f: F) -> Self::Output
     where
-        F: FnMut(Self::Item, Self::Item) -> U,
+        F: FnMut(&Self::Item) -> U,
     {
-        (f(self.0, other.0), f(self.1, other.1))
+        array::from_fn(|i| f(&self[i]))
     }
 }
 
-impl<T, U> ZipMap<U> for (T, T, T) {
-    type Output = (U, U, U);
+impl<T, U, const N: usize> ZipMap<U> for [T; N] {
+    type Output = [U; N];
 
     fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
     where
         F: FnMut(Self::Item, Self::Item) -> U,
     {
-        (f(self.0, other.0), f(self.1, other.1), f(self.2, other.2))
+        let mut items = IntoIterator::into_iter(other);
+        <[T; N]>::map(self, |a| f(a, items.next().unwrap()))
+    }
+}
+
+impl<T, U, const N: usize> ZipMap3<U> for [T; N] {
+    type Output = [U; N];
+
+    fn zip_map3<F>(self, b: Self, c: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item, Self::Item) -> U,
+    {
+        let mut items = IntoIterator::into_iter(b).zip(c);
+        <[T; N]>::map(self, |a| {
+            let (b, c) = items.next().unwrap();
+            f(a, b, c)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::adjunct::{
+        AsItems, Cast, Compare, Fold, FoldRef, FromFn, FromItems, IterItems, Map, MapAssign,
+        MapRef, MapWithIndex, Mask, PerComponent, Swizzle, TryFromItems, TryMap, ZipMap, ZipMap3,
+        ZipWith,
+    };
+
+    #[test]
+    fn array_map_zip_map() {
+        assert_eq!([2, 4, 6], Map::map([1, 2, 3], |x| x * 2));
+        assert_eq!(["1", "2"], Map::map([1, 2], |x: i32| x.to_string()));
+        assert_eq!([5, 7, 9], [1, 2, 3].zip_map([4, 5, 6], |a, b| a + b));
+        assert_eq!([4, 10, 18], [1, 2, 3].per_item_product([4, 5, 6]));
+    }
+
+    #[test]
+    fn array_fold() {
+        assert_eq!(6, [1, 2, 3].fold(0, |sum, x| sum + x));
+        assert_eq!(
+            String::from("abc"),
+            ["a", "b", "c"].fold(String::new(), |mut text, x| {
+                text.push_str(x);
+                text
+            }),
+        );
+        assert_eq!(6, [1, 2, 3].sum());
+        assert_eq!(24, [2, 3, 4].product());
+    }
+
+    #[test]
+    fn array_from_items() {
+        assert_eq!(Some([1, 2, 3]), <[i32; 3]>::from_items(vec![1, 2, 3]));
+        // Surplus items are ignored.
+        assert_eq!(Some([1, 2]), <[i32; 2]>::from_items(vec![1, 2, 3]));
+        assert_eq!(None, <[i32; 3]>::from_items(vec![1, 2]));
+    }
+
+    #[test]
+    fn per_component_min_max_clamp() {
+        let a = [1.0, -2.0, 3.0];
+        let b = [0.0, 2.0, 3.0];
+        assert_eq!([0.0, -2.0, 3.0], a.per_component_min(b));
+        assert_eq!([1.0, 2.0, 3.0], a.per_component_max(b));
+        assert_eq!(
+            [0.5, -1.0, 2.0],
+            a.per_component_clamp([-1.0, -1.0, -1.0], [0.5, 1.0, 2.0]),
+        );
+        assert!([f64::NAN].per_component_max([1.0])[0].is_nan());
+        assert_eq!([1.0], [1.0].per_component_max([f64::NAN]));
+    }
+
+    #[test]
+    fn per_component_abs_signum() {
+        let a = [1.0, -2.0, 0.0];
+        assert_eq!([1.0, 2.0, 0.0], a.per_component_abs());
+        assert_eq!([1, -1, 0], [3, -2, 0].per_component_signum());
+    }
+
+    #[test]
+    fn checked_saturating_wrapping() {
+        let a = [i32::MAX, 1];
+        let b = [1, 1];
+        assert_eq!(None, a.checked_add(b));
+        assert_eq!(Some([i32::MAX, 2]), [i32::MAX - 1, 1].checked_add(b));
+        assert_eq!(Some([i32::MAX - 1, 0]), a.checked_sub(b));
+        assert_eq!([i32::MAX, 2], a.saturating_add(b));
+        assert_eq!([0u8, 0], [1u8, 0].saturating_sub([2, 1]));
+        assert_eq!([i32::MIN, 2], a.wrapping_add(b));
+        assert_eq!([255u8, 0], [0u8, 1].wrapping_sub([1, 1]));
+        assert_eq!([0u8, 4], [128u8, 2].wrapping_mul([2, 2]));
+        assert_eq!(None, [128u8, 2].checked_mul([2, 2]));
+    }
+
+    #[test]
+    fn iter_as_items() {
+        let items = [String::from("a"), String::from("b")];
+        assert_eq!(["a", "b"], items.as_items());
+        assert_eq!(
+            vec!["a", "b"],
+            items.iter_items().map(String::as_str).collect::<Vec<_>>(),
+        );
+        let items = (1, 2, 3);
+        assert_eq!(6, items.iter_items().sum::<i32>());
+        assert_eq!(Some(&1), items.iter_items().next());
+    }
+
+    #[test]
+    fn map_with_index() {
+        assert_eq!(
+            [0, 2, 6],
+            [1, 2, 3].map_with_index(|index, item| index * item)
+        );
+        assert_eq!(
+            (1.0, 0.5),
+            (2.0, 2.0).map_with_index(|index, item: f64| item.min(1.0 / ((index + 1) as f64))),
+        );
+    }
+
+    #[test]
+    fn zip_map3() {
+        let clamp = |x: f64, min: f64, max: f64| x.max(min).min(max);
+        assert_eq!(
+            [0.0, 0.5, 1.0],
+            [-1.0, 0.5, 2.0].zip_map3([0.0; 3], [1.0; 3], clamp)
+        );
+        assert_eq!((7, 10), (1, 2).per_item_mul_add((3, 4), (4, 2)));
+    }
+
+    #[test]
+    fn min_max_component() {
+        assert_eq!(Some(-2.0), [1.0, -2.0, 3.0].min_component());
+        assert_eq!(Some(3.0), (1.0, -2.0, 3.0).max_component());
+        assert!([1.0, f64::NAN].max_component().unwrap().is_nan());
+        assert_eq!(Some(1), [1.0, -2.0, -2.0].min_component_index());
+        assert_eq!(Some(2), [1.0, -2.0, 3.0, 3.0].max_component_index());
+        assert_eq!(None, [1.0, f64::NAN, 3.0].max_component_index());
+    }
+
+    #[test]
+    fn from_fn() {
+        assert_eq!(
+            (0, 1, 4),
+            <(usize, usize, usize)>::from_fn(|index| index * index)
+        );
+        assert_eq!((1, 2), <(usize, usize)>::from_fn(|index| index + 1));
+    }
+
+    #[test]
+    fn map_assign() {
+        let mut items = (1, 2, 3);
+        items.map_assign(|item| *item += 1);
+        assert_eq!((2, 3, 4), items);
+        items.zip_map_assign((1, 1, 1), |a, b| *a -= b);
+        assert_eq!((1, 2, 3), items);
+    }
+
+    #[test]
+    fn map_fold_ref() {
+        // `String` is not `Copy` and the items are not moved.
+        let items = [String::from("a"), String::from("bc")];
+        assert_eq!([1, 2], items.map_ref(String::len));
+        assert_eq!(3, items.fold_ref(0, |n, item| n + item.len()));
+        let items = (String::from("a"), String::from("bc"));
+        assert_eq!((1, 2), items.map_ref(String::len));
+        assert_eq!(
+            "abc",
+            items.fold_ref(String::new(), |text, item| text + item)
+        );
+        assert_eq!("a", items.0);
+    }
+
+    #[test]
+    fn swizzle() {
+        let items = [1, 2, 3];
+        assert_eq!(Some([3, 3, 1, 2]), items.swizzle([2, 2, 0, 1]));
+        assert_eq!(None, items.swizzle::<[i32; 2], 2>([0, 3]));
+        assert_eq!(None, items.swizzle::<[i32; 2], 3>([0, 1, 2]));
+        assert_eq!((3, 2), items.zy::<(i32, i32)>());
+        assert_eq!([3, 2, 1], items.zyx::<[i32; 3]>());
+        assert_eq!([2, 1], (1, 2).yx::<[i32; 2]>());
+    }
+
+    #[test]
+    fn try_from_items() {
+        assert_eq!(Some((1, 2, 3)), TryFromItems::try_from_items(vec![1, 2, 3]));
+        assert_eq!(None, <(i32, i32)>::try_from_items(vec![1, 2, 3]));
+        assert_eq!(None, <(i32, i32)>::try_from_items(vec![1]));
+        // `FromItems` ignores additional items.
+        assert_eq!(Some((1, 2)), FromItems::from_items(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn try_map() {
+        let mut n = 0;
+        let result = TryMap::try_map([1, -1, 2], |item: i32| {
+            n += 1;
+            u32::try_from(item)
+        });
+        assert!(result.is_err());
+        assert_eq!(2, n);
+        assert_eq!(
+            Ok((2u32, 4u32)),
+            (1, 2).try_map(|item: u32| item.checked_mul(2).ok_or(()))
+        );
+    }
+
+    #[test]
+    fn cast() {
+        assert_eq!(Some([1.0f32, 0.5]), Cast::cast::<f32>([1.0f64, 0.5]));
+        assert_eq!(Some((1u8, 2u8)), (1i64, 2i64).cast::<u8>());
+        assert_eq!(None, (1i64, -2i64).cast::<u8>());
+        assert_eq!(None, Cast::cast::<i32>([1.0, f64::NAN]));
+    }
+
+    #[test]
+    fn compare_mask() {
+        let (a, b) = ([1.0, 2.0, f64::NAN], [2.0, 2.0, 0.0]);
+        assert_eq!([true, false, false], a.cmp_lt(b));
+        assert_eq!([true, true, false], a.cmp_le(b));
+        assert_eq!([false, true, false], a.cmp_eq(b));
+        assert_eq!([true, false, true], a.cmp_ne(b));
+        assert!(a.cmp_ne(b).any_true());
+        assert!(!a.cmp_ne(b).all_true());
+        assert_eq!((1, 2), (1, 3).cmp_lt((2, 2)).select((1, 3), (2, 2)));
+    }
+
+    #[test]
+    fn zip_with() {
+        let indices = (2usize, 0usize, 1usize);
+        let items = [10, 20, 30];
+        assert_eq!(
+            [30, 10, 20],
+            items.zip_with(indices, |_, index| items[index])
+        );
+        assert_eq!(
+            (1.5f32, 2.0f32),
+            (1.0f32, 2.0f32).zip_with([0.5f64, 0.0f64], |a, b| a + (b as f32)),
+        );
     }
 }
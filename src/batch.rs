@@ -0,0 +1,325 @@
+//! Structure-of-arrays batches of points and vectors.
+//!
+//! This module provides `Batch`, which stores many points or vectors in
+//! structure-of-arrays order: each component (axis) is stored in its own
+//! contiguous buffer. Bulk operations like `map_components`, `dot`, and `aabb`
+//! are computed by loops over these buffers, which the compiler can vectorize
+//! for primitive scalars. Elements can be pushed and read as ordinary points
+//! and vectors, and `Batch` implements adjunct traits like `Map`, `ZipMap`,
+//! and `Fold` with its elements as items.
+//!
+//! This module requires the `std` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::batch::Batch;
+//!
+//! let batch: Batch<Point2<f64>> = vec![Point2::new(1.0, -1.0), Point2::new(-2.0, 3.0)]
+//!     .into_iter()
+//!     .collect();
+//! let aabb = batch.aabb().unwrap();
+//! assert_eq!(Point2::new(-2.0, -1.0), aabb.origin);
+//! ```
+
+#![cfg(feature = "std")]
+
+use decorum::cmp::{self, IntrinsicOrd};
+use num::{Num, Zero};
+use std::iter::FromIterator;
+
+use crate::adjunct::{Adjunct, Fold, FromItems, IntoItems, Map, ZipMap};
+use crate::query::Aabb;
+use crate::space::{EuclideanSpace, FiniteDimensional, Scalar};
+
+/// Batch of points or vectors in structure-of-arrays order.
+///
+/// See the module documentation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Batch<S>
+where
+    S: Adjunct,
+{
+    components: Vec<Vec<S::Item>>,
+}
+
+impl<S> Batch<S>
+where
+    S: Adjunct + FiniteDimensional,
+{
+    pub fn new() -> Self {
+        Batch {
+            components: (0..S::dimensions()).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Batch {
+            components: (0..S::dimensions())
+                .map(|_| Vec::with_capacity(capacity))
+                .collect(),
+        }
+    }
+
+    /// Gets the number of elements in the batch.
+    pub fn len(&self) -> usize {
+        self.components[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the contiguous buffer of a component of all elements.
+    ///
+    /// Returns `None` if the axis is out of bounds.
+    pub fn component(&self, axis: usize) -> Option<&[S::Item]> {
+        self.components.get(axis).map(Vec::as_slice)
+    }
+
+    /// Gets the mutable contiguous buffer of a component of all elements.
+    ///
+    /// Returns `None` if the axis is out of bounds.
+    pub fn component_mut(&mut self, axis: usize) -> Option<&mut [S::Item]> {
+        self.components.get_mut(axis).map(Vec::as_mut_slice)
+    }
+
+    pub fn push(&mut self, element: S)
+    where
+        S: IntoItems,
+    {
+        for (component, item) in self.components.iter_mut().zip(element.into_items()) {
+            component.push(item);
+        }
+    }
+
+    /// Gets the element at the given index.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<S>
+    where
+        S: FromItems,
+        S::Item: Copy,
+    {
+        if index < self.len() {
+            S::from_items(self.components.iter().map(|component| component[index]))
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = S>
+    where
+        S: FromItems,
+        S::Item: Copy,
+    {
+        (0..self.len()).map(move |index| self.get(index).unwrap())
+    }
+
+    /// Maps the components of all elements in place.
+    pub fn map_components<F>(&mut self, mut f: F)
+    where
+        S::Item: Copy,
+        F: FnMut(S::Item) -> S::Item,
+    {
+        for component in self.components.iter_mut() {
+            for item in component.iter_mut() {
+                *item = f(*item);
+            }
+        }
+    }
+
+    /// Computes the dot product of each pair of elements in two batches.
+    ///
+    /// Returns `None` if the batches have different lengths.
+    pub fn dot(&self, other: &Self) -> Option<Vec<S::Item>>
+    where
+        S::Item: Copy + Num,
+    {
+        if self.len() != other.len() {
+            return None;
+        }
+        let mut products = vec![Zero::zero(); self.len()];
+        for (a, b) in self.components.iter().zip(other.components.iter()) {
+            for ((product, a), b) in products.iter_mut().zip(a.iter()).zip(b.iter()) {
+                *product = *product + (*a * *b);
+            }
+        }
+        Some(products)
+    }
+}
+
+impl<S> Batch<S>
+where
+    S: Adjunct<Item = Scalar<S>> + EuclideanSpace + FiniteDimensional + FromItems,
+    Scalar<S>: IntrinsicOrd,
+{
+    /// Gets the axis-aligned bounding box of the points in the batch.
+    ///
+    /// Returns `None` if the batch is empty. See `Aabb::from_points`.
+    pub fn aabb(&self) -> Option<Aabb<S>> {
+        if self.is_empty() {
+            return None;
+        }
+        let bound = |f: fn(Scalar<S>, Scalar<S>) -> Scalar<S>| {
+            S::from_items(self.components.iter().map(|component| {
+                component[1..]
+                    .iter()
+                    .fold(component[0], |bound, item| f(bound, *item))
+            }))
+        };
+        let lower = bound(cmp::min_or_undefined)?;
+        let upper = bound(cmp::max_or_undefined)?;
+        Some(Aabb {
+            origin: lower,
+            extent: upper - lower,
+        })
+    }
+}
+
+impl<S> Adjunct for Batch<S>
+where
+    S: Adjunct,
+{
+    type Item = S;
+}
+
+impl<S> Default for Batch<S>
+where
+    S: Adjunct + FiniteDimensional,
+{
+    fn default() -> Self {
+        Batch::new()
+    }
+}
+
+impl<S> Extend<S> for Batch<S>
+where
+    S: FiniteDimensional + IntoItems,
+{
+    fn extend<I>(&mut self, elements: I)
+    where
+        I: IntoIterator<Item = S>,
+    {
+        for element in elements {
+            self.push(element);
+        }
+    }
+}
+
+impl<S> Fold for Batch<S>
+where
+    S: FiniteDimensional + FromItems,
+    S::Item: Copy,
+{
+    fn fold<T, F>(self, seed: T, f: F) -> T
+    where
+        F: FnMut(T, Self::Item) -> T,
+    {
+        self.iter().fold(seed, f)
+    }
+}
+
+impl<S> FromIterator<S> for Batch<S>
+where
+    S: FiniteDimensional + IntoItems,
+{
+    fn from_iter<I>(elements: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut batch = Batch::new();
+        batch.extend(elements);
+        batch
+    }
+}
+
+impl<S, T> Map<T> for Batch<S>
+where
+    S: FiniteDimensional + FromItems,
+    S::Item: Copy,
+    T: FiniteDimensional + IntoItems,
+{
+    type Output = Batch<T>;
+
+    fn map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> T,
+    {
+        self.iter().map(f).collect()
+    }
+}
+
+/// Maps pairs of elements in two batches.
+///
+/// If the batches have different lengths, then the output has the length of
+/// the shorter batch.
+impl<S, T> ZipMap<T> for Batch<S>
+where
+    S: FiniteDimensional + FromItems,
+    S::Item: Copy,
+    T: FiniteDimensional + IntoItems,
+{
+    type Output = Batch<T>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> T,
+    {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+
+    use crate::adjunct::{Fold, Map, ZipMap};
+    use crate::batch::Batch;
+    use crate::query::Aabb;
+
+    type E3 = Point3<f64>;
+
+    fn points() -> Vec<E3> {
+        vec![
+            E3::new(1.0, 2.0, 3.0),
+            E3::new(-1.0, 0.5, 4.0),
+            E3::new(2.0, -3.0, 0.0),
+        ]
+    }
+
+    #[test]
+    fn batch_elements_e3() {
+        let batch: Batch<E3> = points().into_iter().collect();
+        assert_eq!(3, batch.len());
+        assert_eq!(points(), batch.iter().collect::<Vec<_>>());
+        assert_eq!(Some(&[2.0, 0.5, -3.0][..]), batch.component(1));
+        assert!(batch.get(3).is_none());
+        assert_eq!(Aabb::from_points(points()), batch.aabb());
+        assert!(Batch::<E3>::new().aabb().is_none());
+    }
+
+    #[test]
+    fn batch_bulk_ops_e3() {
+        let a: Batch<Vector3<f64>> = points().into_iter().map(|point| point.coords).collect();
+        let b = a.clone().map(|vector| vector * 2.0);
+        assert_eq!(Some(vec![28.0, 34.5, 26.0]), a.dot(&b));
+        let mut c = a.clone();
+        c.map_components(|x| x * 2.0);
+        assert_eq!(b, c);
+        assert_eq!(
+            Vector3::new(2.0, -0.5, 7.0),
+            a.clone().fold(Vector3::zeros(), |sum, vector| sum + vector)
+        );
+        let d = a.clone().zip_map(b, |a, b| b - a);
+        assert_eq!(a, d);
+    }
+}
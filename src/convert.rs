@@ -0,0 +1,79 @@
+//! Conversions between spaces of integrated crates.
+//!
+//! This module provides traits for converting between types that represent
+//! the same space, such as `Point3` from `cgmath` and `Point3` from
+//! `nalgebra`. Conversions are built on adjunct traits and require that the
+//! dimensions and scalars of both types are the same, so they are lossless.
+
+use crate::adjunct::{FromItems, IntoItems};
+use crate::space::{FiniteDimensional, Matrix};
+
+/// Conversion from a space with the same dimensions and scalar.
+///
+/// This trait is implemented for vectors and points.
+pub trait FromSpace<T>: Sized {
+    fn from_space(space: T) -> Self;
+}
+
+impl<T, U> FromSpace<U> for T
+where
+    T: FiniteDimensional + FromItems,
+    U: FiniteDimensional<N = T::N> + IntoItems<Item = T::Item>,
+{
+    fn from_space(space: U) -> Self {
+        T::from_items(space.into_items()).expect("inconsistent dimensions")
+    }
+}
+
+/// Conversion into a space with the same dimensions and scalar.
+///
+/// This trait is implemented for all types that implement `FromSpace` with
+/// their counterpart.
+pub trait IntoSpace<T>: Sized {
+    fn into_space(self) -> T;
+}
+
+impl<T, U> IntoSpace<U> for T
+where
+    U: FromSpace<T>,
+{
+    fn into_space(self) -> U {
+        U::from_space(self)
+    }
+}
+
+/// Conversion from a matrix with the same dimensions and scalar.
+///
+/// Items of matrices must be in column-major order.
+pub trait FromMatrix<T>: Sized {
+    fn from_matrix(matrix: T) -> Self;
+}
+
+impl<T, U> FromMatrix<U> for T
+where
+    T: FromItems + Matrix,
+    U: IntoItems<Item = T::Item> + Matrix<Scalar = T::Scalar>,
+    U::Row: FiniteDimensional<N = <T::Row as FiniteDimensional>::N>,
+    U::Column: FiniteDimensional<N = <T::Column as FiniteDimensional>::N>,
+{
+    fn from_matrix(matrix: U) -> Self {
+        T::from_items(matrix.into_items()).expect("inconsistent dimensions")
+    }
+}
+
+/// Conversion into a matrix with the same dimensions and scalar.
+///
+/// This trait is implemented for all types that implement `FromMatrix` with
+/// their counterpart.
+pub trait IntoMatrix<T>: Sized {
+    fn into_matrix(self) -> T;
+}
+
+impl<T, U> IntoMatrix<U> for T
+where
+    U: FromMatrix<T>,
+{
+    fn into_matrix(self) -> U {
+        U::from_matrix(self)
+    }
+}
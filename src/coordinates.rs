@@ -0,0 +1,219 @@
+//! Polar, spherical, and cylindrical coordinates.
+//!
+//! This module provides coordinate systems that can be converted to and from
+//! points in any two- or three-dimensional `EuclideanSpace`. Angles are
+//! measured in radians. Azimuths are measured counterclockwise in the $xy$
+//! plane from the $x$ axis and inclinations are measured from the $z$ axis,
+//! following ISO 80000-2. This module requires the `std` feature or, for `f32`
+//! and `f64` scalars, the `libm` feature.
+
+#![cfg(any(feature = "std", feature = "libm"))]
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use typenum::{U2, U3};
+
+use crate::ops::{Sqrt, Transcendental};
+use crate::space::{EuclideanSpace, FiniteDimensional, Vector, VectorSpace};
+
+/// Polar coordinates $(r, \theta)$ in two dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Polar<T> {
+    /// The distance from the origin.
+    pub radius: T,
+    /// The angle from the $x$ axis.
+    pub azimuth: T,
+}
+
+impl<T> Polar<T>
+where
+    T: Sqrt + Transcendental,
+{
+    pub fn from_point<S>(point: S) -> Self
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U2>,
+        Vector<S>: VectorSpace<Scalar = T>,
+    {
+        let (x, y) = point.into_coordinates().into_xy();
+        Polar {
+            radius: Sqrt::sqrt((x * x) + (y * y)),
+            azimuth: Transcendental::atan2(y, x),
+        }
+    }
+
+    pub fn into_point<S>(self) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U2>,
+        Vector<S>: VectorSpace<Scalar = T>,
+    {
+        let Polar { radius, azimuth } = self;
+        S::from_xy(
+            radius * Transcendental::cos(azimuth),
+            radius * Transcendental::sin(azimuth),
+        )
+    }
+}
+
+/// Spherical coordinates $(r, \theta, \varphi)$ in three dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Spherical<T> {
+    /// The distance from the origin.
+    pub radius: T,
+    /// The angle from the $z$ axis.
+    pub inclination: T,
+    /// The angle of the projection onto the $xy$ plane from the $x$ axis.
+    pub azimuth: T,
+}
+
+impl<T> Spherical<T>
+where
+    T: Sqrt + Transcendental,
+{
+    /// Converts a point into spherical coordinates.
+    ///
+    /// The inclination and azimuth of the origin are zero.
+    pub fn from_point<S>(point: S) -> Self
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: VectorSpace<Scalar = T>,
+    {
+        let (x, y, z) = point.into_coordinates().into_xyz();
+        let radius = Sqrt::sqrt((x * x) + (y * y) + (z * z));
+        let inclination = if radius.is_zero() {
+            T::zero()
+        }
+        else {
+            Transcendental::acos(num::clamp(z / radius, -T::one(), T::one()))
+        };
+        Spherical {
+            radius,
+            inclination,
+            azimuth: Transcendental::atan2(y, x),
+        }
+    }
+
+    pub fn into_point<S>(self) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: VectorSpace<Scalar = T>,
+    {
+        let Spherical {
+            radius,
+            inclination,
+            azimuth,
+        } = self;
+        let planar = radius * Transcendental::sin(inclination);
+        S::from_xyz(
+            planar * Transcendental::cos(azimuth),
+            planar * Transcendental::sin(azimuth),
+            radius * Transcendental::cos(inclination),
+        )
+    }
+}
+
+/// Cylindrical coordinates $(\rho, \varphi, z)$ in three dimensions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Cylindrical<T> {
+    /// The distance from the $z$ axis.
+    pub radius: T,
+    /// The angle of the projection onto the $xy$ plane from the $x$ axis.
+    pub azimuth: T,
+    /// The distance along the $z$ axis.
+    pub height: T,
+}
+
+impl<T> Cylindrical<T>
+where
+    T: Sqrt + Transcendental,
+{
+    pub fn from_point<S>(point: S) -> Self
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: VectorSpace<Scalar = T>,
+    {
+        let (x, y, z) = point.into_coordinates().into_xyz();
+        Cylindrical {
+            radius: Sqrt::sqrt((x * x) + (y * y)),
+            azimuth: Transcendental::atan2(y, x),
+            height: z,
+        }
+    }
+
+    pub fn into_point<S>(self) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<S>: VectorSpace<Scalar = T>,
+    {
+        let Cylindrical {
+            radius,
+            azimuth,
+            height,
+        } = self;
+        S::from_xyz(
+            radius * Transcendental::cos(azimuth),
+            radius * Transcendental::sin(azimuth),
+            height,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{Point2, Point3};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use crate::coordinates::{Cylindrical, Polar, Spherical};
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn cylindrical_round_trip_e3() {
+        let point = E3::new(0.0, 2.0, -1.0);
+        let cylindrical = Cylindrical::from_point(point);
+        assert_abs_diff_eq!(2.0, cylindrical.radius, epsilon = 1e-9);
+        assert_abs_diff_eq!(FRAC_PI_2, cylindrical.azimuth, epsilon = 1e-9);
+        assert_abs_diff_eq!(-1.0, cylindrical.height, epsilon = 1e-9);
+        let round = cylindrical.into_point::<E3>();
+        assert_abs_diff_eq!(
+            point.coords.as_slice(),
+            round.coords.as_slice(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn polar_round_trip_e2() {
+        let point = E2::new(1.0, 1.0);
+        let polar = Polar::from_point(point);
+        assert_abs_diff_eq!(2.0f64.sqrt(), polar.radius, epsilon = 1e-9);
+        assert_abs_diff_eq!(FRAC_PI_4, polar.azimuth, epsilon = 1e-9);
+        let round = polar.into_point::<E2>();
+        assert_abs_diff_eq!(
+            point.coords.as_slice(),
+            round.coords.as_slice(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn spherical_round_trip_e3() {
+        let point = E3::new(0.0, 1.0, 0.0);
+        let spherical = Spherical::from_point(point);
+        assert_abs_diff_eq!(1.0, spherical.radius, epsilon = 1e-9);
+        assert_abs_diff_eq!(FRAC_PI_2, spherical.inclination, epsilon = 1e-9);
+        assert_abs_diff_eq!(FRAC_PI_2, spherical.azimuth, epsilon = 1e-9);
+        let round = spherical.into_point::<E3>();
+        assert_abs_diff_eq!(
+            point.coords.as_slice(),
+            round.coords.as_slice(),
+            epsilon = 1e-9
+        );
+        let origin = Spherical::from_point(E3::origin());
+        assert_eq!(0.0, origin.inclination);
+    }
+}
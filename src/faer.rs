@@ -0,0 +1,291 @@
+//! Pure Rust non-trivial linear algebra using `faer`.
+//!
+//! This module provides the same queries and decompositions as the `lapack`
+//! module, but does not depend on a LAPACK library nor any other native
+//! toolchain. If both the `lapack` and `linalg-faer` features are enabled, then
+//! queries like `Plane::from_points` are implemented by the `lapack` module.
+
+#![cfg(feature = "linalg-faer")]
+
+use ::faer::traits::RealField;
+use ::faer::{Mat, Side};
+use approx::abs_diff_eq;
+use num::Zero;
+use typenum::type_operators::Cmp;
+use typenum::{Greater, Unsigned, U2};
+
+use crate::adjunct::{FromItems, IntoItems};
+use crate::ops::Sqrt;
+use crate::query::{self, Line, Plane, Unit};
+use crate::space::{EuclideanSpace, FiniteDimensional, Matrix, Scalar, SquareMatrix, Vector};
+use crate::Error;
+
+/// Scalar types that can be used with `faer`.
+pub trait Faer: RealField + Sqrt {}
+
+impl<T> Faer for T where T: RealField + Sqrt {}
+
+#[cfg(not(all(feature = "lapack", target_os = "linux")))]
+impl<S> Plane<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    <S as FiniteDimensional>::N: Cmp<U2, Output = Greater>,
+{
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: Faer,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]>,
+    {
+        svd_plane(points)
+    }
+
+    /// Computes a best-fit plane from a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points`.
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Faer,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]>,
+    {
+        query::try_fit_points(points.as_ref(), |points| Self::from_points(points))
+    }
+}
+
+#[cfg(not(all(feature = "lapack", target_os = "linux")))]
+impl<S> Line<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+{
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: Faer,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]>,
+    {
+        svd_line(points)
+    }
+
+    /// Computes a best-fit line from a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points`.
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Faer,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]>,
+    {
+        query::try_fit_points(points.as_ref(), |points| Self::from_points(points))
+    }
+}
+
+/// Computes the eigenvalues and eigenvectors of a symmetric matrix.
+///
+/// Unlike the implementations of `SymmetricEigen`, this function supports
+/// square matrices of any type and size. See
+/// `SymmetricEigen::symmetric_eigen`.
+pub fn symmetric_eigen<M>(matrix: &M) -> Option<(M::Column, M)>
+where
+    M: FromItems + SquareMatrix,
+    M::Column: FromItems,
+    M::Row: FiniteDimensional<N = <M::Column as FiniteDimensional>::N>,
+    M::Scalar: Faer,
+{
+    let n = M::row_count();
+    let m = Mat::from_fn(n, n, |i, j| Matrix::scalar_component(matrix, i, j).unwrap());
+    // Eigenvalues are sorted in nondecreasing order.
+    let eigen = m.self_adjoint_eigen(Side::Lower).ok()?;
+    let u = eigen.U();
+    Some((
+        M::Column::from_items(eigen.S().column_vector().iter().cloned())?,
+        M::from_items((0..n).flat_map(|j| u.col(j).iter().cloned()))?,
+    ))
+}
+
+/// Computes the singular value decomposition of a matrix.
+///
+/// Unlike the implementations of `Decompose`, this function supports square
+/// matrices of any type and size. See `Decompose::svd`.
+pub fn svd<M>(matrix: &M) -> Option<(M, M::Column, M)>
+where
+    M: FromItems + SquareMatrix,
+    M::Column: FromItems,
+    M::Row: FiniteDimensional<N = <M::Column as FiniteDimensional>::N>,
+    M::Scalar: Faer,
+{
+    let n = M::row_count();
+    let m = Mat::from_fn(n, n, |i, j| Matrix::scalar_component(matrix, i, j).unwrap());
+    // Singular values are sorted in nonincreasing order.
+    let svd = m.svd().ok()?;
+    let (u, v) = (svd.U(), svd.V());
+    Some((
+        M::from_items((0..n).flat_map(|j| u.col(j).iter().cloned()))?,
+        M::Column::from_items(svd.S().column_vector().iter().cloned())?,
+        M::from_items((0..n).flat_map(|j| v.col(j).iter().cloned()))?,
+    ))
+}
+
+/// Maps columnar data into a matrix.
+///
+/// Produces a matrix with a column for each input.
+fn map_into_matrix<I, T, U, F>(columns: I, f: F) -> Option<Mat<U::Item>>
+where
+    I: AsRef<[T]>,
+    U: FiniteDimensional + IntoItems,
+    U::Item: Faer,
+    F: Fn(&T) -> U,
+{
+    let columns = columns
+        .as_ref()
+        .iter()
+        .map(|column| f(column).into_items().into_iter().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let m = <U as FiniteDimensional>::N::USIZE;
+    if columns.iter().all(|column| column.len() == m) {
+        Some(Mat::from_fn(m, columns.len(), |i, j| columns[j][i].clone()))
+    }
+    else {
+        None
+    }
+}
+
+/// Computes a best-fit plane from a set of points.
+///
+/// The plane is fit using least squares via a singular value decomposition.
+pub(crate) fn svd_plane<S, I>(points: I) -> Option<Plane<S>>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    <S as FiniteDimensional>::N: Cmp<U2, Output = Greater>,
+    Scalar<S>: Faer,
+    Vector<S>: FromItems + IntoItems,
+    I: AsRef<[S]>,
+{
+    // Singular values are sorted in nonincreasing order, so the last column of
+    // `U` is the direction with the least variance. This is also true if there
+    // are fewer points than dimensions, because the remaining columns of the
+    // full decomposition span the null space.
+    let (centroid, normal) = svd_column(points, |n| n - 1)?;
+    Some(Plane {
+        origin: centroid,
+        normal: Unit::try_from_inner(normal)?,
+    })
+}
+
+/// Computes a best-fit line from a set of points.
+///
+/// The line is fit using total least squares via a singular value
+/// decomposition.
+pub(crate) fn svd_line<S, I>(points: I) -> Option<Line<S>>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: Faer,
+    Vector<S>: FromItems + IntoItems,
+    I: AsRef<[S]>,
+{
+    // The first column of `U` is the direction with the greatest variance.
+    let (centroid, direction) = svd_column(points, |_| 0)?;
+    Some(Line {
+        origin: centroid,
+        direction: Unit::try_from_inner(direction)?,
+    })
+}
+
+/// Computes the centroid of a set of points and a column of `U` in the
+/// singular value decomposition of the points relative to their centroid.
+///
+/// The column is selected by a function of the number of columns in `U`.
+/// Returns `None` if the greatest singular value is zero, in which case the
+/// points are coincident and no column of `U` is meaningful.
+fn svd_column<S, I, F>(points: I, f: F) -> Option<(S, Vector<S>)>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: Faer,
+    Vector<S>: FromItems + IntoItems,
+    I: AsRef<[S]>,
+    F: FnOnce(usize) -> usize,
+{
+    let points = points.as_ref();
+    let centroid = EuclideanSpace::centroid(points.iter().cloned())?;
+    let m = map_into_matrix(points, |point| *point - centroid)?;
+    let svd = m.svd().ok()?;
+    // Singular values are sorted in nonincreasing order.
+    let sigma = svd.S().column_vector().iter().next().cloned()?;
+    if abs_diff_eq!(sigma, Zero::zero()) {
+        return None;
+    }
+    let u = svd.U();
+    let column = Vector::<S>::from_items(u.col(f(u.ncols())).iter().cloned())?;
+    Some((centroid, column))
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{Matrix2, Point3};
+
+    use crate::faer;
+    use crate::space::EuclideanSpace;
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn determined_svd_plane_e3() {
+        let plane = faer::svd_plane::<E3, _>(vec![
+            EuclideanSpace::from_xyz(1.0, 0.0, 0.0),
+            EuclideanSpace::from_xyz(0.0, 1.0, 0.0),
+            EuclideanSpace::from_xyz(0.0, 0.0, 0.0),
+        ])
+        .unwrap();
+        let normal = plane.normal.get();
+        assert_abs_diff_eq!(0.0, normal.x);
+        assert_abs_diff_eq!(0.0, normal.y);
+        assert_abs_diff_eq!(1.0, normal.z.abs());
+    }
+
+    #[test]
+    fn overdetermined_svd_plane_e3() {
+        let plane = faer::svd_plane::<E3, _>(vec![
+            EuclideanSpace::from_xyz(1.0, 1.0, 0.0),
+            EuclideanSpace::from_xyz(2.0, 1.0, 0.0),
+            EuclideanSpace::from_xyz(3.0, 1.0, 0.0),
+            EuclideanSpace::from_xyz(2.0, 1.0, 0.0),
+            EuclideanSpace::from_xyz(2.0, 2.0, 0.0),
+            EuclideanSpace::from_xyz(2.0, 3.0, 0.0),
+        ])
+        .unwrap();
+        let normal = plane.normal.get();
+        assert_abs_diff_eq!(0.0, normal.x);
+        assert_abs_diff_eq!(0.0, normal.y);
+        assert_abs_diff_eq!(1.0, normal.z.abs());
+    }
+
+    #[test]
+    fn coincident_svd_line_plane_e3() {
+        let points = vec![E3::new(1.0, 2.0, 3.0), E3::new(1.0, 2.0, 3.0)];
+        assert!(faer::svd_line::<E3, _>(&points).is_none());
+        assert!(faer::svd_plane::<E3, _>(&points).is_none());
+    }
+
+    #[test]
+    fn symmetric_eigen_e2() {
+        let (eigenvalues, eigenvectors) =
+            faer::symmetric_eigen(&Matrix2::new(2.0, 1.0, 1.0, 2.0)).unwrap();
+        assert_abs_diff_eq!(1.0, eigenvalues.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(3.0, eigenvalues.y, epsilon = 1e-9);
+        let eigenvector = eigenvectors.column(1);
+        assert_abs_diff_eq!(eigenvector.x, eigenvector.y, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn svd_e2() {
+        let (u, sigma, v) = faer::svd(&Matrix2::new(3.0, 0.0, 0.0, -4.0)).unwrap();
+        assert_abs_diff_eq!(4.0, sigma.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(3.0, sigma.y, epsilon = 1e-9);
+        let m = u * Matrix2::from_diagonal(&sigma) * v.transpose();
+        assert_abs_diff_eq!(3.0, m[(0, 0)], epsilon = 1e-9);
+        assert_abs_diff_eq!(-4.0, m[(1, 1)], epsilon = 1e-9);
+    }
+}
@@ -1,20 +1,22 @@
 #![cfg(feature = "geometry-cgmath")]
+// Imports in this module (e.g., `EuclideanSpace`) shadow items of the same name
+// that are re-exported from `cgmath`. This is intentional.
+#![allow(hidden_glob_reexports)]
 
-use approx::AbsDiffEq;
 use arrayvec::ArrayVec;
-use decorum::{Real, R64};
-use num::{Num, NumCast};
+use decorum::R64;
+use num::{Num, NumCast, One, Zero};
 use typenum::consts::{U2, U3, U4};
 
 use crate::adjunct::{
     Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, Truncate, ZipMap,
 };
-use crate::ops::{Cross, Dot, Interpolate};
+use crate::ops::{Cross, Dot, Factor, Interpolate, MulMN, MulV, Outer, Real, Rotate, Sqrt, Wedge};
 use crate::space::{
-    AffineSpace, Basis, DualSpace, EuclideanSpace, FiniteDimensional, Homogeneous, InnerSpace,
-    VectorSpace,
+    self, AffineSpace, Basis, CoordinateLayout, DualSpace, EuclideanSpace, ExtendMatrix,
+    FiniteDimensional, Homogeneous, InnerSpace, Matrix, SquareMatrix, TruncateMatrix, VectorSpace,
 };
-use crate::{AsPosition, AsPositionMut};
+use crate::{AsPosition, AsPositionMut, RealScalar};
 
 #[doc(hidden)]
 pub use cgmath::*;
@@ -176,7 +178,7 @@ where
 
 impl<T> DualSpace for Vector2<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Dual = Self;
 
@@ -187,7 +189,7 @@ where
 
 impl<T> DualSpace for Vector3<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Dual = Self;
 
@@ -198,7 +200,7 @@ where
 
 impl<T> DualSpace for Vector4<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Dual = Self;
 
@@ -308,16 +310,29 @@ impl<T> FromItems for Vector3<T> {
     }
 }
 
+impl<T> FromItems for Vector4<T> {
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter().take(4);
+        match (items.next(), items.next(), items.next(), items.next()) {
+            (Some(a), Some(b), Some(c), Some(d)) => Some(Vector4::new(a, b, c, d)),
+            _ => None,
+        }
+    }
+}
+
 impl<T> Homogeneous for Vector2<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type ProjectiveSpace = Vector3<T>;
 }
 
 impl<T> Homogeneous for Vector3<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type ProjectiveSpace = Vector4<T>;
 }
@@ -328,36 +343,39 @@ impl<T> InnerSpace for Vector3<T> where T: BaseFloat + Real {}
 
 impl<T> InnerSpace for Vector4<T> where T: BaseFloat + Real {}
 
-impl<T> Interpolate for Vector2<T>
+impl<T, F> Interpolate<Self, F> for Vector2<T>
 where
-    T: Num + NumCast,
+    F: Factor,
+    T: Copy + Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        self.zip_map(other, |a, b| crate::lerp(a, b, f))
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
     }
 }
 
-impl<T> Interpolate for Vector3<T>
+impl<T, F> Interpolate<Self, F> for Vector3<T>
 where
-    T: Num + NumCast,
+    F: Factor,
+    T: Copy + Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        self.zip_map(other, |a, b| crate::lerp(a, b, f))
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
     }
 }
 
-impl<T> Interpolate for Vector4<T>
+impl<T, F> Interpolate<Self, F> for Vector4<T>
 where
-    T: Num + NumCast,
+    F: Factor,
+    T: Copy + Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        self.zip_map(other, |a, b| crate::lerp(a, b, f))
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
     }
 }
 
@@ -377,6 +395,14 @@ impl<T> IntoItems for Vector3<T> {
     }
 }
 
+impl<T> IntoItems for Vector4<T> {
+    type Output = ArrayVec<[T; 4]>;
+
+    fn into_items(self) -> Self::Output {
+        ArrayVec::from([self.x, self.y, self.z, self.w])
+    }
+}
+
 impl<T, U> Map<U> for Vector2<T> {
     type Output = Vector2<U>;
 
@@ -430,7 +456,7 @@ where
 
 impl<T> VectorSpace for Vector2<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Scalar = T;
 
@@ -449,7 +475,7 @@ where
 
 impl<T> VectorSpace for Vector3<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Scalar = T;
 
@@ -469,7 +495,7 @@ where
 
 impl<T> VectorSpace for Vector4<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Scalar = T;
 
@@ -484,6 +510,28 @@ where
     }
 }
 
+impl<T> Wedge for Vector2<T>
+where
+    T: BaseFloat,
+{
+    type Output = T;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        self.perp_dot(other)
+    }
+}
+
+impl<T> Wedge for Vector3<T>
+where
+    T: BaseFloat,
+{
+    type Output = Self;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(self, other)
+    }
+}
+
 impl<T, U> ZipMap<U> for Vector2<T> {
     type Output = Vector2<U>;
 
@@ -532,14 +580,14 @@ impl<T> Adjunct for Point3<T> {
 
 impl<T> AffineSpace for Point2<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Translation = Vector2<T>;
 }
 
 impl<T> AffineSpace for Point3<T>
 where
-    T: AbsDiffEq + BaseNum + Real,
+    T: BaseNum + RealScalar,
 {
     type Translation = Vector3<T>;
 }
@@ -613,6 +661,11 @@ impl<T> Extend<Point3<T>> for Point2<T> {
     }
 }
 
+// Points and vectors are `repr(C)` structures with the same fields.
+unsafe impl<T> CoordinateLayout for Point2<T> where Self: EuclideanSpace {}
+
+unsafe impl<T> CoordinateLayout for Point3<T> where Self: EuclideanSpace {}
+
 impl<T> EuclideanSpace for Point2<T>
 where
     T: BaseFloat + Real,
@@ -699,14 +752,15 @@ impl<T> FromItems for Point3<T> {
     }
 }
 
-impl<T> Interpolate for Point2<T>
+impl<T, F> Interpolate<Self, F> for Point2<T>
 where
-    T: Num + NumCast,
+    F: Factor,
+    T: Copy + Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        self.zip_map(other, |a, b| crate::lerp(a, b, f))
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
     }
 }
 
@@ -726,14 +780,15 @@ impl<T> IntoItems for Point3<T> {
     }
 }
 
-impl<T> Interpolate for Point3<T>
+impl<T, F> Interpolate<Self, F> for Point3<T>
 where
-    T: Num + NumCast,
+    F: Factor,
+    T: Copy + Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        self.zip_map(other, |a, b| crate::lerp(a, b, f))
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
     }
 }
 
@@ -787,3 +842,483 @@ impl<T, U> ZipMap<U> for Point3<T> {
         Point3::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
     }
 }
+
+/// Implements adjunct, vector space, and matrix traits for a square matrix
+/// type.
+///
+/// Items of `cgmath` matrices are in column-major order.
+macro_rules! impl_square_matrix {
+    ($m:ident, $v:ident, $d:literal, { $($c:ident),+ $(,)? } $(,)?) => {
+        impl<T> Adjunct for $m<T> {
+            type Item = T;
+        }
+
+        impl<T> Converged for $m<T>
+        where
+            T: Copy,
+        {
+            fn converged(value: Self::Item) -> Self {
+                $m {
+                    $($c: Converged::converged(value),)+
+                }
+            }
+        }
+
+        impl<T> Fold for $m<T>
+        where
+            T: Copy,
+        {
+            fn fold<U, F>(self, seed: U, mut f: F) -> U
+            where
+                F: FnMut(U, Self::Item) -> U,
+            {
+                $(let seed = self.$c.fold(seed, &mut f);)+
+                seed
+            }
+        }
+
+        impl<T> FromItems for $m<T> {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                let mut items = items.into_iter();
+                Some($m {
+                    $($c: FromItems::from_items(items.by_ref())?,)+
+                })
+            }
+        }
+
+        impl<T, F> Interpolate<Self, F> for $m<T>
+        where
+            F: Factor,
+            T: Copy + Num + NumCast,
+        {
+            type Output = Self;
+
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                self.zip_map(other, crate::lerp_by(f))
+            }
+        }
+
+        impl<T> IntoItems for $m<T>
+        where
+            T: Copy,
+        {
+            type Output = ArrayVec<[T; $d]>;
+
+            fn into_items(self) -> Self::Output {
+                self.fold(ArrayVec::new(), |mut items, a| {
+                    items.push(a);
+                    items
+                })
+            }
+        }
+
+        impl<T, U> Map<U> for $m<T> {
+            type Output = $m<U>;
+
+            fn map<F>(self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> U,
+            {
+                $m {
+                    $($c: Map::map(self.$c, &mut f),)+
+                }
+            }
+        }
+
+        impl<T> Matrix for $m<T>
+        where
+            T: BaseFloat + RealScalar,
+        {
+            type Row = $v<T>;
+            type Column = $v<T>;
+            type Transpose = Self;
+
+            fn row_component(&self, index: usize) -> Option<Self::Row> {
+                Matrix::column_component(&Matrix::transpose(*self), index)
+            }
+
+            fn column_component(&self, index: usize) -> Option<Self::Column> {
+                IntoIterator::into_iter([$(self.$c),+]).nth(index)
+            }
+
+            fn transpose(self) -> Self::Transpose {
+                cgmath::Matrix::transpose(&self)
+            }
+        }
+
+        impl<T> MulMN<$m<T>> for $m<T>
+        where
+            T: BaseFloat + RealScalar,
+        {
+            type Output = $m<T>;
+
+            fn mul_mn(self, other: $m<T>) -> <Self as MulMN<$m<T>>>::Output {
+                self * other
+            }
+        }
+
+        impl<T> MulV<$v<T>> for $m<T>
+        where
+            T: BaseFloat,
+        {
+            type Output = $v<T>;
+
+            fn mul_v(self, vector: $v<T>) -> Self::Output {
+                self * vector
+            }
+        }
+
+        impl<T> Outer for $v<T>
+        where
+            T: BaseFloat + RealScalar,
+        {
+            type Output = $m<T>;
+        }
+
+        impl<T> SquareMatrix for $m<T>
+        where
+            T: BaseFloat + RealScalar,
+        {
+            fn multiplicative_identity() -> Self {
+                <$m<T> as cgmath::SquareMatrix>::identity()
+            }
+
+            fn determinant(&self) -> Self::Scalar {
+                cgmath::SquareMatrix::determinant(self)
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                cgmath::SquareMatrix::invert(self)
+            }
+        }
+
+        impl<T> VectorSpace for $m<T>
+        where
+            T: BaseFloat + RealScalar,
+        {
+            type Scalar = T;
+
+            fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+                self.into_items().get(index).copied()
+            }
+        }
+
+        impl<T, U> ZipMap<U> for $m<T> {
+            type Output = $m<U>;
+
+            fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> U,
+            {
+                $m {
+                    $($c: ZipMap::zip_map(self.$c, other.$c, &mut f),)+
+                }
+            }
+        }
+    };
+}
+impl_square_matrix!(Matrix2, Vector2, 4, { x, y });
+impl_square_matrix!(Matrix3, Vector3, 9, { x, y, z });
+impl_square_matrix!(Matrix4, Vector4, 16, { x, y, z, w });
+
+/// Implements `ExtendMatrix` and `TruncateMatrix` between a square matrix type
+/// and a square matrix type with one additional dimension.
+macro_rules! impl_extend_truncate_matrix {
+    ($lower:ident, $higher:ident, { $($c:ident),+ $(,)? } $(,)?) => {
+        impl<T> ExtendMatrix<$higher<T>> for $lower<T>
+        where
+            T: BaseFloat + RealScalar,
+        {
+            fn extend_matrix(self) -> $higher<T> {
+                $higher::from(self)
+            }
+        }
+
+        impl<T> TruncateMatrix<$lower<T>> for $higher<T>
+        where
+            T: BaseFloat + RealScalar,
+        {
+            fn truncate_matrix(self) -> $lower<T> {
+                $lower::from_cols($(self.$c.truncate()),+)
+            }
+        }
+    };
+}
+impl_extend_truncate_matrix!(Matrix2, Matrix3, { x, y });
+impl_extend_truncate_matrix!(Matrix3, Matrix4, { x, y, z });
+
+impl<T> Adjunct for Quaternion<T> {
+    type Item = T;
+}
+
+impl<T> Converged for Quaternion<T>
+where
+    T: Copy,
+{
+    fn converged(value: Self::Item) -> Self {
+        Quaternion::from_sv(value, Vector3::converged(value))
+    }
+}
+
+impl<T> Fold for Quaternion<T>
+where
+    T: Copy,
+{
+    fn fold<U, F>(self, mut seed: U, mut f: F) -> U
+    where
+        F: FnMut(U, Self::Item) -> U,
+    {
+        for a in &[self.s, self.v.x, self.v.y, self.v.z] {
+            seed = f(seed, *a);
+        }
+        seed
+    }
+}
+
+impl<T> FromItems for Quaternion<T> {
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter();
+        let s = items.next()?;
+        Vector3::from_items(items).map(|v| Quaternion::from_sv(s, v))
+    }
+}
+
+impl<T, F> Interpolate<Self, F> for Quaternion<T>
+where
+    F: Factor,
+    T: Copy + Num + NumCast,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
+    }
+}
+
+impl<T> IntoItems for Quaternion<T> {
+    type Output = ArrayVec<[T; 4]>;
+
+    fn into_items(self) -> Self::Output {
+        ArrayVec::from([self.s, self.v.x, self.v.y, self.v.z])
+    }
+}
+
+impl<T, U> Map<U> for Quaternion<T> {
+    type Output = Quaternion<U>;
+
+    fn map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        Quaternion::from_sv(f(self.s), Map::map(self.v, f))
+    }
+}
+
+impl<T, U> ZipMap<U> for Quaternion<T> {
+    type Output = Quaternion<U>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> U,
+    {
+        Quaternion::from_sv(f(self.s, other.s), ZipMap::zip_map(self.v, other.v, f))
+    }
+}
+
+// Unlike `Quaternion`, `Basis2` and `Basis3` are always rotations, so they do
+// not implement adjunct traits and are interpolated along the shortest arc.
+
+impl<T, F> Interpolate<Self, F> for Basis2<T>
+where
+    F: Factor,
+    T: BaseFloat,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        let difference = other * cgmath::Rotation::invert(&self);
+        let m = difference.as_ref();
+        let angle = Rad::atan2(m.x.y, m.x.x) * <T as NumCast>::from(f).unwrap();
+        <Basis2<T> as cgmath::Rotation2<T>>::from_angle(angle) * self
+    }
+}
+
+impl<T, F> Interpolate<Self, F> for Basis3<T>
+where
+    F: Factor,
+    T: BaseFloat,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        Quaternion::from(self)
+            .slerp(Quaternion::from(other), <T as NumCast>::from(f).unwrap())
+            .into()
+    }
+}
+
+/// Implements `Rotate` for a rotation type and the points and vectors that it
+/// rotates.
+macro_rules! impl_rotate {
+    ($r:ident => $p:ident, $v:ident $(,)?) => {
+        impl<T> Rotate<$r<T>> for $r<T>
+        where
+            T: BaseFloat,
+        {
+            type Output = Self;
+
+            fn rotate(self, rotation: $r<T>) -> Self::Output {
+                rotation * self
+            }
+        }
+
+        impl<T> Rotate<$r<T>> for $p<T>
+        where
+            T: BaseFloat,
+        {
+            type Output = Self;
+
+            fn rotate(self, rotation: $r<T>) -> Self::Output {
+                cgmath::Rotation::rotate_point(&rotation, self)
+            }
+        }
+
+        impl<T> Rotate<$r<T>> for $v<T>
+        where
+            T: BaseFloat,
+        {
+            type Output = Self;
+
+            fn rotate(self, rotation: $r<T>) -> Self::Output {
+                cgmath::Rotation::rotate_vector(&rotation, self)
+            }
+        }
+    };
+}
+impl_rotate!(Basis2 => Point2, Vector2);
+impl_rotate!(Basis3 => Point3, Vector3);
+impl_rotate!(Quaternion => Point3, Vector3);
+
+/// Implements `Rotation` for a rotation type.
+macro_rules! impl_rotation {
+    ($r:ident => $p:ident, $v:ident $(,)?) => {
+        impl<T> space::Rotation<$p<T>> for $r<T>
+        where
+            T: BaseFloat + Real,
+        {
+            fn identity() -> Self {
+                One::one()
+            }
+
+            fn rotate_point(&self, point: $p<T>) -> $p<T> {
+                cgmath::Rotation::rotate_point(self, point)
+            }
+
+            fn rotate_vector(&self, vector: $v<T>) -> $v<T> {
+                cgmath::Rotation::rotate_vector(self, vector)
+            }
+
+            fn compose(self, other: Self) -> Self {
+                other * self
+            }
+
+            fn inverse(self) -> Self {
+                cgmath::Rotation::invert(&self)
+            }
+        }
+    };
+}
+impl_rotation!(Basis2 => Point2, Vector2);
+impl_rotation!(Basis3 => Point3, Vector3);
+impl_rotation!(Quaternion => Point3, Vector3);
+
+/// Implements `Transform` for a homogeneous matrix type.
+macro_rules! impl_homogeneous_transform {
+    ($m:ident => $p:ident, $v:ident $(,)?) => {
+        impl<T> space::Transform<$p<T>> for $m<T>
+        where
+            T: BaseFloat + Real,
+        {
+            fn identity() -> Self {
+                <Self as cgmath::Transform<$p<T>>>::one()
+            }
+
+            fn transform_point(&self, point: $p<T>) -> $p<T> {
+                <Self as cgmath::Transform<$p<T>>>::transform_point(self, point)
+            }
+
+            fn transform_vector(&self, vector: $v<T>) -> $v<T> {
+                <Self as cgmath::Transform<$p<T>>>::transform_vector(self, vector)
+            }
+
+            fn compose(self, other: Self) -> Self {
+                <Self as cgmath::Transform<$p<T>>>::concat(&other, &self)
+            }
+
+            fn inverse(self) -> Option<Self> {
+                <Self as cgmath::Transform<$p<T>>>::inverse_transform(&self)
+            }
+        }
+    };
+}
+impl_homogeneous_transform!(Matrix3 => Point2, Vector2);
+impl_homogeneous_transform!(Matrix4 => Point3, Vector3);
+
+impl<T> space::Quaternion for Quaternion<T>
+where
+    T: BaseFloat + Real + Sqrt,
+{
+    type Vector = Vector3<T>;
+    type Matrix = Matrix3<T>;
+
+    fn from_axis_angle(axis: Self::Vector, angle: T) -> Option<Self> {
+        InnerSpace::normalize(axis).map(|axis| Rotation3::from_axis_angle(axis, Rad(angle)))
+    }
+
+    fn conjugate(self) -> Self {
+        Quaternion::conjugate(self)
+    }
+
+    fn normalize(self) -> Option<Self> {
+        if cgmath::InnerSpace::magnitude2(self).is_zero() {
+            None
+        }
+        else {
+            Some(cgmath::InnerSpace::normalize(self))
+        }
+    }
+
+    fn into_rotation_matrix(self) -> Self::Matrix {
+        Matrix3::from(self)
+    }
+
+    fn slerp(self, other: Self, f: R64) -> Self {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        let other = if cgmath::InnerSpace::dot(self, other) < Zero::zero() {
+            -other
+        }
+        else {
+            other
+        };
+        Quaternion::slerp(self, other, <T as NumCast>::from(f).unwrap())
+    }
+
+    fn nlerp(self, other: Self, f: R64) -> Self {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        let other = if cgmath::InnerSpace::dot(self, other) < Zero::zero() {
+            -other
+        }
+        else {
+            other
+        };
+        Quaternion::nlerp(self, other, <T as NumCast>::from(f).unwrap())
+    }
+}
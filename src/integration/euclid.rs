@@ -0,0 +1,696 @@
+#![cfg(feature = "geometry-euclid")]
+
+// `num` is named via an absolute path, because `euclid` exports a module of the
+// same name.
+//
+// The unit type `U` of `euclid` types is preserved in associated types. For
+// example, the `CoordinateSpace` of `Point2D<T, U>` is `Vector2D<T, U>`.
+
+use ::num::{Num, NumCast, One, Zero};
+use arrayvec::ArrayVec;
+use decorum::cmp::IntrinsicOrd;
+use typenum::consts::{U2, U3};
+
+use crate::adjunct::{
+    Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, Truncate, ZipMap,
+};
+use crate::ops::{Cross, Dot, Factor, Interpolate, Wedge};
+use crate::query::Aabb;
+use crate::space::{
+    AffineSpace, Basis, CoordinateLayout, DualSpace, EuclideanSpace, FiniteDimensional,
+    Homogeneous, InnerSpace, VectorSpace,
+};
+use crate::{AsPosition, AsPositionMut, RealScalar};
+
+#[doc(hidden)]
+pub use euclid::*;
+
+impl<T, U> Adjunct for Vector2D<T, U> {
+    type Item = T;
+}
+
+impl<T, U> Adjunct for Vector3D<T, U> {
+    type Item = T;
+}
+
+impl<T, U> Basis for Vector2D<T, U>
+where
+    T: One + Zero,
+{
+    type Bases = ArrayVec<[Self; 2]>;
+
+    fn canonical_basis() -> Self::Bases {
+        ArrayVec::from([
+            Self::canonical_basis_component(0).unwrap(),
+            Self::canonical_basis_component(1).unwrap(),
+        ])
+    }
+
+    fn canonical_basis_component(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Vector2D::new(One::one(), Zero::zero())),
+            1 => Some(Vector2D::new(Zero::zero(), One::one())),
+            _ => None,
+        }
+    }
+}
+
+impl<T, U> Basis for Vector3D<T, U>
+where
+    T: One + Zero,
+{
+    type Bases = ArrayVec<[Self; 3]>;
+
+    fn canonical_basis() -> Self::Bases {
+        ArrayVec::from([
+            Self::canonical_basis_component(0).unwrap(),
+            Self::canonical_basis_component(1).unwrap(),
+            Self::canonical_basis_component(2).unwrap(),
+        ])
+    }
+
+    fn canonical_basis_component(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Vector3D::new(One::one(), Zero::zero(), Zero::zero())),
+            1 => Some(Vector3D::new(Zero::zero(), One::one(), Zero::zero())),
+            2 => Some(Vector3D::new(Zero::zero(), Zero::zero(), One::one())),
+            _ => None,
+        }
+    }
+}
+
+impl<T, U> Converged for Vector2D<T, U>
+where
+    T: Copy,
+{
+    fn converged(value: Self::Item) -> Self {
+        Vector2D::new(value, value)
+    }
+}
+
+impl<T, U> Converged for Vector3D<T, U>
+where
+    T: Copy,
+{
+    fn converged(value: Self::Item) -> Self {
+        Vector3D::new(value, value, value)
+    }
+}
+
+impl<T, U> Cross for Vector3D<T, U>
+where
+    T: Copy + Num,
+{
+    type Output = Self;
+
+    fn cross(self, other: Self) -> Self::Output {
+        Vector3D::cross(self, other)
+    }
+}
+
+impl<T, U> Dot for Vector2D<T, U>
+where
+    T: Copy + Num,
+{
+    type Output = T;
+
+    fn dot(self, other: Self) -> Self::Output {
+        Vector2D::dot(self, other)
+    }
+}
+
+impl<T, U> Dot for Vector3D<T, U>
+where
+    T: Copy + Num,
+{
+    type Output = T;
+
+    fn dot(self, other: Self) -> Self::Output {
+        Vector3D::dot(self, other)
+    }
+}
+
+impl<T, U> DualSpace for Vector2D<T, U>
+where
+    T: RealScalar,
+{
+    type Dual = Self;
+
+    fn transpose(self) -> Self::Dual {
+        self
+    }
+}
+
+impl<T, U> DualSpace for Vector3D<T, U>
+where
+    T: RealScalar,
+{
+    type Dual = Self;
+
+    fn transpose(self) -> Self::Dual {
+        self
+    }
+}
+
+impl<T, U> Extend<Vector3D<T, U>> for Vector2D<T, U> {
+    fn extend(self, z: T) -> Vector3D<T, U> {
+        let Vector2D { x, y, .. } = self;
+        Vector3D::new(x, y, z)
+    }
+}
+
+impl<T, U> FiniteDimensional for Vector2D<T, U> {
+    type N = U2;
+}
+
+impl<T, U> FiniteDimensional for Vector3D<T, U> {
+    type N = U3;
+}
+
+impl<T, U> Fold for Vector2D<T, U> {
+    fn fold<V, F>(self, mut seed: V, mut f: F) -> V
+    where
+        F: FnMut(V, Self::Item) -> V,
+    {
+        let Vector2D { x, y, .. } = self;
+        seed = f(seed, x);
+        seed = f(seed, y);
+        seed
+    }
+}
+
+impl<T, U> Fold for Vector3D<T, U> {
+    fn fold<V, F>(self, mut seed: V, mut f: F) -> V
+    where
+        F: FnMut(V, Self::Item) -> V,
+    {
+        let Vector3D { x, y, z, .. } = self;
+        seed = f(seed, x);
+        seed = f(seed, y);
+        seed = f(seed, z);
+        seed
+    }
+}
+
+impl<T, U> FromItems for Vector2D<T, U> {
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter().take(2);
+        match (items.next(), items.next()) {
+            (Some(a), Some(b)) => Some(Vector2D::new(a, b)),
+            _ => None,
+        }
+    }
+}
+
+impl<T, U> FromItems for Vector3D<T, U> {
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter().take(3);
+        match (items.next(), items.next(), items.next()) {
+            (Some(a), Some(b), Some(c)) => Some(Vector3D::new(a, b, c)),
+            _ => None,
+        }
+    }
+}
+
+impl<T, U> Homogeneous for Vector2D<T, U>
+where
+    T: RealScalar,
+{
+    type ProjectiveSpace = Vector3D<T, U>;
+}
+
+impl<T, U> InnerSpace for Vector2D<T, U> where T: RealScalar {}
+
+impl<T, U> InnerSpace for Vector3D<T, U> where T: RealScalar {}
+
+impl<T, U, F> Interpolate<Self, F> for Vector2D<T, U>
+where
+    F: Factor,
+    T: Copy + Num + NumCast,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
+    }
+}
+
+impl<T, U, F> Interpolate<Self, F> for Vector3D<T, U>
+where
+    F: Factor,
+    T: Copy + Num + NumCast,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
+    }
+}
+
+impl<T, U> IntoItems for Vector2D<T, U> {
+    type Output = ArrayVec<[T; 2]>;
+
+    fn into_items(self) -> Self::Output {
+        let Vector2D { x, y, .. } = self;
+        ArrayVec::from([x, y])
+    }
+}
+
+impl<T, U> IntoItems for Vector3D<T, U> {
+    type Output = ArrayVec<[T; 3]>;
+
+    fn into_items(self) -> Self::Output {
+        let Vector3D { x, y, z, .. } = self;
+        ArrayVec::from([x, y, z])
+    }
+}
+
+impl<T, U, V> Map<V> for Vector2D<T, U> {
+    type Output = Vector2D<V, U>;
+
+    fn map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> V,
+    {
+        let Vector2D { x, y, .. } = self;
+        Vector2D::new(f(x), f(y))
+    }
+}
+
+impl<T, U, V> Map<V> for Vector3D<T, U> {
+    type Output = Vector3D<V, U>;
+
+    fn map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> V,
+    {
+        let Vector3D { x, y, z, .. } = self;
+        Vector3D::new(f(x), f(y), f(z))
+    }
+}
+
+impl<T, U> Truncate<Vector2D<T, U>> for Vector3D<T, U> {
+    fn truncate(self) -> (Vector2D<T, U>, T) {
+        let Vector3D { x, y, z, .. } = self;
+        (Vector2D::new(x, y), z)
+    }
+}
+
+impl<T, U> VectorSpace for Vector2D<T, U>
+where
+    T: RealScalar,
+{
+    type Scalar = T;
+
+    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+        match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            _ => None,
+        }
+    }
+
+    fn into_xy(self) -> (Self::Scalar, Self::Scalar) {
+        (self.x, self.y)
+    }
+}
+
+impl<T, U> VectorSpace for Vector3D<T, U>
+where
+    T: RealScalar,
+{
+    type Scalar = T;
+
+    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+        match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            2 => Some(self.z),
+            _ => None,
+        }
+    }
+
+    fn into_xyz(self) -> (Self::Scalar, Self::Scalar, Self::Scalar) {
+        (self.x, self.y, self.z)
+    }
+}
+
+impl<T, U> Wedge for Vector2D<T, U>
+where
+    T: Copy + Num,
+{
+    type Output = T;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        Vector2D::cross(self, other)
+    }
+}
+
+impl<T, U> Wedge for Vector3D<T, U>
+where
+    T: Copy + Num,
+{
+    type Output = Self;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        Vector3D::cross(self, other)
+    }
+}
+
+impl<T, U, V> ZipMap<V> for Vector2D<T, U> {
+    type Output = Vector2D<V, U>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> V,
+    {
+        Vector2D::new(f(self.x, other.x), f(self.y, other.y))
+    }
+}
+
+impl<T, U, V> ZipMap<V> for Vector3D<T, U> {
+    type Output = Vector3D<V, U>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> V,
+    {
+        Vector3D::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+    }
+}
+
+impl<T, U> Adjunct for Point2D<T, U> {
+    type Item = T;
+}
+
+impl<T, U> Adjunct for Point3D<T, U> {
+    type Item = T;
+}
+
+impl<T, U> AffineSpace for Point2D<T, U>
+where
+    T: RealScalar,
+{
+    type Translation = Vector2D<T, U>;
+}
+
+impl<T, U> AffineSpace for Point3D<T, U>
+where
+    T: RealScalar,
+{
+    type Translation = Vector3D<T, U>;
+}
+
+impl<T, U> AsPosition for Point2D<T, U>
+where
+    Self: EuclideanSpace,
+{
+    type Position = Self;
+
+    fn as_position(&self) -> &Self::Position {
+        self
+    }
+}
+
+impl<T, U> AsPosition for Point3D<T, U>
+where
+    Self: EuclideanSpace,
+{
+    type Position = Self;
+
+    fn as_position(&self) -> &Self::Position {
+        self
+    }
+}
+
+impl<T, U> AsPositionMut for Point2D<T, U>
+where
+    Self: EuclideanSpace,
+{
+    fn as_position_mut(&mut self) -> &mut Self::Position {
+        self
+    }
+}
+
+impl<T, U> AsPositionMut for Point3D<T, U>
+where
+    Self: EuclideanSpace,
+{
+    fn as_position_mut(&mut self) -> &mut Self::Position {
+        self
+    }
+}
+
+impl<T, U> Converged for Point2D<T, U>
+where
+    T: Copy,
+{
+    fn converged(value: Self::Item) -> Self {
+        Point2D::new(value, value)
+    }
+}
+
+impl<T, U> Converged for Point3D<T, U>
+where
+    T: Copy,
+{
+    fn converged(value: Self::Item) -> Self {
+        Point3D::new(value, value, value)
+    }
+}
+
+impl<T, U> Extend<Point3D<T, U>> for Point2D<T, U> {
+    fn extend(self, z: T) -> Point3D<T, U> {
+        let Point2D { x, y, .. } = self;
+        Point3D::new(x, y, z)
+    }
+}
+
+// Points and vectors are `repr(C)` structures with the same fields.
+unsafe impl<T, U> CoordinateLayout for Point2D<T, U> where Self: EuclideanSpace {}
+
+unsafe impl<T, U> CoordinateLayout for Point3D<T, U> where Self: EuclideanSpace {}
+
+impl<T, U> EuclideanSpace for Point2D<T, U>
+where
+    T: RealScalar,
+{
+    type CoordinateSpace = Vector2D<T, U>;
+
+    fn origin() -> Self {
+        Point2D::new(Zero::zero(), Zero::zero())
+    }
+}
+
+impl<T, U> EuclideanSpace for Point3D<T, U>
+where
+    T: RealScalar,
+{
+    type CoordinateSpace = Vector3D<T, U>;
+
+    fn origin() -> Self {
+        Point3D::new(Zero::zero(), Zero::zero(), Zero::zero())
+    }
+}
+
+impl<T, U> FiniteDimensional for Point2D<T, U> {
+    type N = U2;
+}
+
+impl<T, U> FiniteDimensional for Point3D<T, U> {
+    type N = U3;
+}
+
+impl<T, U> Fold for Point2D<T, U> {
+    fn fold<V, F>(self, mut seed: V, mut f: F) -> V
+    where
+        F: FnMut(V, Self::Item) -> V,
+    {
+        let Point2D { x, y, .. } = self;
+        seed = f(seed, x);
+        seed = f(seed, y);
+        seed
+    }
+}
+
+impl<T, U> Fold for Point3D<T, U> {
+    fn fold<V, F>(self, mut seed: V, mut f: F) -> V
+    where
+        F: FnMut(V, Self::Item) -> V,
+    {
+        let Point3D { x, y, z, .. } = self;
+        seed = f(seed, x);
+        seed = f(seed, y);
+        seed = f(seed, z);
+        seed
+    }
+}
+
+impl<T, U> FromItems for Point2D<T, U> {
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter().take(2);
+        match (items.next(), items.next()) {
+            (Some(a), Some(b)) => Some(Point2D::new(a, b)),
+            _ => None,
+        }
+    }
+}
+
+impl<T, U> FromItems for Point3D<T, U> {
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter().take(3);
+        match (items.next(), items.next(), items.next()) {
+            (Some(a), Some(b), Some(c)) => Some(Point3D::new(a, b, c)),
+            _ => None,
+        }
+    }
+}
+
+impl<T, U, F> Interpolate<Self, F> for Point2D<T, U>
+where
+    F: Factor,
+    T: Copy + Num + NumCast,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
+    }
+}
+
+impl<T, U, F> Interpolate<Self, F> for Point3D<T, U>
+where
+    F: Factor,
+    T: Copy + Num + NumCast,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
+    }
+}
+
+impl<T, U> IntoItems for Point2D<T, U> {
+    type Output = ArrayVec<[T; 2]>;
+
+    fn into_items(self) -> Self::Output {
+        let Point2D { x, y, .. } = self;
+        ArrayVec::from([x, y])
+    }
+}
+
+impl<T, U> IntoItems for Point3D<T, U> {
+    type Output = ArrayVec<[T; 3]>;
+
+    fn into_items(self) -> Self::Output {
+        let Point3D { x, y, z, .. } = self;
+        ArrayVec::from([x, y, z])
+    }
+}
+
+impl<T, U, V> Map<V> for Point2D<T, U> {
+    type Output = Point2D<V, U>;
+
+    fn map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> V,
+    {
+        let Point2D { x, y, .. } = self;
+        Point2D::new(f(x), f(y))
+    }
+}
+
+impl<T, U, V> Map<V> for Point3D<T, U> {
+    type Output = Point3D<V, U>;
+
+    fn map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> V,
+    {
+        let Point3D { x, y, z, .. } = self;
+        Point3D::new(f(x), f(y), f(z))
+    }
+}
+
+impl<T, U> Truncate<Point2D<T, U>> for Point3D<T, U> {
+    fn truncate(self) -> (Point2D<T, U>, T) {
+        let Point3D { x, y, z, .. } = self;
+        (Point2D::new(x, y), z)
+    }
+}
+
+impl<T, U, V> ZipMap<V> for Point2D<T, U> {
+    type Output = Point2D<V, U>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> V,
+    {
+        Point2D::new(f(self.x, other.x), f(self.y, other.y))
+    }
+}
+
+impl<T, U, V> ZipMap<V> for Point3D<T, U> {
+    type Output = Point3D<V, U>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> V,
+    {
+        Point3D::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+    }
+}
+
+impl<T, U> From<Box2D<T, U>> for Aabb<Point2D<T, U>>
+where
+    T: RealScalar,
+{
+    fn from(bounds: Box2D<T, U>) -> Self {
+        Aabb {
+            origin: bounds.min,
+            extent: bounds.max - bounds.min,
+        }
+    }
+}
+
+impl<T, U> From<Aabb<Point2D<T, U>>> for Box2D<T, U>
+where
+    T: IntrinsicOrd + RealScalar,
+{
+    fn from(aabb: Aabb<Point2D<T, U>>) -> Self {
+        Box2D::new(aabb.lower_bound(), aabb.upper_bound())
+    }
+}
+
+impl<T, U> From<Box3D<T, U>> for Aabb<Point3D<T, U>>
+where
+    T: RealScalar,
+{
+    fn from(bounds: Box3D<T, U>) -> Self {
+        Aabb {
+            origin: bounds.min,
+            extent: bounds.max - bounds.min,
+        }
+    }
+}
+
+impl<T, U> From<Aabb<Point3D<T, U>>> for Box3D<T, U>
+where
+    T: IntrinsicOrd + RealScalar,
+{
+    fn from(aabb: Aabb<Point3D<T, U>>) -> Self {
+        Box3D::new(aabb.lower_bound(), aabb.upper_bound())
+    }
+}
@@ -0,0 +1,378 @@
+#![cfg(feature = "scalar-fixed")]
+
+//! Fixed-point scalars.
+//!
+//! Theon requires that scalars implement `Real`, which cannot be implemented
+//! for the foreign types of the `fixed` crate. This module provides the `Fixed`
+//! wrapper, which implements `Real` and other numeric traits for signed
+//! fixed-point types like `I32F32`. For example, `Point2<Fixed<I32F32>>` from
+//! `nalgebra` implements `EuclideanSpace` and can be used with queries.
+//!
+//! Operations are computed using fixed-point arithmetic and never convert to
+//! floating-point representations. Fixed-point types have no infinities nor
+//! `NaN`s:
+//!
+//! - Saturated values (`MAX` and `MIN`) are considered infinite.
+//! - Division by zero saturates rather than panics.
+//! - The square root of a negative value is zero rather than `NaN`.
+//! - Transcendental functions (e.g., `sin` and `exp`) are not implemented, so
+//!   `Fixed` implements `Sqrt` but not `Transcendental`.
+
+use approx::AbsDiffEq;
+use decorum::cmp::IntrinsicOrd;
+use decorum::{Encoding, Infinite};
+use num::{Bounded, Num, NumCast, One, Signed, ToPrimitive, Zero};
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[doc(hidden)]
+pub use fixed::*;
+
+use fixed::types::{I16F16, I32F32};
+
+use crate::ops::{Real, Sqrt};
+
+/// Fixed-point scalar.
+///
+/// Wraps a signed fixed-point type from the `fixed` crate and implements
+/// `Real`, so this type can be used as the scalar of vector spaces.
+/// Implementations are provided for `I16F16` and `I32F32`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Fixed<T>(pub T);
+
+/// Implements numeric traits for a `Fixed` wrapper of a signed fixed-point
+/// type.
+macro_rules! impl_fixed {
+    ($t:ident $(,)?) => {
+        impl AbsDiffEq for Fixed<$t> {
+            type Epsilon = Self;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <Self as Encoding>::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.0.abs_diff(other.0) <= epsilon.0.unsigned_abs()
+            }
+        }
+
+        impl Bounded for Fixed<$t> {
+            fn min_value() -> Self {
+                Fixed($t::MIN)
+            }
+
+            fn max_value() -> Self {
+                Fixed($t::MAX)
+            }
+        }
+
+        impl Div for Fixed<$t> {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self::Output {
+                if other.0 == $t::ZERO {
+                    if self.0 < $t::ZERO {
+                        Fixed($t::MIN)
+                    }
+                    else {
+                        Fixed($t::MAX)
+                    }
+                }
+                else {
+                    Fixed(self.0.saturating_div(other.0))
+                }
+            }
+        }
+
+        impl DivAssign for Fixed<$t> {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl Encoding for Fixed<$t> {
+            const MAX: Self = Fixed($t::MAX);
+            const MIN: Self = Fixed($t::MIN);
+            const MIN_POSITIVE: Self = Fixed($t::DELTA);
+            const EPSILON: Self = Fixed($t::DELTA);
+
+            fn classify(self) -> FpCategory {
+                if self.0 == $t::ZERO {
+                    FpCategory::Zero
+                }
+                else if self.is_infinite() {
+                    FpCategory::Infinite
+                }
+                else {
+                    FpCategory::Normal
+                }
+            }
+
+            fn is_normal(self) -> bool {
+                self.classify() == FpCategory::Normal
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.0 >= $t::ZERO
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.0 < $t::ZERO
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                let sign = if self.0 < $t::ZERO { -1 } else { 1 };
+                (
+                    self.0.to_bits().unsigned_abs() as u64,
+                    -($t::FRAC_NBITS as i16),
+                    sign,
+                )
+            }
+        }
+
+        impl From<$t> for Fixed<$t> {
+            fn from(value: $t) -> Self {
+                Fixed(value)
+            }
+        }
+
+        impl From<Fixed<$t>> for $t {
+            fn from(value: Fixed<$t>) -> Self {
+                value.0
+            }
+        }
+
+        impl Infinite for Fixed<$t> {
+            const INFINITY: Self = Fixed($t::MAX);
+            const NEG_INFINITY: Self = Fixed($t::MIN);
+
+            fn is_infinite(self) -> bool {
+                self.0 == $t::MAX || self.0 == $t::MIN
+            }
+
+            fn is_finite(self) -> bool {
+                !self.is_infinite()
+            }
+        }
+
+        impl IntrinsicOrd for Fixed<$t> {
+            fn is_undefined(&self) -> bool {
+                false
+            }
+
+            fn min_max_or_undefined(&self, other: &Self) -> (Self, Self) {
+                if self <= other {
+                    (*self, *other)
+                }
+                else {
+                    (*other, *self)
+                }
+            }
+        }
+
+        impl Num for Fixed<$t> {
+            type FromStrRadixErr = RadixParseFixedError;
+
+            fn from_str_radix(source: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                <$t as Num>::from_str_radix(source, radix).map(Fixed)
+            }
+        }
+
+        impl NumCast for Fixed<$t> {
+            fn from<T>(value: T) -> Option<Self>
+            where
+                T: ToPrimitive,
+            {
+                // Convert integers directly and only use floating-point
+                // conversions for values with a fractional part.
+                match value.to_f64() {
+                    Some(x) if x.fract() != 0.0 => $t::checked_from_num(x),
+                    _ => value
+                        .to_i128()
+                        .and_then($t::checked_from_num)
+                        .or_else(|| value.to_u128().and_then($t::checked_from_num)),
+                }
+                .map(Fixed)
+            }
+        }
+
+        impl One for Fixed<$t> {
+            fn one() -> Self {
+                Fixed($t::ONE)
+            }
+        }
+
+impl Real for Fixed<$t> {
+            fn floor(self) -> Self {
+                Fixed(self.0.floor())
+            }
+
+            fn recip(self) -> Self {
+                Self::one() / self
+            }
+        }
+
+                impl Signed for Fixed<$t> {
+            fn abs(&self) -> Self {
+                Fixed(self.0.abs())
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                if self <= other {
+                    Zero::zero()
+                }
+                else {
+                    *self - *other
+                }
+            }
+
+            fn signum(&self) -> Self {
+                Fixed(self.0.signum())
+            }
+
+            fn is_positive(&self) -> bool {
+                self.0.is_positive()
+            }
+
+            fn is_negative(&self) -> bool {
+                self.0.is_negative()
+            }
+        }
+
+        impl ToPrimitive for Fixed<$t> {
+            fn to_i64(&self) -> Option<i64> {
+                self.0.checked_to_num()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.0.checked_to_num()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                self.0.checked_to_num()
+            }
+        }
+
+        impl Sqrt for Fixed<$t> {
+            fn sqrt(self) -> Self {
+                Fixed(self.0.checked_sqrt().unwrap_or($t::ZERO))
+            }
+        }
+
+        impl Zero for Fixed<$t> {
+            fn zero() -> Self {
+                Fixed($t::ZERO)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == $t::ZERO
+            }
+        }
+
+        impl Neg for Fixed<$t> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Fixed(-self.0)
+            }
+        }
+
+        impl_fixed!(@operator $t, Add, add, AddAssign, add_assign);
+        impl_fixed!(@operator $t, Mul, mul, MulAssign, mul_assign);
+        impl_fixed!(@operator $t, Rem, rem, RemAssign, rem_assign);
+        impl_fixed!(@operator $t, Sub, sub, SubAssign, sub_assign);
+    };
+    (@operator $t:ident, $o:ident, $f:ident, $oa:ident, $fa:ident $(,)?) => {
+        impl $o for Fixed<$t> {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self::Output {
+                Fixed($o::$f(self.0, other.0))
+            }
+        }
+
+        impl $oa for Fixed<$t> {
+            fn $fa(&mut self, other: Self) {
+                $oa::$fa(&mut self.0, other.0)
+            }
+        }
+    };
+}
+impl_fixed!(I16F16);
+impl_fixed!(I32F32);
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::Point2;
+    use num::{Num, NumCast, One, Zero};
+
+    use crate::integration::fixed::types::{I16F16, I32F32};
+    use crate::integration::fixed::{Fixed, RadixParseFixedError};
+    use crate::ops::{Real, Sqrt};
+    use crate::query::{Intersection, Segment, SegmentSegment};
+
+    type F32 = Fixed<I32F32>;
+
+    fn fixed(value: f64) -> F32 {
+        Fixed(I32F32::from_num(value))
+    }
+
+    #[test]
+    fn real_fixed() {
+        assert_eq!(fixed(-2.0), Real::floor(fixed(-1.5)));
+        assert_eq!(fixed(0.25), Real::recip(fixed(4.0)));
+        // Division by zero saturates.
+        assert_eq!(Fixed(I32F32::MAX), Real::recip(F32::zero()));
+    }
+
+    #[test]
+    fn sqrt_fixed() {
+        assert_eq!(fixed(1.5), Sqrt::sqrt(fixed(2.25)));
+        assert_eq!(F32::zero(), Sqrt::sqrt(fixed(-1.0)));
+    }
+
+    #[test]
+    fn from_str_radix_fixed() {
+        assert_eq!(Ok(fixed(2.5)), F32::from_str_radix("10.1", 2));
+        assert_eq!(Ok(fixed(-16.5)), F32::from_str_radix("-10.8", 16));
+        assert_eq!(
+            Err(RadixParseFixedError::UnsupportedRadix),
+            F32::from_str_radix("10", 3),
+        );
+        assert!(F32::from_str_radix("1.0.0", 10).is_err());
+    }
+
+    #[test]
+    fn num_cast_fixed() {
+        assert_eq!(
+            Some(Fixed(I16F16::from_num(7))),
+            <Fixed<I16F16> as NumCast>::from(7u8),
+        );
+        assert_eq!(
+            Some(Fixed(I32F32::from_num(i32::MAX))),
+            <F32 as NumCast>::from(i32::MAX),
+        );
+        assert_eq!(Some(fixed(-0.75)), <F32 as NumCast>::from(-0.75));
+        // Out of range values and `NaN` cannot be converted.
+        assert_eq!(None, <F32 as NumCast>::from(i64::MAX));
+        assert_eq!(None, <F32 as NumCast>::from(u128::MAX));
+        assert_eq!(None, <F32 as NumCast>::from(f64::NAN));
+    }
+
+    #[test]
+    fn segment_segment_intersection_fixed() {
+        let segment = |start: (f64, f64), end: (f64, f64)| Segment {
+            start: Point2::new(fixed(start.0), fixed(start.1)),
+            end: Point2::new(fixed(end.0), fixed(end.1)),
+        };
+        let a = segment((0.0, 0.0), (2.0, 2.0));
+        assert_eq!(
+            Some(SegmentSegment::Point(Point2::new(F32::one(), F32::one()))),
+            a.intersection(&segment((0.0, 2.0), (2.0, 0.0))),
+        );
+        assert_eq!(None, a.intersection(&segment((1.0, 0.0), (3.0, 2.0))));
+    }
+}
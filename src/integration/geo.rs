@@ -0,0 +1,309 @@
+#![cfg(feature = "geometry-geo")]
+
+// `geo` re-exports the types of `geo-types`, which is the crate integrated here
+// so that the algorithms of `geo` are not required.
+//
+// `Coord` is used as both a point and a vector in `geo`, so it is its own
+// `CoordinateSpace` and `Translation`. `geo_types::Line` is named via the crate
+// path, because it collides with `query::Line`.
+
+use arrayvec::ArrayVec;
+use core::convert::TryFrom;
+use decorum::cmp::IntrinsicOrd;
+use num::{One, Zero};
+use typenum::consts::U2;
+
+use crate::adjunct::{Adjunct, Converged, Fold, FromItems, IntoItems, Map, ZipMap};
+use crate::ops::{Dot, Factor, Interpolate, Sqrt, Wedge};
+use crate::query::{self, Aabb, Unit};
+use crate::space::{
+    AffineSpace, Basis, CoordinateLayout, DualSpace, EuclideanSpace, FiniteDimensional, InnerSpace,
+    VectorSpace,
+};
+use crate::{AsPosition, AsPositionMut, RealScalar};
+
+#[doc(hidden)]
+pub use geo_types::*;
+
+impl<T> Adjunct for Coord<T>
+where
+    T: CoordNum,
+{
+    type Item = T;
+}
+
+impl<T> AffineSpace for Coord<T>
+where
+    T: CoordNum + RealScalar,
+{
+    type Translation = Self;
+}
+
+impl<T> AsPosition for Coord<T>
+where
+    Self: EuclideanSpace,
+    T: CoordNum,
+{
+    type Position = Self;
+
+    fn as_position(&self) -> &Self::Position {
+        self
+    }
+}
+
+impl<T> AsPositionMut for Coord<T>
+where
+    Self: EuclideanSpace,
+    T: CoordNum,
+{
+    fn as_position_mut(&mut self) -> &mut Self::Position {
+        self
+    }
+}
+
+impl<T> Basis for Coord<T>
+where
+    T: CoordNum,
+{
+    type Bases = ArrayVec<[Self; 2]>;
+
+    fn canonical_basis() -> Self::Bases {
+        ArrayVec::from([
+            Self::canonical_basis_component(0).unwrap(),
+            Self::canonical_basis_component(1).unwrap(),
+        ])
+    }
+
+    fn canonical_basis_component(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(Coord {
+                x: One::one(),
+                y: Zero::zero(),
+            }),
+            1 => Some(Coord {
+                x: Zero::zero(),
+                y: One::one(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Converged for Coord<T>
+where
+    T: CoordNum,
+{
+    fn converged(value: Self::Item) -> Self {
+        Coord { x: value, y: value }
+    }
+}
+
+unsafe impl<T> CoordinateLayout for Coord<T>
+where
+    Self: EuclideanSpace,
+    T: CoordNum,
+{
+}
+
+impl<T> Dot for Coord<T>
+where
+    T: CoordNum,
+{
+    type Output = T;
+
+    fn dot(self, other: Self) -> Self::Output {
+        (self.x * other.x) + (self.y * other.y)
+    }
+}
+
+impl<T> DualSpace for Coord<T>
+where
+    T: CoordNum + RealScalar,
+{
+    type Dual = Self;
+
+    fn transpose(self) -> Self::Dual {
+        self
+    }
+}
+
+impl<T> EuclideanSpace for Coord<T>
+where
+    T: CoordNum + RealScalar,
+{
+    type CoordinateSpace = Self;
+
+    fn origin() -> Self {
+        Coord::zero()
+    }
+}
+
+impl<T> FiniteDimensional for Coord<T>
+where
+    T: CoordNum,
+{
+    type N = U2;
+}
+
+impl<T> Fold for Coord<T>
+where
+    T: CoordNum,
+{
+    fn fold<U, F>(self, mut seed: U, mut f: F) -> U
+    where
+        F: FnMut(U, Self::Item) -> U,
+    {
+        let Coord { x, y } = self;
+        seed = f(seed, x);
+        seed = f(seed, y);
+        seed
+    }
+}
+
+impl<T> FromItems for Coord<T>
+where
+    T: CoordNum,
+{
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut items = items.into_iter().take(2);
+        match (items.next(), items.next()) {
+            (Some(x), Some(y)) => Some(Coord { x, y }),
+            _ => None,
+        }
+    }
+}
+
+impl<T> InnerSpace for Coord<T> where T: CoordNum + RealScalar {}
+
+impl<T, F> Interpolate<Self, F> for Coord<T>
+where
+    F: Factor,
+    T: CoordNum,
+{
+    type Output = Self;
+
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        self.zip_map(other, crate::lerp_by(f))
+    }
+}
+
+impl<T> IntoItems for Coord<T>
+where
+    T: CoordNum,
+{
+    type Output = ArrayVec<[T; 2]>;
+
+    fn into_items(self) -> Self::Output {
+        let Coord { x, y } = self;
+        ArrayVec::from([x, y])
+    }
+}
+
+impl<T, U> Map<U> for Coord<T>
+where
+    T: CoordNum,
+    U: CoordNum,
+{
+    type Output = Coord<U>;
+
+    fn map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        let Coord { x, y } = self;
+        Coord { x: f(x), y: f(y) }
+    }
+}
+
+impl<T> VectorSpace for Coord<T>
+where
+    T: CoordNum + RealScalar,
+{
+    type Scalar = T;
+
+    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+        match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            _ => None,
+        }
+    }
+
+    fn into_xy(self) -> (Self::Scalar, Self::Scalar) {
+        self.x_y()
+    }
+}
+
+impl<T> Wedge for Coord<T>
+where
+    T: CoordNum,
+{
+    type Output = T;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        (self.x * other.y) - (self.y * other.x)
+    }
+}
+
+impl<T, U> ZipMap<U> for Coord<T>
+where
+    T: CoordNum,
+    U: CoordNum,
+{
+    type Output = Coord<U>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> U,
+    {
+        Coord {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+        }
+    }
+}
+
+impl<T> From<Rect<T>> for Aabb<Coord<T>>
+where
+    T: CoordNum + RealScalar,
+{
+    fn from(rect: Rect<T>) -> Self {
+        Aabb {
+            origin: rect.min(),
+            extent: rect.max() - rect.min(),
+        }
+    }
+}
+
+impl<T> From<Aabb<Coord<T>>> for Rect<T>
+where
+    T: CoordNum + IntrinsicOrd + RealScalar,
+{
+    fn from(aabb: Aabb<Coord<T>>) -> Self {
+        Rect::new(aabb.lower_bound(), aabb.upper_bound())
+    }
+}
+
+/// Converts a line segment into a `query::Line` through its endpoints.
+///
+/// The origin of the line is the start of the segment and its direction is
+/// toward the end of the segment. Fails and returns the segment if its
+/// endpoints are the same.
+impl<T> TryFrom<geo_types::Line<T>> for query::Line<Coord<T>>
+where
+    T: CoordNum + RealScalar + Sqrt,
+{
+    type Error = geo_types::Line<T>;
+
+    fn try_from(line: geo_types::Line<T>) -> Result<Self, Self::Error> {
+        Unit::try_from_inner(line.delta())
+            .map(|direction| query::Line {
+                origin: line.start,
+                direction,
+            })
+            .ok_or(line)
+    }
+}
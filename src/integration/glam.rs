@@ -2,12 +2,16 @@
 
 use arrayvec::ArrayVec;
 use decorum::R64;
+use num::{One, Zero};
 use typenum::consts::{U2, U3, U4};
 
-use crate::adjunct::{Adjunct, Converged, Extend, Fold, Map, Truncate, ZipMap};
-use crate::ops::{Cross, Dot, Interpolate};
+use crate::adjunct::{
+    Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, Truncate, ZipMap,
+};
+use crate::ops::{Cross, Dot, Factor, Interpolate, MulMN, Outer, Wedge};
 use crate::space::{
-    AffineSpace, Basis, DualSpace, EuclideanSpace, FiniteDimensional, Homogeneous, InnerSpace,
+    AffineSpace, Basis, CoordinateLayout, DualSpace, EuclideanSpace, ExtendMatrix,
+    FiniteDimensional, Homogeneous, InnerSpace, Matrix, Quaternion, SquareMatrix, TruncateMatrix,
     VectorSpace,
 };
 use crate::{AsPosition, AsPositionMut};
@@ -15,183 +19,226 @@ use crate::{AsPosition, AsPositionMut};
 #[doc(hidden)]
 pub use glam::*;
 
-impl Adjunct for Vec2 {
-    type Item = f32;
-}
-
-impl Adjunct for Vec3 {
-    type Item = f32;
-}
+// `glam` provides many types that differ only in their scalar type and
+// dimensionality, so implementations are generated by macros rather than
+// written by hand for each type.
 
-impl Adjunct for Vec3A {
-    type Item = f32;
-}
+/// Implements adjunct traits, `Basis`, `Dot`, and `FiniteDimensional` for a
+/// vector type with any scalar type.
+macro_rules! impl_vector_adjunct {
+    ($v:ident, $t:ty, $n:ident, $d:literal $(,)?) => {
+        impl Adjunct for $v {
+            type Item = $t;
+        }
 
-impl Adjunct for Vec4 {
-    type Item = f32;
-}
+        impl Basis for $v {
+            type Bases = ArrayVec<[Self; $d]>;
 
-impl AffineSpace for Vec2 {
-    type Translation = Self;
-}
+            fn canonical_basis() -> Self::Bases {
+                ArrayVec::from(Self::AXES)
+            }
 
-impl AffineSpace for Vec3 {
-    type Translation = Self;
-}
+            fn canonical_basis_component(index: usize) -> Option<Self> {
+                Self::AXES.get(index).copied()
+            }
+        }
 
-impl AffineSpace for Vec3A {
-    type Translation = Self;
-}
+        impl Converged for $v {
+            fn converged(value: Self::Item) -> Self {
+                Self::splat(value)
+            }
+        }
 
-impl AsPosition for Vec2 {
-    type Position = Self;
+        impl Dot for $v {
+            type Output = $t;
 
-    fn as_position(&self) -> &Self::Position {
-        self
-    }
-}
-
-impl AsPosition for Vec3 {
-    type Position = Self;
+            fn dot(self, other: Self) -> Self::Output {
+                Self::dot(self, other)
+            }
+        }
 
-    fn as_position(&self) -> &Self::Position {
-        self
-    }
-}
+        impl FiniteDimensional for $v {
+            type N = $n;
+        }
 
-impl AsPosition for Vec3A {
-    type Position = Self;
+        impl Fold for $v {
+            fn fold<T, F>(self, seed: T, f: F) -> T
+            where
+                F: FnMut(T, Self::Item) -> T,
+            {
+                self.to_array().iter().cloned().fold(seed, f)
+            }
+        }
 
-    fn as_position(&self) -> &Self::Position {
-        self
-    }
-}
+        impl FromItems for $v {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                items
+                    .into_iter()
+                    .take($d)
+                    .collect::<ArrayVec<[$t; $d]>>()
+                    .into_inner()
+                    .ok()
+                    .map(Self::from_array)
+            }
+        }
 
-impl AsPositionMut for Vec2 {
-    fn as_position_mut(&mut self) -> &mut Self::Position {
-        self
-    }
-}
+        impl IntoItems for $v {
+            type Output = ArrayVec<[$t; $d]>;
 
-impl AsPositionMut for Vec3 {
-    fn as_position_mut(&mut self) -> &mut Self::Position {
-        self
-    }
-}
+            fn into_items(self) -> Self::Output {
+                self.to_array().into()
+            }
+        }
 
-impl AsPositionMut for Vec3A {
-    fn as_position_mut(&mut self) -> &mut Self::Position {
-        self
-    }
-}
+        impl Map<$t> for $v {
+            type Output = Self;
 
-impl Basis for Vec2 {
-    type Bases = ArrayVec<[Self; 2]>;
+            fn map<F>(self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> $t,
+            {
+                Self::from_array(self.to_array().map(f))
+            }
+        }
 
-    fn canonical_basis() -> Self::Bases {
-        ArrayVec::from([
-            Self::canonical_basis_component(0).unwrap(),
-            Self::canonical_basis_component(1).unwrap(),
-        ])
-    }
+        impl ZipMap<$t> for $v {
+            type Output = Self;
 
-    fn canonical_basis_component(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::unit_x()),
-            1 => Some(Self::unit_y()),
-            _ => None,
+            fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> $t,
+            {
+                let mut items = IntoIterator::into_iter(other.to_array());
+                Self::from_array(self.to_array().map(|a| f(a, items.next().unwrap())))
+            }
+        }
+    };
+}
+impl_vector_adjunct!(Vec2, f32, U2, 2);
+impl_vector_adjunct!(Vec3, f32, U3, 3);
+impl_vector_adjunct!(Vec3A, f32, U3, 3);
+impl_vector_adjunct!(Vec4, f32, U4, 4);
+impl_vector_adjunct!(DVec2, f64, U2, 2);
+impl_vector_adjunct!(DVec3, f64, U3, 3);
+impl_vector_adjunct!(DVec4, f64, U4, 4);
+impl_vector_adjunct!(IVec2, i32, U2, 2);
+impl_vector_adjunct!(IVec3, i32, U3, 3);
+impl_vector_adjunct!(IVec4, i32, U4, 4);
+
+/// Implements vector space traits for a vector type with a floating-point
+/// scalar type.
+macro_rules! impl_vector_space {
+    ($v:ident, $t:ty $(,)?) => {
+        impl DualSpace for $v {
+            type Dual = Self;
+
+            fn transpose(self) -> Self::Dual {
+                self
+            }
         }
-    }
-}
 
-impl Basis for Vec3 {
-    type Bases = ArrayVec<[Self; 3]>;
+        impl InnerSpace for $v {}
 
-    fn canonical_basis() -> Self::Bases {
-        ArrayVec::from([
-            Self::canonical_basis_component(0).unwrap(),
-            Self::canonical_basis_component(1).unwrap(),
-            Self::canonical_basis_component(2).unwrap(),
-        ])
-    }
+        impl<F> Interpolate<Self, F> for $v
+        where
+            F: Factor,
+        {
+            type Output = Self;
 
-    fn canonical_basis_component(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::unit_x()),
-            1 => Some(Self::unit_y()),
-            2 => Some(Self::unit_z()),
-            _ => None,
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                self.zip_map(other, crate::lerp_by(f))
+            }
         }
-    }
-}
 
-impl Basis for Vec3A {
-    type Bases = ArrayVec<[Self; 3]>;
+        impl VectorSpace for $v {
+            type Scalar = $t;
 
-    fn canonical_basis() -> Self::Bases {
-        ArrayVec::from([
-            Self::canonical_basis_component(0).unwrap(),
-            Self::canonical_basis_component(1).unwrap(),
-            Self::canonical_basis_component(2).unwrap(),
-        ])
-    }
+            fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+                self.to_array().get(index).copied()
+            }
 
-    fn canonical_basis_component(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::unit_x()),
-            1 => Some(Self::unit_y()),
-            2 => Some(Self::unit_z()),
-            _ => None,
+            fn zero() -> Self {
+                Self::ZERO
+            }
+        }
+    };
+}
+impl_vector_space!(Vec2, f32);
+impl_vector_space!(Vec3, f32);
+impl_vector_space!(Vec3A, f32);
+impl_vector_space!(Vec4, f32);
+impl_vector_space!(DVec2, f64);
+impl_vector_space!(DVec3, f64);
+impl_vector_space!(DVec4, f64);
+
+/// Implements Euclidean space traits for a vector type that is used as a
+/// point.
+macro_rules! impl_euclidean_space {
+    ($v:ident $(,)?) => {
+        impl AffineSpace for $v {
+            type Translation = Self;
         }
-    }
-}
 
-impl Basis for Vec4 {
-    type Bases = ArrayVec<[Self; 4]>;
+        impl AsPosition for $v {
+            type Position = Self;
 
-    fn canonical_basis() -> Self::Bases {
-        ArrayVec::from([
-            Self::canonical_basis_component(0).unwrap(),
-            Self::canonical_basis_component(1).unwrap(),
-            Self::canonical_basis_component(2).unwrap(),
-            Self::canonical_basis_component(3).unwrap(),
-        ])
-    }
+            fn as_position(&self) -> &Self::Position {
+                self
+            }
+        }
 
-    fn canonical_basis_component(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::unit_x()),
-            1 => Some(Self::unit_y()),
-            2 => Some(Self::unit_z()),
-            3 => Some(Self::unit_w()),
-            _ => None,
+        impl AsPositionMut for $v {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                self
+            }
         }
-    }
-}
 
-impl Converged for Vec2 {
-    fn converged(value: Self::Item) -> Self {
-        Self::splat(value)
-    }
-}
+        unsafe impl CoordinateLayout for $v {}
 
-impl Converged for Vec3 {
-    fn converged(value: Self::Item) -> Self {
-        Self::splat(value)
-    }
-}
+        impl EuclideanSpace for $v {
+            type CoordinateSpace = Self;
 
-impl Converged for Vec3A {
-    fn converged(value: Self::Item) -> Self {
-        Self::splat(value)
-    }
-}
+            fn origin() -> Self {
+                Self::ZERO
+            }
+        }
+    };
+}
+impl_euclidean_space!(Vec2);
+impl_euclidean_space!(Vec3);
+impl_euclidean_space!(Vec3A);
+impl_euclidean_space!(DVec2);
+impl_euclidean_space!(DVec3);
+
+/// Implements `Extend` and `Truncate` between a vector type and a vector type
+/// with one additional dimension.
+macro_rules! impl_extend_truncate {
+    ($lower:ident, $higher:ident $(,)?) => {
+        impl Extend<$higher> for $lower {
+            fn extend(self, item: Self::Item) -> $higher {
+                $higher::from($lower::extend(self, item))
+            }
+        }
 
-impl Converged for Vec4 {
-    fn converged(value: Self::Item) -> Self {
-        Self::splat(value)
-    }
+        impl Truncate<$lower> for $higher {
+            fn truncate(self) -> ($lower, Self::Item) {
+                let item = *self.to_array().last().unwrap();
+                ($lower::from($higher::truncate(self)), item)
+            }
+        }
+    };
 }
+impl_extend_truncate!(Vec2, Vec3);
+impl_extend_truncate!(Vec2, Vec3A);
+impl_extend_truncate!(Vec3, Vec4);
+impl_extend_truncate!(Vec3A, Vec4);
+impl_extend_truncate!(DVec2, DVec3);
+impl_extend_truncate!(DVec3, DVec4);
+impl_extend_truncate!(IVec2, IVec3);
+impl_extend_truncate!(IVec3, IVec4);
 
 impl Cross for Vec3 {
     type Output = Self;
@@ -209,167 +256,75 @@ impl Cross for Vec3A {
     }
 }
 
-impl Dot for Vec2 {
-    type Output = f32;
-
-    fn dot(self, other: Self) -> Self::Output {
-        Self::dot(self, other)
-    }
-}
-
-impl Dot for Vec3 {
-    type Output = f32;
+impl Cross for DVec3 {
+    type Output = Self;
 
-    fn dot(self, other: Self) -> Self::Output {
-        Self::dot(self, other)
+    fn cross(self, other: Self) -> Self::Output {
+        Self::cross(self, other)
     }
 }
 
-impl Dot for Vec3A {
-    type Output = f32;
+impl Cross for IVec3 {
+    type Output = Self;
 
-    fn dot(self, other: Self) -> Self::Output {
-        Self::dot(self, other)
+    fn cross(self, other: Self) -> Self::Output {
+        Self::cross(self, other)
     }
 }
 
-impl Dot for Vec4 {
+impl Wedge for Vec2 {
     type Output = f32;
 
-    fn dot(self, other: Self) -> Self::Output {
-        Self::dot(self, other)
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::perp_dot(self, other)
     }
 }
 
-impl DualSpace for Vec2 {
-    type Dual = Self;
+impl Wedge for DVec2 {
+    type Output = f64;
 
-    fn transpose(self) -> Self::Dual {
-        self
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::perp_dot(self, other)
     }
 }
 
-impl DualSpace for Vec3 {
-    type Dual = Self;
-
-    fn transpose(self) -> Self::Dual {
-        self
-    }
-}
-
-impl DualSpace for Vec3A {
-    type Dual = Self;
-
-    fn transpose(self) -> Self::Dual {
-        self
-    }
-}
-
-impl DualSpace for Vec4 {
-    type Dual = Self;
-
-    fn transpose(self) -> Self::Dual {
-        self
-    }
-}
+impl Wedge for IVec2 {
+    type Output = i32;
 
-impl Extend<Vec3> for Vec2 {
-    fn extend(self, w: Self::Item) -> Vec3 {
-        self.extend(w)
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::perp_dot(self, other)
     }
 }
 
-impl Extend<Vec3A> for Vec2 {
-    fn extend(self, w: Self::Item) -> Vec3A {
-        self.extend(w).into()
-    }
-}
-
-impl Extend<Vec4> for Vec3 {
-    fn extend(self, w: Self::Item) -> Vec4 {
-        self.extend(w)
-    }
-}
-
-impl Extend<Vec4> for Vec3A {
-    fn extend(self, w: Self::Item) -> Vec4 {
-        self.extend(w)
-    }
-}
-
-impl EuclideanSpace for Vec2 {
-    type CoordinateSpace = Self;
-
-    fn origin() -> Self {
-        Self::zero()
-    }
-}
-
-impl EuclideanSpace for Vec3 {
-    type CoordinateSpace = Self;
+impl Wedge for Vec3 {
+    type Output = Self;
 
-    fn origin() -> Self {
-        Self::zero()
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(self, other)
     }
 }
 
-impl EuclideanSpace for Vec3A {
-    type CoordinateSpace = Self;
+impl Wedge for Vec3A {
+    type Output = Self;
 
-    fn origin() -> Self {
-        Self::zero()
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(self, other)
     }
 }
 
-impl FiniteDimensional for Vec2 {
-    type N = U2;
-}
-
-impl FiniteDimensional for Vec3 {
-    type N = U3;
-}
-
-impl FiniteDimensional for Vec3A {
-    type N = U3;
-}
-
-impl FiniteDimensional for Vec4 {
-    type N = U4;
-}
-
-impl Fold for Vec2 {
-    fn fold<T, F>(self, seed: T, f: F) -> T
-    where
-        F: FnMut(T, Self::Item) -> T,
-    {
-        <[f32; 2]>::from(self).iter().cloned().fold(seed, f)
-    }
-}
+impl Wedge for DVec3 {
+    type Output = Self;
 
-impl Fold for Vec3 {
-    fn fold<T, F>(self, seed: T, f: F) -> T
-    where
-        F: FnMut(T, Self::Item) -> T,
-    {
-        <[f32; 3]>::from(self).iter().cloned().fold(seed, f)
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(self, other)
     }
 }
 
-impl Fold for Vec3A {
-    fn fold<T, F>(self, seed: T, f: F) -> T
-    where
-        F: FnMut(T, Self::Item) -> T,
-    {
-        <[f32; 3]>::from(self).iter().cloned().fold(seed, f)
-    }
-}
+impl Wedge for IVec3 {
+    type Output = Self;
 
-impl Fold for Vec4 {
-    fn fold<T, F>(self, seed: T, f: F) -> T
-    where
-        F: FnMut(T, Self::Item) -> T,
-    {
-        <[f32; 4]>::from(self).iter().cloned().fold(seed, f)
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(self, other)
     }
 }
 
@@ -385,283 +340,263 @@ impl Homogeneous for Vec3A {
     type ProjectiveSpace = Vec4;
 }
 
-impl InnerSpace for Vec2 {}
-
-impl InnerSpace for Vec3 {}
-
-impl InnerSpace for Vec3A {}
-
-impl InnerSpace for Vec4 {}
-
-impl Interpolate for Vec2 {
-    type Output = Self;
-
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        Self::lerp(self, other, f64::from(f) as f32)
-    }
-}
-
-impl Interpolate for Vec3 {
-    type Output = Self;
-
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        Self::lerp(self, other, f64::from(f) as f32)
-    }
-}
-
-impl Interpolate for Vec3A {
-    type Output = Self;
-
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        Self::lerp(self, other, f64::from(f) as f32)
-    }
-}
-
-impl Interpolate for Vec4 {
-    type Output = Self;
-
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        Self::lerp(self, other, f64::from(f) as f32)
-    }
-}
-
-impl Map<f32> for Vec2 {
-    type Output = Self;
-
-    fn map<F>(self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> f32,
-    {
-        let [x, y]: [f32; 2] = self.into();
-        [f(x), f(y)].into()
-    }
-}
-
-impl Map<f32> for Vec3 {
-    type Output = Self;
-
-    fn map<F>(self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> f32,
-    {
-        let [x, y, z]: [f32; 3] = self.into();
-        [f(x), f(y), f(z)].into()
-    }
-}
-
-impl Map<f32> for Vec3A {
-    type Output = Self;
-
-    fn map<F>(self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> f32,
-    {
-        let [x, y, z]: [f32; 3] = self.into();
-        [f(x), f(y), f(z)].into()
-    }
-}
-
-impl Map<f32> for Vec4 {
-    type Output = Self;
-
-    #[allow(clippy::many_single_char_names)]
-    fn map<F>(self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> f32,
-    {
-        let [x, y, z, w]: [f32; 4] = self.into();
-        [f(x), f(y), f(z), f(w)].into()
-    }
-}
-
-impl Truncate<Vec2> for Vec3 {
-    fn truncate(self) -> (Vec2, Self::Item) {
-        let z = self.z();
-        (self.truncate(), z)
-    }
-}
-
-impl Truncate<Vec2> for Vec3A {
-    fn truncate(self) -> (Vec2, Self::Item) {
-        let z = self.z();
-        (self.truncate(), z)
-    }
+impl Homogeneous for DVec2 {
+    type ProjectiveSpace = DVec3;
 }
 
-impl Truncate<Vec3> for Vec4 {
-    fn truncate(self) -> (Vec3, Self::Item) {
-        let z = self.z();
-        (self.truncate().into(), z)
-    }
+impl Homogeneous for DVec3 {
+    type ProjectiveSpace = DVec4;
 }
 
-impl Truncate<Vec3A> for Vec4 {
-    fn truncate(self) -> (Vec3A, Self::Item) {
-        let z = self.z();
-        (self.truncate(), z)
-    }
-}
-
-impl VectorSpace for Vec2 {
-    type Scalar = f32;
-
-    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
-        match index {
-            0 => Some(self.x()),
-            1 => Some(self.y()),
-            _ => None,
+/// Implements adjunct, vector space, and matrix traits for a square matrix
+/// type.
+///
+/// Items of `glam` matrices are in column-major order.
+macro_rules! impl_square_matrix {
+    ($m:ident, $v:ident, $t:ty, $d:literal $(,)?) => {
+        impl Adjunct for $m {
+            type Item = $t;
         }
-    }
-
-    fn into_xy(self) -> (Self::Scalar, Self::Scalar) {
-        self.into()
-    }
-
-    fn zero() -> Self {
-        Self::zero()
-    }
-}
-
-impl VectorSpace for Vec3 {
-    type Scalar = f32;
 
-    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
-        match index {
-            0 => Some(self.x()),
-            1 => Some(self.y()),
-            2 => Some(self.z()),
-            _ => None,
+        impl Converged for $m {
+            fn converged(value: Self::Item) -> Self {
+                Self::from_cols_array(&[value; $d])
+            }
         }
-    }
-
-    fn into_xyz(self) -> (Self::Scalar, Self::Scalar, Self::Scalar) {
-        self.into()
-    }
 
-    fn zero() -> Self {
-        Self::zero()
-    }
-}
-
-impl VectorSpace for Vec3A {
-    type Scalar = f32;
+        impl Fold for $m {
+            fn fold<T, F>(self, seed: T, f: F) -> T
+            where
+                F: FnMut(T, Self::Item) -> T,
+            {
+                self.to_cols_array().iter().cloned().fold(seed, f)
+            }
+        }
 
-    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
-        match index {
-            0 => Some(self.x()),
-            1 => Some(self.y()),
-            2 => Some(self.z()),
-            _ => None,
+        impl FromItems for $m {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                items
+                    .into_iter()
+                    .take($d)
+                    .collect::<ArrayVec<[$t; $d]>>()
+                    .into_inner()
+                    .ok()
+                    .map(|items| Self::from_cols_array(&items))
+            }
         }
-    }
 
-    fn into_xyz(self) -> (Self::Scalar, Self::Scalar, Self::Scalar) {
-        self.into()
-    }
+        impl<F> Interpolate<Self, F> for $m
+        where
+            F: Factor,
+        {
+            type Output = Self;
 
-    fn zero() -> Self {
-        Self::zero()
-    }
-}
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                self.zip_map(other, crate::lerp_by(f))
+            }
+        }
 
-impl VectorSpace for Vec4 {
-    type Scalar = f32;
+        impl IntoItems for $m {
+            type Output = ArrayVec<[$t; $d]>;
 
-    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
-        match index {
-            0 => Some(self.x()),
-            1 => Some(self.y()),
-            2 => Some(self.z()),
-            3 => Some(self.w()),
-            _ => None,
+            fn into_items(self) -> Self::Output {
+                self.to_cols_array().into()
+            }
         }
-    }
 
-    fn zero() -> Self {
-        Self::zero()
-    }
-}
+        impl Map<$t> for $m {
+            type Output = Self;
 
-impl ZipMap<f32> for Vec2 {
-    type Output = Self;
+            fn map<F>(self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> $t,
+            {
+                Self::from_cols_array(&self.to_cols_array().map(f))
+            }
+        }
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item, Self::Item) -> f32,
-    {
-        let [x1, y1]: [f32; 2] = self.into();
-        let [x2, y2]: [f32; 2] = other.into();
-        From::from([f(x1, x2), f(y1, y2)])
-    }
-}
+        impl Matrix for $m {
+            type Row = $v;
+            type Column = $v;
+            type Transpose = Self;
+
+            fn row_component(&self, index: usize) -> Option<Self::Row> {
+                if index < <Self as Matrix>::row_count() {
+                    Some($m::row(self, index))
+                }
+                else {
+                    None
+                }
+            }
+
+            fn column_component(&self, index: usize) -> Option<Self::Column> {
+                if index < <Self as Matrix>::column_count() {
+                    Some($m::col(self, index))
+                }
+                else {
+                    None
+                }
+            }
+
+            fn transpose(self) -> Self::Transpose {
+                $m::transpose(&self)
+            }
+        }
 
-impl ZipMap<f32> for Vec3 {
-    type Output = Self;
+        impl MulMN<$m> for $m {
+            type Output = $m;
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item, Self::Item) -> f32,
-    {
-        let [x1, y1, z1]: [f32; 3] = self.into();
-        let [x2, y2, z2]: [f32; 3] = other.into();
-        From::from([f(x1, x2), f(y1, y2), f(z1, z2)])
-    }
-}
+            fn mul_mn(self, other: $m) -> <Self as MulMN<$m>>::Output {
+                self * other
+            }
+        }
 
-impl ZipMap<f32> for Vec3A {
-    type Output = Self;
+        impl Outer for $v {
+            type Output = $m;
+        }
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item, Self::Item) -> f32,
-    {
-        let [x1, y1, z1]: [f32; 3] = self.into();
-        let [x2, y2, z2]: [f32; 3] = other.into();
-        From::from([f(x1, x2), f(y1, y2), f(z1, z2)])
-    }
+        impl SquareMatrix for $m {
+            fn multiplicative_identity() -> Self {
+                Self::IDENTITY
+            }
+
+            fn determinant(&self) -> Self::Scalar {
+                $m::determinant(self)
+            }
+
+            fn inverse(&self) -> Option<Self> {
+                if $m::determinant(self) == 0.0 {
+                    None
+                }
+                else {
+                    Some($m::inverse(self))
+                }
+            }
+        }
 
-    fn per_item_sum(self, other: Self) -> Self::Output
-    where
-        Self: Adjunct<Item = f32>,
-    {
-        // The `Add` implementation uses `_mm_add_ps`.
-        self + other
-    }
+        impl VectorSpace for $m {
+            type Scalar = $t;
 
-    fn per_item_product(self, other: Self) -> Self::Output
-    where
-        Self: Adjunct<Item = f32>,
-    {
-        // The `Mul` implementation uses `_mm_mul_ps`.
-        self * other
-    }
-}
+            fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+                self.to_cols_array().get(index).copied()
+            }
 
-impl ZipMap<f32> for Vec4 {
-    type Output = Self;
+            fn zero() -> Self {
+                Self::ZERO
+            }
+        }
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item, Self::Item) -> f32,
-    {
-        let [x1, y1, z1, w1]: [f32; 4] = self.into();
-        let [x2, y2, z2, w2]: [f32; 4] = other.into();
-        From::from([f(x1, x2), f(y1, y2), f(z1, z2), f(w1, w2)])
-    }
+        impl ZipMap<$t> for $m {
+            type Output = Self;
 
-    fn per_item_sum(self, other: Self) -> Self::Output
-    where
-        Self: Adjunct<Item = f32>,
-    {
-        // The `Add` implementation uses `_mm_add_ps`.
-        self + other
-    }
+            fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> $t,
+            {
+                let mut items = IntoIterator::into_iter(other.to_cols_array());
+                Self::from_cols_array(&self.to_cols_array().map(|a| f(a, items.next().unwrap())))
+            }
+        }
+    };
+}
+impl_square_matrix!(Mat2, Vec2, f32, 4);
+impl_square_matrix!(Mat3, Vec3, f32, 9);
+impl_square_matrix!(Mat3A, Vec3A, f32, 9);
+impl_square_matrix!(Mat4, Vec4, f32, 16);
+impl_square_matrix!(DMat2, DVec2, f64, 4);
+impl_square_matrix!(DMat3, DVec3, f64, 9);
+impl_square_matrix!(DMat4, DVec4, f64, 16);
+
+/// Implements `ExtendMatrix` and `TruncateMatrix` between a square matrix type
+/// and a square matrix type with one additional dimension.
+macro_rules! impl_extend_truncate_matrix {
+    ($lower:ident, $higher:ident, $from_lower:ident, $from_higher:ident $(,)?) => {
+        impl ExtendMatrix<$higher> for $lower {
+            fn extend_matrix(self) -> $higher {
+                $higher::$from_lower(self)
+            }
+        }
 
-    fn per_item_product(self, other: Self) -> Self::Output
-    where
-        Self: Adjunct<Item = f32>,
-    {
-        // The `Mul` implementation uses `_mm_mul_ps`.
-        self * other
+        impl TruncateMatrix<$lower> for $higher {
+            fn truncate_matrix(self) -> $lower {
+                $lower::$from_higher(self)
+            }
+        }
+    };
+}
+impl_extend_truncate_matrix!(Mat2, Mat3, from_mat2, from_mat3);
+impl_extend_truncate_matrix!(Mat2, Mat3A, from_mat2, from_mat3a);
+impl_extend_truncate_matrix!(Mat3, Mat4, from_mat3, from_mat4);
+impl_extend_truncate_matrix!(Mat3A, Mat4, from_mat3a, from_mat4);
+impl_extend_truncate_matrix!(DMat2, DMat3, from_mat2, from_mat3);
+impl_extend_truncate_matrix!(DMat3, DMat4, from_mat3, from_mat4);
+
+/// Implements `Quaternion` for a quaternion type.
+macro_rules! impl_quaternion {
+    ($q:ident, $v:ident, $m:ident, $t:ty $(,)?) => {
+        impl Quaternion for $q {
+            type Vector = $v;
+            type Matrix = $m;
+
+            fn from_axis_angle(
+                axis: Self::Vector,
+                angle: <$v as VectorSpace>::Scalar,
+            ) -> Option<Self> {
+                InnerSpace::normalize(axis).map(|axis| $q::from_axis_angle(axis, angle))
+            }
+
+            fn conjugate(self) -> Self {
+                $q::conjugate(self)
+            }
+
+            fn normalize(self) -> Option<Self> {
+                if self.length_squared() > 0.0 {
+                    Some($q::normalize(self))
+                }
+                else {
+                    None
+                }
+            }
+
+            fn into_rotation_matrix(self) -> Self::Matrix {
+                $m::from_quat(self)
+            }
+
+            // `glam` interpolates along the shortest arc.
+            fn slerp(self, other: Self, f: R64) -> Self {
+                let f = num::clamp(f, Zero::zero(), One::one());
+                $q::slerp(self, other, f64::from(f) as $t)
+            }
+
+            fn nlerp(self, other: Self, f: R64) -> Self {
+                let f = num::clamp(f, Zero::zero(), One::one());
+                $q::lerp(self, other, f64::from(f) as $t)
+            }
+        }
+    };
+}
+impl_quaternion!(Quat, Vec3, Mat3, f32);
+impl_quaternion!(DQuat, DVec3, DMat3, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::integration::glam::{DVec2, Vec3};
+    use crate::ops::Interpolate;
+
+    #[test]
+    fn lerp_clamp() {
+        let a = Vec3::new(0.0, 1.0, 2.0);
+        let b = Vec3::new(2.0, 3.0, 4.0);
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), Interpolate::lerp(a, b, 0.5f64));
+        // The factor is clamped to $[0, 1]$.
+        assert_eq!(a, Interpolate::lerp(a, b, -1.0f64));
+        assert_eq!(b, Interpolate::lerp(a, b, 2.0f64));
+        assert_eq!(
+            DVec2::new(1.0, 1.0),
+            Interpolate::lerp(DVec2::ZERO, DVec2::ONE, 1.5f32)
+        );
     }
 }
@@ -0,0 +1,454 @@
+#![cfg(feature = "scalar-half")]
+
+//! Half-precision scalars.
+//!
+//! Theon requires that scalars implement `Real`, which cannot be implemented
+//! for the foreign `f16` and `bf16` types. This module provides the `F16` and
+//! `BF16` wrappers, which implement `Real` and other numeric traits and can be
+//! used as the scalar type of vector spaces. For example, `Vector3<F16>` from
+//! `nalgebra` implements `InnerSpace`.
+//!
+//! Arithmetic is performed by `half` and other operations (e.g., `sqrt`) are
+//! computed by promoting to `f32` and rounding the result to half-precision.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::f32::consts;
+use decorum::cmp::IntrinsicOrd;
+use decorum::{Encoding, Infinite, Nan, Real};
+use num::{Bounded, Float, Num, NumCast, One, Signed, ToPrimitive, Zero};
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[doc(hidden)]
+pub use half::*;
+
+/// Implements numeric traits for a half-precision wrapper by delegating to
+/// `half` or promoting to `f32`.
+macro_rules! impl_half {
+    ($p:ident, $t:ident $(,)?) => {
+        impl $p {
+            fn promote(self) -> f32 {
+                self.0.to_f32()
+            }
+
+            fn demote(value: f32) -> Self {
+                $p($t::from_f32(value))
+            }
+
+            const fn demote_const(value: f32) -> Self {
+                $p($t::from_f32_const(value))
+            }
+        }
+
+        impl AbsDiffEq for $p {
+            type Epsilon = Self;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <Self as Encoding>::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.promote()
+                    .abs_diff_eq(&other.promote(), epsilon.promote())
+            }
+        }
+
+        impl Bounded for $p {
+            fn min_value() -> Self {
+                <Self as Encoding>::MIN
+            }
+
+            fn max_value() -> Self {
+                <Self as Encoding>::MAX
+            }
+        }
+
+        impl Encoding for $p {
+            const MAX: Self = $p($t::MAX);
+            const MIN: Self = $p($t::MIN);
+            const MIN_POSITIVE: Self = $p($t::MIN_POSITIVE);
+            const EPSILON: Self = $p($t::EPSILON);
+
+            fn classify(self) -> FpCategory {
+                self.0.classify()
+            }
+
+            fn is_normal(self) -> bool {
+                self.0.is_normal()
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.0.is_sign_positive()
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.0.is_sign_negative()
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                Float::integer_decode(self.0)
+            }
+        }
+
+        impl Float for $p {
+            impl_half!(@unary floor, ceil, round, trunc, fract, recip);
+            impl_half!(@unary sqrt, cbrt, exp, exp2, exp_m1, ln, log2, log10, ln_1p);
+            impl_half!(@unary sin, cos, tan, asin, acos, atan, sinh, cosh, tanh);
+            impl_half!(@unary asinh, acosh, atanh, abs, signum);
+            impl_half!(@binary powf, log, hypot, atan2, max, min);
+
+            fn nan() -> Self {
+                <Self as Nan>::NAN
+            }
+
+            fn infinity() -> Self {
+                <Self as Infinite>::INFINITY
+            }
+
+            fn neg_infinity() -> Self {
+                <Self as Infinite>::NEG_INFINITY
+            }
+
+            fn neg_zero() -> Self {
+                $p($t::NEG_ZERO)
+            }
+
+            fn min_value() -> Self {
+                <Self as Encoding>::MIN
+            }
+
+            fn min_positive_value() -> Self {
+                <Self as Encoding>::MIN_POSITIVE
+            }
+
+            fn max_value() -> Self {
+                <Self as Encoding>::MAX
+            }
+
+            fn is_nan(self) -> bool {
+                self.0.is_nan()
+            }
+
+            fn is_infinite(self) -> bool {
+                self.0.is_infinite()
+            }
+
+            fn is_finite(self) -> bool {
+                self.0.is_finite()
+            }
+
+            fn is_normal(self) -> bool {
+                self.0.is_normal()
+            }
+
+            fn classify(self) -> FpCategory {
+                self.0.classify()
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.0.is_sign_positive()
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.0.is_sign_negative()
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Self::demote(self.promote().mul_add(a.promote(), b.promote()))
+            }
+
+            fn powi(self, n: i32) -> Self {
+                Self::demote(self.promote().powi(n))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = self.promote().sin_cos();
+                (Self::demote(sin), Self::demote(cos))
+            }
+
+            fn abs_sub(self, other: Self) -> Self {
+                Signed::abs_sub(&self, &other)
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                Float::integer_decode(self.0)
+            }
+        }
+
+        impl From<$t> for $p {
+            fn from(value: $t) -> Self {
+                $p(value)
+            }
+        }
+
+        impl From<$p> for $t {
+            fn from(value: $p) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$p> for f32 {
+            fn from(value: $p) -> Self {
+                value.promote()
+            }
+        }
+
+        impl Infinite for $p {
+            const INFINITY: Self = $p($t::INFINITY);
+            const NEG_INFINITY: Self = $p($t::NEG_INFINITY);
+
+            fn is_infinite(self) -> bool {
+                self.0.is_infinite()
+            }
+
+            fn is_finite(self) -> bool {
+                self.0.is_finite()
+            }
+        }
+
+        impl IntrinsicOrd for $p {
+            fn is_undefined(&self) -> bool {
+                self.0.is_nan()
+            }
+
+            fn min_max_or_undefined(&self, other: &Self) -> (Self, Self) {
+                if self.is_undefined() || other.is_undefined() {
+                    (<Self as Nan>::NAN, <Self as Nan>::NAN)
+                }
+                else if self <= other {
+                    (*self, *other)
+                }
+                else {
+                    (*other, *self)
+                }
+            }
+        }
+
+        impl Nan for $p {
+            const NAN: Self = $p($t::NAN);
+
+            fn is_nan(self) -> bool {
+                self.0.is_nan()
+            }
+        }
+
+        impl Num for $p {
+            type FromStrRadixErr = <f32 as Num>::FromStrRadixErr;
+
+            fn from_str_radix(source: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                f32::from_str_radix(source, radix).map(Self::demote)
+            }
+        }
+
+        impl NumCast for $p {
+            fn from<T>(value: T) -> Option<Self>
+            where
+                T: ToPrimitive,
+            {
+                value.to_f32().map(Self::demote)
+            }
+        }
+
+        impl One for $p {
+            fn one() -> Self {
+                $p($t::ONE)
+            }
+        }
+
+        impl Real for $p {
+            const E: Self = $p::demote_const(consts::E);
+            const PI: Self = $p::demote_const(consts::PI);
+            const FRAC_1_PI: Self = $p::demote_const(consts::FRAC_1_PI);
+            const FRAC_2_PI: Self = $p::demote_const(consts::FRAC_2_PI);
+            const FRAC_2_SQRT_PI: Self = $p::demote_const(consts::FRAC_2_SQRT_PI);
+            const FRAC_PI_2: Self = $p::demote_const(consts::FRAC_PI_2);
+            const FRAC_PI_3: Self = $p::demote_const(consts::FRAC_PI_3);
+            const FRAC_PI_4: Self = $p::demote_const(consts::FRAC_PI_4);
+            const FRAC_PI_6: Self = $p::demote_const(consts::FRAC_PI_6);
+            const FRAC_PI_8: Self = $p::demote_const(consts::FRAC_PI_8);
+            const SQRT_2: Self = $p::demote_const(consts::SQRT_2);
+            const FRAC_1_SQRT_2: Self = $p::demote_const(consts::FRAC_1_SQRT_2);
+            const LN_2: Self = $p::demote_const(consts::LN_2);
+            const LN_10: Self = $p::demote_const(consts::LN_10);
+            const LOG2_E: Self = $p::demote_const(consts::LOG2_E);
+            const LOG10_E: Self = $p::demote_const(consts::LOG10_E);
+
+            impl_half!(@unary floor, ceil, round, trunc, fract, recip);
+            impl_half!(@unary sqrt, cbrt, exp, exp2, exp_m1, ln, log2, log10, ln_1p);
+            impl_half!(@unary sin, cos, tan, asin, acos, atan, sinh, cosh, tanh);
+            impl_half!(@unary asinh, acosh, atanh);
+            impl_half!(@binary powf, log, hypot, atan2);
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Self::demote(self.promote().mul_add(a.promote(), b.promote()))
+            }
+
+            fn powi(self, n: i32) -> Self {
+                Self::demote(self.promote().powi(n))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = self.promote().sin_cos();
+                (Self::demote(sin), Self::demote(cos))
+            }
+        }
+
+        impl RelativeEq for $p {
+            fn default_max_relative() -> Self::Epsilon {
+                <Self as Encoding>::EPSILON
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.promote().relative_eq(
+                    &other.promote(),
+                    epsilon.promote(),
+                    max_relative.promote(),
+                )
+            }
+        }
+
+        impl Signed for $p {
+            fn abs(&self) -> Self {
+                Self::demote(self.promote().abs())
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                if self <= other {
+                    Zero::zero()
+                }
+                else {
+                    *self - *other
+                }
+            }
+
+            fn signum(&self) -> Self {
+                Self::demote(self.promote().signum())
+            }
+
+            fn is_positive(&self) -> bool {
+                self.promote().is_sign_positive() && !self.0.is_nan()
+            }
+
+            fn is_negative(&self) -> bool {
+                self.promote().is_sign_negative() && !self.0.is_nan()
+            }
+        }
+
+        impl ToPrimitive for $p {
+            fn to_i64(&self) -> Option<i64> {
+                self.promote().to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.promote().to_u64()
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                Some(self.promote())
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0.to_f64())
+            }
+        }
+
+        impl UlpsEq for $p {
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    true
+                }
+                else if self.0.is_sign_positive() != other.0.is_sign_positive() {
+                    false
+                }
+                else {
+                    let (a, b) = (self.0.to_bits(), other.0.to_bits());
+                    <u32 as From<u16>>::from(a.abs_diff(b)) <= max_ulps
+                }
+            }
+        }
+
+        impl Zero for $p {
+            fn zero() -> Self {
+                $p($t::ZERO)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.promote() == 0.0
+            }
+        }
+
+        impl Neg for $p {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $p(-self.0)
+            }
+        }
+
+        impl_half!(@operator $p, Add, add, AddAssign, add_assign);
+        impl_half!(@operator $p, Div, div, DivAssign, div_assign);
+        impl_half!(@operator $p, Mul, mul, MulAssign, mul_assign);
+        impl_half!(@operator $p, Rem, rem, RemAssign, rem_assign);
+        impl_half!(@operator $p, Sub, sub, SubAssign, sub_assign);
+    };
+    (@unary $($f:ident),+ $(,)?) => {
+        $(
+            fn $f(self) -> Self {
+                Self::demote(self.promote().$f())
+            }
+        )+
+    };
+    (@binary $($f:ident),+ $(,)?) => {
+        $(
+            fn $f(self, other: Self) -> Self {
+                Self::demote(self.promote().$f(other.promote()))
+            }
+        )+
+    };
+    (@operator $p:ident, $o:ident, $f:ident, $oa:ident, $fa:ident $(,)?) => {
+        impl $o for $p {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self::Output {
+                $p($o::$f(self.0, other.0))
+            }
+        }
+
+        impl $oa for $p {
+            fn $fa(&mut self, other: Self) {
+                $oa::$fa(&mut self.0, other.0)
+            }
+        }
+    };
+}
+
+/// IEEE-754 half-precision (`binary16`) scalar.
+///
+/// Wraps `f16` and implements `Real`, so this type can be used as the scalar of
+/// vector spaces.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct F16(pub f16);
+
+impl_half!(F16, f16);
+
+/// `bfloat16` half-precision scalar.
+///
+/// Wraps `bf16` and implements `Real`, so this type can be used as the scalar
+/// of vector spaces.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct BF16(pub bf16);
+
+impl_half!(BF16, bf16);
@@ -1,17 +1,17 @@
 #![cfg(feature = "geometry-mint")]
 
 // TODO: It is not possible to implement vector space traits for `mint` types,
-//       because they require foreign traits on foreign types.
+//       because they require foreign traits on foreign types. This includes
+//       `Matrix` and `SquareMatrix`, which require `VectorSpace`.
 // TODO: Implement as many traits as possible.
 
 use arrayvec::ArrayVec;
-use decorum::R64;
 use num::{Num, NumCast, One, Zero};
 use std::ops::Neg;
 use typenum::{U2, U3};
 
 use crate::adjunct::{Adjunct, Converged, Fold, FromItems, IntoItems, Map, ZipMap};
-use crate::ops::{Cross, Dot, Interpolate, Project};
+use crate::ops::{Cross, Dot, Factor, Interpolate, Project, Wedge};
 use crate::space::{Basis, FiniteDimensional};
 
 #[doc(hidden)]
@@ -182,13 +182,14 @@ impl<T> FromItems for Vector3<T> {
     }
 }
 
-impl<T> Interpolate for Vector2<T>
+impl<T, F> Interpolate<Self, F> for Vector2<T>
 where
+    F: Factor,
     T: Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
+    fn lerp(self, other: Self, f: F) -> Self::Output {
         Vector2 {
             x: crate::lerp(self.x, other.x, f),
             y: crate::lerp(self.y, other.y, f),
@@ -196,13 +197,14 @@ where
     }
 }
 
-impl<T> Interpolate for Vector3<T>
+impl<T, F> Interpolate<Self, F> for Vector3<T>
 where
+    F: Factor,
     T: Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
+    fn lerp(self, other: Self, f: F) -> Self::Output {
         Vector3 {
             x: crate::lerp(self.x, other.x, f),
             y: crate::lerp(self.y, other.y, f),
@@ -312,6 +314,28 @@ where
     }
 }
 
+impl<T> Wedge for Vector2<T>
+where
+    T: Copy + Num,
+{
+    type Output = T;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        (self.x * other.y) - (self.y * other.x)
+    }
+}
+
+impl<T> Wedge for Vector3<T>
+where
+    T: Copy + Neg<Output = T> + Num,
+{
+    type Output = Self;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        Cross::cross(self, other)
+    }
+}
+
 impl<T, U> ZipMap<U> for Vector2<T> {
     type Output = Vector2<U>;
 
@@ -355,13 +379,14 @@ where
     type Item = T;
 }
 
-impl<T> Interpolate for Point2<T>
+impl<T, F> Interpolate<Self, F> for Point2<T>
 where
+    F: Factor,
     T: Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
+    fn lerp(self, other: Self, f: F) -> Self::Output {
         Point2 {
             x: crate::lerp(self.x, other.x, f),
             y: crate::lerp(self.y, other.y, f),
@@ -369,13 +394,14 @@ where
     }
 }
 
-impl<T> Interpolate for Point3<T>
+impl<T, F> Interpolate<Self, F> for Point3<T>
 where
+    F: Factor,
     T: Num + NumCast,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
+    fn lerp(self, other: Self, f: F) -> Self::Output {
         Point3 {
             x: crate::lerp(self.x, other.x, f),
             y: crate::lerp(self.y, other.y, f),
@@ -383,3 +409,103 @@ where
         }
     }
 }
+
+/// Implements adjunct traits and `Interpolate` for a type that converts to and
+/// from an array of its items.
+///
+/// Items are in the same order as the array representation of the type. For
+/// example, items of `ColumnMatrix3` are in column-major order, items of
+/// `RowMatrix3` are in row-major order, and items of `Quaternion` are ordered
+/// with its vector part first.
+macro_rules! impl_array_adjunct {
+    ($t:ident, $n:literal $(,)?) => {
+        impl<T> Adjunct for $t<T> {
+            type Item = T;
+        }
+
+        impl<T> Converged for $t<T>
+        where
+            T: Clone,
+        {
+            fn converged(value: T) -> Self {
+                <[T; $n]>::converged(value).into()
+            }
+        }
+
+        impl<T> Fold for $t<T> {
+            fn fold<U, F>(self, seed: U, f: F) -> U
+            where
+                F: FnMut(U, Self::Item) -> U,
+            {
+                <[T; $n]>::from(self).fold(seed, f)
+            }
+        }
+
+        impl<T> FromItems for $t<T>
+        where
+            T: Clone,
+        {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                <[T; $n]>::from_items(items).map(Self::from)
+            }
+        }
+
+        impl<T, F> Interpolate<Self, F> for $t<T>
+        where
+            F: Factor,
+            T: Clone + Num + NumCast,
+        {
+            type Output = Self;
+
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                self.zip_map(other, crate::lerp_by(f))
+            }
+        }
+
+        impl<T> IntoItems for $t<T> {
+            type Output = [T; $n];
+
+            fn into_items(self) -> Self::Output {
+                self.into()
+            }
+        }
+
+        impl<T, U> Map<U> for $t<T>
+        where
+            U: Clone,
+        {
+            type Output = $t<U>;
+
+            fn map<F>(self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> U,
+            {
+                <[T; $n]>::from(self).map(f).into()
+            }
+        }
+
+        impl<T, U> ZipMap<U> for $t<T>
+        where
+            U: Clone,
+        {
+            type Output = $t<U>;
+
+            fn zip_map<F>(self, other: Self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> U,
+            {
+                <[T; $n]>::from(self).zip_map(other.into(), f).into()
+            }
+        }
+    };
+}
+impl_array_adjunct!(Quaternion, 4);
+impl_array_adjunct!(ColumnMatrix2, 4);
+impl_array_adjunct!(ColumnMatrix3, 9);
+impl_array_adjunct!(ColumnMatrix4, 16);
+impl_array_adjunct!(RowMatrix2, 4);
+impl_array_adjunct!(RowMatrix3, 9);
+impl_array_adjunct!(RowMatrix4, 16);
@@ -14,7 +14,17 @@ pub mod decorum {
 
 // Feature modules. These are empty unless Cargo features are enabled.
 pub mod cgmath;
+pub mod euclid;
+pub mod fixed;
+pub mod geo;
 pub mod glam;
+pub mod half;
 pub mod mint;
 pub mod nalgebra;
+pub mod ndarray;
+pub mod parry;
+pub mod rational;
+pub mod rstar;
 pub mod ultraviolet;
+pub mod vek;
+pub mod wide;
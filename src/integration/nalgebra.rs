@@ -1,58 +1,69 @@
 #![cfg(feature = "geometry-nalgebra")]
+// Imports in this module (e.g., the `Matrix` trait) shadow items of the same
+// name that are re-exported from `nalgebra`. This is intentional.
+#![allow(hidden_glob_reexports)]
 
-use approx::AbsDiffEq;
 use arrayvec::ArrayVec;
-use decorum::{Real, R64};
+use decorum::R64;
 use nalgebra::base::allocator::Allocator;
 use nalgebra::base::default_allocator::DefaultAllocator;
 use nalgebra::base::dimension::{
-    DimName, DimNameAdd, DimNameDiff, DimNameMax, DimNameMaximum, DimNameMin, DimNameSub,
+    Dim, DimName, DimNameAdd, DimNameDiff, DimNameMax, DimNameMaximum, DimNameMin, DimNameSub,
     DimNameSum, U1,
 };
 use num::{Num, NumCast, One, Zero};
-use std::ops::{AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::array;
+use std::iter;
+use std::ops::{AddAssign, Mul, MulAssign, Neg, Range, Sub};
+use std::slice;
 use typenum::NonZero;
 
 use crate::adjunct::{
-    Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, Truncate, ZipMap,
+    Adjunct, AsItems, Converged, Extend, Fold, FoldRef, FromItems, IntoItems, IterItems, Map,
+    MapAssign, MapRef, Truncate, ZipMap, ZipMap3,
+};
+use crate::ops::{
+    Cross, Decompose, Dot, Factor, Interpolate, MulMN, MulV, Outer, Real, Rotate, Wedge,
 };
-use crate::ops::{Cross, Dot, Interpolate, MulMN};
 use crate::space::{
-    AffineSpace, Basis, DualSpace, EuclideanSpace, FiniteDimensional, Homogeneous, InnerSpace,
-    Matrix, SquareMatrix, VectorSpace,
+    self, AffineSpace, Basis, CoordinateLayout, DualSpace, DynDimensional, DynVectorSpace,
+    EuclideanSpace, ExtendMatrix, FiniteDimensional, Homogeneous, InnerSpace, Matrix, RigidMotion,
+    SquareMatrix, Transform, TruncateMatrix, VectorSpace,
 };
-use crate::{AsPosition, AsPositionMut};
+use crate::{AsPosition, AsPositionMut, RealScalar, RealScalarAssign};
 
 #[doc(hidden)]
 pub use nalgebra::*;
 
-impl<T, R, C> Adjunct for MatrixMN<T, R, C>
+mod simd;
+
+// Some implementations for matrices only require `Dim` rather than `DimName`,
+// so dynamically sized types like `DVector` and `DMatrix` implement these
+// traits. Such types are not `Copy` and their dimensions are not known
+// statically, so they cannot implement `Converged`, `FromItems`, nor vector
+// space traits. Operations like `ZipMap` panic if dimensions differ.
+
+impl<T, R, C> Adjunct for OMatrix<T, R, C>
 where
     T: Scalar,
-    R: DimName,
-    C: DimName,
+    R: Dim,
+    C: Dim,
     DefaultAllocator: Allocator<T, R, C>,
 {
     type Item = T;
 }
 
-impl<T, D> Basis for VectorN<T, D>
+impl<T, D> Basis for OVector<T, D>
 where
     T: One + Scalar + Zero,
     D: DimName,
     DefaultAllocator: Allocator<T, D>,
     Self: FiniteDimensional,
 {
-    type Bases = Vec<Self>;
+    type Bases = iter::Map<Range<usize>, fn(usize) -> Self>;
 
     fn canonical_basis() -> Self::Bases {
-        (0..D::dim())
-            .map(|dimension| {
-                let mut basis = Self::zeros();
-                *basis.get_mut(dimension).unwrap() = T::one();
-                basis
-            })
-            .collect()
+        (0..D::dim()).map(|dimension| Self::canonical_basis_component(dimension).unwrap())
     }
 
     fn canonical_basis_component(index: usize) -> Option<Self> {
@@ -67,7 +78,7 @@ where
     }
 }
 
-impl<T, R, C> Converged for MatrixMN<T, R, C>
+impl<T, R, C> Converged for OMatrix<T, R, C>
 where
     T: Scalar,
     R: DimName,
@@ -81,81 +92,111 @@ where
 
 impl<T> Cross for Vector3<T>
 where
-    // TODO: Is the `Copy` requirement too strict? See `Fold` implementation.
-    T: Copy + Num + Scalar,
+    T: Num + Scalar,
     <<T as Mul>::Output as Sub>::Output: Neg<Output = T>,
 {
     type Output = Self;
 
     fn cross(self, other: Self) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(cross) = simd::cross(&self, &other) {
+            return cross;
+        }
         let [ax, ay, az]: [T; 3] = self.into();
         let [bx, by, bz]: [T; 3] = other.into();
         Vector3::new(
-            (ay * bz) - (az * by),
-            (az * bx) - (ax * bz),
+            (ay.clone() * bz.clone()) - (az.clone() * by.clone()),
+            (az * bx.clone()) - (ax.clone() * bz),
             (ax * by) - (ay * bx),
         )
     }
 }
 
-impl<T, D> Dot for VectorN<T, D>
+impl<T, D> Dot for OVector<T, D>
 where
     T: AddAssign + MulAssign + Num + Scalar,
-    D: DimName,
+    D: Dim,
     DefaultAllocator: Allocator<T, D>,
 {
     type Output = T;
 
     fn dot(self, other: Self) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(dot) = simd::dot(&self, &other) {
+            return dot;
+        }
         nalgebra::Matrix::dot(&self, &other)
     }
 }
 
-impl<T, R, C> DualSpace for MatrixMN<T, R, C>
+impl<T, R, C> DualSpace for OMatrix<T, R, C>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalarAssign + Scalar,
     R: DimName + DimNameMin<C, Output = U1>,
     C: DimName + DimNameMin<R, Output = U1>,
     DefaultAllocator: Allocator<T, R, C> + Allocator<T, C, R>,
-    MatrixMN<T, C, R>: Copy + FiniteDimensional<N = <Self as FiniteDimensional>::N>,
+    OMatrix<T, C, R>: Copy + FiniteDimensional<N = <Self as FiniteDimensional>::N>,
     Self: Copy + FiniteDimensional,
 {
-    type Dual = MatrixMN<T, C, R>;
+    type Dual = OMatrix<T, C, R>;
 
     fn transpose(self) -> Self::Dual {
         nalgebra::Matrix::transpose(&self)
     }
 }
 
-impl<T, D> Extend<VectorN<T, DimNameSum<D, U1>>> for VectorN<T, D>
+impl<T, D> Extend<OVector<T, DimNameSum<D, U1>>> for OVector<T, D>
 where
     T: AddAssign + MulAssign + Real + Scalar,
     D: DimName + DimNameAdd<U1>,
     DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>>,
 {
-    fn extend(self, x: T) -> VectorN<T, DimNameSum<D, U1>> {
-        VectorN::<_, DimNameSum<D, _>>::from_iterator(self.into_iter().cloned().chain(Some(x)))
+    fn extend(self, x: T) -> OVector<T, DimNameSum<D, U1>> {
+        OVector::<_, DimNameSum<D, _>>::from_iterator(self.into_iter().cloned().chain(Some(x)))
     }
 }
 
-impl<T, R, C> FiniteDimensional for MatrixMN<T, R, C>
+impl<T, D> ExtendMatrix<OMatrix<T, DimNameSum<D, U1>, DimNameSum<D, U1>>> for OMatrix<T, D, D>
+where
+    T: Real + Scalar,
+    D: DimName + DimNameAdd<U1>,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, DimNameSum<D, U1>, DimNameSum<D, U1>>,
+    Self: Matrix<Scalar = T>,
+    OMatrix<T, DimNameSum<D, U1>, DimNameSum<D, U1>>: Matrix<Scalar = T>,
+{
+    fn extend_matrix(self) -> OMatrix<T, DimNameSum<D, U1>, DimNameSum<D, U1>> {
+        let n = D::dim();
+        OMatrix::<T, DimNameSum<D, U1>, DimNameSum<D, U1>>::from_fn(|row, column| {
+            if row < n && column < n {
+                self[(row, column)]
+            }
+            else if row == column {
+                T::one()
+            }
+            else {
+                T::zero()
+            }
+        })
+    }
+}
+
+impl<T, R, C> FiniteDimensional for OMatrix<T, R, C>
 where
     T: Scalar,
     R: DimName + DimNameMax<C> + DimNameMin<C, Output = U1> + ToTypenum,
     <DimNameMaximum<R, C> as ToTypenum>::Typenum: NonZero,
     C: DimName + ToTypenum,
     DefaultAllocator: Allocator<T, R, C>,
-    <R as nalgebra::DimNameMax<C>>::Output: nalgebra::ToTypenum
+    <R as nalgebra::DimNameMax<C>>::Output: nalgebra::ToTypenum,
 {
     type N = <DimNameMaximum<R, C> as ToTypenum>::Typenum;
 }
 
-impl<T, R, C> Fold for MatrixMN<T, R, C>
+impl<T, R, C> Fold for OMatrix<T, R, C>
 where
-    // TODO: Re-examine adjunct traits that take items by value.
-    T: Clone + Scalar,
-    R: DimName,
-    C: DimName,
+    T: Scalar,
+    R: Dim,
+    C: Dim,
     DefaultAllocator: Allocator<T, R, C>,
 {
     fn fold<U, F>(self, mut seed: U, mut f: F) -> U
@@ -169,139 +210,291 @@ where
     }
 }
 
-impl<T, R, C> FromItems for MatrixMN<T, R, C>
+impl<T, R, C> FoldRef for OMatrix<T, R, C>
 where
     T: Scalar,
-    R: DimName,
-    C: DimName,
+    R: Dim,
+    C: Dim,
     DefaultAllocator: Allocator<T, R, C>,
 {
-    fn from_items<I>(items: I) -> Option<Self>
+    fn fold_ref<U, F>(&self, seed: U, f: F) -> U
     where
-        I: IntoIterator<Item = Self::Item>,
+        F: FnMut(U, &Self::Item) -> U,
     {
-        Some(Self::from_iterator(items))
+        self.iter().fold(seed, f)
     }
 }
 
-impl<T> Homogeneous for Vector2<T>
+impl<T, R, C> FromItems for OMatrix<T, R, C>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: Scalar,
+    R: DimName,
+    C: DimName,
+    DefaultAllocator: Allocator<T, R, C>,
 {
-    type ProjectiveSpace = Vector3<T>;
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        // `from_iterator` panics if there are too few items, so any missing
+        // items are filled and then detected by counting.
+        let n = R::dim() * C::dim();
+        let mut items = items.into_iter().take(n).peekable();
+        let filler = match items.peek() {
+            Some(item) => item.clone(),
+            None => {
+                return if n == 0 {
+                    Some(Self::from_iterator(iter::empty()))
+                }
+                else {
+                    None
+                };
+            }
+        };
+        let mut count = 0;
+        let output = Self::from_iterator(items.inspect(|_| count += 1).chain(iter::repeat(filler)));
+        if count == n {
+            Some(output)
+        }
+        else {
+            None
+        }
+    }
 }
 
-impl<T> Homogeneous for Vector3<T>
+impl<T, D> Homogeneous for OVector<T, D>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalarAssign + Scalar,
+    D: DimName + DimNameAdd<U1>,
+    DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>>,
+    Self: Copy + FiniteDimensional,
+    OVector<T, DimNameSum<D, U1>>: Copy + FiniteDimensional,
 {
-    type ProjectiveSpace = Vector4<T>;
+    type ProjectiveSpace = OVector<T, DimNameSum<D, U1>>;
 }
 
-impl<T, D> InnerSpace for VectorN<T, D>
+impl<T, D> InnerSpace for OVector<T, D>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalarAssign + Scalar,
     D: DimName,
     DefaultAllocator: Allocator<T, D>,
     Self: Copy,
 {
 }
 
-impl<T, R, C> Interpolate for MatrixMN<T, R, C>
+impl<T, R, C, F> Interpolate<Self, F> for OMatrix<T, R, C>
 where
+    F: Factor,
     T: Num + NumCast + Scalar,
-    R: DimName,
-    C: DimName,
+    R: Dim,
+    C: Dim,
     DefaultAllocator: Allocator<T, R, C>,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        MatrixMN::<T, R, C>::zip_map(&self, &other, |a, b| crate::lerp(a, b, f))
+    fn lerp(self, other: Self, f: F) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(lerp) = simd::lerp(&self, &other, f) {
+            return lerp;
+        }
+        OMatrix::<T, R, C>::zip_map(&self, &other, crate::lerp_by(f))
     }
 }
 
-impl<T> IntoItems for Vector2<T>
+impl<T, R, C> AsItems for OMatrix<T, R, C>
 where
     T: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C>,
 {
-    type Output = ArrayVec<[T; 2]>;
-
-    fn into_items(self) -> Self::Output {
-        let array: [T; 2] = self.into();
-        array.into()
+    fn as_items(&self) -> &[Self::Item] {
+        self.as_slice()
     }
 }
 
-impl<T> IntoItems for Vector3<T>
+impl<T, const R: usize, const C: usize> IntoItems for SMatrix<T, R, C>
 where
     T: Scalar,
 {
-    type Output = ArrayVec<[T; 3]>;
+    type Output = iter::Flatten<array::IntoIter<[T; R], C>>;
 
     fn into_items(self) -> Self::Output {
-        let array: [T; 3] = self.into();
-        array.into()
+        IntoIterator::into_iter(self.data.0).flatten()
     }
 }
 
-impl<T, U, R, C> Map<U> for MatrixMN<T, R, C>
+impl<T> DynDimensional for DVector<T>
 where
     T: Scalar,
-    U: Scalar,
-    R: DimName,
-    C: DimName,
-    DefaultAllocator: Allocator<T, R, C> + Allocator<U, R, C>,
 {
-    type Output = MatrixMN<U, R, C>;
-
-    fn map<F>(self, f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> U,
-    {
-        MatrixMN::<T, R, C>::map(&self, f)
+    fn dimensions(&self) -> usize {
+        self.len()
     }
 }
 
-// TODO: Use a (more) generic implementation.
-impl<T> Matrix for Matrix2<T>
+impl<T> DynVectorSpace for DVector<T>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalar + Scalar,
 {
-    type Row = RowVector2<T>;
-    type Column = Vector2<T>;
-    type Transpose = Self;
+    type Scalar = T;
 
-    fn row_component(&self, index: usize) -> Option<Self::Row> {
-        if index < <Self as Matrix>::row_count() {
-            Some(nalgebra::Matrix::row(self, index).into_owned())
+    fn zero(dimensions: usize) -> Self {
+        DVector::from_element(dimensions, Zero::zero())
+    }
+
+    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+        self.get(index).copied()
+    }
+
+    fn map_scalars<F>(self, f: F) -> Self
+    where
+        F: FnMut(Self::Scalar) -> Self::Scalar,
+    {
+        DVector::map(&self, f)
+    }
+
+    fn checked_zip_map<F>(self, other: Self, f: F) -> Option<Self>
+    where
+        F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar,
+    {
+        if self.len() == other.len() {
+            Some(DVector::zip_map(&self, &other, f))
         }
         else {
             None
         }
     }
 
-    fn column_component(&self, index: usize) -> Option<Self::Column> {
-        if index < <Self as Matrix>::column_count() {
-            Some(nalgebra::Matrix::column(self, index).into_owned())
+    fn checked_dot(&self, other: &Self) -> Option<Self::Scalar> {
+        if self.len() == other.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .fold(Zero::zero(), |dot, (a, b)| dot + (*a * *b)),
+            )
         }
         else {
             None
         }
     }
+}
+
+impl<T> IntoItems for DMatrix<T>
+where
+    T: Scalar,
+{
+    type Output = Vec<T>;
+
+    fn into_items(self) -> Self::Output {
+        self.data.into()
+    }
+}
 
-    fn transpose(self) -> Self::Transpose {
-        nalgebra::Matrix::transpose(&self)
+impl<T> IntoItems for DVector<T>
+where
+    T: Scalar,
+{
+    type Output = Vec<T>;
+
+    fn into_items(self) -> Self::Output {
+        self.data.into()
     }
 }
 
-impl<T> Matrix for Matrix3<T>
+impl<T, R, C> IterItems for OMatrix<T, R, C>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C>,
 {
-    type Row = RowVector3<T>;
-    type Column = Vector3<T>;
-    type Transpose = Self;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
+
+    fn iter_items(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T, U, R, C> Map<U> for OMatrix<T, R, C>
+where
+    T: Scalar,
+    U: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C> + Allocator<U, R, C>,
+{
+    type Output = OMatrix<U, R, C>;
+
+    fn map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        OMatrix::<T, R, C>::map(&self, f)
+    }
+}
+
+impl<T, R, C> MapAssign for OMatrix<T, R, C>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    fn map_assign<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Self::Item),
+    {
+        self.iter_mut().for_each(f)
+    }
+}
+
+impl<T, U, R, C> MapRef<U> for OMatrix<T, R, C>
+where
+    T: Scalar,
+    U: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C> + Allocator<U, R, C>,
+{
+    type Output = OMatrix<U, R, C>;
+
+    fn map_ref<F>(&self, f: F) -> Self::Output
+    where
+        F: FnMut(&Self::Item) -> U,
+    {
+        OMatrix::from_iterator_generic(
+            self.shape_generic().0,
+            self.shape_generic().1,
+            self.iter().map(f),
+        )
+    }
+}
+
+impl<T, R, C> Matrix for OMatrix<T, R, C>
+where
+    T: RealScalarAssign + Scalar,
+    R: DimName,
+    C: DimName,
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, C, R>
+        + Allocator<T, U1, C>
+        + Allocator<T, C, U1>
+        + Allocator<T, U1, R>
+        + Allocator<T, R, U1>,
+    OMatrix<T, U1, C>: DualSpace<Dual = OMatrix<T, C, U1>> + VectorSpace<Scalar = T>,
+    OMatrix<T, C, U1>: DualSpace<Dual = OMatrix<T, U1, C>> + VectorSpace<Scalar = T>,
+    OMatrix<T, U1, R>: DualSpace<Dual = OMatrix<T, R, U1>> + VectorSpace<Scalar = T>,
+    OMatrix<T, R, U1>: DualSpace<Dual = OMatrix<T, U1, R>> + VectorSpace<Scalar = T>,
+    OMatrix<T, C, R>: VectorSpace<Scalar = T>,
+    Self: VectorSpace<Scalar = T>,
+{
+    type Row = OMatrix<T, U1, C>;
+    type Column = OMatrix<T, R, U1>;
+    type Transpose = OMatrix<T, C, R>;
 
     fn row_component(&self, index: usize) -> Option<Self::Row> {
         if index < <Self as Matrix>::row_count() {
@@ -326,67 +519,115 @@ where
     }
 }
 
-// TODO: Use a (more) generic implementation.
-impl<T> MulMN<Matrix2<T>> for Matrix2<T>
+impl<T, R, C, K> MulMN<OMatrix<T, C, K>> for OMatrix<T, R, C>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalarAssign + Scalar,
+    R: DimName,
+    C: DimName,
+    K: DimName,
+    DefaultAllocator: Allocator<T, R, C>
+        + Allocator<T, C, K>
+        + Allocator<T, R, K>
+        + Allocator<T, U1, C>
+        + Allocator<T, C, U1>,
+    OMatrix<T, C, K>: Matrix<Scalar = T, Column = OMatrix<T, C, U1>>,
+    OMatrix<T, R, K>: Matrix<Scalar = T>,
+    Self: Matrix<Scalar = T, Row = OMatrix<T, U1, C>>
+        + Mul<OMatrix<T, C, K>, Output = OMatrix<T, R, K>>,
+    OMatrix<T, U1, C>: DualSpace<Dual = OMatrix<T, C, U1>>,
+    OMatrix<T, C, U1>: FiniteDimensional<N = <OMatrix<T, U1, C> as FiniteDimensional>::N>
+        + VectorSpace<Scalar = T>,
 {
-    type Output = Matrix2<T>;
+    type Output = OMatrix<T, R, K>;
 
-    fn mul_mn(self, other: Matrix2<T>) -> <Self as MulMN<Matrix2<T>>>::Output {
+    fn mul_mn(self, other: OMatrix<T, C, K>) -> <Self as MulMN<OMatrix<T, C, K>>>::Output {
         self * other
     }
 }
 
-impl<T> MulMN<Matrix3<T>> for Matrix3<T>
+impl<T, R, C> MulV<OVector<T, C>> for OMatrix<T, R, C>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, C> + Allocator<T, R>,
+    Self: Mul<OVector<T, C>, Output = OVector<T, R>>,
 {
-    type Output = Matrix3<T>;
+    type Output = OVector<T, R>;
 
-    fn mul_mn(self, other: Matrix3<T>) -> <Self as MulMN<Matrix3<T>>>::Output {
-        self * other
+    fn mul_v(self, vector: OVector<T, C>) -> Self::Output {
+        self * vector
     }
 }
 
-impl<T> SquareMatrix for Matrix2<T>
+impl<T, R, C> Outer<OVector<T, C>> for OVector<T, R>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalarAssign + Scalar,
+    R: DimName,
+    C: DimName,
+    DefaultAllocator: Allocator<T, R> + Allocator<T, C> + Allocator<T, U1, C> + Allocator<T, R, C>,
+    OMatrix<T, R, C>: Matrix<Scalar = T>,
+    OVector<T, R>: FiniteDimensional + VectorSpace<Scalar = T>,
+    OVector<T, C>: FiniteDimensional + VectorSpace<Scalar = T>,
 {
-    fn multiplicative_identity() -> Self {
-        nalgebra::Matrix2::<T>::identity()
+    type Output = OMatrix<T, R, C>;
+
+    fn outer(self, other: OVector<T, C>) -> <Self as Outer<OVector<T, C>>>::Output {
+        OMatrix::<T, R, C>::from_fn(|i, j| self[i] * other[j])
     }
 }
 
-impl<T> SquareMatrix for Matrix3<T>
+impl<T, D> SquareMatrix for OMatrix<T, D, D>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalarAssign + Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, U1, D> + Allocator<T, D, U1>,
+    OMatrix<T, U1, D>: FiniteDimensional<N = <OVector<T, D> as FiniteDimensional>::N>,
+    Self: Matrix<Scalar = T, Row = OMatrix<T, U1, D>, Column = OVector<T, D>>
+        + Mul<Output = Self>
+        + Mul<OVector<T, D>, Output = OVector<T, D>>,
+    OVector<T, D>: DualSpace<Dual = OMatrix<T, U1, D>>,
 {
     fn multiplicative_identity() -> Self {
-        nalgebra::Matrix3::<T>::identity()
+        OMatrix::<T, D, D>::identity()
+    }
+}
+
+impl<T, D> TruncateMatrix<OMatrix<T, DimNameDiff<D, U1>, DimNameDiff<D, U1>>> for OMatrix<T, D, D>
+where
+    T: Real + Scalar,
+    D: DimName + DimNameSub<U1>,
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, DimNameDiff<D, U1>, DimNameDiff<D, U1>>,
+    Self: Matrix<Scalar = T>,
+    OMatrix<T, DimNameDiff<D, U1>, DimNameDiff<D, U1>>: Matrix<Scalar = T>,
+{
+    fn truncate_matrix(self) -> OMatrix<T, DimNameDiff<D, U1>, DimNameDiff<D, U1>> {
+        OMatrix::<T, DimNameDiff<D, U1>, DimNameDiff<D, U1>>::from_fn(|row, column| {
+            self[(row, column)]
+        })
     }
 }
 
-impl<T, D> Truncate<VectorN<T, DimNameDiff<D, U1>>> for VectorN<T, D>
+impl<T, D> Truncate<OVector<T, DimNameDiff<D, U1>>> for OVector<T, D>
 where
     T: Real + Scalar,
     D: DimName + DimNameSub<U1>,
     DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameDiff<D, U1>>,
 {
-    fn truncate(self) -> (VectorN<T, DimNameDiff<D, U1>>, T) {
+    fn truncate(self) -> (OVector<T, DimNameDiff<D, U1>>, T) {
         let n = self.len();
         let x = *self.get(n - 1).unwrap();
         (
-            VectorN::<_, DimNameDiff<D, _>>::from_iterator(self.into_iter().take(n - 1).cloned()),
+            OVector::<_, DimNameDiff<D, _>>::from_iterator(self.into_iter().take(n - 1).cloned()),
             x,
         )
     }
 }
 
 // TODO: This is too general. Only "linear" types should implement this.
-impl<T, R, C> VectorSpace for MatrixMN<T, R, C>
+impl<T, R, C> VectorSpace for OMatrix<T, R, C>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar,
+    T: RealScalarAssign + Scalar,
     R: DimName,
     C: DimName,
     DefaultAllocator: Allocator<T, R, C>,
@@ -399,21 +640,64 @@ where
     }
 }
 
-impl<T, U, R, C> ZipMap<U> for MatrixMN<T, R, C>
+impl<T> Wedge for Vector2<T>
+where
+    T: Copy + Num + Scalar,
+{
+    type Output = T;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        let [ax, ay]: [T; 2] = self.into();
+        let [bx, by]: [T; 2] = other.into();
+        (ax * by) - (ay * bx)
+    }
+}
+
+impl<T> Wedge for Vector3<T>
+where
+    T: Copy + Num + Scalar,
+    <<T as Mul>::Output as Sub>::Output: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        Cross::cross(self, other)
+    }
+}
+
+impl<T, U, R, C> ZipMap<U> for OMatrix<T, R, C>
 where
     T: Scalar,
     U: Scalar,
-    R: DimName,
-    C: DimName,
+    R: Dim,
+    C: Dim,
     DefaultAllocator: Allocator<T, R, C> + Allocator<U, R, C>,
 {
-    type Output = MatrixMN<U, R, C>;
+    type Output = OMatrix<U, R, C>;
 
     fn zip_map<F>(self, other: Self, f: F) -> Self::Output
     where
         F: FnMut(Self::Item, Self::Item) -> U,
     {
-        MatrixMN::<T, R, C>::zip_map(&self, &other, f)
+        OMatrix::<T, R, C>::zip_map(&self, &other, f)
+    }
+}
+
+impl<T, U, R, C> ZipMap3<U> for OMatrix<T, R, C>
+where
+    T: Scalar,
+    U: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C> + Allocator<U, R, C>,
+{
+    type Output = OMatrix<U, R, C>;
+
+    fn zip_map3<F>(self, b: Self, c: Self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item, Self::Item) -> U,
+    {
+        OMatrix::<T, R, C>::zip_zip_map(&self, &b, &c, f)
     }
 }
 
@@ -428,12 +712,12 @@ where
 
 impl<T, D> AffineSpace for OPoint<T, D>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar + SubAssign,
+    T: RealScalarAssign + Scalar,
     D: DimName,
     DefaultAllocator: Allocator<T, D>,
     <DefaultAllocator as Allocator<T, D>>::Buffer: Copy,
 {
-    type Translation = VectorN<T, D>;
+    type Translation = OVector<T, D>;
 }
 
 impl<T, D> AsPosition for OPoint<T, D>
@@ -469,7 +753,7 @@ where
     DefaultAllocator: Allocator<T, D>,
 {
     fn converged(value: Self::Item) -> Self {
-        OPoint::from(VectorN::<T, D>::converged(value))
+        OPoint::from(OVector::<T, D>::converged(value))
     }
 }
 
@@ -478,23 +762,33 @@ where
     T: Scalar,
     D: DimName + DimNameAdd<U1>,
     DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>>,
-    VectorN<T, D>: Adjunct<Item = T> + Extend<VectorN<T, DimNameSum<D, U1>>>,
+    OVector<T, D>: Adjunct<Item = T> + Extend<OVector<T, DimNameSum<D, U1>>>,
 {
     fn extend(self, x: T) -> OPoint<T, DimNameSum<D, U1>> {
         self.coords.extend(x).into()
     }
 }
 
+// `OPoint` is a `repr(C)` structure with a single `OVector` field.
+unsafe impl<T, D> CoordinateLayout for OPoint<T, D>
+where
+    Self: EuclideanSpace,
+    T: Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D>,
+{
+}
+
 impl<T, D> EuclideanSpace for OPoint<T, D>
 where
-    T: AbsDiffEq + AddAssign + MulAssign + NumCast + Real + Scalar + SubAssign,
+    T: RealScalarAssign + Scalar,
     D: DimName + ToTypenum,
     D::Typenum: NonZero,
     DefaultAllocator: Allocator<T, D>,
     <DefaultAllocator as Allocator<T, D>>::Buffer: Copy,
-    VectorN<T, D>: FiniteDimensional<N = Self::N>,
+    OVector<T, D>: FiniteDimensional<N = Self::N>,
 {
-    type CoordinateSpace = VectorN<T, D>;
+    type CoordinateSpace = OVector<T, D>;
 
     fn origin() -> Self {
         OPoint::<T, D>::origin()
@@ -529,6 +823,20 @@ where
     }
 }
 
+impl<T, D> FoldRef for OPoint<T, D>
+where
+    T: Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D>,
+{
+    fn fold_ref<U, F>(&self, seed: U, f: F) -> U
+    where
+        F: FnMut(U, &Self::Item) -> U,
+    {
+        self.coords.fold_ref(seed, f)
+    }
+}
+
 impl<T, D> FromItems for OPoint<T, D>
 where
     T: Scalar,
@@ -539,44 +847,59 @@ where
     where
         I: IntoIterator<Item = Self::Item>,
     {
-        Some(OPoint::from(VectorN::from_iterator(items)))
+        OVector::<T, D>::from_items(items).map(OPoint::from)
     }
 }
 
-impl<T, D> Interpolate for OPoint<T, D>
+impl<T, D, F> Interpolate<Self, F> for OPoint<T, D>
 where
+    F: Factor,
     T: Num + NumCast + Scalar,
     D: DimName,
     DefaultAllocator: Allocator<T, D>,
 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
+    fn lerp(self, other: Self, f: F) -> Self::Output {
         OPoint::from(self.coords.lerp(other.coords, f))
     }
 }
 
-impl<T> IntoItems for Point2<T>
+impl<T, D> AsItems for OPoint<T, D>
+where
+    T: Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D>,
+{
+    fn as_items(&self) -> &[Self::Item] {
+        self.coords.as_items()
+    }
+}
+
+impl<T, const D: usize> IntoItems for Point<T, D>
 where
     T: Scalar,
 {
-    type Output = ArrayVec<[T; 2]>;
+    type Output = <SVector<T, D> as IntoItems>::Output;
 
     fn into_items(self) -> Self::Output {
-        let array: [T; 2] = self.coords.into();
-        array.into()
+        self.coords.into_items()
     }
 }
 
-impl<T> IntoItems for Point3<T>
+impl<T, D> IterItems for OPoint<T, D>
 where
     T: Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D>,
 {
-    type Output = ArrayVec<[T; 3]>;
+    type Iter<'a>
+        = slice::Iter<'a, T>
+    where
+        Self: 'a;
 
-    fn into_items(self) -> Self::Output {
-        let array: [T; 3] = self.coords.into();
-        array.into()
+    fn iter_items(&self) -> Self::Iter<'_> {
+        self.coords.iter_items()
     }
 }
 
@@ -597,12 +920,43 @@ where
     }
 }
 
+impl<T, D> MapAssign for OPoint<T, D>
+where
+    T: Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D>,
+{
+    fn map_assign<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Self::Item),
+    {
+        self.coords.map_assign(f)
+    }
+}
+
+impl<T, U, D> MapRef<U> for OPoint<T, D>
+where
+    T: Scalar,
+    U: Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D> + Allocator<U, D>,
+{
+    type Output = OPoint<U, D>;
+
+    fn map_ref<F>(&self, f: F) -> Self::Output
+    where
+        F: FnMut(&Self::Item) -> U,
+    {
+        OPoint::from(self.coords.map_ref(f))
+    }
+}
+
 impl<T, D> Truncate<OPoint<T, DimNameDiff<D, U1>>> for OPoint<T, D>
 where
     T: Scalar,
     D: DimName + DimNameSub<U1>,
     DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameDiff<D, U1>>,
-    VectorN<T, D>: Adjunct<Item = T> + Truncate<VectorN<T, DimNameDiff<D, U1>>>,
+    OVector<T, D>: Adjunct<Item = T> + Truncate<OVector<T, DimNameDiff<D, U1>>>,
 {
     fn truncate(self) -> (OPoint<T, DimNameDiff<D, U1>>, T) {
         let (vector, x) = self.coords.truncate();
@@ -626,3 +980,294 @@ where
         OPoint::from(self.coords.zip_map(other.coords, f))
     }
 }
+
+impl<T, U, D> ZipMap3<U> for OPoint<T, D>
+where
+    T: Scalar,
+    U: Scalar,
+    D: DimName,
+    DefaultAllocator: Allocator<T, D> + Allocator<U, D>,
+{
+    type Output = OPoint<U, D>;
+
+    fn zip_map3<F>(self, b: Self, c: Self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item, Self::Item) -> U,
+    {
+        OPoint::from(self.coords.zip_map3(b.coords, c.coords, f))
+    }
+}
+
+/// Implements `Rotate` for a rotation type and the points and vectors that it
+/// rotates.
+macro_rules! impl_rotate {
+    ($r:ident => $($t:ident),+ $(,)?) => {
+        impl<T> Rotate<$r<T>> for $r<T>
+        where
+            T: RealField,
+        {
+            type Output = Self;
+
+            fn rotate(self, rotation: $r<T>) -> Self::Output {
+                rotation * self
+            }
+        }
+
+        $(
+            impl<T> Rotate<$r<T>> for $t<T>
+            where
+                T: RealField,
+            {
+                type Output = Self;
+
+                fn rotate(self, rotation: $r<T>) -> Self::Output {
+                    rotation * self
+                }
+            }
+        )+
+    };
+}
+impl_rotate!(Rotation2 => Point2, Vector2);
+impl_rotate!(Rotation3 => Point3, Vector3);
+impl_rotate!(UnitComplex => Point2, Vector2);
+impl_rotate!(UnitQuaternion => Point3, Vector3);
+
+/// Implements `Rotation` for a rotation type.
+macro_rules! impl_rotation {
+    ($r:ident => $p:ident, $v:ident $(,)?) => {
+        impl<T> space::Rotation<$p<T>> for $r<T>
+        where
+            T: RealField + RealScalar,
+        {
+            fn identity() -> Self {
+                $r::identity()
+            }
+
+            fn rotate_point(&self, point: $p<T>) -> $p<T> {
+                $r::transform_point(self, &point)
+            }
+
+            fn rotate_vector(&self, vector: $v<T>) -> $v<T> {
+                $r::transform_vector(self, &vector)
+            }
+
+            fn compose(self, other: Self) -> Self {
+                other * self
+            }
+
+            fn inverse(self) -> Self {
+                $r::inverse(&self)
+            }
+        }
+    };
+}
+impl_rotation!(Rotation2 => Point2, Vector2);
+impl_rotation!(Rotation3 => Point3, Vector3);
+impl_rotation!(UnitComplex => Point2, Vector2);
+impl_rotation!(UnitQuaternion => Point3, Vector3);
+
+impl<T> space::Quaternion for Quaternion<T>
+where
+    T: RealField + RealScalar,
+{
+    type Vector = Vector3<T>;
+    type Matrix = Matrix3<T>;
+
+    fn from_axis_angle(axis: Self::Vector, angle: T) -> Option<Self> {
+        if axis.norm_squared().is_zero() {
+            None
+        }
+        else {
+            Some(UnitQuaternion::from_axis_angle(&Unit::new_normalize(axis), angle).into_inner())
+        }
+    }
+
+    fn conjugate(self) -> Self {
+        Quaternion::conjugate(&self)
+    }
+
+    fn normalize(self) -> Option<Self> {
+        if self.norm_squared().is_zero() {
+            None
+        }
+        else {
+            Some(Quaternion::normalize(&self))
+        }
+    }
+
+    fn into_rotation_matrix(self) -> Self::Matrix {
+        UnitQuaternion::new_unchecked(self)
+            .to_rotation_matrix()
+            .into_inner()
+    }
+
+    fn slerp(self, other: Self, f: R64) -> Self {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        UnitQuaternion::new_unchecked(self)
+            .slerp(
+                &UnitQuaternion::new_unchecked(other),
+                <T as NumCast>::from(f).unwrap(),
+            )
+            .into_inner()
+    }
+
+    fn nlerp(self, other: Self, f: R64) -> Self {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        let other = if self.dot(&other) < Zero::zero() {
+            -other
+        }
+        else {
+            other
+        };
+        UnitQuaternion::new_unchecked(self)
+            .nlerp(
+                &UnitQuaternion::new_unchecked(other),
+                <T as NumCast>::from(f).unwrap(),
+            )
+            .into_inner()
+    }
+}
+
+/// Implements `Transform` for an affine transformation type.
+macro_rules! impl_transform {
+    ($t:ident => $p:ident, $v:ident $(,)?) => {
+        impl<T> Transform<$p<T>> for $t<T>
+        where
+            T: RealField + RealScalar,
+        {
+            fn identity() -> Self {
+                $t::identity()
+            }
+
+            fn transform_point(&self, point: $p<T>) -> $p<T> {
+                $t::transform_point(self, &point)
+            }
+
+            fn transform_vector(&self, vector: $v<T>) -> $v<T> {
+                $t::transform_vector(self, &vector)
+            }
+
+            fn compose(self, other: Self) -> Self {
+                other * self
+            }
+
+            fn inverse(self) -> Option<Self> {
+                Some($t::inverse(&self))
+            }
+        }
+    };
+}
+impl_transform!(Isometry2 => Point2, Vector2);
+impl_transform!(Isometry3 => Point3, Vector3);
+impl_transform!(IsometryMatrix2 => Point2, Vector2);
+impl_transform!(IsometryMatrix3 => Point3, Vector3);
+impl_transform!(Similarity2 => Point2, Vector2);
+impl_transform!(Similarity3 => Point3, Vector3);
+impl_transform!(SimilarityMatrix2 => Point2, Vector2);
+impl_transform!(SimilarityMatrix3 => Point3, Vector3);
+
+/// Implements `Transform` for a homogeneous matrix type.
+macro_rules! impl_homogeneous_transform {
+    ($m:ident => $p:ident, $v:ident $(,)?) => {
+        impl<T> Transform<$p<T>> for $m<T>
+        where
+            T: RealField + RealScalar,
+        {
+            fn identity() -> Self {
+                $m::identity()
+            }
+
+            fn transform_point(&self, point: $p<T>) -> $p<T> {
+                $m::transform_point(self, &point)
+            }
+
+            fn transform_vector(&self, vector: $v<T>) -> $v<T> {
+                $m::transform_vector(self, &vector)
+            }
+
+            fn compose(self, other: Self) -> Self {
+                other * self
+            }
+
+            fn inverse(self) -> Option<Self> {
+                self.try_inverse()
+            }
+        }
+    };
+}
+impl_homogeneous_transform!(Matrix3 => Point2, Vector2);
+impl_homogeneous_transform!(Matrix4 => Point3, Vector3);
+
+/// Implements `Decompose` for a square matrix type.
+macro_rules! impl_decompose {
+    ($m:ident, $v:ident, $d:literal $(,)?) => {
+        impl<T> Decompose for $m<T>
+        where
+            T: RealField + RealScalarAssign,
+        {
+            fn svd(&self) -> Option<(Self, Self::Column, Self)> {
+                let svd = nalgebra::SVD::new(*self, true, true);
+                let (u, v_t) = (svd.u?, svd.v_t?);
+                let mut indices = (0..$d).collect::<ArrayVec<[usize; $d]>>();
+                indices.sort_by(|i, j| {
+                    svd.singular_values[*j]
+                        .partial_cmp(&svd.singular_values[*i])
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+                Some((
+                    $m::from_fn(|i, j| u[(i, indices[j])]),
+                    $v::from_fn(|i, _| svd.singular_values[indices[i]]),
+                    $m::from_fn(|i, j| v_t[(indices[j], i)]),
+                ))
+            }
+        }
+    };
+}
+impl_decompose!(Matrix2, Vector2, 2);
+impl_decompose!(Matrix3, Vector3, 3);
+impl_decompose!(Matrix4, Vector4, 4);
+
+/// Implements `Interpolate` and `RigidMotion` for an isometry type.
+macro_rules! impl_rigid_motion {
+    ($t:ident => $r:ident, $p:ident, $v:ident $(,)?) => {
+        impl<T, F> Interpolate<Self, F> for $t<T>
+        where
+            F: Factor,
+            T: NumCast + RealField,
+        {
+            type Output = Self;
+
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                let f = num::clamp(f, Zero::zero(), One::one());
+                self.lerp_slerp(&other, <T as NumCast>::from(f).unwrap())
+            }
+        }
+
+        impl<T> RigidMotion<$p<T>> for $t<T>
+        where
+            T: RealField + RealScalar,
+        {
+            type Rotation = $r<T>;
+
+            fn from_rotation_translation(rotation: Self::Rotation, translation: $v<T>) -> Self {
+                $t::from_parts(translation.into(), rotation)
+            }
+
+            fn rotation(&self) -> Self::Rotation {
+                self.rotation
+            }
+
+            fn translation(&self) -> $v<T> {
+                self.translation.vector
+            }
+
+            fn invert(self) -> Self {
+                $t::inverse(&self)
+            }
+        }
+    };
+}
+impl_rigid_motion!(Isometry2 => UnitComplex, Point2, Vector2);
+impl_rigid_motion!(Isometry3 => UnitQuaternion, Point3, Vector3);
+impl_rigid_motion!(IsometryMatrix2 => Rotation2, Point2, Vector2);
+impl_rigid_motion!(IsometryMatrix3 => Rotation3, Point3, Vector3);
@@ -0,0 +1,212 @@
+//! SIMD fast paths for small vectors.
+//!
+//! The `Dot`, `Cross`, and `Interpolate` implementations for `nalgebra` types
+//! are generic over their scalar type. When the `simd` feature is enabled,
+//! these implementations first attempt the functions of this module, which
+//! compute these operations using the SIMD types of `wide` for two- and
+//! three-dimensional vectors of `f32` and `f64`. Types are matched using
+//! `Any`, so this dispatch is resolved at compile time and other types are
+//! unaffected.
+//!
+//! Lanes are summed pairwise, so dot products may differ from the generic
+//! implementations in the least significant bits.
+
+#![cfg(feature = "simd")]
+
+use nalgebra::{Vector2, Vector3};
+use num::{NumCast, One, Zero};
+use std::any::Any;
+use std::ops::{Add, Mul, Sub};
+use wide::{f32x4, f64x2, f64x4};
+
+use crate::ops::Factor;
+
+/// Vectors that can be packed into a SIMD type.
+trait Packed: Any + Copy {
+    type Scalar: Any + Copy + NumCast + One + Sub<Output = Self::Scalar>;
+    type Simd: Copy + Add<Output = Self::Simd> + Mul<Output = Self::Simd> + Sub<Output = Self::Simd>;
+
+    /// Packs the components of the vector. Unused lanes are zero.
+    fn pack(&self) -> Self::Simd;
+
+    fn unpack(simd: Self::Simd) -> Self;
+
+    fn splat(scalar: Self::Scalar) -> Self::Simd;
+
+    fn sum(simd: Self::Simd) -> Self::Scalar;
+
+    fn packed_dot<V, T>(a: &V, b: &V) -> Option<T>
+    where
+        V: Any,
+        T: Any,
+    {
+        let (a, b) = (downcast_ref::<_, Self>(a)?, downcast_ref::<_, Self>(b)?);
+        cast(Self::sum(a.pack() * b.pack()))
+    }
+
+    fn packed_lerp<V, F>(a: &V, b: &V, f: F) -> Option<V>
+    where
+        V: Any,
+        F: Factor,
+    {
+        let (a, b) = (downcast_ref::<_, Self>(a)?, downcast_ref::<_, Self>(b)?);
+        let g = <Self::Scalar as NumCast>::from(num::clamp(f, Zero::zero(), One::one()))?;
+        let h = Self::Scalar::one() - g;
+        cast(Self::unpack(
+            (a.pack() * Self::splat(h)) + (b.pack() * Self::splat(g)),
+        ))
+    }
+}
+
+/// Three-dimensional vectors that can be packed into a SIMD type.
+trait Packed3: Packed {
+    /// Packs the components of the vector in $yzx$ order.
+    fn pack_yzx(&self) -> Self::Simd;
+
+    /// Packs the components of the vector in $zxy$ order.
+    fn pack_zxy(&self) -> Self::Simd;
+
+    fn packed_cross<V>(a: &V, b: &V) -> Option<V>
+    where
+        V: Any,
+    {
+        let (a, b) = (downcast_ref::<_, Self>(a)?, downcast_ref::<_, Self>(b)?);
+        cast(Self::unpack(
+            (a.pack_yzx() * b.pack_zxy()) - (a.pack_zxy() * b.pack_yzx()),
+        ))
+    }
+}
+
+macro_rules! impl_packed {
+    ($v:ident<$s:ident> => $w:ident, ($($i:tt),+), ($($z:expr),*) $(,)?) => {
+        impl Packed for $v<$s> {
+            type Scalar = $s;
+            type Simd = $w;
+
+            fn pack(&self) -> Self::Simd {
+                $w::new([$(self[$i],)+ $($z,)*])
+            }
+
+            fn unpack(simd: Self::Simd) -> Self {
+                let lanes = simd.to_array();
+                $v::new($(lanes[$i]),+)
+            }
+
+            fn splat(scalar: Self::Scalar) -> Self::Simd {
+                $w::splat(scalar)
+            }
+
+            fn sum(simd: Self::Simd) -> Self::Scalar {
+                simd.reduce_add()
+            }
+        }
+    };
+}
+impl_packed!(Vector2<f32> => f32x4, (0, 1), (0.0, 0.0));
+impl_packed!(Vector2<f64> => f64x2, (0, 1), ());
+impl_packed!(Vector3<f32> => f32x4, (0, 1, 2), (0.0));
+impl_packed!(Vector3<f64> => f64x4, (0, 1, 2), (0.0));
+
+macro_rules! impl_packed3 {
+    ($s:ident => $w:ident $(,)?) => {
+        impl Packed3 for Vector3<$s> {
+            fn pack_yzx(&self) -> Self::Simd {
+                $w::new([self.y, self.z, self.x, 0.0])
+            }
+
+            fn pack_zxy(&self) -> Self::Simd {
+                $w::new([self.z, self.x, self.y, 0.0])
+            }
+        }
+    };
+}
+impl_packed3!(f32 => f32x4);
+impl_packed3!(f64 => f64x4);
+
+/// Computes the dot product of two vectors, if they can be packed.
+pub fn dot<V, T>(a: &V, b: &V) -> Option<T>
+where
+    V: Any,
+    T: Any,
+{
+    <Vector3<f32> as Packed>::packed_dot(a, b)
+        .or_else(|| <Vector3<f64> as Packed>::packed_dot(a, b))
+        .or_else(|| <Vector2<f32> as Packed>::packed_dot(a, b))
+        .or_else(|| <Vector2<f64> as Packed>::packed_dot(a, b))
+}
+
+/// Computes the cross product of two vectors, if they can be packed.
+pub fn cross<V>(a: &V, b: &V) -> Option<V>
+where
+    V: Any,
+{
+    <Vector3<f32> as Packed3>::packed_cross(a, b)
+        .or_else(|| <Vector3<f64> as Packed3>::packed_cross(a, b))
+}
+
+/// Linearly interpolates between two vectors, if they can be packed.
+pub fn lerp<V, F>(a: &V, b: &V, f: F) -> Option<V>
+where
+    V: Any,
+    F: Factor,
+{
+    <Vector3<f32> as Packed>::packed_lerp(a, b, f)
+        .or_else(|| <Vector3<f64> as Packed>::packed_lerp(a, b, f))
+        .or_else(|| <Vector2<f32> as Packed>::packed_lerp(a, b, f))
+        .or_else(|| <Vector2<f64> as Packed>::packed_lerp(a, b, f))
+}
+
+fn downcast_ref<T, U>(value: &T) -> Option<&U>
+where
+    T: Any,
+    U: Any,
+{
+    (value as &dyn Any).downcast_ref()
+}
+
+fn cast<T, U>(value: T) -> Option<U>
+where
+    T: Any,
+    U: Any,
+{
+    let mut value = Some(value);
+    (&mut value as &mut dyn Any)
+        .downcast_mut::<Option<U>>()
+        .and_then(Option::take)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Vector2, Vector3};
+
+    use crate::integration::nalgebra::simd;
+    use crate::ops::{Cross, Dot, Interpolate};
+
+    #[test]
+    fn simd_dispatch() {
+        let a = Vector3::<f32>::new(1.0, 2.0, 3.0);
+        let b = Vector3::<f32>::new(-4.0, 0.5, 2.0);
+        assert_eq!(Some(nalgebra::Matrix::dot(&a, &b)), simd::dot(&a, &b));
+        assert_eq!(Some(nalgebra::Matrix::cross(&a, &b)), simd::cross(&a, &b));
+        assert_eq!(
+            Some(a.zip_map(&b, crate::lerp_by(0.25f64))),
+            simd::lerp(&a, &b, 0.25f64)
+        );
+        // The trait implementations use the same fast paths.
+        assert_eq!(nalgebra::Matrix::cross(&a, &b), Cross::cross(a, b));
+        assert_eq!(nalgebra::Matrix::dot(&a, &b), Dot::dot(a, b));
+        assert_eq!(
+            nalgebra::Matrix::lerp(&a, &b, 0.25),
+            Interpolate::lerp(a, b, 0.25f32)
+        );
+        let a = Vector2::<f64>::new(1.0, 2.0);
+        assert_eq!(Some(5.0f64), simd::dot(&a, &a));
+        // Other types are not packed.
+        assert_eq!(
+            None,
+            simd::dot::<_, i32>(&Vector3::new(1, 2, 3), &Vector3::new(1, 2, 3))
+        );
+        assert_eq!(None, simd::dot::<_, f32>(&[1.0f32; 3], &[1.0f32; 3]));
+        assert_eq!(None, simd::cross(&a, &a));
+    }
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "geometry-ndarray")]
+
+use num::Zero;
+use std::ops::{Add, Mul};
+
+use crate::adjunct::{Adjunct, Fold, FromItems, IntoItems, Map, ZipMap};
+use crate::ops::Dot;
+
+#[doc(hidden)]
+pub use ndarray::*;
+
+// `ndarray` arrays are dynamically sized and are not `Copy`, so they cannot
+// implement `VectorSpace` nor `InnerSpace`. Arrays implement adjunct traits and
+// `Dot` instead. These implementations are generic with respect to storage, so
+// views like `ArrayView1` are also supported, but outputs are always owned.
+//
+// The shapes of arrays are checked at runtime. `ZipMap` panics if the shapes of
+// its inputs differ and `FromItems` fails if there are no items.
+
+impl<S, D> Adjunct for ArrayBase<S, D>
+where
+    S: RawData,
+    D: Dimension,
+{
+    type Item = S::Elem;
+}
+
+impl<T, S> Dot for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = T>,
+    T: Add<Output = T> + Clone + Mul<Output = T> + Zero,
+{
+    type Output = T;
+
+    fn dot(self, other: Self) -> Self::Output {
+        self.zip_map(other, |a, b| a * b).sum()
+    }
+}
+
+impl<T, S, D> Fold for ArrayBase<S, D>
+where
+    S: Data<Elem = T>,
+    T: Clone,
+    D: Dimension,
+{
+    fn fold<U, F>(self, seed: U, f: F) -> U
+    where
+        F: FnMut(U, Self::Item) -> U,
+    {
+        self.iter().cloned().fold(seed, f)
+    }
+}
+
+impl<T> FromItems for Array1<T> {
+    fn from_items<I>(items: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let items = items.into_iter().collect::<Vec<_>>();
+        if items.is_empty() {
+            None
+        }
+        else {
+            Some(Array1::from(items))
+        }
+    }
+}
+
+impl<T, S> IntoItems for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = T>,
+    T: Clone,
+{
+    type Output = Vec<T>;
+
+    fn into_items(self) -> Self::Output {
+        self.to_vec()
+    }
+}
+
+impl<T, U, S, D> Map<U> for ArrayBase<S, D>
+where
+    S: Data<Elem = T>,
+    T: Clone,
+    D: Dimension,
+{
+    type Output = Array<U, D>;
+
+    fn map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        self.mapv(f)
+    }
+}
+
+impl<T, U, S, D> ZipMap<U> for ArrayBase<S, D>
+where
+    S: Data<Elem = T>,
+    T: Clone,
+    D: Dimension,
+{
+    type Output = Array<U, D>;
+
+    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item, Self::Item) -> U,
+    {
+        assert_eq!(self.shape(), other.shape(), "arrays differ in shape");
+        let items = self
+            .iter()
+            .cloned()
+            .zip(other.iter().cloned())
+            .map(|(a, b)| f(a, b))
+            .collect();
+        Array::from_shape_vec(self.raw_dim(), items).unwrap()
+    }
+}
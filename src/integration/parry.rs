@@ -0,0 +1,103 @@
+#![cfg(all(any(feature = "parry2d", feature = "parry3d"), feature = "std"))]
+
+// `parry` depends on a version of `nalgebra` that differs from the version
+// integrated by Theon, so conversions are implemented for any `EuclideanSpace`
+// with the same dimensionality and scalar type rather than for particular
+// point types. Points and vectors are converted via their components.
+
+// TODO: Implement conversions for `parry` balls and segments once the query
+//       module provides corresponding types.
+
+use core::convert::TryFrom;
+
+use crate::query::{Aabb, Ray, Unit};
+use crate::space::{EuclideanSpace, FiniteDimensional, Vector, VectorSpace};
+
+#[cfg(feature = "parry2d")]
+pub mod parry2d {
+    #[doc(hidden)]
+    pub use parry2d::*;
+}
+
+#[cfg(feature = "parry3d")]
+pub mod parry3d {
+    #[doc(hidden)]
+    pub use parry3d::*;
+}
+
+/// Implements conversions between query types and `parry` types.
+macro_rules! impl_parry {
+    (
+        $parry:ident,
+        $n:ident,
+        $into:ident => ($($c:ident),+),
+        $from:ident $(,)?
+    ) => {
+        impl<S> From<$parry::bounding_volume::Aabb> for Aabb<S>
+        where
+            S: EuclideanSpace + FiniteDimensional<N = typenum::$n>,
+            Vector<S>: VectorSpace<Scalar = $parry::math::Real>,
+        {
+            fn from(aabb: $parry::bounding_volume::Aabb) -> Self {
+                let origin = S::$from($(aabb.mins.$c),+);
+                Aabb {
+                    origin,
+                    extent: S::$from($(aabb.maxs.$c),+) - origin,
+                }
+            }
+        }
+
+        impl<S> From<Aabb<S>> for $parry::bounding_volume::Aabb
+        where
+            S: EuclideanSpace + FiniteDimensional<N = typenum::$n>,
+            Vector<S>: VectorSpace<Scalar = $parry::math::Real>,
+        {
+            fn from(aabb: Aabb<S>) -> Self {
+                let ($($c),+) = aabb.origin.$into();
+                let mins = $parry::math::Point::new($($c),+);
+                let ($($c),+) = aabb.endpoint().$into();
+                let maxs = $parry::math::Point::new($($c),+);
+                $parry::bounding_volume::Aabb::new(mins.inf(&maxs), mins.sup(&maxs))
+            }
+        }
+
+        /// Converts a `parry` ray into a query `Ray`.
+        ///
+        /// Fails and returns the `parry` ray if its direction cannot be
+        /// normalized.
+        impl<S> TryFrom<$parry::query::Ray> for Ray<S>
+        where
+            S: EuclideanSpace + FiniteDimensional<N = typenum::$n>,
+            Vector<S>: VectorSpace<Scalar = $parry::math::Real>,
+        {
+            type Error = $parry::query::Ray;
+
+            fn try_from(ray: $parry::query::Ray) -> Result<Self, Self::Error> {
+                Unit::try_from_inner(Vector::<S>::$from($(ray.dir.$c),+))
+                    .map(|direction| Ray {
+                        origin: S::$from($(ray.origin.$c),+),
+                        direction,
+                    })
+                    .ok_or(ray)
+            }
+        }
+
+        impl<S> From<Ray<S>> for $parry::query::Ray
+        where
+            S: EuclideanSpace + FiniteDimensional<N = typenum::$n>,
+            Vector<S>: VectorSpace<Scalar = $parry::math::Real>,
+        {
+            fn from(ray: Ray<S>) -> Self {
+                let ($($c),+) = ray.origin.$into();
+                let origin = $parry::math::Point::new($($c),+);
+                let ($($c),+) = ray.direction.into_inner().$into();
+                let direction = $parry::math::Vector::new($($c),+);
+                $parry::query::Ray::new(origin, direction)
+            }
+        }
+    };
+}
+#[cfg(feature = "parry2d")]
+impl_parry!(parry2d, U2, into_xy => (x, y), from_xy);
+#[cfg(feature = "parry3d")]
+impl_parry!(parry3d, U3, into_xyz => (x, y, z), from_xyz);
@@ -0,0 +1,712 @@
+#![cfg(feature = "scalar-rational")]
+
+//! Rational scalars.
+//!
+//! Theon requires that scalars implement `Real`, which cannot be implemented
+//! for the foreign `Ratio` type of the `num` crate. This module provides the
+//! `Rational` wrapper, which implements `Real` and other numeric traits for
+//! ratios of signed primitive integers like `Ratio<i64>`. For example,
+//! `Point2<Rational<i64>>` from `nalgebra` implements `EuclideanSpace` and can
+//! be used with queries.
+//!
+//! Operations are computed exactly using rational arithmetic, which is useful
+//! for exact computational geometry. Rational types have no infinities nor
+//! `NaN`s:
+//!
+//! - Saturated values (`MAX` and `MIN`) are considered infinite.
+//! - Division by zero saturates rather than panics.
+//! - The default epsilon used for approximate comparisons is zero, so these
+//!   comparisons are exact.
+//! - Irrational functions (e.g., `sqrt` and `sin`) are not implemented, so
+//!   `Rational` implements neither `Sqrt` nor `Transcendental`.
+//!
+//! Primitive integers may overflow, so `Rational<i128>` may be preferable when
+//! numerators and denominators grow large. Arbitrary precision ratios like
+//! `BigRational` never overflow, but are not `Copy` and cannot be used as the
+//! scalar of a `VectorSpace`. Instead, the exact predicates and intersections
+//! in this module only require that the items of points implement `Field`, so
+//! they accept points like `[BigRational; 2]` and `Point2<BigRational>`.
+
+use approx::AbsDiffEq;
+use decorum::cmp::IntrinsicOrd;
+use decorum::{Encoding, Infinite};
+use num::{Bounded, Float, Num, NumCast, One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use typenum::{U2, U3};
+
+use crate::adjunct::AsItems;
+use crate::ops::{Field, Real};
+use crate::space::FiniteDimensional;
+
+#[doc(hidden)]
+pub use num::rational::*;
+
+/// Rational scalar.
+///
+/// Wraps a `Ratio` of signed primitive integers and implements `Real`, so this
+/// type can be used as the scalar of vector spaces. Implementations are
+/// provided for `i32`, `i64`, and `i128`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct Rational<T>(pub Ratio<T>);
+
+// `Ratio` implements comparison traits only for integer types, so these traits
+// cannot be derived.
+impl<T> Eq for Rational<T> where Ratio<T>: Eq {}
+
+impl<T> Hash for Rational<T>
+where
+    Ratio<T>: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.hash(state)
+    }
+}
+
+impl<T> Ord for Rational<T>
+where
+    Ratio<T>: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> PartialEq for Rational<T>
+where
+    Ratio<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> PartialOrd for Rational<T>
+where
+    Ratio<T>: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Implements numeric traits for a `Rational` wrapper of a signed primitive
+/// integer type.
+macro_rules! impl_rational {
+    ($t:ident $(,)?) => {
+        impl AbsDiffEq for Rational<$t> {
+            type Epsilon = Self;
+
+            fn default_epsilon() -> Self::Epsilon {
+                Zero::zero()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                (*self - *other).abs() <= epsilon
+            }
+        }
+
+        impl Bounded for Rational<$t> {
+            fn min_value() -> Self {
+                <Self as Encoding>::MIN
+            }
+
+            fn max_value() -> Self {
+                <Self as Encoding>::MAX
+            }
+        }
+
+        impl Div for Rational<$t> {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self::Output {
+                if other.is_zero() {
+                    if self.is_negative() {
+                        <Self as Encoding>::MIN
+                    }
+                    else {
+                        <Self as Encoding>::MAX
+                    }
+                }
+                else {
+                    Rational(self.0 / other.0)
+                }
+            }
+        }
+
+        impl DivAssign for Rational<$t> {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl Encoding for Rational<$t> {
+            const MAX: Self = Rational(Ratio::new_raw($t::MAX, 1));
+            const MIN: Self = Rational(Ratio::new_raw($t::MIN, 1));
+            const MIN_POSITIVE: Self = Rational(Ratio::new_raw(1, $t::MAX));
+            const EPSILON: Self = Rational(Ratio::new_raw(1, $t::MAX));
+
+            fn classify(self) -> FpCategory {
+                if self.is_zero() {
+                    FpCategory::Zero
+                }
+                else if self.is_infinite() {
+                    FpCategory::Infinite
+                }
+                else {
+                    FpCategory::Normal
+                }
+            }
+
+            fn is_normal(self) -> bool {
+                self.classify() == FpCategory::Normal
+            }
+
+            fn is_sign_positive(self) -> bool {
+                !self.is_negative()
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.is_negative()
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                // Ratios have no binary floating-point encoding, so the nearest
+                // `f64` is decoded instead.
+                Float::integer_decode(self.to_f64().unwrap_or(f64::NAN))
+            }
+        }
+
+        impl From<$t> for Rational<$t> {
+            fn from(value: $t) -> Self {
+                Rational(Ratio::from_integer(value))
+            }
+        }
+
+        impl From<Ratio<$t>> for Rational<$t> {
+            fn from(value: Ratio<$t>) -> Self {
+                Rational(value)
+            }
+        }
+
+        impl From<Rational<$t>> for Ratio<$t> {
+            fn from(value: Rational<$t>) -> Self {
+                value.0
+            }
+        }
+
+        impl Infinite for Rational<$t> {
+            const INFINITY: Self = <Self as Encoding>::MAX;
+            const NEG_INFINITY: Self = <Self as Encoding>::MIN;
+
+            fn is_infinite(self) -> bool {
+                self == <Self as Encoding>::MAX || self == <Self as Encoding>::MIN
+            }
+
+            fn is_finite(self) -> bool {
+                !self.is_infinite()
+            }
+        }
+
+        impl IntrinsicOrd for Rational<$t> {
+            fn is_undefined(&self) -> bool {
+                false
+            }
+
+            fn min_max_or_undefined(&self, other: &Self) -> (Self, Self) {
+                if self <= other {
+                    (*self, *other)
+                }
+                else {
+                    (*other, *self)
+                }
+            }
+        }
+
+        impl Num for Rational<$t> {
+            type FromStrRadixErr = ParseRatioError;
+
+            fn from_str_radix(source: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                Ratio::from_str_radix(source, radix).map(Rational)
+            }
+        }
+
+        impl NumCast for Rational<$t> {
+            fn from<T>(value: T) -> Option<Self>
+            where
+                T: ToPrimitive,
+            {
+                // Prefer exact integer conversions and only approximate
+                // floating-point values with a fractional part.
+                match value.to_f64() {
+                    Some(x) if x.fract() != 0.0 => Ratio::approximate_float(x),
+                    _ => <$t as NumCast>::from(value).map(Ratio::from_integer),
+                }
+                .map(Rational)
+            }
+        }
+
+        impl One for Rational<$t> {
+            fn one() -> Self {
+                Rational(Ratio::one())
+            }
+        }
+
+        impl Real for Rational<$t> {
+            fn floor(self) -> Self {
+                Rational(self.0.floor())
+            }
+
+            fn recip(self) -> Self {
+                Self::one() / self
+            }
+        }
+
+        impl Signed for Rational<$t> {
+            fn abs(&self) -> Self {
+                Rational(self.0.abs())
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                Rational(self.0.abs_sub(&other.0))
+            }
+
+            fn signum(&self) -> Self {
+                Rational(self.0.signum())
+            }
+
+            fn is_positive(&self) -> bool {
+                self.0.is_positive()
+            }
+
+            fn is_negative(&self) -> bool {
+                self.0.is_negative()
+            }
+        }
+
+        impl ToPrimitive for Rational<$t> {
+            fn to_i64(&self) -> Option<i64> {
+                self.0.to_integer().to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.0.to_integer().to_u64()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0.numer().to_f64()? / self.0.denom().to_f64()?)
+            }
+        }
+
+        impl Zero for Rational<$t> {
+            fn zero() -> Self {
+                Rational(Ratio::zero())
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+        }
+
+        impl Neg for Rational<$t> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Rational(-self.0)
+            }
+        }
+
+        impl_rational!(@operator $t, Add, add, AddAssign, add_assign);
+        impl_rational!(@operator $t, Mul, mul, MulAssign, mul_assign);
+        impl_rational!(@operator $t, Rem, rem, RemAssign, rem_assign);
+        impl_rational!(@operator $t, Sub, sub, SubAssign, sub_assign);
+    };
+    (@operator $t:ident, $o:ident, $f:ident, $oa:ident, $fa:ident $(,)?) => {
+        impl $o for Rational<$t> {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self::Output {
+                Rational($o::$f(self.0, other.0))
+            }
+        }
+
+        impl $oa for Rational<$t> {
+            fn $fa(&mut self, other: Self) {
+                $oa::$fa(&mut self.0, other.0)
+            }
+        }
+    };
+}
+impl_rational!(i32);
+impl_rational!(i64);
+impl_rational!(i128);
+
+/// Intersection of segments computed exactly.
+///
+/// Points are given by their $x$ and $y$ coordinates.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SegmentIntersection<T> {
+    Point([T; 2]),
+    /// The overlap of collinear segments.
+    ///
+    /// The overlap is directed like the first segment.
+    Segment([T; 2], [T; 2]),
+}
+
+/// Determines the orientation of a triangle in two dimensions exactly.
+///
+/// Returns `Ordering::Greater` if `c` is to the left of the directed line from
+/// `a` to `b` (the points are in counterclockwise order), `Ordering::Less` if
+/// `c` is to the right, and `Ordering::Equal` if the points are collinear.
+/// This is the sign of `signed_area`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate num;
+/// # extern crate theon;
+/// #
+/// use num::BigRational;
+/// use std::cmp::Ordering;
+/// use theon::integration::rational;
+///
+/// let third = |n: i64| BigRational::new(n.into(), 3.into());
+/// let a = [third(1), third(1)];
+/// let b = [third(2), third(2)];
+/// let c = [third(3), third(3)];
+/// assert_eq!(Ordering::Equal, rational::orient2d(&a, &b, &c));
+/// ```
+pub fn orient2d<P>(a: &P, b: &P, c: &P) -> Ordering
+where
+    P: AsItems + FiniteDimensional<N = U2>,
+    P::Item: Field,
+{
+    let ab = difference2(a.as_items(), b.as_items());
+    let ac = difference2(a.as_items(), c.as_items());
+    sign(perp_dot(&ab, &ac))
+}
+
+/// Determines the orientation of a triangle in three dimensions as viewed
+/// from a point exactly.
+///
+/// Returns `Ordering::Greater` if the points of the triangle $\overline{abc}$
+/// appear in counterclockwise order when viewed from `d`, `Ordering::Less` if
+/// they appear in clockwise order, and `Ordering::Equal` if the points are
+/// coplanar. This is the sign of `signed_volume`.
+pub fn orient3d<P>(a: &P, b: &P, c: &P, d: &P) -> Ordering
+where
+    P: AsItems + FiniteDimensional<N = U3>,
+    P::Item: Field,
+{
+    let a = a.as_items();
+    let [bx, by, bz] = difference3(a, b.as_items());
+    let [cx, cy, cz] = difference3(a, c.as_items());
+    let [dx, dy, dz] = difference3(a, d.as_items());
+    let x = (by.clone() * cz.clone()) - (bz.clone() * cy.clone());
+    let y = (bz * cx.clone()) - (bx.clone() * cz);
+    let z = (bx * cy) - (by * cx);
+    sign((x * dx) + (y * dy) + (z * dz))
+}
+
+/// Intersects segments in two dimensions exactly.
+///
+/// Segments are given by their start and end points. Segments intersect at a
+/// point unless they are collinear, in which case their overlap may be a
+/// segment. Degenerate segments are treated as points.
+pub fn segment_intersection<P>(
+    (a0, a1): (&P, &P),
+    (b0, b1): (&P, &P),
+) -> Option<SegmentIntersection<P::Item>>
+where
+    P: AsItems + FiniteDimensional<N = U2>,
+    P::Item: Field,
+{
+    let zero = P::Item::zero();
+    let one = P::Item::one();
+    let within = |t: &P::Item| *t >= zero && *t <= one;
+    let (a0, a1, b0, b1) = (a0.as_items(), a1.as_items(), b0.as_items(), b1.as_items());
+    let r = difference2(a0, a1);
+    let s = difference2(b0, b1);
+    let rr = dot(&r, &r);
+    let ss = dot(&s, &s);
+    let qp = difference2(a0, b0);
+    let point = |t: P::Item| -> [P::Item; 2] {
+        [
+            a0[0].clone() + (r[0].clone() * t.clone()),
+            a0[1].clone() + (r[1].clone() * t),
+        ]
+    };
+    if rr.is_zero() {
+        // The first segment is degenerate. Intersect its point with the other
+        // segment.
+        let a0 = [a0[0].clone(), a0[1].clone()];
+        return if ss.is_zero() {
+            dot(&qp, &qp)
+                .is_zero()
+                .then_some(SegmentIntersection::Point(a0))
+        }
+        else {
+            let t = -dot(&qp, &s);
+            (t >= zero && t <= ss && perp_dot(&qp, &s).is_zero())
+                .then_some(SegmentIntersection::Point(a0))
+        };
+    }
+    if ss.is_zero() {
+        // The other segment is degenerate. Intersect its point with the first
+        // segment.
+        let t = dot(&qp, &r);
+        return (t >= zero && t <= rr && perp_dot(&qp, &r).is_zero())
+            .then(|| SegmentIntersection::Point([b0[0].clone(), b0[1].clone()]));
+    }
+    let denominator = perp_dot(&r, &s);
+    if denominator.is_zero() {
+        if !perp_dot(&qp, &r).is_zero() {
+            // The segments are parallel but not collinear.
+            return None;
+        }
+        // The segments are collinear. Project the other segment onto the first
+        // segment and clamp its parameters to the first segment.
+        let t0 = dot(&qp, &r) / rr.clone();
+        let t1 = t0.clone() + (dot(&s, &r) / rr);
+        let (min, max) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        let min = if min < zero { zero } else { min };
+        let max = if max > one { one } else { max };
+        if min < max {
+            Some(SegmentIntersection::Segment(point(min), point(max)))
+        }
+        else if min == max {
+            Some(SegmentIntersection::Point(point(min)))
+        }
+        else {
+            None
+        }
+    }
+    else {
+        let t = perp_dot(&qp, &s) / denominator.clone();
+        let u = perp_dot(&qp, &r) / denominator;
+        (within(&t) && within(&u)).then(|| SegmentIntersection::Point(point(t)))
+    }
+}
+
+fn difference2<T>(a: &[T], b: &[T]) -> [T; 2]
+where
+    T: Field,
+{
+    [b[0].clone() - a[0].clone(), b[1].clone() - a[1].clone()]
+}
+
+fn difference3<T>(a: &[T], b: &[T]) -> [T; 3]
+where
+    T: Field,
+{
+    [
+        b[0].clone() - a[0].clone(),
+        b[1].clone() - a[1].clone(),
+        b[2].clone() - a[2].clone(),
+    ]
+}
+
+fn dot<T>(a: &[T; 2], b: &[T; 2]) -> T
+where
+    T: Field,
+{
+    (a[0].clone() * b[0].clone()) + (a[1].clone() * b[1].clone())
+}
+
+fn perp_dot<T>(a: &[T; 2], b: &[T; 2]) -> T
+where
+    T: Field,
+{
+    (a[0].clone() * b[1].clone()) - (a[1].clone() * b[0].clone())
+}
+
+fn sign<T>(value: T) -> Ordering
+where
+    T: Field,
+{
+    if value > T::zero() {
+        Ordering::Greater
+    }
+    else if value < T::zero() {
+        Ordering::Less
+    }
+    else {
+        Ordering::Equal
+    }
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use decorum::Encoding;
+    use nalgebra::{Point2, Point3};
+    use num::{BigRational, NumCast, One, Zero};
+    use std::cmp::Ordering;
+
+    use crate::integration::rational::{self, Ratio, Rational, SegmentIntersection};
+    use crate::ops::{self, Real};
+    use crate::query::{Intersection, Segment, SegmentSegment};
+
+    type R2 = Point2<Rational<i64>>;
+
+    fn ratio(numer: i64, denom: i64) -> Rational<i64> {
+        Rational(Ratio::new(numer, denom))
+    }
+
+    fn big(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(numer.into(), denom.into())
+    }
+
+    #[test]
+    fn real_rational() {
+        assert_eq!(ratio(-2, 1), Real::floor(ratio(-3, 2)));
+        assert_eq!(ratio(3, 2), Real::recip(ratio(2, 3)));
+        // Division by zero saturates.
+        assert_eq!(<Rational<i64> as Encoding>::MAX, Real::recip(ratio(0, 1)));
+        // Decoding does not panic and approximates the ratio.
+        let (mantissa, exponent, sign) = ratio(1, 2).integer_decode();
+        assert_eq!(
+            0.5,
+            sign as f64 * mantissa as f64 * 2.0f64.powi(exponent.into())
+        );
+    }
+
+    #[test]
+    fn num_cast_rational() {
+        assert_eq!(Some(ratio(7, 1)), <Rational<i64> as NumCast>::from(7u8));
+        assert_eq!(Some(ratio(-3, 4)), <Rational<i64> as NumCast>::from(-0.75));
+        assert_eq!(
+            Some(Rational(Ratio::from_integer(i128::MAX))),
+            <Rational<i128> as NumCast>::from(i128::MAX),
+        );
+    }
+
+    #[test]
+    fn orient2d_big_rational() {
+        // These points are collinear, but their coordinates cannot be
+        // represented exactly as floating-point values.
+        let a = [big(1, 3), big(1, 3)];
+        let b = [big(2, 3), big(2, 3)];
+        assert_eq!(
+            Ordering::Equal,
+            rational::orient2d(&a, &b, &[big(1, 1), big(1, 1)])
+        );
+        assert_eq!(
+            Ordering::Greater,
+            rational::orient2d(&a, &b, &[big(1, 1), big(1, 1) + big(1, i64::MAX)]),
+        );
+        assert_eq!(
+            Ordering::Less,
+            rational::orient2d(&a, &b, &[big(1, 1), big(1, 1) - big(1, i64::MAX)]),
+        );
+    }
+
+    #[test]
+    fn orient2d_rational() {
+        let a = R2::new(ratio(1, 3), ratio(1, 3));
+        let b = R2::new(ratio(2, 3), ratio(2, 3));
+        let c = R2::new(ratio(1, 1), ratio(1, 1));
+        assert_eq!(Ordering::Equal, rational::orient2d(&a, &b, &c));
+        assert!(ops::signed_area(a, b, c).is_zero());
+    }
+
+    #[test]
+    fn orient3d_big_rational() {
+        let a = Point3::new(big(0, 1), big(0, 1), big(0, 1));
+        let b = Point3::new(big(1, 3), big(0, 1), big(0, 1));
+        let c = Point3::new(big(0, 1), big(1, 3), big(0, 1));
+        assert_eq!(
+            Ordering::Greater,
+            rational::orient3d(&a, &b, &c, &Point3::new(big(0, 1), big(0, 1), big(1, 7))),
+        );
+        assert_eq!(
+            Ordering::Less,
+            rational::orient3d(&a, &b, &c, &Point3::new(big(0, 1), big(0, 1), big(-1, 7))),
+        );
+        assert_eq!(
+            Ordering::Equal,
+            rational::orient3d(&a, &b, &c, &Point3::new(big(1, 7), big(2, 7), big(0, 1))),
+        );
+    }
+
+    #[test]
+    fn segment_intersection_big_rational() {
+        let a0 = [big(0, 1), big(0, 1)];
+        let a1 = [big(1, 1), big(1, 1)];
+        // Crossing.
+        assert_eq!(
+            Some(SegmentIntersection::Point([big(1, 3), big(1, 3)])),
+            rational::segment_intersection(
+                (&a0, &a1),
+                (&[big(0, 1), big(2, 3)], &[big(2, 3), big(0, 1)]),
+            ),
+        );
+        // Parallel.
+        assert_eq!(
+            None,
+            rational::segment_intersection(
+                (&a0, &a1),
+                (&[big(1, 3), big(0, 1)], &[big(4, 3), big(1, 1)]),
+            ),
+        );
+        // Collinear.
+        assert_eq!(
+            Some(SegmentIntersection::Segment(
+                [big(1, 3), big(1, 3)],
+                [big(1, 1), big(1, 1)],
+            )),
+            rational::segment_intersection(
+                (&a0, &a1),
+                (&[big(2, 1), big(2, 1)], &[big(1, 3), big(1, 3)]),
+            ),
+        );
+        assert_eq!(
+            Some(SegmentIntersection::Point([big(1, 1), big(1, 1)])),
+            rational::segment_intersection(
+                (&a0, &a1),
+                (&[big(1, 1), big(1, 1)], &[big(2, 1), big(2, 1)]),
+            ),
+        );
+        // Degenerate.
+        let p = [big(1, 7), big(1, 7)];
+        assert_eq!(
+            Some(SegmentIntersection::Point(p.clone())),
+            rational::segment_intersection((&a0, &a1), (&p, &p)),
+        );
+        assert_eq!(
+            Some(SegmentIntersection::Point(p.clone())),
+            rational::segment_intersection((&p, &p), (&a0, &a1)),
+        );
+        let q = [big(1, 7), big(2, 7)];
+        assert_eq!(None, rational::segment_intersection((&a0, &a1), (&q, &q)));
+    }
+
+    #[test]
+    fn segment_segment_intersection_rational() {
+        let segment = |start: R2, end: R2| Segment { start, end };
+        let a = segment(R2::origin(), R2::new(Rational::one(), Rational::one()));
+        assert_eq!(
+            Some(SegmentSegment::Point(R2::new(ratio(1, 3), ratio(1, 3)))),
+            a.intersection(&segment(
+                R2::new(Rational::zero(), ratio(2, 3)),
+                R2::new(ratio(2, 3), Rational::zero()),
+            )),
+        );
+        // The segments are parallel and nearly collinear. Approximate
+        // comparisons of rational scalars are exact, so they do not intersect.
+        assert_eq!(
+            None,
+            a.intersection(&segment(
+                R2::new(ratio(1, 1_000_000), Rational::zero()),
+                R2::new(ratio(1_000_001, 1_000_000), Rational::one()),
+            )),
+        );
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "rstar")]
+
+// Points in `rstar` are arrays of scalars. Theon's query types are indexed by
+// converting the coordinates of their points into arrays with the same
+// dimensionality. Foreign point types cannot implement `RTreeObject` here, but
+// arrays already implement it in `rstar`.
+
+use typenum::consts::{U2, U3, U4};
+use typenum::{NonZero, Unsigned};
+
+use crate::adjunct::FromItems;
+use crate::query::Aabb;
+use crate::space::{EuclideanSpace, FiniteDimensional, Scalar, VectorSpace};
+
+#[doc(hidden)]
+pub use rstar::*;
+
+/// Dimensionality with a corresponding `rstar` point type.
+///
+/// Associates the dimensionality of a `EuclideanSpace` with an array of its
+/// scalars.
+pub trait PointDimension<T>: NonZero + Unsigned {
+    type Point: FromItems<Item = T> + Point<Scalar = T>;
+}
+
+impl<T> PointDimension<T> for U2
+where
+    T: RTreeNum,
+{
+    type Point = [T; 2];
+}
+
+impl<T> PointDimension<T> for U3
+where
+    T: RTreeNum,
+{
+    type Point = [T; 3];
+}
+
+impl<T> PointDimension<T> for U4
+where
+    T: RTreeNum,
+{
+    type Point = [T; 4];
+}
+
+/// `rstar` point type of a `EuclideanSpace`.
+pub type PointOf<S> = <<S as FiniteDimensional>::N as PointDimension<Scalar<S>>>::Point;
+
+/// Converts a point into an `rstar` point.
+pub fn into_point<S>(point: S) -> PointOf<S>
+where
+    S: EuclideanSpace,
+    S::N: PointDimension<Scalar<S>>,
+{
+    let coordinates = point.into_coordinates();
+    PointOf::<S>::from_items(
+        (0..S::dimensions()).map(|index| coordinates.scalar_component(index).unwrap()),
+    )
+    .unwrap()
+}
+
+impl<S> RTreeObject for Aabb<S>
+where
+    S: EuclideanSpace,
+    S::N: PointDimension<Scalar<S>>,
+{
+    type Envelope = AABB<PointOf<S>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_corners(into_point(self.origin), into_point(self.endpoint()))
+    }
+}
+
+impl<S> PointDistance for Aabb<S>
+where
+    S: EuclideanSpace,
+    S::N: PointDimension<Scalar<S>>,
+{
+    fn distance_2(&self, point: &PointOf<S>) -> Scalar<S> {
+        self.envelope().distance_2(point)
+    }
+
+    fn contains_point(&self, point: &PointOf<S>) -> bool {
+        self.envelope().contains_point(point)
+    }
+}
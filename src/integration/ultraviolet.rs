@@ -1,150 +1,157 @@
 #![cfg(feature = "geometry-ultraviolet")]
 
 use arrayvec::ArrayVec;
-use decorum::R64;
 use typenum::consts::{U2, U3, U4};
-use ultraviolet::interp::Lerp;
-use ultraviolet::vec::{Vec2, Vec3, Vec4};
 
-use crate::adjunct::{Adjunct, Converged, Extend, Fold, Map, Truncate, ZipMap};
-use crate::ops::{Cross, Dot, Interpolate};
+use crate::adjunct::{
+    Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, Truncate, ZipMap,
+};
+use crate::ops::{Cross, Dot, Factor, Interpolate, Wedge};
 use crate::space::{
-    AffineSpace, Basis, DualSpace, EuclideanSpace, FiniteDimensional, Homogeneous, InnerSpace,
-    VectorSpace,
+    AffineSpace, Basis, CoordinateLayout, DualSpace, EuclideanSpace, FiniteDimensional,
+    Homogeneous, InnerSpace, VectorSpace,
 };
 use crate::{AsPosition, AsPositionMut};
 
+// Types like `Vec3` are not imported explicitly, because such imports shadow
+// this re-export.
 #[doc(hidden)]
 pub use ultraviolet::*;
 
-impl Adjunct for Vec2 {
-    type Item = f32;
-}
-
-impl Adjunct for Vec3 {
-    type Item = f32;
-}
-
-impl Adjunct for Vec4 {
-    type Item = f32;
-}
-
-impl AffineSpace for Vec2 {
-    type Translation = Self;
-}
-
-impl AffineSpace for Vec3 {
-    type Translation = Self;
-}
-
-impl AsPosition for Vec2 {
-    type Position = Self;
-
-    fn as_position(&self) -> &Self::Position {
-        self
-    }
-}
+// `ultraviolet` provides scalar and wide (SIMD) variants of its types that
+// differ only in their scalar type, so implementations are generated by macros
+// rather than written by hand for each type.
+//
+// The scalar type of wide types like `Vec3x8` is a SIMD type like `f32x8`,
+// which does not implement `Real`. Wide types implement adjunct traits, but do
+// not implement vector space traits. SIMD scalar wrappers like `F32x8` in the
+// `wide` integration can be used with other vector types instead.
+
+/// Implements adjunct traits, `Basis`, `Dot`, and `FiniteDimensional` for an
+/// array-like type with any scalar type.
+macro_rules! impl_vector_adjunct {
+    ($v:ident, $t:ty, $n:ident, $d:literal, [$($basis:ident),+ $(,)?] $(,)?) => {
+        impl Adjunct for $v {
+            type Item = $t;
+        }
 
-impl AsPosition for Vec3 {
-    type Position = Self;
+        impl Basis for $v {
+            type Bases = ArrayVec<[Self; $d]>;
 
-    fn as_position(&self) -> &Self::Position {
-        self
-    }
-}
+            fn canonical_basis() -> Self::Bases {
+                ArrayVec::from([$(Self::$basis()),+])
+            }
+        }
 
-impl AsPositionMut for Vec2 {
-    fn as_position_mut(&mut self) -> &mut Self::Position {
-        self
-    }
-}
+        impl Converged for $v {
+            fn converged(value: Self::Item) -> Self {
+                Self::broadcast(value)
+            }
+        }
 
-impl AsPositionMut for Vec3 {
-    fn as_position_mut(&mut self) -> &mut Self::Position {
-        self
-    }
-}
+        impl Dot for $v {
+            type Output = $t;
 
-impl Basis for Vec2 {
-    type Bases = ArrayVec<[Self; 2]>;
+            fn dot(self, other: Self) -> Self::Output {
+                Self::dot(&self, other)
+            }
+        }
 
-    fn canonical_basis() -> Self::Bases {
-        ArrayVec::from([
-            Self::canonical_basis_component(0).unwrap(),
-            Self::canonical_basis_component(1).unwrap(),
-        ])
-    }
+        impl FiniteDimensional for $v {
+            type N = $n;
+        }
 
-    fn canonical_basis_component(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::unit_x()),
-            1 => Some(Self::unit_y()),
-            _ => None,
+        impl Fold for $v {
+            fn fold<T, F>(self, seed: T, f: F) -> T
+            where
+                F: FnMut(T, Self::Item) -> T,
+            {
+                self.as_array().iter().cloned().fold(seed, f)
+            }
         }
-    }
-}
 
-impl Basis for Vec3 {
-    type Bases = ArrayVec<[Self; 3]>;
+        impl FromItems for $v {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                items
+                    .into_iter()
+                    .take($d)
+                    .collect::<ArrayVec<[$t; $d]>>()
+                    .into_inner()
+                    .ok()
+                    .map(Self::from)
+            }
+        }
 
-    fn canonical_basis() -> Self::Bases {
-        ArrayVec::from([
-            Self::canonical_basis_component(0).unwrap(),
-            Self::canonical_basis_component(1).unwrap(),
-            Self::canonical_basis_component(2).unwrap(),
-        ])
-    }
+        impl IntoItems for $v {
+            type Output = ArrayVec<[$t; $d]>;
 
-    fn canonical_basis_component(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::unit_x()),
-            1 => Some(Self::unit_y()),
-            2 => Some(Self::unit_z()),
-            _ => None,
+            fn into_items(self) -> Self::Output {
+                (*self.as_array()).into()
+            }
         }
-    }
-}
 
-impl Basis for Vec4 {
-    type Bases = ArrayVec<[Self; 4]>;
+        impl Map<$t> for $v {
+            type Output = Self;
 
-    fn canonical_basis() -> Self::Bases {
-        ArrayVec::from([
-            Self::canonical_basis_component(0).unwrap(),
-            Self::canonical_basis_component(1).unwrap(),
-            Self::canonical_basis_component(2).unwrap(),
-            Self::canonical_basis_component(3).unwrap(),
-        ])
-    }
-
-    fn canonical_basis_component(index: usize) -> Option<Self> {
-        match index {
-            0 => Some(Self::unit_x()),
-            1 => Some(Self::unit_y()),
-            2 => Some(Self::unit_z()),
-            3 => Some(Self::unit_w()),
-            _ => None,
+            fn map<F>(self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> $t,
+            {
+                Self::from(self.as_array().map(f))
+            }
         }
-    }
-}
 
-impl Converged for Vec2 {
-    fn converged(value: Self::Item) -> Self {
-        Self::broadcast(value)
-    }
-}
+        impl ZipMap<$t> for $v {
+            type Output = Self;
 
-impl Converged for Vec3 {
-    fn converged(value: Self::Item) -> Self {
-        Self::broadcast(value)
-    }
-}
+            fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> $t,
+            {
+                let mut items = IntoIterator::into_iter(*other.as_array());
+                Self::from(self.as_array().map(|a| f(a, items.next().unwrap())))
+            }
+        }
+    };
+}
+impl_vector_adjunct!(Vec2, f32, U2, 2, [unit_x, unit_y]);
+impl_vector_adjunct!(Vec3, f32, U3, 3, [unit_x, unit_y, unit_z]);
+impl_vector_adjunct!(Vec4, f32, U4, 4, [unit_x, unit_y, unit_z, unit_w]);
+impl_vector_adjunct!(Vec2x4, f32x4, U2, 2, [unit_x, unit_y]);
+impl_vector_adjunct!(Vec3x4, f32x4, U3, 3, [unit_x, unit_y, unit_z]);
+impl_vector_adjunct!(Vec4x4, f32x4, U4, 4, [unit_x, unit_y, unit_z, unit_w]);
+impl_vector_adjunct!(Vec2x8, f32x8, U2, 2, [unit_x, unit_y]);
+impl_vector_adjunct!(Vec3x8, f32x8, U3, 3, [unit_x, unit_y, unit_z]);
+impl_vector_adjunct!(Vec4x8, f32x8, U4, 4, [unit_x, unit_y, unit_z, unit_w]);
+
+/// Implements `Extend` and `Truncate` between a vector type and a vector type
+/// with one additional dimension.
+macro_rules! impl_extend_truncate {
+    ($lower:ident, $higher:ident $(,)?) => {
+        impl Extend<$higher> for $lower {
+            fn extend(self, item: Self::Item) -> $higher {
+                $higher::from_items(self.into_items().into_iter().chain(Some(item))).unwrap()
+            }
+        }
 
-impl Converged for Vec4 {
-    fn converged(value: Self::Item) -> Self {
-        Self::broadcast(value)
-    }
+        impl Truncate<$lower> for $higher {
+            fn truncate(self) -> ($lower, Self::Item) {
+                let mut items = self.into_items();
+                let item = items.pop().unwrap();
+                ($lower::from_items(items).unwrap(), item)
+            }
+        }
+    };
 }
+impl_extend_truncate!(Vec2, Vec3);
+impl_extend_truncate!(Vec3, Vec4);
+impl_extend_truncate!(Vec2x4, Vec3x4);
+impl_extend_truncate!(Vec3x4, Vec4x4);
+impl_extend_truncate!(Vec2x8, Vec3x8);
+impl_extend_truncate!(Vec3x8, Vec4x8);
 
 impl Cross for Vec3 {
     type Output = Self;
@@ -154,306 +161,249 @@ impl Cross for Vec3 {
     }
 }
 
-impl Dot for Vec2 {
-    type Output = f32;
+impl Cross for Vec3x4 {
+    type Output = Self;
 
-    fn dot(self, other: Self) -> Self::Output {
-        Self::dot(&self, other)
+    fn cross(self, other: Self) -> Self::Output {
+        Self::cross(&self, other)
     }
 }
 
-impl Dot for Vec3 {
-    type Output = f32;
+impl Cross for Vec3x8 {
+    type Output = Self;
 
-    fn dot(self, other: Self) -> Self::Output {
-        Self::dot(&self, other)
+    fn cross(self, other: Self) -> Self::Output {
+        Self::cross(&self, other)
     }
 }
 
-impl Dot for Vec4 {
+impl Wedge for Vec2 {
     type Output = f32;
 
-    fn dot(self, other: Self) -> Self::Output {
-        Self::dot(&self, other)
-    }
-}
-
-impl DualSpace for Vec2 {
-    type Dual = Self;
-
-    fn transpose(self) -> Self::Dual {
-        self
+    fn wedge(self, other: Self) -> Self::Output {
+        (self.x * other.y) - (self.y * other.x)
     }
 }
 
-impl DualSpace for Vec3 {
-    type Dual = Self;
+impl Wedge for Vec2x4 {
+    type Output = f32x4;
 
-    fn transpose(self) -> Self::Dual {
-        self
+    fn wedge(self, other: Self) -> Self::Output {
+        (self.x * other.y) - (self.y * other.x)
     }
 }
 
-impl DualSpace for Vec4 {
-    type Dual = Self;
+impl Wedge for Vec2x8 {
+    type Output = f32x8;
 
-    fn transpose(self) -> Self::Dual {
-        self
+    fn wedge(self, other: Self) -> Self::Output {
+        (self.x * other.y) - (self.y * other.x)
     }
 }
 
-impl Extend<Vec3> for Vec2 {
-    fn extend(self, z: Self::Item) -> Vec3 {
-        let (x, y) = self.into();
-        [x, y, z].into()
-    }
-}
-
-impl Extend<Vec4> for Vec3 {
-    fn extend(self, w: Self::Item) -> Vec4 {
-        let (x, y, z) = self.into();
-        [x, y, z, w].into()
-    }
-}
-
-impl EuclideanSpace for Vec2 {
-    type CoordinateSpace = Self;
-
-    fn origin() -> Self {
-        Self::zero()
-    }
-}
-
-impl EuclideanSpace for Vec3 {
-    type CoordinateSpace = Self;
-
-    fn origin() -> Self {
-        Self::zero()
-    }
-}
-
-impl FiniteDimensional for Vec2 {
-    type N = U2;
-}
-
-impl FiniteDimensional for Vec3 {
-    type N = U3;
-}
-
-impl FiniteDimensional for Vec4 {
-    type N = U4;
-}
-
-impl Fold for Vec2 {
-    fn fold<T, F>(self, seed: T, f: F) -> T
-    where
-        F: FnMut(T, Self::Item) -> T,
-    {
-        self.as_slice().iter().cloned().fold(seed, f)
-    }
-}
-
-impl Fold for Vec3 {
-    fn fold<T, F>(self, seed: T, f: F) -> T
-    where
-        F: FnMut(T, Self::Item) -> T,
-    {
-        self.as_slice().iter().cloned().fold(seed, f)
-    }
-}
-
-impl Fold for Vec4 {
-    fn fold<T, F>(self, seed: T, f: F) -> T
-    where
-        F: FnMut(T, Self::Item) -> T,
-    {
-        self.as_slice().iter().cloned().fold(seed, f)
-    }
-}
-
-impl Homogeneous for Vec2 {
-    type ProjectiveSpace = Vec3;
-}
-
-impl Homogeneous for Vec3 {
-    type ProjectiveSpace = Vec4;
-}
-
-impl InnerSpace for Vec2 {}
-
-impl InnerSpace for Vec3 {}
-
-impl InnerSpace for Vec4 {}
-
-impl Interpolate for Vec2 {
+impl Wedge for Vec3 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        <Self as Lerp<f32>>::lerp(&self, other, f64::from(f) as f32)
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(&self, other)
     }
 }
 
-impl Interpolate for Vec3 {
+impl Wedge for Vec3x4 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        <Self as Lerp<f32>>::lerp(&self, other, f64::from(f) as f32)
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(&self, other)
     }
 }
 
-impl Interpolate for Vec4 {
+impl Wedge for Vec3x8 {
     type Output = Self;
 
-    fn lerp(self, other: Self, f: R64) -> Self::Output {
-        <Self as Lerp<f32>>::lerp(&self, other, f64::from(f) as f32)
+    fn wedge(self, other: Self) -> Self::Output {
+        Self::cross(&self, other)
     }
 }
 
-impl Map<f32> for Vec2 {
-    type Output = Self;
+/// Implements vector space traits for a vector type with a scalar (non-SIMD)
+/// scalar type.
+macro_rules! impl_vector_space {
+    ($v:ident $(,)?) => {
+        impl DualSpace for $v {
+            type Dual = Self;
 
-    fn map<F>(self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> f32,
-    {
-        let (x, y) = self.into();
-        [f(x), f(y)].into()
-    }
-}
+            fn transpose(self) -> Self::Dual {
+                self
+            }
+        }
 
-impl Map<f32> for Vec3 {
-    type Output = Self;
+        impl InnerSpace for $v {}
 
-    fn map<F>(self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> f32,
-    {
-        let (x, y, z) = self.into();
-        [f(x), f(y), f(z)].into()
-    }
-}
+        impl<F> Interpolate<Self, F> for $v
+        where
+            F: Factor,
+        {
+            type Output = Self;
 
-impl Map<f32> for Vec4 {
-    type Output = Self;
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                self.zip_map(other, crate::lerp_by(f))
+            }
+        }
 
-    #[allow(clippy::many_single_char_names)]
-    fn map<F>(self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item) -> f32,
-    {
-        let (x, y, z, w) = self.into();
-        [f(x), f(y), f(z), f(w)].into()
-    }
-}
+        impl VectorSpace for $v {
+            type Scalar = f32;
 
-impl Truncate<Vec2> for Vec3 {
-    fn truncate(self) -> (Vec2, Self::Item) {
-        let z = self.z;
-        (self.into(), z)
-    }
-}
+            fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+                self.as_array().get(index).copied()
+            }
 
-impl Truncate<Vec3> for Vec4 {
-    fn truncate(self) -> (Vec3, Self::Item) {
-        let w = self.w;
-        (self.into(), w)
-    }
-}
+            fn zero() -> Self {
+                Self::zero()
+            }
+        }
+    };
+}
+impl_vector_space!(Vec2);
+impl_vector_space!(Vec3);
+impl_vector_space!(Vec4);
+
+/// Implements Euclidean space traits for a vector type that is used as a
+/// point.
+macro_rules! impl_euclidean_space {
+    ($v:ident $(,)?) => {
+        impl AffineSpace for $v {
+            type Translation = Self;
+        }
 
-impl VectorSpace for Vec2 {
-    type Scalar = f32;
+        impl AsPosition for $v {
+            type Position = Self;
 
-    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
-        match index {
-            0 => Some(self.x),
-            1 => Some(self.y),
-            _ => None,
+            fn as_position(&self) -> &Self::Position {
+                self
+            }
         }
-    }
 
-    fn into_xy(self) -> (Self::Scalar, Self::Scalar) {
-        self.into()
-    }
+        impl AsPositionMut for $v {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                self
+            }
+        }
 
-    fn zero() -> Self {
-        Self::zero()
-    }
-}
+        unsafe impl CoordinateLayout for $v {}
 
-impl VectorSpace for Vec3 {
-    type Scalar = f32;
+        impl EuclideanSpace for $v {
+            type CoordinateSpace = Self;
 
-    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
-        match index {
-            0 => Some(self.x),
-            1 => Some(self.y),
-            2 => Some(self.z),
-            _ => None,
+            fn origin() -> Self {
+                Self::zero()
+            }
         }
-    }
+    };
+}
+impl_euclidean_space!(Vec2);
+impl_euclidean_space!(Vec3);
 
-    fn into_xyz(self) -> (Self::Scalar, Self::Scalar, Self::Scalar) {
-        self.into()
-    }
+impl Homogeneous for Vec2 {
+    type ProjectiveSpace = Vec3;
+}
 
-    fn zero() -> Self {
-        Self::zero()
-    }
+impl Homogeneous for Vec3 {
+    type ProjectiveSpace = Vec4;
 }
 
-impl VectorSpace for Vec4 {
-    type Scalar = f32;
+// TODO: `ultraviolet` matrices do not implement `Neg`, so they cannot
+//       implement `VectorSpace` nor `Matrix`.
+/// Implements adjunct traits for a matrix type.
+///
+/// Items of `ultraviolet` matrices are in column-major order.
+macro_rules! impl_matrix_adjunct {
+    ($m:ident, $d:literal $(,)?) => {
+        impl Adjunct for $m {
+            type Item = f32;
+        }
 
-    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
-        match index {
-            0 => Some(self.x),
-            1 => Some(self.y),
-            2 => Some(self.z),
-            3 => Some(self.w),
-            _ => None,
+        impl Converged for $m {
+            fn converged(value: Self::Item) -> Self {
+                Self::from([value; $d])
+            }
         }
-    }
 
-    fn zero() -> Self {
-        Self::zero()
-    }
-}
+        impl Fold for $m {
+            fn fold<T, F>(self, seed: T, f: F) -> T
+            where
+                F: FnMut(T, Self::Item) -> T,
+            {
+                self.as_array().iter().cloned().fold(seed, f)
+            }
+        }
 
-impl ZipMap<f32> for Vec2 {
-    type Output = Self;
+        impl FromItems for $m {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                items
+                    .into_iter()
+                    .take($d)
+                    .collect::<ArrayVec<[f32; $d]>>()
+                    .into_inner()
+                    .ok()
+                    .map(Self::from)
+            }
+        }
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item, Self::Item) -> f32,
-    {
-        let (x1, y1) = self.into();
-        let (x2, y2) = other.into();
-        [f(x1, x2), f(y1, y2)].into()
-    }
-}
+        impl IntoItems for $m {
+            type Output = ArrayVec<[f32; $d]>;
 
-impl ZipMap<f32> for Vec3 {
-    type Output = Self;
+            fn into_items(self) -> Self::Output {
+                (*self.as_array()).into()
+            }
+        }
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item, Self::Item) -> f32,
-    {
-        let (x1, y1, z1) = self.into();
-        let (x2, y2, z2) = other.into();
-        [f(x1, x2), f(y1, y2), f(z1, z2)].into()
-    }
-}
+        impl Map<f32> for $m {
+            type Output = Self;
 
-impl ZipMap<f32> for Vec4 {
-    type Output = Self;
+            fn map<F>(self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> f32,
+            {
+                Self::from(self.as_array().map(f))
+            }
+        }
 
-    fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
-    where
-        F: FnMut(Self::Item, Self::Item) -> f32,
-    {
-        let (x1, y1, z1, w1) = self.into();
-        let (x2, y2, z2, w2) = other.into();
-        [f(x1, x2), f(y1, y2), f(z1, z2), f(w1, w2)].into()
+        impl ZipMap<f32> for $m {
+            type Output = Self;
+
+            fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> f32,
+            {
+                let mut items = IntoIterator::into_iter(*other.as_array());
+                Self::from(self.as_array().map(|a| f(a, items.next().unwrap())))
+            }
+        }
+    };
+}
+impl_matrix_adjunct!(Mat2, 4);
+impl_matrix_adjunct!(Mat3, 9);
+impl_matrix_adjunct!(Mat4, 16);
+
+#[cfg(test)]
+mod tests {
+    use crate::integration::ultraviolet::{Vec2, Vec3};
+    use crate::ops::Interpolate;
+
+    #[test]
+    fn lerp_clamp() {
+        let a = Vec3::new(0.0, 1.0, 2.0);
+        let b = Vec3::new(2.0, 3.0, 4.0);
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), a.lerp(b, 0.5f64));
+        // The factor is clamped to $[0, 1]$.
+        assert_eq!(a, a.lerp(b, -1.0f64));
+        assert_eq!(b, a.lerp(b, 2.0f64));
+        assert_eq!(
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 0.0).lerp(Vec2::new(1.0, 1.0), 1.5f32)
+        );
     }
 }
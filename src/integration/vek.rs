@@ -0,0 +1,561 @@
+#![cfg(feature = "geometry-vek")]
+
+use arrayvec::ArrayVec;
+use decorum::cmp::IntrinsicOrd;
+use decorum::R64;
+use num::{Float, Num, NumCast, One, Zero};
+use typenum::consts::{U2, U3, U4};
+
+use crate::adjunct::{
+    Adjunct, Converged, Extend, Fold, FromItems, IntoItems, Map, Truncate, ZipMap,
+};
+use crate::ops::{Cross, Dot, Factor, Interpolate, MulMN, Outer, Sqrt, Wedge};
+use crate::query;
+use crate::space::{
+    self, AffineSpace, Basis, CoordinateLayout, DualSpace, EuclideanSpace, ExtendMatrix,
+    FiniteDimensional, Homogeneous, InnerSpace, Matrix, SquareMatrix, TruncateMatrix, VectorSpace,
+};
+use crate::{AsPosition, AsPositionMut, RealScalar};
+
+// Types like `Aabb` are not imported explicitly, because such imports shadow
+// this re-export. `approx` is named via an absolute path, because `vek`
+// re-exports a crate of the same name.
+#[doc(hidden)]
+pub use vek::*;
+
+// `vek` provides vector and matrix types of several dimensions with nearly
+// identical APIs, so implementations are generated by macros rather than
+// written by hand for each type.
+
+/// Implements adjunct traits, `Basis`, `Dot`, and `FiniteDimensional` for a
+/// vector type.
+macro_rules! impl_vector_adjunct {
+    ($v:ident, $n:ident, $d:literal, [$($basis:ident),+ $(,)?] $(,)?) => {
+        impl<T> Adjunct for $v<T> {
+            type Item = T;
+        }
+
+        impl<T> Basis for $v<T>
+        where
+            T: One + Zero,
+        {
+            type Bases = ArrayVec<[Self; $d]>;
+
+            fn canonical_basis() -> Self::Bases {
+                ArrayVec::from([$($v::$basis()),+])
+            }
+        }
+
+        impl<T> Converged for $v<T>
+        where
+            T: Copy,
+        {
+            fn converged(value: Self::Item) -> Self {
+                $v::broadcast(value)
+            }
+        }
+
+        impl<T> Dot for $v<T>
+        where
+            T: Num,
+        {
+            type Output = T;
+
+            fn dot(self, other: Self) -> Self::Output {
+                $v::dot(self, other)
+            }
+        }
+
+        impl<T> FiniteDimensional for $v<T> {
+            type N = $n;
+        }
+
+        impl<T> Fold for $v<T> {
+            fn fold<U, F>(self, seed: U, f: F) -> U
+            where
+                F: FnMut(U, Self::Item) -> U,
+            {
+                IntoIterator::into_iter(self.into_array()).fold(seed, f)
+            }
+        }
+
+        impl<T> FromItems for $v<T> {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                items
+                    .into_iter()
+                    .take($d)
+                    .collect::<ArrayVec<[T; $d]>>()
+                    .into_inner()
+                    .ok()
+                    .map($v::from)
+            }
+        }
+
+        impl<T, F> Interpolate<Self, F> for $v<T>
+        where
+            F: Factor,
+            T: Copy + Num + NumCast,
+        {
+            type Output = Self;
+
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                self.zip_map(other, crate::lerp_by(f))
+            }
+        }
+
+        impl<T> IntoItems for $v<T> {
+            type Output = ArrayVec<[T; $d]>;
+
+            fn into_items(self) -> Self::Output {
+                self.into_array().into()
+            }
+        }
+
+        impl<T, U> Map<U> for $v<T> {
+            type Output = $v<U>;
+
+            fn map<F>(self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> U,
+            {
+                $v::map(self, f)
+            }
+        }
+
+        impl<T, U> ZipMap<U> for $v<T> {
+            type Output = $v<U>;
+
+            fn zip_map<F>(self, other: Self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> U,
+            {
+                $v::map2(self, other, f)
+            }
+        }
+    };
+}
+impl_vector_adjunct!(Vec2, U2, 2, [unit_x, unit_y]);
+impl_vector_adjunct!(Vec3, U3, 3, [unit_x, unit_y, unit_z]);
+impl_vector_adjunct!(Vec4, U4, 4, [unit_x, unit_y, unit_z, unit_w]);
+
+/// Implements vector space traits for a vector type.
+macro_rules! impl_vector_space {
+    ($v:ident $(,)?) => {
+        impl<T> DualSpace for $v<T>
+        where
+            T: RealScalar,
+        {
+            type Dual = Self;
+
+            fn transpose(self) -> Self::Dual {
+                self
+            }
+        }
+
+        impl<T> InnerSpace for $v<T> where T: RealScalar {}
+
+        impl<T> VectorSpace for $v<T>
+        where
+            T: RealScalar,
+        {
+            type Scalar = T;
+
+            fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+                self.as_slice().get(index).copied()
+            }
+        }
+    };
+}
+impl_vector_space!(Vec2);
+impl_vector_space!(Vec3);
+impl_vector_space!(Vec4);
+
+/// Implements Euclidean space traits for a vector type that is used as a
+/// point.
+macro_rules! impl_euclidean_space {
+    ($v:ident $(,)?) => {
+        impl<T> AffineSpace for $v<T>
+        where
+            T: RealScalar,
+        {
+            type Translation = Self;
+        }
+
+        impl<T> AsPosition for $v<T>
+        where
+            Self: EuclideanSpace,
+        {
+            type Position = Self;
+
+            fn as_position(&self) -> &Self::Position {
+                self
+            }
+        }
+
+        impl<T> AsPositionMut for $v<T>
+        where
+            Self: EuclideanSpace,
+        {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                self
+            }
+        }
+
+        unsafe impl<T> CoordinateLayout for $v<T> where Self: EuclideanSpace {}
+
+        impl<T> EuclideanSpace for $v<T>
+        where
+            T: RealScalar,
+        {
+            type CoordinateSpace = Self;
+
+            fn origin() -> Self {
+                $v::zero()
+            }
+        }
+    };
+}
+impl_euclidean_space!(Vec2);
+impl_euclidean_space!(Vec3);
+
+/// Implements `Extend` and `Truncate` between a vector type and a vector type
+/// with one additional dimension.
+macro_rules! impl_extend_truncate {
+    ($lower:ident, $higher:ident $(,)?) => {
+        impl<T> Extend<$higher<T>> for $lower<T> {
+            fn extend(self, item: Self::Item) -> $higher<T> {
+                $higher::from_items(self.into_items().into_iter().chain(Some(item))).unwrap()
+            }
+        }
+
+        impl<T> Truncate<$lower<T>> for $higher<T> {
+            fn truncate(self) -> ($lower<T>, Self::Item) {
+                let mut items = self.into_items();
+                let item = items.pop().unwrap();
+                ($lower::from_items(items).unwrap(), item)
+            }
+        }
+    };
+}
+impl_extend_truncate!(Vec2, Vec3);
+impl_extend_truncate!(Vec3, Vec4);
+
+impl<T> Cross for Vec3<T>
+where
+    T: Copy + Num,
+{
+    type Output = Self;
+
+    fn cross(self, other: Self) -> Self::Output {
+        Vec3::cross(self, other)
+    }
+}
+
+impl<T> Wedge for Vec2<T>
+where
+    T: Copy + Num,
+{
+    type Output = T;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        (self.x * other.y) - (self.y * other.x)
+    }
+}
+
+impl<T> Wedge for Vec3<T>
+where
+    T: Copy + Num,
+{
+    type Output = Self;
+
+    fn wedge(self, other: Self) -> Self::Output {
+        Vec3::cross(self, other)
+    }
+}
+
+impl<T> Homogeneous for Vec2<T>
+where
+    T: RealScalar,
+{
+    type ProjectiveSpace = Vec3<T>;
+}
+
+impl<T> Homogeneous for Vec3<T>
+where
+    T: RealScalar,
+{
+    type ProjectiveSpace = Vec4<T>;
+}
+
+/// Implements adjunct, vector space, and matrix traits for a square matrix
+/// type.
+///
+/// Items of `vek` matrices are in column-major order.
+macro_rules! impl_square_matrix {
+    ($m:ident, $v:ident, $d:literal $(,)?) => {
+        impl<T> Adjunct for $m<T> {
+            type Item = T;
+        }
+
+        impl<T> Converged for $m<T>
+        where
+            T: Copy,
+        {
+            fn converged(value: Self::Item) -> Self {
+                $m::from_col_array([value; $d])
+            }
+        }
+
+        impl<T> Fold for $m<T> {
+            fn fold<U, F>(self, seed: U, f: F) -> U
+            where
+                F: FnMut(U, Self::Item) -> U,
+            {
+                IntoIterator::into_iter(self.into_col_array()).fold(seed, f)
+            }
+        }
+
+        impl<T> FromItems for $m<T> {
+            fn from_items<I>(items: I) -> Option<Self>
+            where
+                I: IntoIterator<Item = Self::Item>,
+            {
+                items
+                    .into_iter()
+                    .take($d)
+                    .collect::<ArrayVec<[T; $d]>>()
+                    .into_inner()
+                    .ok()
+                    .map($m::from_col_array)
+            }
+        }
+
+        impl<T, F> Interpolate<Self, F> for $m<T>
+        where
+            F: Factor,
+            T: Copy + Num + NumCast,
+        {
+            type Output = Self;
+
+            fn lerp(self, other: Self, f: F) -> Self::Output {
+                self.zip_map(other, crate::lerp_by(f))
+            }
+        }
+
+        impl<T> IntoItems for $m<T> {
+            type Output = ArrayVec<[T; $d]>;
+
+            fn into_items(self) -> Self::Output {
+                self.into_col_array().into()
+            }
+        }
+
+        impl<T, U> Map<U> for $m<T> {
+            type Output = $m<U>;
+
+            fn map<F>(self, f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item) -> U,
+            {
+                $m::map(self, f)
+            }
+        }
+
+        impl<T> Matrix for $m<T>
+        where
+            T: MulAdd<Output = T> + RealScalar,
+        {
+            type Row = $v<T>;
+            type Column = $v<T>;
+            type Transpose = Self;
+
+            fn row_component(&self, index: usize) -> Option<Self::Row> {
+                self.into_row_arrays().get(index).copied().map($v::from)
+            }
+
+            fn column_component(&self, index: usize) -> Option<Self::Column> {
+                self.into_col_arrays().get(index).copied().map($v::from)
+            }
+
+            fn transpose(self) -> Self::Transpose {
+                self.transposed()
+            }
+        }
+
+        impl<T> MulMN<$m<T>> for $m<T>
+        where
+            T: MulAdd<Output = T> + RealScalar,
+        {
+            type Output = $m<T>;
+
+            fn mul_mn(self, other: $m<T>) -> <Self as MulMN<$m<T>>>::Output {
+                self * other
+            }
+        }
+
+        impl<T> Outer for $v<T>
+        where
+            T: MulAdd<Output = T> + RealScalar,
+        {
+            type Output = $m<T>;
+        }
+
+        impl<T> SquareMatrix for $m<T>
+        where
+            T: MulAdd<Output = T> + RealScalar,
+        {
+            fn multiplicative_identity() -> Self {
+                $m::identity()
+            }
+        }
+
+        impl<T> VectorSpace for $m<T>
+        where
+            T: RealScalar,
+        {
+            type Scalar = T;
+
+            fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+                self.into_col_array().get(index).copied()
+            }
+        }
+
+        impl<T, U> ZipMap<U> for $m<T> {
+            type Output = $m<U>;
+
+            fn zip_map<F>(self, other: Self, mut f: F) -> Self::Output
+            where
+                F: FnMut(Self::Item, Self::Item) -> U,
+            {
+                let mut items = IntoIterator::into_iter(other.into_col_array());
+                $m::from_col_array(self.into_col_array().map(|a| f(a, items.next().unwrap())))
+            }
+        }
+    };
+}
+impl_square_matrix!(Mat2, Vec2, 4);
+impl_square_matrix!(Mat3, Vec3, 9);
+impl_square_matrix!(Mat4, Vec4, 16);
+
+/// Implements `ExtendMatrix` and `TruncateMatrix` between a square matrix type
+/// and a square matrix type with one additional dimension.
+macro_rules! impl_extend_truncate_matrix {
+    ($lower:ident, $higher:ident $(,)?) => {
+        impl<T> ExtendMatrix<$higher<T>> for $lower<T>
+        where
+            T: MulAdd<Output = T> + RealScalar,
+        {
+            fn extend_matrix(self) -> $higher<T> {
+                $higher::from(self)
+            }
+        }
+
+        impl<T> TruncateMatrix<$lower<T>> for $higher<T>
+        where
+            T: MulAdd<Output = T> + RealScalar,
+        {
+            fn truncate_matrix(self) -> $lower<T> {
+                $lower::from(self)
+            }
+        }
+    };
+}
+impl_extend_truncate_matrix!(Mat2, Mat3);
+impl_extend_truncate_matrix!(Mat3, Mat4);
+
+impl<T> space::Quaternion for Quaternion<T>
+where
+    T: Clamp + Float + Lerp<T, Output = T> + MulAdd<Output = T> + RealScalar + Sqrt,
+{
+    type Vector = Vec3<T>;
+    type Matrix = Mat3<T>;
+
+    fn from_axis_angle(axis: Self::Vector, angle: T) -> Option<Self> {
+        InnerSpace::normalize(axis).map(|axis| Quaternion::rotation_3d(angle, axis))
+    }
+
+    fn conjugate(self) -> Self {
+        Quaternion::conjugate(self)
+    }
+
+    fn normalize(self) -> Option<Self> {
+        if self.magnitude_squared().is_zero() {
+            None
+        }
+        else {
+            Some(self.normalized())
+        }
+    }
+
+    fn into_rotation_matrix(self) -> Self::Matrix {
+        Mat3::from(Mat4::from(self))
+    }
+
+    // `vek` interpolates along the shortest arc for spherical interpolation.
+    fn slerp(self, other: Self, f: R64) -> Self {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        Quaternion::slerp(self, other, <T as NumCast>::from(f).unwrap())
+    }
+
+    fn nlerp(self, other: Self, f: R64) -> Self {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        let other = if self.dot(other) < Zero::zero() {
+            -other
+        }
+        else {
+            other
+        };
+        Lerp::lerp_unclamped(self, other, <T as NumCast>::from(f).unwrap())
+    }
+}
+
+impl<T> From<Aabr<T>> for query::Aabb<Vec2<T>>
+where
+    T: RealScalar,
+{
+    fn from(bounds: Aabr<T>) -> Self {
+        query::Aabb {
+            origin: bounds.min,
+            extent: bounds.max - bounds.min,
+        }
+    }
+}
+
+impl<T> From<query::Aabb<Vec2<T>>> for Aabr<T>
+where
+    T: IntrinsicOrd + RealScalar,
+{
+    fn from(aabb: query::Aabb<Vec2<T>>) -> Self {
+        Aabr {
+            min: aabb.lower_bound(),
+            max: aabb.upper_bound(),
+        }
+    }
+}
+
+impl<T> From<Aabb<T>> for query::Aabb<Vec3<T>>
+where
+    T: RealScalar,
+{
+    fn from(bounds: Aabb<T>) -> Self {
+        query::Aabb {
+            origin: bounds.min,
+            extent: bounds.max - bounds.min,
+        }
+    }
+}
+
+impl<T> From<query::Aabb<Vec3<T>>> for Aabb<T>
+where
+    T: IntrinsicOrd + RealScalar,
+{
+    fn from(aabb: query::Aabb<Vec3<T>>) -> Self {
+        Aabb {
+            min: aabb.lower_bound(),
+            max: aabb.upper_bound(),
+        }
+    }
+}
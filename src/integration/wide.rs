@@ -0,0 +1,566 @@
+#![cfg(feature = "scalar-wide")]
+
+//! SIMD scalars.
+//!
+//! Theon requires that scalars implement `Real`, which cannot be implemented
+//! for the foreign SIMD types of the `wide` crate. This module provides
+//! wrappers like `F32x8`, which implement `Real` and other numeric traits and
+//! can be used as the scalar type of vector spaces. For example,
+//! `Point3<F32x8>` from `nalgebra` implements `EuclideanSpace` and represents
+//! eight points at once.
+//!
+//! Arithmetic and other operations (e.g., `sqrt`) are computed independently
+//! for each lane. However, queries produce a single result, so comparisons and
+//! predicates are only `true` if they are `true` for **all** lanes:
+//!
+//! - Values are partially ordered. Two values are only ordered if all of their
+//!   lanes are ordered in the same way, so `a <= b` if and only if each lane of
+//!   `a` is less than or equal to the corresponding lane of `b`.
+//! - Predicates like `is_positive` are `true` only if they are `true` for each
+//!   lane. `is_nan` and `is_infinite` are `true` if they are `true` for any
+//!   lane.
+//! - Conversions to primitive types (e.g., `to_f64`) fail unless all lanes are
+//!   the same.
+//! - `integer_decode` decodes only the first lane.
+//!
+//! For example, a ray–`Aabb` intersection of eight rays only produces times of
+//! impact if all eight rays intersect the `Aabb`, in which case the times of
+//! impact are given for each lane.
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use decorum::cmp::IntrinsicOrd;
+use decorum::{Encoding, Infinite, Nan, Real};
+use num::{Bounded, Num, NumCast, One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::num::FpCategory;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[doc(hidden)]
+pub use wide::*;
+
+/// Implements numeric traits for a SIMD wrapper by delegating to `wide` or
+/// computing each lane independently.
+macro_rules! impl_wide {
+    ($p:ident, $t:ident, $s:ident, $n:literal $(,)?) => {
+        impl $p {
+            /// Constructs a value with all lanes set to the given scalar.
+            pub const fn splat(value: $s) -> Self {
+                $p($t::new([value; $n]))
+            }
+
+            /// Constructs a value from an array of lanes.
+            pub const fn from_lanes(lanes: [$s; $n]) -> Self {
+                $p($t::new(lanes))
+            }
+
+            /// Gets the lanes of the value as an array.
+            pub fn into_lanes(self) -> [$s; $n] {
+                self.0.to_array()
+            }
+
+            fn map_lanes<F>(self, f: F) -> Self
+            where
+                F: FnMut($s) -> $s,
+            {
+                Self::from_lanes(self.into_lanes().map(f))
+            }
+
+            fn zip_map_lanes<F>(self, other: Self, mut f: F) -> Self
+            where
+                F: FnMut($s, $s) -> $s,
+            {
+                let mut lanes = IntoIterator::into_iter(other.into_lanes());
+                self.map_lanes(|a| f(a, lanes.next().unwrap()))
+            }
+
+            fn all_lanes<F>(self, f: F) -> bool
+            where
+                F: FnMut(&$s) -> bool,
+            {
+                self.into_lanes().iter().all(f)
+            }
+
+            fn any_lanes<F>(self, f: F) -> bool
+            where
+                F: FnMut(&$s) -> bool,
+            {
+                self.into_lanes().iter().any(f)
+            }
+
+            fn all_zipped_lanes<F>(self, other: Self, mut f: F) -> bool
+            where
+                F: FnMut($s, $s) -> bool,
+            {
+                self.into_lanes()
+                    .iter()
+                    .zip(other.into_lanes().iter())
+                    .all(|(a, b)| f(*a, *b))
+            }
+
+            fn uniform(self) -> Option<$s> {
+                let lanes = self.into_lanes();
+                if lanes.iter().all(|x| x.to_bits() == lanes[0].to_bits()) {
+                    Some(lanes[0])
+                }
+                else {
+                    None
+                }
+            }
+        }
+
+        impl AbsDiffEq for $p {
+            type Epsilon = Self;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <Self as Encoding>::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.zip_map_lanes(*other, |a, b| a - b)
+                    .all_zipped_lanes(epsilon, |difference, epsilon| {
+                        difference.abs() <= epsilon
+                    })
+            }
+        }
+
+        impl Bounded for $p {
+            fn min_value() -> Self {
+                <Self as Encoding>::MIN
+            }
+
+            fn max_value() -> Self {
+                <Self as Encoding>::MAX
+            }
+        }
+
+        impl Encoding for $p {
+            const MAX: Self = $p::splat($s::MAX);
+            const MIN: Self = $p::splat($s::MIN);
+            const MIN_POSITIVE: Self = $p::splat($s::MIN_POSITIVE);
+            const EPSILON: Self = $p::splat($s::EPSILON);
+
+            fn classify(self) -> FpCategory {
+                if self.is_nan() {
+                    FpCategory::Nan
+                }
+                else if self.is_infinite() {
+                    FpCategory::Infinite
+                }
+                else if self.any_lanes(|x| x.classify() == FpCategory::Subnormal) {
+                    FpCategory::Subnormal
+                }
+                else if self.is_zero() {
+                    FpCategory::Zero
+                }
+                else {
+                    FpCategory::Normal
+                }
+            }
+
+            fn is_normal(self) -> bool {
+                self.all_lanes(|x| x.is_normal())
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.all_lanes(|x| x.is_sign_positive())
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.all_lanes(|x| x.is_sign_negative())
+            }
+
+            // There is no encoding of all lanes in a single integer, so only
+            // the first lane is decoded. See the module documentation.
+            fn integer_decode(self) -> (u64, i16, i8) {
+                <$s as num::Float>::integer_decode(self.into_lanes()[0])
+            }
+        }
+
+        impl From<$t> for $p {
+            fn from(value: $t) -> Self {
+                $p(value)
+            }
+        }
+
+        impl From<$p> for $t {
+            fn from(value: $p) -> Self {
+                value.0
+            }
+        }
+
+        impl From<[$s; $n]> for $p {
+            fn from(lanes: [$s; $n]) -> Self {
+                $p::from_lanes(lanes)
+            }
+        }
+
+        impl From<$p> for [$s; $n] {
+            fn from(value: $p) -> Self {
+                value.into_lanes()
+            }
+        }
+
+        impl Infinite for $p {
+            const INFINITY: Self = $p::splat($s::INFINITY);
+            const NEG_INFINITY: Self = $p::splat($s::NEG_INFINITY);
+
+            fn is_infinite(self) -> bool {
+                self.any_lanes(|x| x.is_infinite())
+            }
+
+            fn is_finite(self) -> bool {
+                self.all_lanes(|x| x.is_finite())
+            }
+        }
+
+        impl IntrinsicOrd for $p {
+            fn is_undefined(&self) -> bool {
+                self.is_nan()
+            }
+
+            fn min_max_or_undefined(&self, other: &Self) -> (Self, Self) {
+                let min = self.zip_map_lanes(*other, |a, b| {
+                    if a.is_nan() || b.is_nan() {
+                        $s::NAN
+                    }
+                    else {
+                        a.min(b)
+                    }
+                });
+                let max = self.zip_map_lanes(*other, |a, b| {
+                    if a.is_nan() || b.is_nan() {
+                        $s::NAN
+                    }
+                    else {
+                        a.max(b)
+                    }
+                });
+                (min, max)
+            }
+        }
+
+        impl Nan for $p {
+            const NAN: Self = $p::splat($s::NAN);
+
+            fn is_nan(self) -> bool {
+                self.any_lanes(|x| x.is_nan())
+            }
+        }
+
+        impl Num for $p {
+            type FromStrRadixErr = <$s as Num>::FromStrRadixErr;
+
+            fn from_str_radix(source: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                <$s as Num>::from_str_radix(source, radix).map($p::splat)
+            }
+        }
+
+        impl NumCast for $p {
+            fn from<T>(value: T) -> Option<Self>
+            where
+                T: ToPrimitive,
+            {
+                <$s as NumCast>::from(value).map($p::splat)
+            }
+        }
+
+        impl One for $p {
+            fn one() -> Self {
+                $p::splat(1.0)
+            }
+        }
+
+        impl PartialEq for $p {
+            fn eq(&self, other: &Self) -> bool {
+                self.all_zipped_lanes(*other, |a, b| a == b)
+            }
+        }
+
+        impl PartialOrd for $p {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                let mut ordering = Ordering::Equal;
+                for (a, b) in self.into_lanes().iter().zip(other.into_lanes().iter()) {
+                    match a.partial_cmp(b)? {
+                        Ordering::Equal => {}
+                        lane if ordering == Ordering::Equal || ordering == lane => {
+                            ordering = lane;
+                        }
+                        _ => {
+                            return None;
+                        }
+                    }
+                }
+                Some(ordering)
+            }
+        }
+
+        impl Real for $p {
+            const E: Self = $p::splat(std::$s::consts::E);
+            const PI: Self = $p::splat(std::$s::consts::PI);
+            const FRAC_1_PI: Self = $p::splat(std::$s::consts::FRAC_1_PI);
+            const FRAC_2_PI: Self = $p::splat(std::$s::consts::FRAC_2_PI);
+            const FRAC_2_SQRT_PI: Self = $p::splat(std::$s::consts::FRAC_2_SQRT_PI);
+            const FRAC_PI_2: Self = $p::splat(std::$s::consts::FRAC_PI_2);
+            const FRAC_PI_3: Self = $p::splat(std::$s::consts::FRAC_PI_3);
+            const FRAC_PI_4: Self = $p::splat(std::$s::consts::FRAC_PI_4);
+            const FRAC_PI_6: Self = $p::splat(std::$s::consts::FRAC_PI_6);
+            const FRAC_PI_8: Self = $p::splat(std::$s::consts::FRAC_PI_8);
+            const SQRT_2: Self = $p::splat(std::$s::consts::SQRT_2);
+            const FRAC_1_SQRT_2: Self = $p::splat(std::$s::consts::FRAC_1_SQRT_2);
+            const LN_2: Self = $p::splat(std::$s::consts::LN_2);
+            const LN_10: Self = $p::splat(std::$s::consts::LN_10);
+            const LOG2_E: Self = $p::splat(std::$s::consts::LOG2_E);
+            const LOG10_E: Self = $p::splat(std::$s::consts::LOG10_E);
+
+            impl_wide!(@unary $s, floor, ceil, round, trunc, fract, recip);
+            impl_wide!(@unary $s, cbrt, exp, exp2, exp_m1, ln, log2, log10, ln_1p);
+            impl_wide!(@unary $s, sin, cos, tan, asin, acos, atan, sinh, cosh, tanh);
+            impl_wide!(@unary $s, asinh, acosh, atanh);
+            impl_wide!(@binary $s, powf, log, hypot, atan2);
+
+            fn sqrt(self) -> Self {
+                $p(self.0.sqrt())
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                $p(self.0.mul_add(a.0, b.0))
+            }
+
+            fn powi(self, n: i32) -> Self {
+                self.map_lanes(|x| x.powi(n))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+        }
+
+        impl RelativeEq for $p {
+            fn default_max_relative() -> Self::Epsilon {
+                <Self as Encoding>::EPSILON
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                let mut epsilon = IntoIterator::into_iter(epsilon.into_lanes());
+                let mut max_relative = IntoIterator::into_iter(max_relative.into_lanes());
+                self.all_zipped_lanes(*other, |a, b| {
+                    a.relative_eq(&b, epsilon.next().unwrap(), max_relative.next().unwrap())
+                })
+            }
+        }
+
+        impl Signed for $p {
+            fn abs(&self) -> Self {
+                $p(self.0.abs())
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                self.zip_map_lanes(*other, |a, b| if a <= b { 0.0 } else { a - b })
+            }
+
+            fn signum(&self) -> Self {
+                self.map_lanes($s::signum)
+            }
+
+            fn is_positive(&self) -> bool {
+                self.all_lanes(|x| *x > 0.0)
+            }
+
+            fn is_negative(&self) -> bool {
+                self.all_lanes(|x| *x < 0.0)
+            }
+        }
+
+        impl ToPrimitive for $p {
+            fn to_i64(&self) -> Option<i64> {
+                self.uniform()?.to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.uniform()?.to_u64()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                self.uniform()?.to_f64()
+            }
+        }
+
+        impl UlpsEq for $p {
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                let mut epsilon = IntoIterator::into_iter(epsilon.into_lanes());
+                self.all_zipped_lanes(*other, |a, b| {
+                    a.ulps_eq(&b, epsilon.next().unwrap(), max_ulps)
+                })
+            }
+        }
+
+        impl Zero for $p {
+            fn zero() -> Self {
+                $p::splat(0.0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.all_lanes(|x| *x == 0.0)
+            }
+        }
+
+        impl Neg for $p {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $p(-self.0)
+            }
+        }
+
+        impl Rem for $p {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self::Output {
+                self.zip_map_lanes(other, |a, b| a % b)
+            }
+        }
+
+        impl RemAssign for $p {
+            fn rem_assign(&mut self, other: Self) {
+                *self = *self % other;
+            }
+        }
+
+        impl_wide!(@operator $p, Add, add, AddAssign, add_assign);
+        impl_wide!(@operator $p, Div, div, DivAssign, div_assign);
+        impl_wide!(@operator $p, Mul, mul, MulAssign, mul_assign);
+        impl_wide!(@operator $p, Sub, sub, SubAssign, sub_assign);
+    };
+    (@unary $s:ident, $($f:ident),+ $(,)?) => {
+        $(
+            fn $f(self) -> Self {
+                self.map_lanes($s::$f)
+            }
+        )+
+    };
+    (@binary $s:ident, $($f:ident),+ $(,)?) => {
+        $(
+            fn $f(self, other: Self) -> Self {
+                self.zip_map_lanes(other, $s::$f)
+            }
+        )+
+    };
+    (@operator $p:ident, $o:ident, $f:ident, $oa:ident, $fa:ident $(,)?) => {
+        impl $o for $p {
+            type Output = Self;
+
+            fn $f(self, other: Self) -> Self::Output {
+                $p($o::$f(self.0, other.0))
+            }
+        }
+
+        impl $oa for $p {
+            fn $fa(&mut self, other: Self) {
+                $oa::$fa(&mut self.0, other.0)
+            }
+        }
+    };
+}
+
+/// SIMD scalar with four `f32` lanes.
+///
+/// Wraps `f32x4` and implements `Real`, so this type can be used as the scalar
+/// of vector spaces.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct F32x4(pub f32x4);
+
+impl_wide!(F32x4, f32x4, f32, 4);
+
+/// SIMD scalar with eight `f32` lanes.
+///
+/// Wraps `f32x8` and implements `Real`, so this type can be used as the scalar
+/// of vector spaces.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct F32x8(pub f32x8);
+
+impl_wide!(F32x8, f32x8, f32, 8);
+
+/// SIMD scalar with two `f64` lanes.
+///
+/// Wraps `f64x2` and implements `Real`, so this type can be used as the scalar
+/// of vector spaces.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct F64x2(pub f64x2);
+
+impl_wide!(F64x2, f64x2, f64, 2);
+
+/// SIMD scalar with four `f64` lanes.
+///
+/// Wraps `f64x4` and implements `Real`, so this type can be used as the scalar
+/// of vector spaces.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct F64x4(pub f64x4);
+
+impl_wide!(F64x4, f64x4, f64, 4);
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use decorum::Encoding;
+    use nalgebra::{Point2, Vector2};
+    use std::cmp::Ordering;
+
+    use crate::integration::wide::{F32x4, F64x2};
+    use crate::query::{Aabb, Intersection, Ray, Unit};
+
+    type E2 = Point2<F32x4>;
+
+    #[test]
+    fn integer_decode_differing_lanes() {
+        let x = F64x2::from_lanes([0.5, 2.0]);
+        assert_eq!(
+            num::Float::integer_decode(0.5f64),
+            Encoding::integer_decode(x)
+        );
+    }
+
+    #[test]
+    fn comparison_all_lanes() {
+        let a = F32x4::from_lanes([1.0, 2.0, 3.0, 4.0]);
+        let b = F32x4::from_lanes([2.0, 2.0, 4.0, 5.0]);
+        assert!(a <= b);
+        assert_eq!(Some(Ordering::Greater), b.partial_cmp(&a));
+        assert_eq!(
+            None,
+            a.partial_cmp(&F32x4::from_lanes([0.0, 3.0, 3.0, 4.0]))
+        );
+    }
+
+    #[test]
+    fn ray_aabb_intersection_lanes() {
+        let aabb = Aabb::<E2> {
+            origin: E2::new(F32x4::splat(1.0), F32x4::splat(-1.0)),
+            extent: Vector2::new(F32x4::splat(2.0), F32x4::splat(2.0)),
+        };
+        // Each lane is a ray along the `x` axis with a different origin.
+        let ray = |xs: [f32; 4]| Ray::<E2> {
+            origin: E2::new(F32x4::from_lanes(xs), F32x4::splat(0.0)),
+            direction: Unit::try_from_inner(Vector2::new(F32x4::splat(1.0), F32x4::splat(0.0)))
+                .unwrap(),
+        };
+        let hit = ray([0.0, -1.0, 2.0, 0.5]).intersection(&aabb).unwrap();
+        assert_eq!([1.0, 2.0, -1.0, 0.5], hit.entry().into_lanes());
+        assert_eq!([3.0, 4.0, 1.0, 2.5], hit.exit().into_lanes());
+        // The intersection fails if any lane misses the `Aabb`.
+        assert!(ray([0.0, 0.0, 0.0, 4.0]).intersection(&aabb).is_none());
+    }
+}
@@ -4,19 +4,24 @@
 
 use ndarray::{Array, Ix2};
 use ndarray_linalg::convert;
+use ndarray_linalg::eigh::Eigh;
+use ndarray_linalg::lapack::UPLO;
 use ndarray_linalg::layout::MatrixLayout;
 use ndarray_linalg::svd::SVDInto;
+use num::Float;
 use typenum::type_operators::Cmp;
 use typenum::{Greater, Unsigned, U2};
 
 use crate::adjunct::{FromItems, IntoItems};
-use crate::query::{Plane, Unit};
-use crate::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use crate::ops::Sqrt;
+use crate::query::{self, Line, Plane, Unit};
+use crate::space::{EuclideanSpace, FiniteDimensional, Matrix, Scalar, SquareMatrix, Vector};
+use crate::Error;
 
 /// Scalar types that can be used with LAPACK.
-pub trait Lapack: ndarray_linalg::types::Lapack + ndarray_linalg::types::Scalar {}
+pub trait Lapack: ndarray_linalg::types::Lapack + ndarray_linalg::types::Scalar + Sqrt {}
 
-impl<T> Lapack for T where T: ndarray_linalg::types::Lapack + ndarray_linalg::types::Scalar {}
+impl<T> Lapack for T where T: ndarray_linalg::types::Lapack + ndarray_linalg::types::Scalar + Sqrt {}
 
 impl<S> Plane<S>
 where
@@ -31,6 +36,102 @@ where
     {
         svd_ev_plane(points)
     }
+
+    /// Computes a best-fit plane from a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points`.
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Lapack,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]>,
+    {
+        query::try_fit_points(points.as_ref(), |points| Self::from_points(points))
+    }
+}
+
+impl<S> Line<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+{
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: Lapack,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]>,
+    {
+        svd_ev_line(points)
+    }
+
+    /// Computes a best-fit line from a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points`.
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Lapack,
+        Vector<S>: FromItems + IntoItems,
+        I: AsRef<[S]>,
+    {
+        query::try_fit_points(points.as_ref(), |points| Self::from_points(points))
+    }
+}
+
+/// Computes the eigenvalues and eigenvectors of a symmetric matrix.
+///
+/// Unlike the implementations of `SymmetricEigen`, this function supports
+/// square matrices of any type and size. See
+/// `SymmetricEigen::symmetric_eigen`.
+pub fn symmetric_eigen<M>(matrix: &M) -> Option<(M::Column, M)>
+where
+    M: FromItems + SquareMatrix,
+    M::Column: FromItems,
+    M::Row: FiniteDimensional<N = <M::Column as FiniteDimensional>::N>,
+    M::Scalar: Lapack + ndarray_linalg::types::Scalar<Real = M::Scalar>,
+{
+    let n = M::row_count();
+    let m = Array::from_shape_fn((n, n), |(i, j)| {
+        Matrix::scalar_component(matrix, i, j).unwrap()
+    });
+    // Eigenvalues are sorted in nondecreasing order.
+    let (eigenvalues, eigenvectors) = m.eigh(UPLO::Lower).ok()?;
+    Some((
+        M::Column::from_items(eigenvalues.iter().cloned())?,
+        // Iterating over the transpose yields items in column-major order.
+        M::from_items(eigenvectors.t().iter().cloned())?,
+    ))
+}
+
+/// Computes the singular value decomposition of a matrix.
+///
+/// Unlike the implementations of `Decompose`, this function supports square
+/// matrices of any type and size. See `Decompose::svd`.
+pub fn svd<M>(matrix: &M) -> Option<(M, M::Column, M)>
+where
+    M: FromItems + SquareMatrix,
+    M::Column: FromItems,
+    M::Row: FiniteDimensional<N = <M::Column as FiniteDimensional>::N>,
+    M::Scalar: Lapack + ndarray_linalg::types::Scalar<Real = M::Scalar>,
+{
+    let n = M::row_count();
+    let m = Array::from_shape_fn((n, n), |(i, j)| {
+        Matrix::scalar_component(matrix, i, j).unwrap()
+    });
+    // Singular values are sorted in nonincreasing order.
+    if let Ok((Some(u), sigma, Some(vt))) = m.svd_into(true, true) {
+        Some((
+            // Iterating over the transpose yields items in column-major order.
+            M::from_items(u.t().iter().cloned())?,
+            M::Column::from_items(sigma.iter().cloned())?,
+            // Iterating over `V^T` yields the items of `V` in column-major
+            // order.
+            M::from_items(vt.iter().cloned())?,
+        ))
+    }
+    else {
+        None
+    }
 }
 
 /// Maps columnar data into a two-dimensional array.
@@ -73,6 +174,10 @@ where
     let m = map_into_array(points, |point| *point - centroid)?;
     // TODO: Fails at runtime if `V^T` is not requested.
     if let Ok((Some(u), sigma, _)) = m.svd_into(true, true) {
+        // The points are coincident if all singular values are zero.
+        if sigma.iter().all(|value| *value <= Float::epsilon()) {
+            return None;
+        }
         let i = sigma
             .iter()
             .enumerate()
@@ -94,6 +199,41 @@ where
     }
 }
 
+/// Computes a best-fit line from a set of points.
+///
+/// The line is fit using total least squares via a singular value
+/// decomposition.
+fn svd_ev_line<S, I>(points: I) -> Option<Line<S>>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: Lapack,
+    Vector<S>: FromItems + IntoItems,
+    I: AsRef<[S]>,
+{
+    let points = points.as_ref();
+    let centroid = EuclideanSpace::centroid(points.iter().cloned())?;
+    let m = map_into_array(points, |point| *point - centroid)?;
+    if let Ok((Some(u), sigma, _)) = m.svd_into(true, true) {
+        // The points are coincident if all singular values are zero.
+        if sigma.iter().all(|value| *value <= Float::epsilon()) {
+            return None;
+        }
+        let i = sigma
+            .iter()
+            .enumerate()
+            .max_by(|(_, v1), (_, v2)| v1.partial_cmp(v2).unwrap())?
+            .0;
+        let direction = Vector::<S>::from_items(u.column(i).into_iter().cloned())?;
+        Some(Line {
+            origin: centroid,
+            direction: Unit::try_from_inner(direction)?,
+        })
+    }
+    else {
+        None
+    }
+}
+
 #[cfg(all(test, feature = "geometry-nalgebra"))]
 mod tests {
     use approx::assert_abs_diff_eq;
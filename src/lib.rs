@@ -1,9 +1,9 @@
 //! **Theon** abstracts Euclidean spaces and geometric queries with support for
 //! popular linear algebra and spatial crates in the Rust ecosystem.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 // TODO: Require the `geometry-nalgebra` feature for doc tests.
 //       See https://github.com/rust-lang/rust/issues/43781
-
 #![doc(
     html_favicon_url = "https://raw.githubusercontent.com/olson-sean-k/theon/master/doc/theon-favicon.ico"
 )]
@@ -12,21 +12,199 @@
 )]
 
 pub mod adjunct;
+pub mod batch;
+pub mod convert;
+pub mod coordinates;
+pub mod faer;
 pub mod integration;
 pub mod lapack;
 pub mod ops;
+pub mod parallel;
+pub mod predicates;
+pub mod proptest;
 pub mod query;
+pub mod random;
 pub mod space;
+pub mod spline;
+pub mod types;
 
-use decorum::R64;
+use approx::AbsDiffEq;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{AddAssign, MulAssign, SubAssign};
+use decorum::cmp::IntrinsicOrd;
 use num::{self, Num, NumCast, One, Zero};
+/// Derives `AsPosition` and `AsPositionMut`.
+///
+/// The position field is the field with a `#[position]` attribute or, if no
+/// field has that attribute, the field named `position`. These derives require
+/// the `derive` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::{Point3, Vector3};
+/// use theon::{AsPosition, AsPositionMut};
+///
+/// #[derive(AsPosition, AsPositionMut)]
+/// pub struct Vertex {
+///     #[position]
+///     point: Point3<f64>,
+///     normal: Vector3<f64>,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use theon_derive::{AsPosition, AsPositionMut};
 
-use crate::space::EuclideanSpace;
+use crate::adjunct::{Adjunct, MapAssign};
+use crate::ops::{Factor, Real};
+use crate::query::Aabb;
+use crate::space::{EuclideanSpace, Scalar, Transform};
 
 pub mod prelude {
-    //! Re-exports commonly used types and traits.
+    //! Re-exports traits.
+    //!
+    //! This module re-exports the traits of Theon, but not its types. Glob
+    //! imports of this module bring the traits and their methods into scope.
+    //!
+    //! `Extend` is re-exported anonymously, because its name conflicts with
+    //! `Extend` in the standard prelude. `Real` and `Transcendental` are not
+    //! re-exported, because their functions conflict with those of
+    //! `decorum::Real`.
+    //!
+    //! # Examples
+    //!
+    //! ```rust
+    //! # extern crate nalgebra;
+    //! # extern crate theon;
+    //! #
+    //! use nalgebra::Point3;
+    //! use theon::prelude::*;
+    //!
+    //! fn centroid<S>(points: &[S]) -> Option<S>
+    //! where
+    //!     S: EuclideanSpace,
+    //! {
+    //!     S::centroid(points.iter().cloned())
+    //! }
+    //!
+    //! let mut points = vec![Point3::new(1.0, 0.0, 0.0)];
+    //! points.extend(Some(Point3::new(-1.0, 0.0, 0.0)));
+    //! assert_eq!(Some(Point3::origin()), centroid(&points));
+    //! ```
+
+    pub use crate::adjunct::{
+        Adjunct, AsItems, Cast, Compare, Converged, Extend as _, Fold, FoldRef, FromFn, FromItems,
+        IntoItems, IterItems, Map, MapAssign, MapRef, MapWithIndex, Mask, PerComponent, Swizzle,
+        Truncate, TryFromItems, TryMap, ZipMap, ZipMap3, ZipWith,
+    };
+    pub use crate::convert::{FromMatrix, FromSpace, IntoMatrix, IntoSpace};
+    pub use crate::ops::{
+        Angle, Barycentric, Cross, Decompose, Dot, Factor, Interpolate, MulMN, MulV, Outer,
+        Project, Reflect, Reject, Rotate, SignedAngle, Sqrt, Wedge,
+    };
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub use crate::ops::{Qr, SymmetricEigen};
+    #[cfg(feature = "std")]
+    pub use crate::query::bvh::AsAabb;
+    pub use crate::query::gjk::SupportFunction;
+    pub use crate::query::sat::SeparatingAxes;
+    pub use crate::query::{ClosestPoint, Contains, Intersection, SignedDistance, Sweep};
+    pub use crate::space::{
+        AffineSpace, Basis, CoordinateLayout, DualSpace, DynDimensional, DynVectorSpace,
+        EuclideanSpace, ExtendMatrix, FiniteDimensional, Homogeneous, InnerSpace, Matrix,
+        MetricSpace, Quaternion, RigidMotion, Rotation, SquareMatrix, Transform, TruncateMatrix,
+        VectorSpace,
+    };
+    pub use crate::{AsPosition, AsPositionMut, RealScalar, RealScalarAssign};
+}
 
-    pub use crate::query::Intersection as _;
+/// Errors of fallible constructors.
+///
+/// Fallible constructors return `Option`, which does not describe why a
+/// construction failed. Many of these constructors have `Result` variants that
+/// instead return this error, such as `Unit::from_inner` and
+/// `Aabb::try_from_points`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// A vector has zero or undefined magnitude and so has no direction.
+    ZeroLength,
+    /// Too few items were given to construct an adjunct.
+    TooFewItems,
+    /// Too many items were given to construct an adjunct.
+    TooManyItems,
+    /// No points or other inputs were given.
+    Empty,
+    /// Inputs are degenerate, such as coincident or collinear points.
+    Degenerate,
+}
+
+impl Display for Error {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        let message = match *self {
+            Error::ZeroLength => "vector has zero or undefined magnitude",
+            Error::TooFewItems => "too few items",
+            Error::TooManyItems => "too many items",
+            Error::Empty => "no inputs",
+            Error::Degenerate => "degenerate inputs",
+        };
+        formatter.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Real scalar of vector spaces.
+///
+/// This trait is an alias for the bounds that Theon requires of the scalars of
+/// vector spaces (see `VectorSpace::Scalar`) and is implemented for all types
+/// that satisfy them. Generic code can use this trait in bounds rather than
+/// repeating its constituent traits, which may change.
+///
+/// This trait is sealed and cannot be implemented directly.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::{Point3, Scalar};
+/// use theon::space::EuclideanSpace;
+/// use theon::RealScalarAssign;
+///
+/// fn midpoint<T>(a: Point3<T>, b: Point3<T>) -> Point3<T>
+/// where
+///     T: RealScalarAssign + Scalar,
+/// {
+///     EuclideanSpace::centroid([a, b]).unwrap()
+/// }
+/// ```
+pub trait RealScalar: AbsDiffEq + NumCast + Real + sealed::Sealed {}
+
+impl<T> RealScalar for T where T: AbsDiffEq + NumCast + Real {}
+
+/// Real scalar with compound assignment operators.
+///
+/// Some integrations require compound assignment operators of scalars, such
+/// as `AddAssign`. As with `RealScalar`, this trait is an alias for these
+/// bounds and is sealed.
+pub trait RealScalarAssign: AddAssign + MulAssign + RealScalar + SubAssign {}
+
+impl<T> RealScalarAssign for T where T: AddAssign + MulAssign + RealScalar + SubAssign {}
+
+mod sealed {
+    use approx::AbsDiffEq;
+    use num::NumCast;
+
+    use crate::ops::Real;
+
+    pub trait Sealed {}
+
+    impl<T> Sealed for T where T: AbsDiffEq + NumCast + Real {}
 }
 
 pub type Position<T> = <T as AsPosition>::Position;
@@ -119,9 +297,21 @@ pub trait AsPositionMut: AsPosition {
         self.transform(f);
         self
     }
+
+    /// Maps the components of the position in place.
+    ///
+    /// Unlike `transform`, this does not construct a new position, which can
+    /// be significantly faster for bulk updates of many positions.
+    fn map_position_assign<F>(&mut self, f: F)
+    where
+        Self::Position: MapAssign,
+        F: FnMut(&mut <Self::Position as Adjunct>::Item),
+    {
+        self.as_position_mut().map_assign(f)
+    }
 }
 
-impl<'a, T> AsPosition for &'a T
+impl<T> AsPosition for &T
 where
     T: AsPosition,
     T::Position: EuclideanSpace,
@@ -133,7 +323,7 @@ where
     }
 }
 
-impl<'a, T> AsPosition for &'a mut T
+impl<T> AsPosition for &mut T
 where
     T: AsPosition,
     T::Position: EuclideanSpace,
@@ -145,7 +335,7 @@ where
     }
 }
 
-impl<'a, T> AsPositionMut for &'a mut T
+impl<T> AsPositionMut for &mut T
 where
     T: AsPositionMut,
 {
@@ -154,13 +344,129 @@ where
     }
 }
 
+/// Transforms the positions of items in place.
+///
+/// # Examples
+///
+/// Translating the vertices of a mesh:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::{Point2, Vector2};
+/// use theon::{transform_positions, AsPosition, AsPositionMut};
+///
+/// pub struct Vertex {
+///     position: Point2<f64>,
+/// }
+/// #
+/// # impl AsPosition for Vertex {
+/// #     type Position = Point2<f64>;
+/// #
+/// #     fn as_position(&self) -> &Self::Position {
+/// #         &self.position
+/// #     }
+/// # }
+/// #
+/// # impl AsPositionMut for Vertex {
+/// #     fn as_position_mut(&mut self) -> &mut Self::Position {
+/// #         &mut self.position
+/// #     }
+/// # }
+///
+/// let mut vertices = vec![
+///     Vertex {
+///         position: Point2::new(0.0, 0.0),
+///     },
+///     Vertex {
+///         position: Point2::new(1.0, 2.0),
+///     },
+/// ];
+/// transform_positions(&mut vertices, |position| position + Vector2::new(1.0, 0.0));
+/// assert_eq!(Point2::new(2.0, 2.0), vertices[1].position);
+/// ```
+pub fn transform_positions<I, F>(items: I, mut f: F)
+where
+    I: IntoIterator,
+    I::Item: AsPositionMut,
+    F: FnMut(&Position<I::Item>) -> Position<I::Item>,
+{
+    for mut item in items {
+        item.transform(&mut f);
+    }
+}
+
+/// Transforms the positions of items in place with a `Transform`.
+///
+/// See `transform_positions`.
+pub fn transform_positions_with<I, T>(items: I, transform: &T)
+where
+    I: IntoIterator,
+    I::Item: AsPositionMut,
+    T: Transform<Position<I::Item>>,
+{
+    transform_positions(items, |position| transform.transform_point(*position))
+}
+
+/// Gets the axis-aligned bounding box of the positions of items.
+///
+/// Returns `None` if there are no items. See `Aabb::from_points`.
+pub fn aabb_of_positions<I>(items: I) -> Option<Aabb<Position<I::Item>>>
+where
+    I: IntoIterator,
+    I::Item: AsPosition,
+    Scalar<Position<I::Item>>: IntrinsicOrd,
+{
+    Aabb::from_points(items.into_iter().map(|item| *item.as_position()))
+}
+
 /// Linearly interpolates between two values.
-pub fn lerp<T>(a: T, b: T, f: R64) -> T
+///
+/// The interpolation is computed in the type of the factor `f`, which is
+/// clamped to $[0, 1]$. To interpolate many values by the same factor, prefer
+/// `lerp_by`.
+pub fn lerp<T, F>(a: T, b: T, f: F) -> T
 where
     T: Num + NumCast,
+    F: Factor,
 {
     let f = num::clamp(f, Zero::zero(), One::one());
-    let af = <R64 as NumCast>::from(a).unwrap() * (R64::one() - f);
-    let bf = <R64 as NumCast>::from(b).unwrap() * f;
+    let af = <F as NumCast>::from(a).unwrap() * (F::one() - f);
+    let bf = <F as NumCast>::from(b).unwrap() * f;
     <T as NumCast>::from(af + bf).unwrap()
 }
+
+/// Creates a function that linearly interpolates between two values.
+///
+/// If `T` can represent fractions, such as `f32`, then the factor `f` is
+/// converted into `T` once and the interpolation is computed in `T`, which
+/// avoids converting each pair of values into the type of the factor.
+/// Otherwise, such as for integers, the returned function is equivalent to
+/// `lerp`. The factor is clamped to $[0, 1]$.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::lerp_by;
+///
+/// assert_eq!(1.5f32, lerp_by(0.25f64)(1.0, 3.0));
+/// assert_eq!(2u8, lerp_by(0.25f64)(1, 5));
+/// ```
+pub fn lerp_by<T, F>(f: F) -> impl Clone + Fn(T, T) -> T
+where
+    T: Clone + Num + NumCast,
+    F: Factor,
+{
+    let f = num::clamp(f, Zero::zero(), One::one());
+    // Integers truncate one half to zero, so this detects scalars that cannot
+    // represent the factor.
+    let half = F::one() / (F::one() + F::one());
+    let g = <T as NumCast>::from(half)
+        .filter(|half| half.clone() + half.clone() == T::one())
+        .and_then(|_| <T as NumCast>::from(f));
+    move |a, b| match g {
+        Some(ref g) => (a * (T::one() - g.clone())) + (b * g.clone()),
+        None => lerp(a, b, f),
+    }
+}
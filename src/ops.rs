@@ -1,8 +1,353 @@
+#[cfg(any(feature = "std", feature = "libm"))]
+use approx::{abs_diff_eq, AbsDiffEq};
+#[cfg(any(feature = "std", feature = "libm"))]
+use arrayvec::ArrayVec;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::array;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::cmp::Ordering;
+use core::ops::{Add, Mul, Neg};
 use decorum::R64;
 use itertools::iproduct;
+use num::{Num, NumCast, One, Signed, Zero};
+use typenum::consts::{U2, U3};
+#[cfg(any(feature = "std", feature = "libm"))]
+use typenum::{type_operators::Cmp, Less, U5};
 
 use crate::adjunct::{Fold, FromItems, ZipMap};
-use crate::space::{DualSpace, FiniteDimensional, Matrix, VectorSpace};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::space::InnerSpace;
+use crate::space::{
+    DualSpace, EuclideanSpace, FiniteDimensional, Matrix, Scalar, SquareMatrix, Vector, VectorSpace,
+};
+
+/// Eigendecomposition of symmetric matrices.
+///
+/// This trait is implemented for square matrices with no more than four
+/// columns using Jacobi rotations, which does not depend on a linear algebra
+/// library but requires the `std` or `libm` feature. These implementations do
+/// not depend on the `lapack` and `linalg-faer` features, which instead provide
+/// `lapack::symmetric_eigen` and `faer::symmetric_eigen` for square matrices of
+/// any size.
+pub trait SymmetricEigen: SquareMatrix
+where
+    Self::Row: FiniteDimensional<N = <Self::Column as FiniteDimensional>::N>,
+{
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix.
+    ///
+    /// Eigenvalues are sorted in nondecreasing order and the corresponding
+    /// eigenvectors form the columns of the matrix. Only the lower triangle of
+    /// the matrix is read.
+    fn symmetric_eigen(&self) -> Option<(Self::Column, Self)>;
+
+    /// Computes the eigenvalues of a symmetric matrix.
+    ///
+    /// Eigenvalues are sorted in nondecreasing order.
+    fn eigenvalues(&self) -> Option<Self::Column> {
+        self.symmetric_eigen().map(|(eigenvalues, _)| eigenvalues)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<M> SymmetricEigen for M
+where
+    M: FromItems + SquareMatrix,
+    M::Column: FromItems,
+    M::Row: FiniteDimensional<N = <M::Column as FiniteDimensional>::N>,
+    <M::Column as FiniteDimensional>::N: Cmp<U5, Output = Less>,
+    M::Scalar: Signed + Sqrt,
+{
+    fn symmetric_eigen(&self) -> Option<(M::Column, M)> {
+        let n = M::row_count();
+        let mut a = [[M::Scalar::zero(); 4]; 4];
+        for (i, row) in a.iter_mut().enumerate().take(n) {
+            for (j, entry) in row.iter_mut().enumerate().take(n) {
+                // Only read the lower triangle.
+                *entry = Matrix::scalar_component(self, i.max(j), i.min(j))?;
+            }
+        }
+        let (eigenvalues, eigenvectors) = jacobi_eigen(a, n);
+        let mut indices = (0..n).collect::<ArrayVec<[usize; 4]>>();
+        indices.sort_unstable_by(|i, j| {
+            eigenvalues[*i]
+                .partial_cmp(&eigenvalues[*j])
+                .unwrap_or(Ordering::Equal)
+        });
+        Some((
+            M::Column::from_items(indices.iter().map(|i| eigenvalues[*i]))?,
+            M::from_items(
+                indices
+                    .iter()
+                    .flat_map(|j| eigenvectors.iter().take(n).map(move |row| row[*j])),
+            )?,
+        ))
+    }
+}
+
+/// Singular value and polar decompositions of square matrices.
+///
+/// This trait is implemented for `nalgebra` matrices. These implementations do
+/// not depend on the `lapack` and `linalg-faer` features, which instead provide
+/// `lapack::svd` and `faer::svd` for square matrices of any type and size.
+pub trait Decompose: SquareMatrix
+where
+    Self::Row: FiniteDimensional<N = <Self::Column as FiniteDimensional>::N>,
+{
+    /// Computes the singular value decomposition of a matrix.
+    ///
+    /// Produces the matrices $U$ and $V$ and the singular values $\Sigma$ such
+    /// that the matrix is $U\Sigma V^T$. Singular values are sorted in
+    /// nonincreasing order and the corresponding left and right singular
+    /// vectors form the columns of $U$ and $V$, respectively.
+    fn svd(&self) -> Option<(Self, Self::Column, Self)>;
+
+    /// Computes the singular values of a matrix.
+    ///
+    /// Singular values are sorted in nonincreasing order.
+    fn singular_values(&self) -> Option<Self::Column> {
+        self.svd().map(|(_, sigma, _)| sigma)
+    }
+
+    /// Computes the polar decomposition of a matrix.
+    ///
+    /// Produces an orthogonal matrix $R$ and a symmetric positive semidefinite
+    /// matrix $P$ such that the matrix is $RP$. $R$ is the orthogonal matrix
+    /// nearest to the matrix, but may be a reflection rather than a rotation
+    /// if the determinant of the matrix is not positive.
+    fn polar(&self) -> Option<(Self, Self)>
+    where
+        Self: FromItems,
+    {
+        let (u, sigma, v) = self.svd()?;
+        let n = Self::row_count();
+        let component = |m: &Self, i, j| Matrix::scalar_component(m, i, j).unwrap();
+        // Items are produced in column-major order.
+        let r = Self::from_items(iproduct!(0..n, 0..n).map(|(j, i)| {
+            (0..n).fold(Zero::zero(), |sum: Self::Scalar, k| {
+                sum + (component(&u, i, k) * component(&v, j, k))
+            })
+        }))?;
+        let p = Self::from_items(iproduct!(0..n, 0..n).map(|(j, i)| {
+            (0..n).fold(Zero::zero(), |sum: Self::Scalar, k| {
+                let sigma = VectorSpace::scalar_component(&sigma, k).unwrap();
+                sum + (component(&v, i, k) * sigma * component(&v, j, k))
+            })
+        }))?;
+        Some((r, p))
+    }
+}
+
+/// QR decomposition of matrices.
+///
+/// Factors an $m\times n$ matrix with $m\geq n$ into a matrix $Q$ of the same
+/// shape with orthonormal columns and an upper triangular $n\times n$ matrix
+/// $R$ with a positive diagonal, such that the matrix is $QR$. The columns of
+/// $Q$ are an orthonormal basis of the columns of the matrix.
+///
+/// The decomposition is computed via Householder reflections, which are
+/// numerically more robust than Gram-Schmidt orthogonalization. This trait is
+/// implemented for matrices with no more than four columns and requires the
+/// `std` or `libm` feature.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub trait Qr: Matrix {
+    /// The upper triangular $n\times n$ matrix $R$.
+    type Triangular: Matrix<Scalar = Self::Scalar>;
+
+    /// Computes the QR decomposition of a matrix.
+    ///
+    /// Returns `None` if the matrix has more columns than rows or its columns
+    /// are linearly dependent.
+    fn qr(&self) -> Option<(Self, Self::Triangular)>;
+
+    /// Orthonormalizes the columns of a matrix.
+    ///
+    /// Produces the matrix $Q$ of the QR decomposition. See `qr`.
+    fn orthonormalize(&self) -> Option<Self> {
+        self.qr().map(|(q, _)| q)
+    }
+
+    /// Solves the linear least squares problem $\min_x\|Ax-b\|$, where $A$ is
+    /// the matrix.
+    ///
+    /// Returns `None` if the QR decomposition of the matrix fails. See `qr`.
+    fn solve_least_squares(&self, b: Self::Column) -> Option<<Self::Row as DualSpace>::Dual>;
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<M> Qr for M
+where
+    M: FromItems + Matrix,
+    M::Column: FromItems + InnerSpace,
+    <M::Row as DualSpace>::Dual: FromItems + Outer,
+    <M::Row as FiniteDimensional>::N: Cmp<U5, Output = Less>,
+    M::Scalar: Signed + Sqrt,
+{
+    type Triangular = <<M::Row as DualSpace>::Dual as Outer>::Output;
+
+    fn qr(&self) -> Option<(Self, Self::Triangular)> {
+        let (m, n) = (M::row_count(), M::column_count());
+        if m < n {
+            return None;
+        }
+        let (zero, one) = (M::Scalar::zero(), M::Scalar::one());
+        let two = one + one;
+        let component = |v: &M::Column, i| VectorSpace::scalar_component(v, i).unwrap();
+        let mut columns = (0..n)
+            .map(|j| self.column_component(j))
+            .collect::<Option<ArrayVec<[M::Column; 4]>>>()?;
+        let mut reflectors = ArrayVec::<[M::Column; 4]>::new();
+        for k in 0..n {
+            // Compute the reflection that zeroes the components of the `k`th
+            // column below the diagonal. The sign of `alpha` is chosen to
+            // avoid cancellation.
+            let x = M::Column::from_items((0..m).map(|i| {
+                if i < k {
+                    zero
+                }
+                else {
+                    component(&columns[k], i)
+                }
+            }))?;
+            let norm = Sqrt::sqrt(x.square_magnitude());
+            if abs_diff_eq!(norm, zero) {
+                return None;
+            }
+            let alpha = if component(&x, k).is_negative() {
+                norm
+            }
+            else {
+                -norm
+            };
+            let u = M::Column::from_items((0..m).map(|i| {
+                if i == k {
+                    component(&x, i) - alpha
+                }
+                else {
+                    component(&x, i)
+                }
+            }))?;
+            let scale = two / u.square_magnitude();
+            for column in columns.iter_mut().skip(k) {
+                *column = *column + -(u * (u.dot(*column) * scale));
+            }
+            reflectors.push(u * Sqrt::sqrt(scale));
+        }
+        // Apply the reflections in reverse order to the canonical basis to
+        // form the columns of `Q`. Negate columns of `Q` and rows of `R` as
+        // needed so that the diagonal of `R` is positive.
+        let signs = (0..n)
+            .map(|k| {
+                if component(&columns[k], k).is_negative() {
+                    -one
+                }
+                else {
+                    one
+                }
+            })
+            .collect::<ArrayVec<[M::Scalar; 4]>>();
+        let mut q = ArrayVec::<[M::Column; 4]>::new();
+        for j in 0..n {
+            let basis = M::Column::from_items((0..m).map(|i| {
+                if i == j {
+                    one
+                }
+                else {
+                    zero
+                }
+            }))?;
+            let column = reflectors
+                .iter()
+                .rev()
+                .fold(basis, |v, u| v + -(*u * u.dot(v)));
+            q.push(column * signs[j]);
+        }
+        let q = M::from_items(
+            q.iter()
+                .flat_map(|column| (0..m).map(move |i| component(column, i))),
+        )?;
+        // Items are produced in column-major order.
+        let r = Self::Triangular::from_items(iproduct!(0..n, 0..n).map(|(j, i)| {
+            if i <= j {
+                component(&columns[j], i) * signs[i]
+            }
+            else {
+                zero
+            }
+        }))?;
+        Some((q, r))
+    }
+
+    fn solve_least_squares(&self, b: Self::Column) -> Option<<Self::Row as DualSpace>::Dual> {
+        let (q, r) = self.qr()?;
+        let n = M::column_count();
+        // Solve $Rx=Q^Tb$ by back substitution.
+        let mut x = ArrayVec::<[M::Scalar; 4]>::new();
+        for i in (0..n).rev() {
+            let c = q.column_component(i)?.dot(b);
+            let sum = x.iter().zip((i + 1..n).rev()).fold(c, |sum, (x, j)| {
+                sum - (Matrix::scalar_component(&r, i, j).unwrap() * *x)
+            });
+            x.push(sum / Matrix::scalar_component(&r, i, i)?);
+        }
+        FromItems::from_items(x.into_iter().rev())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+/// Computes the eigenvalues and eigenvectors of a symmetric matrix.
+///
+/// Only the leading $n\times n$ block of the matrix is decomposed. The matrix
+/// is diagonalized by cyclic Jacobi rotations, which only require square roots
+/// and converge quickly for small matrices (for $2\times2$ matrices, a single
+/// rotation is exact). The eigenvectors form the columns of the output matrix
+/// in the same order as the eigenvalues, which are not sorted.
+pub(crate) fn jacobi_eigen<T, const N: usize>(mut a: [[T; N]; N], n: usize) -> ([T; N], [[T; N]; N])
+where
+    T: AbsDiffEq + Copy + PartialOrd + Signed + Sqrt,
+{
+    const SWEEPS: usize = 32;
+
+    let (zero, one) = (T::zero(), T::one());
+    let two = one + one;
+    let mut v = [[zero; N]; N];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = one;
+    }
+    let pairs = || (0..n).flat_map(move |p| ((p + 1)..n).map(move |q| (p, q)));
+    for _ in 0..SWEEPS {
+        let off = pairs().fold(zero, |off, (p, q)| off + (a[p][q] * a[p][q]));
+        if abs_diff_eq!(off, zero) {
+            break;
+        }
+        for (p, q) in pairs() {
+            if abs_diff_eq!(a[p][q], zero) {
+                continue;
+            }
+            // Compute the rotation that zeroes the off-diagonal entry at
+            // `(p, q)`, choosing the smaller angle for stability.
+            let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+            let sign = if theta < zero { -one } else { one };
+            let t = sign / (theta.abs() + Sqrt::sqrt((theta * theta) + one));
+            let c = one / Sqrt::sqrt((t * t) + one);
+            let s = t * c;
+            let rotate = |x: &mut T, y: &mut T| {
+                let (x0, y0) = (*x, *y);
+                *x = (c * x0) - (s * y0);
+                *y = (s * x0) + (c * y0);
+            };
+            // Rotate the columns of both matrices and then the rows of `a`.
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (head, tail) = row.split_at_mut(q);
+                rotate(&mut head[p], &mut tail[0]);
+            }
+            let (head, tail) = a.split_at_mut(q);
+            for (x, y) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                rotate(x, y);
+            }
+        }
+    }
+    (array::from_fn(|i| a[i][i]), v)
+}
 
 pub trait Project<T = Self> {
     type Output;
@@ -10,13 +355,265 @@ pub trait Project<T = Self> {
     fn project(self, other: T) -> Self::Output;
 }
 
-pub trait Interpolate<T = Self>: Sized {
+/// Unsigned angle between vectors.
+///
+/// The angle is measured in radians and is in the interval $[0, \pi]$.
+pub trait Angle<T = Self> {
+    type Output;
+
+    fn angle(self, other: T) -> Self::Output;
+}
+
+/// Signed angle between vectors in two dimensions.
+///
+/// The angle is measured in radians and is in the interval $(-\pi, \pi]$. The
+/// angle is positive if `other` is counterclockwise from `self`.
+pub trait SignedAngle<T = Self> {
+    type Output;
+
+    fn signed_angle(self, other: T) -> Self::Output;
+}
+
+/// Rejection of vectors.
+///
+/// The rejection of a vector from another vector is the component of the
+/// vector that is orthogonal to the other vector, such that the projection and
+/// rejection sum to the vector. The rejection of a point from a `Plane` is the
+/// component of its displacement from the plane that is orthogonal to the
+/// plane.
+pub trait Reject<T = Self> {
+    type Output;
+
+    fn reject(self, other: T) -> Self::Output;
+}
+
+/// Reflection of points and vectors.
+///
+/// Reflecting a vector about a unit vector reflects it across the hyperplane
+/// orthogonal to the unit vector, such as reflecting a direction about a
+/// surface normal. Reflecting a point about a `Plane` or `Line` mirrors the
+/// point across the plane or line.
+pub trait Reflect<T> {
+    type Output;
+
+    fn reflect(self, other: T) -> Self::Output;
+}
+
+/// Ordered field.
+///
+/// Exact predicates and intersections only require the operations of an
+/// ordered field. Unlike `Real`, this trait does not require `Copy`, so it is
+/// implemented for arbitrary precision types like `BigRational`.
+pub trait Field: Clone + Neg<Output = Self> + Num + PartialOrd {}
+
+impl<T> Field for T where T: Clone + Neg<Output = T> + Num + PartialOrd {}
+
+/// Real number scalar.
+///
+/// This trait is required of the scalars of vector spaces. It only provides
+/// functions that are exact for an ordered field, so rational and fixed-point
+/// types can implement it. It is implemented for all types that implement
+/// `decorum::Real`, such as `f32`, `f64`, and `R64`, but is much smaller, so
+/// scalar types from crates that are unaware of `decorum` can implement it
+/// directly.
+///
+/// Square roots and transcendental functions are provided by the `Sqrt` and
+/// `Transcendental` traits, which are not required of scalars.
+pub trait Real: Copy + Field + Signed {
+    fn floor(self) -> Self;
+    fn recip(self) -> Self;
+}
+
+impl<T> Real for T
+where
+    T: decorum::Real,
+{
+    fn floor(self) -> Self {
+        decorum::Real::floor(self)
+    }
+
+    fn recip(self) -> Self {
+        decorum::Real::recip(self)
+    }
+}
+
+/// Square root of scalars.
+///
+/// With the `std` feature, this trait is implemented for all types that
+/// implement `decorum::Real`. Otherwise, this trait is implemented for `f32`
+/// and `f64` using `libm` when the `libm` feature is enabled.
+pub trait Sqrt {
+    fn sqrt(self) -> Self;
+}
+
+#[cfg(feature = "std")]
+impl<T> Sqrt for T
+where
+    T: decorum::Real,
+{
+    fn sqrt(self) -> Self {
+        decorum::Real::sqrt(self)
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl Sqrt for f32 {
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl Sqrt for f64 {
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+}
+
+/// Transcendental functions of scalars.
+///
+/// As with `Sqrt`, this trait is implemented for all types that implement
+/// `decorum::Real` with the `std` feature and otherwise for `f32` and `f64`
+/// using `libm` when the `libm` feature is enabled. Rational and fixed-point
+/// scalars do not implement this trait, so functions that require it, such as
+/// `Angle::angle`, cannot be used with those scalars.
+pub trait Transcendental: Real {
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+#[cfg(feature = "std")]
+impl<T> Transcendental for T
+where
+    T: decorum::Real,
+{
+    fn powf(self, n: Self) -> Self {
+        decorum::Real::powf(self, n)
+    }
+
+    fn sin(self) -> Self {
+        decorum::Real::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        decorum::Real::cos(self)
+    }
+
+    fn acos(self) -> Self {
+        decorum::Real::acos(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        decorum::Real::atan2(self, other)
+    }
+}
+
+/// Implements `Transcendental` for a primitive floating-point type using
+/// `libm`.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+macro_rules! impl_transcendental {
+    ($t:ident => $powf:ident, $sin:ident, $cos:ident, $acos:ident, $atan2:ident $(,)?) => {
+        impl Transcendental for $t {
+            fn powf(self, n: Self) -> Self {
+                libm::$powf(self, n)
+            }
+
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+
+            fn acos(self) -> Self {
+                libm::$acos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+        }
+    };
+}
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_transcendental!(f32 => powf, sinf, cosf, acosf, atan2f);
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_transcendental!(f64 => pow, sin, cos, acos, atan2);
+
+/// Real number that can be used as an interpolation factor.
+///
+/// This trait is implemented for all real numbers that can be converted from
+/// other numeric types, such as `f32`, `f64`, and `R64`.
+pub trait Factor: NumCast + Real {}
+
+impl<T> Factor for T where T: NumCast + Real {}
+
+/// Linear interpolation.
+///
+/// The interpolation factor `F` can be any `Factor`. Implementations are
+/// generic over `F`, so for example `Vector3<f32>` can be interpolated with an
+/// `f32` factor directly. The factor defaults to `R64` in bounds.
+pub trait Interpolate<T = Self, F = R64>: Sized {
     type Output;
 
-    fn lerp(self, other: T, f: R64) -> Self::Output;
+    fn lerp(self, other: T, f: F) -> Self::Output;
 
-    fn midpoint(self, other: T) -> Self::Output {
-        self.lerp(other, 0.5.into())
+    fn midpoint(self, other: T) -> Self::Output
+    where
+        F: Factor,
+    {
+        self.lerp(other, F::one() / (F::one() + F::one()))
+    }
+}
+
+/// Barycentric coordinates with respect to a triangle.
+///
+/// Barycentric coordinates $(u, v, w)$ weight the vertices $(a, b, c)$ of a
+/// triangle, such that a point is given by $ua + vb + wc$ where
+/// $u + v + w = 1$. All weights are in $[0, 1]$ if and only if the point is
+/// in the triangle.
+pub trait Barycentric: Sized {
+    type Weight;
+
+    /// Computes the combination of the vertices of a triangle with the given
+    /// weights.
+    fn from_barycentric(
+        triangle: (Self, Self, Self),
+        weights: (Self::Weight, Self::Weight, Self::Weight),
+    ) -> Self;
+
+    /// Computes the barycentric coordinates of a point with respect to a
+    /// triangle.
+    ///
+    /// If the point is not in the plane of the triangle, then the coordinates
+    /// of its projection onto that plane are computed. Returns `None` if the
+    /// triangle is degenerate.
+    fn into_barycentric(
+        self,
+        triangle: (Self, Self, Self),
+    ) -> Option<(Self::Weight, Self::Weight, Self::Weight)>;
+}
+
+/// Interpolates between three values with barycentric weights.
+///
+/// Unlike `Barycentric`, this function supports any type that implements
+/// `Interpolate` by composing linear interpolations. Interpolation is clamped,
+/// so the weights must be nonnegative and sum to one.
+pub fn barycentric_lerp<T>(a: T, b: T, c: T, weights: (R64, R64, R64)) -> T
+where
+    T: Interpolate<Output = T>,
+{
+    let (u, v, w) = weights;
+    let uv = u + v;
+    if uv.is_zero() {
+        c
+    }
+    else {
+        a.lerp(b, v / uv).lerp(c, w)
     }
 }
 
@@ -26,12 +623,159 @@ pub trait Dot<T = Self> {
     fn dot(self, other: T) -> Self::Output;
 }
 
+impl<T, const N: usize> Dot for [T; N]
+where
+    T: Add<Output = T> + Mul<Output = T> + Zero,
+{
+    type Output = T;
+
+    fn dot(self, other: Self) -> Self::Output {
+        self.per_item_product(other).sum()
+    }
+}
+
 pub trait Cross<T = Self> {
     type Output;
 
     fn cross(self, other: T) -> Self::Output;
 }
 
+/// Computes the scalar triple product $\vec{a}\cdot(\vec{b}\times\vec{c})$.
+///
+/// The scalar triple product is the signed volume of the parallelepiped
+/// spanned by the vectors. It is positive if the vectors form a right-handed
+/// system and zero if the vectors are coplanar.
+pub fn scalar_triple<T>(a: T, b: T, c: T) -> <T as Dot>::Output
+where
+    T: Cross<Output = T> + Dot,
+{
+    a.dot(b.cross(c))
+}
+
+/// Computes the vector triple product $\vec{a}\times(\vec{b}\times\vec{c})$.
+///
+/// The vector triple product lies in the plane spanned by $\vec{b}$ and
+/// $\vec{c}$.
+pub fn vector_triple<T>(a: T, b: T, c: T) -> T
+where
+    T: Cross<Output = T>,
+{
+    a.cross(b.cross(c))
+}
+
+/// Exterior (wedge) product of vectors.
+///
+/// The wedge product $\vec{u}\wedge\vec{v}$ is a bivector: the oriented plane
+/// segment spanned by the vectors. In two dimensions, bivectors are represented
+/// by a scalar, which is the signed area of the parallelogram spanned by the
+/// vectors. In three dimensions, bivectors are represented by their dual
+/// vector, which is the same as the cross product.
+pub trait Wedge<T = Self> {
+    type Output;
+
+    fn wedge(self, other: T) -> Self::Output;
+}
+
+/// Computes the signed area of a triangle in two dimensions.
+///
+/// The area is positive if the points are in counterclockwise order and
+/// negative if the points are in clockwise order. The area is zero if the
+/// points are collinear.
+pub fn signed_area<S>(a: S, b: S, c: S) -> Scalar<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    Vector<S>: Wedge<Output = Scalar<S>>,
+{
+    let two = Scalar::<S>::one() + One::one();
+    (b - a).wedge(c - a) / two
+}
+
+/// Computes the signed volume of a tetrahedron in three dimensions.
+///
+/// The volume is positive if the point `d` is on the side of the triangle
+/// $\overline{abc}$ from which its points appear in counterclockwise order. The
+/// volume is zero if the points are coplanar.
+pub fn signed_volume<S>(a: S, b: S, c: S, d: S) -> Scalar<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Wedge<Output = Vector<S>>,
+{
+    let six = Scalar::<S>::one() + One::one() + One::one() + One::one() + One::one() + One::one();
+    (b - a).wedge(c - a).dot(d - a) / six
+}
+
+/// Application of a matrix to a column vector.
+///
+/// Applying an $m\times n$ matrix to a column vector with $n$ components
+/// yields a column vector with $m$ components. For `Matrix` types, the output
+/// is typically the `Column` type of the matrix. Arrays of arrays are
+/// interpreted as matrices in column-major order, such that `[[T; M]; N]` is an
+/// $m\times n$ matrix.
+pub trait MulV<T> {
+    type Output;
+
+    fn mul_v(self, vector: T) -> Self::Output;
+}
+
+impl<T, const M: usize, const N: usize> MulV<[T; N]> for [[T; M]; N]
+where
+    T: Add<Output = T> + Copy + Mul<Output = T> + Zero,
+{
+    type Output = [T; M];
+
+    fn mul_v(self, vector: [T; N]) -> Self::Output {
+        let mut output = [Zero::zero(); M];
+        for (column, x) in self.iter().zip(vector.iter()) {
+            for (y, a) in output.iter_mut().zip(column.iter()) {
+                *y = *y + (*a * *x);
+            }
+        }
+        output
+    }
+}
+
+/// Outer product of vectors.
+///
+/// The outer product $\vec{u}\otimes\vec{v}$ is the matrix
+/// $\vec{u}\vec{v}^T$, with a row for each component of $\vec{u}$ and a
+/// column for each component of $\vec{v}$.
+pub trait Outer<T = Self>: FiniteDimensional + VectorSpace
+where
+    T: FiniteDimensional + VectorSpace<Scalar = Self::Scalar>,
+{
+    // TODO: This implementation requires `FromItems`, which could be
+    //       cumbersome to implement.
+    type Output: FromItems + Matrix<Scalar = Self::Scalar>;
+
+    fn outer(self, other: T) -> <Self as Outer<T>>::Output {
+        // Items are produced in column-major order.
+        FromItems::from_items(
+            iproduct!(0..T::dimensions(), 0..Self::dimensions()).map(|(j, i)| {
+                VectorSpace::scalar_component(&self, i).unwrap()
+                    * VectorSpace::scalar_component(&other, j).unwrap()
+            }),
+        )
+        .unwrap()
+    }
+}
+
+/// Rotation of points, vectors, and rotations.
+///
+/// Rotating a point or vector applies the given rotation to it. Rotating a
+/// rotation composes the rotations, such that the given rotation is applied
+/// after `self`.
+pub trait Rotate<R> {
+    type Output;
+
+    fn rotate(self, rotation: R) -> Self::Output;
+}
+
+/// Product of conformable matrices.
+///
+/// The product of an $m\times n$ matrix and an $n\times k$ matrix is an
+/// $m\times k$ matrix. Matrices need not be square and column vectors are
+/// $n\times 1$ matrices, so this also describes matrix-vector products where
+/// vectors implement `Matrix`, as with `nalgebra`.
 pub trait MulMN<T = Self>: Matrix
 where
     T: Matrix<Scalar = Self::Scalar>,
@@ -56,3 +800,76 @@ where
         .unwrap()
     }
 }
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{Matrix2, Matrix3, Matrix3x2, Vector3};
+
+    use crate::ops::{Decompose, Dot, Qr, SymmetricEigen};
+
+    #[test]
+    fn dot_array() {
+        assert_eq!(32, [1, 2, 3].dot([4, 5, 6]));
+        assert_eq!(0.0, [1.0, 0.0].dot([0.0, 1.0]));
+        assert_eq!(25.0, [3.0, 4.0].dot([3.0, 4.0]));
+    }
+
+    #[test]
+    fn polar_e2() {
+        // A rotation by a quarter turn followed by a scale along `x`.
+        let m = Matrix2::new(0.0, -1.0, 2.0, 0.0);
+        let (r, p) = Decompose::polar(&m).unwrap();
+        let product = r * p;
+        for (expected, actual) in m.iter().zip(product.iter()) {
+            assert_abs_diff_eq!(*expected, *actual, epsilon = 1e-9);
+        }
+        assert_abs_diff_eq!(1.0, r.determinant(), epsilon = 1e-9);
+        assert_abs_diff_eq!(2.0, p[(0, 0)], epsilon = 1e-9);
+        assert_abs_diff_eq!(1.0, p[(1, 1)], epsilon = 1e-9);
+        assert_abs_diff_eq!(0.0, p[(0, 1)], epsilon = 1e-9);
+    }
+
+    #[test]
+    fn symmetric_eigen_e3() {
+        // An inertia tensor with a principal axis along `z`. Only the lower
+        // triangle is read, so the upper triangle is left zeroed.
+        let m = Matrix3::new(2.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0);
+        let (eigenvalues, eigenvectors) = SymmetricEigen::symmetric_eigen(&m).unwrap();
+        assert_abs_diff_eq!(1.0, eigenvalues.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(3.0, eigenvalues.y, epsilon = 1e-9);
+        assert_abs_diff_eq!(5.0, eigenvalues.z, epsilon = 1e-9);
+        let product = eigenvectors.transpose() * eigenvectors;
+        for (expected, actual) in Matrix3::<f64>::identity().iter().zip(product.iter()) {
+            assert_abs_diff_eq!(*expected, *actual, epsilon = 1e-9);
+        }
+        let eigenvector = eigenvectors.column(1);
+        assert_abs_diff_eq!(eigenvector.x, eigenvector.y, epsilon = 1e-9);
+        assert_abs_diff_eq!(1.0, eigenvectors.column(2).z.abs(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn qr_least_squares() {
+        // Fit the line `y = a + bt` to the points `(0, 1)`, `(1, 2)`, and
+        // `(2, 2)`.
+        let m = Matrix3x2::new(1.0, 0.0, 1.0, 1.0, 1.0, 2.0);
+        let (q, r) = Qr::qr(&m).unwrap();
+        let product = q * r;
+        for (expected, actual) in m.iter().zip(product.iter()) {
+            assert_abs_diff_eq!(*expected, *actual, epsilon = 1e-9);
+        }
+        let identity = q.transpose() * q;
+        for (expected, actual) in Matrix2::<f64>::identity().iter().zip(identity.iter()) {
+            assert_abs_diff_eq!(*expected, *actual, epsilon = 1e-9);
+        }
+        assert!(r[(0, 0)] > 0.0 && r[(1, 1)] > 0.0);
+        assert_abs_diff_eq!(0.0, r[(1, 0)]);
+
+        let x = m.solve_least_squares(Vector3::new(1.0, 2.0, 2.0)).unwrap();
+        assert_abs_diff_eq!(7.0 / 6.0, x.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(0.5, x.y, epsilon = 1e-9);
+
+        // Linearly dependent columns have no decomposition.
+        assert!(Qr::qr(&Matrix3x2::new(1.0, 2.0, 1.0, 2.0, 1.0, 2.0)).is_none());
+    }
+}
@@ -0,0 +1,171 @@
+//! Parallel bulk operations using `rayon`.
+//!
+//! This module provides parallel versions of bulk operations over slices of
+//! points and items with positions, such as `EuclideanSpace::centroid`,
+//! `Aabb::from_points`, and `transform_positions`. These operations distribute
+//! work over the `rayon` thread pool and are only beneficial for large sets of
+//! points; for small sets, prefer the sequential operations. A `Bvh` can also
+//! be built in parallel using `Bvh::par_from_items`.
+//!
+//! This module requires the `rayon` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::parallel;
+//!
+//! let points: Vec<_> = (0..1000)
+//!     .map(|n| Point2::new(n as f64, -(n as f64)))
+//!     .collect();
+//! let aabb = parallel::aabb_from_points(&points).unwrap();
+//! assert_eq!(Point2::new(0.0, -999.0), aabb.origin);
+//! ```
+
+#![cfg(feature = "rayon")]
+
+use decorum::cmp::IntrinsicOrd;
+use num::{One, Zero};
+use rayon::prelude::*;
+
+use crate::query::Aabb;
+use crate::space::{EuclideanSpace, Scalar, Transform, Vector, VectorSpace};
+use crate::{AsPosition, AsPositionMut, Position};
+
+/// The number of points bounded by each task in `aabb_from_points`.
+const CHUNK_SIZE: usize = 1024;
+
+/// Computes the centroid (arithmetic mean) of points in parallel.
+///
+/// Returns `None` if there are no points. See `EuclideanSpace::centroid`.
+pub fn centroid<S>(points: &[S]) -> Option<S>
+where
+    S: EuclideanSpace + Sync,
+    Vector<S>: Send,
+    Scalar<S>: Send,
+{
+    // Partial means are merged with their weights (counts), which avoids
+    // summing all coordinates before dividing.
+    let (mean, total) = points
+        .par_iter()
+        .map(|point| (point.into_coordinates(), Scalar::<S>::one()))
+        .reduce(
+            || (Vector::<S>::zero(), Zero::zero()),
+            |(a, m), (b, n)| {
+                let total = m + n;
+                if total.is_zero() {
+                    (a, total)
+                }
+                else {
+                    (a + ((b + -a) * (n / total)), total)
+                }
+            },
+        );
+    if total.is_zero() {
+        None
+    }
+    else {
+        Some(S::from_coordinates(mean))
+    }
+}
+
+/// Creates an `Aabb` that bounds a set of points in parallel.
+///
+/// Returns `None` if the set of points is empty. See `Aabb::from_points`.
+pub fn aabb_from_points<S>(points: &[S]) -> Option<Aabb<S>>
+where
+    S: EuclideanSpace + Sync,
+    Aabb<S>: Send,
+    Scalar<S>: IntrinsicOrd,
+{
+    points
+        .par_chunks(CHUNK_SIZE)
+        .filter_map(|points| Aabb::from_points(points.iter().copied()))
+        .reduce_with(|a, b| a.union(&b))
+}
+
+/// Gets the axis-aligned bounding box of the positions of items in parallel.
+///
+/// Returns `None` if there are no items. See `aabb_of_positions`.
+pub fn aabb_of_positions<T>(items: &[T]) -> Option<Aabb<Position<T>>>
+where
+    T: AsPosition + Sync,
+    Aabb<Position<T>>: Send,
+    Scalar<Position<T>>: IntrinsicOrd,
+{
+    items
+        .par_chunks(CHUNK_SIZE)
+        .filter_map(|items| Aabb::from_points(items.iter().map(|item| *item.as_position())))
+        .reduce_with(|a, b| a.union(&b))
+}
+
+/// Transforms the positions of items in place in parallel.
+///
+/// See `transform_positions`.
+pub fn transform_positions<T, F>(items: &mut [T], f: F)
+where
+    T: AsPositionMut + Send,
+    F: Fn(&Position<T>) -> Position<T> + Sync,
+{
+    items.par_iter_mut().for_each(|item| item.transform(&f));
+}
+
+/// Transforms the positions of items in place with a `Transform` in parallel.
+///
+/// See `transform_positions_with`.
+pub fn transform_positions_with<T, U>(items: &mut [T], transform: &U)
+where
+    T: AsPositionMut + Send,
+    U: Transform<Position<T>> + Sync,
+{
+    transform_positions(items, |position| transform.transform_point(*position))
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Isometry3, Point3, Vector3};
+
+    use crate::parallel;
+    use crate::query::Aabb;
+    use crate::space::EuclideanSpace;
+
+    type E3 = Point3<f64>;
+
+    fn points() -> Vec<E3> {
+        (0..5000)
+            .map(|n| {
+                let n = n as f64;
+                E3::new(n, n % 7.0, -n * 2.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parallel_centroid_aabb_e3() {
+        let points = points();
+        let centroid = parallel::centroid(&points).unwrap();
+        let expected = E3::centroid(points.iter().copied()).unwrap();
+        assert!((centroid - expected).norm() < 1e-6);
+        assert_eq!(
+            Aabb::from_points(points.iter().copied()),
+            parallel::aabb_from_points(&points)
+        );
+        assert_eq!(
+            parallel::aabb_from_points(&points),
+            parallel::aabb_of_positions(&points)
+        );
+        assert!(parallel::centroid::<E3>(&[]).is_none());
+        assert!(parallel::aabb_from_points::<E3>(&[]).is_none());
+    }
+
+    #[test]
+    fn parallel_transform_positions_e3() {
+        let mut points = points();
+        parallel::transform_positions_with(&mut points, &Isometry3::translation(1.0, 0.0, 0.0));
+        parallel::transform_positions(&mut points, |point| point + Vector3::z());
+        assert_eq!(E3::new(11.0, 3.0, -19.0), points[10]);
+    }
+}
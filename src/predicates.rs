@@ -0,0 +1,285 @@
+//! Robust geometric predicates.
+//!
+//! This module provides adaptively exact orientation and in-circle predicates
+//! using the `robust` crate, which implements the predicates of Jonathan
+//! Shewchuk. Unlike tests of the sign of `signed_area` or `signed_volume`,
+//! these predicates are always correct, even for nearly degenerate inputs, so
+//! algorithms like Delaunay triangulation and convex hulls can depend on them
+//! for consistent decisions.
+//!
+//! Predicates are computed with `f64` coordinates and are exact for scalars
+//! that convert to `f64` exactly, such as `f32`, `f64`, and `R64`.
+//!
+//! This module requires the `robust` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::predicates::{self, Orientation};
+//!
+//! let a = Point2::new(0.5, 0.5);
+//! let b = Point2::new(12.0, 12.0);
+//! let c = Point2::new(24.0, 24.0 + 1e-14);
+//! assert_eq!(Orientation::Counterclockwise, predicates::orient2d(a, b, c));
+//! ```
+
+#![cfg(feature = "robust")]
+
+use ::robust::{Coord, Coord3D};
+use typenum::{U2, U3};
+
+use crate::space::{EuclideanSpace, FiniteDimensional, Scalar};
+
+/// Orientation of points.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    Clockwise,
+    Counterclockwise,
+    /// The points are collinear (in two dimensions) or coplanar (in three
+    /// dimensions).
+    Degenerate,
+}
+
+/// Location of a point with respect to a circle or sphere.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Containment {
+    Inside,
+    /// The point is on the circle or sphere.
+    Boundary,
+    Outside,
+}
+
+/// Determines the orientation of a triangle in two dimensions.
+///
+/// The orientation is counterclockwise if `c` is to the left of the directed
+/// line from `a` to `b`. This is the sign of `signed_area`.
+pub fn orient2d<S>(a: S, b: S, c: S) -> Orientation
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    Scalar<S>: Into<f64>,
+{
+    orientation(::robust::orient2d(coord(a), coord(b), coord(c)))
+}
+
+/// Determines the orientation of a triangle in three dimensions as viewed
+/// from a point.
+///
+/// The orientation is counterclockwise if the points of the triangle
+/// $\overline{abc}$ appear in counterclockwise order when viewed from `d`.
+/// This is the sign of `signed_volume`.
+pub fn orient3d<S>(a: S, b: S, c: S, d: S) -> Orientation
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Scalar<S>: Into<f64>,
+{
+    // The `robust` crate uses the opposite sign.
+    orientation(-::robust::orient3d(
+        coord3(a),
+        coord3(b),
+        coord3(c),
+        coord3(d),
+    ))
+}
+
+/// Determines the location of a point with respect to the circle that passes
+/// through the points of a triangle.
+///
+/// The points of the triangle may be in either order. Returns `None` if the
+/// points of the triangle are collinear.
+pub fn incircle<S>(a: S, b: S, c: S, d: S) -> Option<Containment>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    Scalar<S>: Into<f64>,
+{
+    let (a, b, c) = (coord(a), coord(b), coord(c));
+    let orientation = ::robust::orient2d(a, b, c);
+    if orientation == 0.0 {
+        None
+    }
+    else {
+        Some(containment(
+            ::robust::incircle(a, b, c, coord(d)) * orientation.signum(),
+        ))
+    }
+}
+
+/// Determines the location of a point with respect to the sphere that passes
+/// through the points of a tetrahedron.
+///
+/// The points of the tetrahedron may be in any order. Returns `None` if the
+/// points of the tetrahedron are coplanar.
+pub fn insphere<S>(a: S, b: S, c: S, d: S, e: S) -> Option<Containment>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Scalar<S>: Into<f64>,
+{
+    let (a, b, c, d) = (coord3(a), coord3(b), coord3(c), coord3(d));
+    let orientation = ::robust::orient3d(a, b, c, d);
+    if orientation == 0.0 {
+        None
+    }
+    else {
+        Some(containment(
+            ::robust::insphere(a, b, c, d, coord3(e)) * orientation.signum(),
+        ))
+    }
+}
+
+fn orientation(determinant: f64) -> Orientation {
+    if determinant > 0.0 {
+        Orientation::Counterclockwise
+    }
+    else if determinant < 0.0 {
+        Orientation::Clockwise
+    }
+    else {
+        Orientation::Degenerate
+    }
+}
+
+fn containment(determinant: f64) -> Containment {
+    if determinant > 0.0 {
+        Containment::Inside
+    }
+    else if determinant < 0.0 {
+        Containment::Outside
+    }
+    else {
+        Containment::Boundary
+    }
+}
+
+fn coord<S>(point: S) -> Coord<f64>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    Scalar<S>: Into<f64>,
+{
+    let (x, y) = point.into_xy();
+    Coord {
+        x: x.into(),
+        y: y.into(),
+    }
+}
+
+fn coord3<S>(point: S) -> Coord3D<f64>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Scalar<S>: Into<f64>,
+{
+    let (x, y, z) = point.into_xyz();
+    Coord3D {
+        x: x.into(),
+        y: y.into(),
+        z: z.into(),
+    }
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Point2, Point3};
+
+    use crate::ops;
+    use crate::predicates::{self, Containment, Orientation};
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn orient_e2_e3() {
+        let (a, b, c) = (E2::new(0.0, 0.0), E2::new(1.0, 0.0), E2::new(0.0, 1.0));
+        assert!(ops::signed_area(a, b, c) > 0.0);
+        assert_eq!(Orientation::Counterclockwise, predicates::orient2d(a, b, c));
+        assert_eq!(Orientation::Clockwise, predicates::orient2d(a, c, b));
+        assert_eq!(
+            Orientation::Degenerate,
+            predicates::orient2d(a, b, E2::new(2.0, 0.0))
+        );
+
+        let (a, b, c, d) = (
+            E3::new(0.0, 0.0, 0.0),
+            E3::new(1.0, 0.0, 0.0),
+            E3::new(0.0, 1.0, 0.0),
+            E3::new(0.0, 0.0, 1.0),
+        );
+        assert!(ops::signed_volume(a, b, c, d) > 0.0);
+        assert_eq!(
+            Orientation::Counterclockwise,
+            predicates::orient3d(a, b, c, d)
+        );
+        assert_eq!(Orientation::Clockwise, predicates::orient3d(a, c, b, d));
+        assert_eq!(
+            Orientation::Degenerate,
+            predicates::orient3d(a, b, c, E3::new(1.0, 1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn orient2d_nearly_collinear() {
+        // These points are nearly collinear, and the sign of the inexact
+        // signed area may be incorrect for small perturbations of `c`.
+        let a = E2::new(0.5, 0.5);
+        let b = E2::new(12.0, 12.0);
+        for ulps in 0..64 {
+            let c = E2::new(24.0, f64::from_bits(24.0f64.to_bits() + ulps));
+            let expected = if ulps == 0 {
+                Orientation::Degenerate
+            }
+            else {
+                Orientation::Counterclockwise
+            };
+            assert_eq!(expected, predicates::orient2d(a, b, c));
+        }
+    }
+
+    #[test]
+    fn incircle_insphere() {
+        let (a, b, c) = (E2::new(1.0, 0.0), E2::new(0.0, 1.0), E2::new(-1.0, 0.0));
+        for (a, b, c) in [(a, b, c), (a, c, b)] {
+            assert_eq!(
+                Some(Containment::Inside),
+                predicates::incircle(a, b, c, E2::new(0.0, 0.5))
+            );
+            assert_eq!(
+                Some(Containment::Boundary),
+                predicates::incircle(a, b, c, E2::new(0.0, -1.0))
+            );
+            assert_eq!(
+                Some(Containment::Outside),
+                predicates::incircle(a, b, c, E2::new(2.0, 0.0))
+            );
+        }
+        assert_eq!(
+            None,
+            predicates::incircle(a, E2::new(2.0, 0.0), E2::new(3.0, 0.0), b)
+        );
+
+        let (a, b, c, d) = (
+            E3::new(1.0, 0.0, 0.0),
+            E3::new(0.0, 1.0, 0.0),
+            E3::new(-1.0, 0.0, 0.0),
+            E3::new(0.0, 0.0, 1.0),
+        );
+        for (c, d) in [(c, d), (d, c)] {
+            assert_eq!(
+                Some(Containment::Inside),
+                predicates::insphere(a, b, c, d, E3::origin())
+            );
+            assert_eq!(
+                Some(Containment::Boundary),
+                predicates::insphere(a, b, c, d, E3::new(0.0, 0.0, -1.0))
+            );
+            assert_eq!(
+                Some(Containment::Outside),
+                predicates::insphere(a, b, c, d, E3::new(0.0, 2.0, 0.0))
+            );
+        }
+        assert_eq!(
+            None,
+            predicates::insphere(a, b, c, E3::new(0.0, -1.0, 0.0), d)
+        );
+    }
+}
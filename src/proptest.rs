@@ -0,0 +1,146 @@
+//! Property testing strategies using `proptest`.
+//!
+//! This module provides strategies that generate valid values of query types
+//! and points of any `EuclideanSpace`. Points are generated within a bounding
+//! region given by an `Aabb` and unit vectors are always normalized. This
+//! module requires the `std` feature.
+
+#![cfg(all(feature = "proptest", feature = "std"))]
+
+use ::proptest::collection;
+use ::proptest::strategy::Strategy;
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+use decorum::cmp::IntrinsicOrd;
+use num::NumCast;
+
+use crate::adjunct::{FromItems, ZipMap};
+use crate::ops::Sqrt;
+use crate::query::{Aabb, Line, Plane, Ray, Unit};
+use crate::space::{EuclideanSpace, Scalar, Vector};
+
+/// Generates vectors with components in the given range.
+fn vector<S>(range: RangeInclusive<f64>) -> impl Strategy<Value = Vector<S>>
+where
+    S: EuclideanSpace,
+    Vector<S>: Debug + FromItems,
+{
+    collection::vec(range, S::dimensions()).prop_filter_map(
+        "component cannot be represented by scalar",
+        |components| {
+            FromItems::from_items(
+                components
+                    .into_iter()
+                    .map(<Scalar<S> as NumCast>::from)
+                    .collect::<Option<Vec<_>>>()?,
+            )
+        },
+    )
+}
+
+/// Generates points within a bounding region.
+pub fn point<S>(region: Aabb<S>) -> impl Strategy<Value = S>
+where
+    S: Debug + EuclideanSpace,
+    Vector<S>: Debug + FromItems,
+{
+    vector::<S>(0.0..=1.0)
+        .prop_map(move |factors| region.origin + region.extent.per_item_product(factors))
+}
+
+/// Generates unit vectors.
+pub fn unit<S>() -> impl Strategy<Value = Unit<Vector<S>>>
+where
+    S: EuclideanSpace,
+    Vector<S>: Debug + FromItems,
+    Scalar<S>: Sqrt,
+{
+    vector::<S>(-1.0..=1.0).prop_filter_map("vector has zero magnitude", Unit::try_from_inner)
+}
+
+/// Generates bounding boxes within a bounding region.
+///
+/// The extent of generated bounding boxes is never negative.
+pub fn aabb<S>(region: Aabb<S>) -> impl Strategy<Value = Aabb<S>>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+    Vector<S>: Debug + FromItems,
+{
+    (point(region), point(region)).prop_map(|(a, b)| {
+        let origin = a.per_item_min_or_undefined(b);
+        Aabb {
+            origin,
+            extent: a.per_item_max_or_undefined(b) - origin,
+        }
+    })
+}
+
+/// Generates lines with origins within a bounding region.
+pub fn line<S>(region: Aabb<S>) -> impl Strategy<Value = Line<S>>
+where
+    S: Debug + EuclideanSpace,
+    Vector<S>: Debug + FromItems,
+    Scalar<S>: Sqrt,
+{
+    (point(region), unit::<S>()).prop_map(|(origin, direction)| Line { origin, direction })
+}
+
+/// Generates planes with origins within a bounding region.
+pub fn plane<S>(region: Aabb<S>) -> impl Strategy<Value = Plane<S>>
+where
+    S: Debug + EuclideanSpace,
+    Vector<S>: Debug + FromItems,
+    Scalar<S>: Sqrt,
+{
+    (point(region), unit::<S>()).prop_map(|(origin, normal)| Plane { origin, normal })
+}
+
+/// Generates rays with origins within a bounding region.
+pub fn ray<S>(region: Aabb<S>) -> impl Strategy<Value = Ray<S>>
+where
+    S: Debug + EuclideanSpace,
+    Vector<S>: Debug + FromItems,
+    Scalar<S>: Sqrt,
+{
+    (point(region), unit::<S>()).prop_map(|(origin, direction)| Ray { origin, direction })
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use ::proptest::prelude::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::Point3;
+
+    use crate::adjunct::{Converged, Fold};
+    use crate::proptest;
+    use crate::query::{Aabb, Intersection};
+
+    type E3 = Point3<f64>;
+
+    fn region() -> Aabb<E3> {
+        Aabb {
+            origin: Converged::converged(-10.0),
+            extent: Converged::converged(20.0),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn aabb_in_region(aabb in proptest::aabb(region())) {
+            prop_assert!(aabb.extent.all(|x| x >= 0.0));
+            prop_assert!(region().intersection(&aabb.origin).is_some());
+            prop_assert!(region().intersection(&aabb.endpoint()).is_some());
+        }
+
+        #[test]
+        fn unit_magnitude(unit in proptest::unit::<E3>()) {
+            assert_abs_diff_eq!(1.0, unit.get().magnitude(), epsilon = 1e-9);
+        }
+
+        #[test]
+        fn ray_in_region(ray in proptest::ray(region())) {
+            prop_assert!(region().intersection(&ray.origin).is_some());
+        }
+    }
+}
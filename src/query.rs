@@ -2,20 +2,56 @@
 //!
 //! This module provides types and traits for performing spatial queries.
 
-use approx::abs_diff_eq;
+use approx::{abs_diff_eq, AbsDiffEq, RelativeEq, UlpsEq};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Deref, Mul, Neg};
 use decorum::cmp::IntrinsicOrd;
 use decorum::Infinite;
-use num::{Bounded, Signed, Zero};
-use std::fmt::{self, Debug, Formatter};
-use std::ops::Neg;
+#[cfg(any(feature = "std", feature = "libm"))]
+use decorum::R64;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num::NumCast;
+use num::{Bounded, One, Signed, Zero};
+#[cfg(all(feature = "serde", any(feature = "std", feature = "libm")))]
+use serde::de::{self, Deserializer};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize, Serializer};
 use typenum::type_operators::Cmp;
-use typenum::{Greater, U0, U1, U2};
+use typenum::{Greater, U0, U1, U2, U3};
+#[cfg(any(feature = "std", feature = "libm"))]
+use typenum::{Less, U4};
 
-use crate::adjunct::{Fold, ZipMap};
-use crate::ops::Dot;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::adjunct::Map;
+use crate::adjunct::{Converged, Extend, Fold, FromItems, ZipMap};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::ops::jacobi_eigen;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::ops::Sqrt;
+use crate::ops::{
+    Angle, Cross, Dot, Outer, Reflect, Reject, Rotate, SignedAngle, SymmetricEigen, Transcendental,
+};
+use crate::query::winding::Winding;
+#[cfg(feature = "bytemuck")]
+use crate::space::CoordinateLayout;
 use crate::space::{
-    Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace,
+    Basis, DualSpace, EuclideanSpace, FiniteDimensional, Homogeneous, InnerSpace, Matrix,
+    Projective, RigidMotion, Rotation, Scalar, SquareMatrix, Transform, Vector, VectorSpace,
 };
+use crate::Error;
+
+pub mod bvh;
+pub mod gjk;
+pub mod grid;
+pub mod kdtree;
+pub mod packet;
+pub mod sat;
+pub mod winding;
 
 // Intersections are implemented for types with a lesser lexographical order.
 // For example, `Intersection` is implemented for `Aabb` before `Plane`, with
@@ -60,7 +96,7 @@ use crate::space::{
 /// # extern crate theon;
 /// #
 /// use nalgebra::Point2;
-/// use theon::query::{Aabb, Intersection, Ray, Unit};
+/// use theon::query::{Aabb, Intersection, Ray, RaySolid, Unit};
 /// use theon::space::{EuclideanSpace, VectorSpace};
 ///
 /// type E2 = Point2<f64>;
@@ -73,8 +109,11 @@ use crate::space::{
 ///     origin: EuclideanSpace::origin(),
 ///     direction: Unit::x(),
 /// };
-/// if let Some((min, max)) = ray.intersection(&aabb) {
-///     // ...
+/// match ray.intersection(&aabb) {
+///     Some(RaySolid::Crossing { entry, exit }) => { /* ... */ }
+///     Some(RaySolid::Inside { exit, .. }) => { /* ... */ }
+///     Some(RaySolid::Tangent(t)) => { /* ... */ }
+///     None => {}
 /// }
 /// ```
 pub trait Intersection<T> {
@@ -82,6 +121,180 @@ pub trait Intersection<T> {
 
     fn intersection(&self, other: &T) -> Option<Self::Output>;
 }
+
+/// Signed distance between geometric objects.
+///
+/// Computes the distance between a pair of objects, which is negative if the
+/// objects overlap. The signed distance between a point and a shape is zero
+/// on the boundary of the shape and negative in its interior. Shapes without
+/// an interior, such as `Segment`, only produce non-negative distances.
+///
+/// As with `Intersection`, a symmetrical implementation is provided for
+/// heterogeneous pairs.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::Point3;
+/// use theon::query::{Plane, SignedDistance, Unit};
+/// use theon::space::EuclideanSpace;
+///
+/// type E3 = Point3<f64>;
+///
+/// let plane = Plane::<E3> {
+///     origin: EuclideanSpace::origin(),
+///     normal: Unit::z(),
+/// };
+/// assert_eq!(-2.0, plane.signed_distance(&E3::new(1.0, 1.0, -2.0)));
+/// ```
+pub trait SignedDistance<T> {
+    type Output;
+
+    fn signed_distance(&self, other: &T) -> Self::Output;
+}
+
+macro_rules! impl_symmetrical_signed_distance {
+    ($a:ident $(,)?) => {
+        /// Symmetrical signed distance.
+        impl<S> SignedDistance<$a<S>> for S
+        where
+            S: EuclideanSpace,
+            $a<S>: SignedDistance<S>,
+        {
+            type Output = <$a<S> as SignedDistance<S>>::Output;
+
+            fn signed_distance(&self, other: &$a<S>) -> Self::Output {
+                other.signed_distance(self)
+            }
+        }
+    };
+    ($a:ident, $b:ident $(,)?) => {
+        /// Symmetrical signed distance.
+        impl<S> SignedDistance<$a<S>> for $b<S>
+        where
+            S: EuclideanSpace,
+            $a<S>: SignedDistance<$b<S>>,
+        {
+            type Output = <$a<S> as SignedDistance<$b<S>>>::Output;
+
+            fn signed_distance(&self, other: &$a<S>) -> Self::Output {
+                other.signed_distance(self)
+            }
+        }
+    };
+}
+
+/// Closest points between geometric objects.
+///
+/// For a point, produces the point on (or in) the shape nearest to the given
+/// point. For a pair of shapes, produces the nearest pair of points, with the
+/// first point on `self` and the second point on the other shape.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::Point2;
+/// use theon::query::{Aabb, ClosestPoint};
+/// use theon::space::{EuclideanSpace, VectorSpace};
+///
+/// type E2 = Point2<f64>;
+///
+/// let aabb = Aabb::<E2> {
+///     origin: EuclideanSpace::origin(),
+///     extent: VectorSpace::from_xy(1.0, 1.0),
+/// };
+/// assert_eq!(E2::new(1.0, 0.5), aabb.closest_point(&E2::new(3.0, 0.5)));
+/// ```
+pub trait ClosestPoint<T> {
+    type Output;
+
+    fn closest_point(&self, other: &T) -> Self::Output;
+}
+
+/// Containment of geometric objects.
+///
+/// Determines if an object entirely encloses another object. Unlike
+/// `Intersection`, containment is not symmetrical: if `a` contains `b`, then
+/// `b` typically does not contain `a`. Objects on the boundary of a shape are
+/// contained by that shape.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::Point2;
+/// use theon::query::{Aabb, Contains, Sphere};
+/// use theon::space::{EuclideanSpace, VectorSpace};
+///
+/// type E2 = Point2<f64>;
+///
+/// let aabb = Aabb::<E2> {
+///     origin: EuclideanSpace::origin(),
+///     extent: VectorSpace::from_xy(4.0, 4.0),
+/// };
+/// let sphere = Sphere::<E2> {
+///     center: EuclideanSpace::from_xy(2.0, 2.0),
+///     radius: 1.0,
+/// };
+/// assert!(aabb.contains(&sphere));
+/// assert!(!sphere.contains(&aabb));
+/// ```
+pub trait Contains<T> {
+    fn contains(&self, other: &T) -> bool;
+}
+
+/// Swept (continuous) intersection of geometric objects.
+///
+/// Determines if an object intersects another object as it moves along a
+/// _displacement_ and produces data describing the first contact. The motion
+/// is parameterized by a _time of impact_ $t$ in $[0, 1]$, at which the object
+/// has moved by $t$ times the displacement. Unlike discrete `Intersection`
+/// queries, swept queries do not miss (tunnel through) thin objects when the
+/// displacement is large.
+///
+/// Motion is relative: to sweep two moving objects, sweep one object by the
+/// difference of their displacements against the other stationary object.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::{Point2, Vector2};
+/// use theon::query::{Aabb, Sweep};
+/// use theon::space::{EuclideanSpace, VectorSpace};
+///
+/// type E2 = Point2<f64>;
+///
+/// let aabb = Aabb::<E2> {
+///     origin: EuclideanSpace::origin(),
+///     extent: VectorSpace::from_xy(1.0, 1.0),
+/// };
+/// let wall = Aabb::<E2> {
+///     origin: EuclideanSpace::from_xy(5.0, -4.0),
+///     extent: VectorSpace::from_xy(0.5, 8.0),
+/// };
+/// // The box moves too far in a single step to overlap the wall at either end
+/// // of its motion, but still impacts it.
+/// let (entry, _) = aabb.sweep(Vector2::new(8.0, 0.0), &wall).unwrap();
+/// assert_eq!(0.5, entry);
+/// ```
+pub trait Sweep<T> {
+    type Displacement;
+    type Output;
+
+    fn sweep(&self, displacement: Self::Displacement, other: &T) -> Option<Self::Output>;
+}
+
 macro_rules! impl_symmetrical_intersection {
     ($a:ident $(,)?) => {
         /// Symmetrical intersection.
@@ -113,10 +326,201 @@ macro_rules! impl_symmetrical_intersection {
     };
 }
 
+/// Implements `approx` traits for a type with an `origin` point and a vector.
+///
+/// Points and vectors are compared component-wise, because `EuclideanSpace`
+/// types are not required to implement `approx` traits.
+macro_rules! impl_approx {
+    ($t:ident, |$x:ident| $vector:expr $(,)?) => {
+        impl<S> AbsDiffEq for $t<S>
+        where
+            S: EuclideanSpace,
+            <Scalar<S> as AbsDiffEq>::Epsilon: Copy,
+        {
+            type Epsilon = <Scalar<S> as AbsDiffEq>::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                Scalar::<S>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                let vectors = |$x: &Self| ($x.origin.into_coordinates(), $vector);
+                let ((a0, a1), (b0, b1)) = (vectors(self), vectors(other));
+                all_scalar_components(&a0, &b0, |a, b| a.abs_diff_eq(b, epsilon))
+                    && all_scalar_components(&a1, &b1, |a, b| a.abs_diff_eq(b, epsilon))
+            }
+        }
+
+        impl<S> RelativeEq for $t<S>
+        where
+            S: EuclideanSpace,
+            Scalar<S>: RelativeEq,
+            <Scalar<S> as AbsDiffEq>::Epsilon: Copy,
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                Scalar::<S>::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                let vectors = |$x: &Self| ($x.origin.into_coordinates(), $vector);
+                let ((a0, a1), (b0, b1)) = (vectors(self), vectors(other));
+                all_scalar_components(&a0, &b0, |a, b| a.relative_eq(b, epsilon, max_relative))
+                    && all_scalar_components(&a1, &b1, |a, b| {
+                        a.relative_eq(b, epsilon, max_relative)
+                    })
+            }
+        }
+
+        impl<S> UlpsEq for $t<S>
+        where
+            S: EuclideanSpace,
+            Scalar<S>: UlpsEq,
+            <Scalar<S> as AbsDiffEq>::Epsilon: Copy,
+        {
+            fn default_max_ulps() -> u32 {
+                Scalar::<S>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                let vectors = |$x: &Self| ($x.origin.into_coordinates(), $vector);
+                let ((a0, a1), (b0, b1)) = (vectors(self), vectors(other));
+                all_scalar_components(&a0, &b0, |a, b| a.ulps_eq(b, epsilon, max_ulps))
+                    && all_scalar_components(&a1, &b1, |a, b| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+    };
+}
+
+/// Implements `approx` traits for a type formed from an array of points and
+/// an optional array of scalars, such as a radius.
+macro_rules! impl_approx_points {
+    ($t:ident, |$x:ident| $points:expr $(, |$y:ident| $scalars:expr)? $(,)?) => {
+        impl<S> AbsDiffEq for $t<S>
+        where
+            S: EuclideanSpace,
+            <Scalar<S> as AbsDiffEq>::Epsilon: Copy,
+        {
+            type Epsilon = <Scalar<S> as AbsDiffEq>::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                Scalar::<S>::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                let points = |$x: &Self| $points;
+                IntoIterator::into_iter(points(self))
+                    .zip(IntoIterator::into_iter(points(other)))
+                    .all(|(a, b)| {
+                        all_scalar_components(
+                            &a.into_coordinates(),
+                            &b.into_coordinates(),
+                            |a, b| a.abs_diff_eq(b, epsilon),
+                        )
+                    })
+                    $(&& {
+                        let scalars = |$y: &Self| $scalars;
+                        IntoIterator::into_iter(scalars(self))
+                            .zip(IntoIterator::into_iter(scalars(other)))
+                            .all(|(a, b)| a.abs_diff_eq(&b, epsilon))
+                    })?
+            }
+        }
+
+        impl<S> RelativeEq for $t<S>
+        where
+            S: EuclideanSpace,
+            Scalar<S>: RelativeEq,
+            <Scalar<S> as AbsDiffEq>::Epsilon: Copy,
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                Scalar::<S>::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                let points = |$x: &Self| $points;
+                IntoIterator::into_iter(points(self))
+                    .zip(IntoIterator::into_iter(points(other)))
+                    .all(|(a, b)| {
+                        all_scalar_components(
+                            &a.into_coordinates(),
+                            &b.into_coordinates(),
+                            |a, b| a.relative_eq(b, epsilon, max_relative),
+                        )
+                    })
+                    $(&& {
+                        let scalars = |$y: &Self| $scalars;
+                        IntoIterator::into_iter(scalars(self))
+                            .zip(IntoIterator::into_iter(scalars(other)))
+                            .all(|(a, b)| a.relative_eq(&b, epsilon, max_relative))
+                    })?
+            }
+        }
+
+        impl<S> UlpsEq for $t<S>
+        where
+            S: EuclideanSpace,
+            Scalar<S>: UlpsEq,
+            <Scalar<S> as AbsDiffEq>::Epsilon: Copy,
+        {
+            fn default_max_ulps() -> u32 {
+                Scalar::<S>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                let points = |$x: &Self| $points;
+                IntoIterator::into_iter(points(self))
+                    .zip(IntoIterator::into_iter(points(other)))
+                    .all(|(a, b)| {
+                        all_scalar_components(
+                            &a.into_coordinates(),
+                            &b.into_coordinates(),
+                            |a, b| a.ulps_eq(b, epsilon, max_ulps),
+                        )
+                    })
+                    $(&& {
+                        let scalars = |$y: &Self| $scalars;
+                        IntoIterator::into_iter(scalars(self))
+                            .zip(IntoIterator::into_iter(scalars(other)))
+                            .all(|(a, b)| a.ulps_eq(&b, epsilon, max_ulps))
+                    })?
+            }
+        }
+    };
+}
+
+/// Determines if a predicate holds for all pairs of components of vectors.
+fn all_scalar_components<V, F>(a: &V, b: &V, mut f: F) -> bool
+where
+    V: FiniteDimensional + VectorSpace,
+    F: FnMut(&V::Scalar, &V::Scalar) -> bool,
+{
+    (0..V::dimensions()).all(
+        |index| match (a.scalar_component(index), b.scalar_component(index)) {
+            (Some(a), Some(b)) => f(&a, &b),
+            _ => false,
+        },
+    )
+}
+
 /// Unit vector.
 ///
 /// Primarily represents a direction within an `InnerSpace`.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(transparent)]
 pub struct Unit<S>
 where
     S: InnerSpace,
@@ -124,6 +528,14 @@ where
     inner: S,
 }
 
+// Note that zeroed and arbitrary values are not unit vectors. Casting to a
+// `Unit` does not normalize its vector.
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Pod for Unit<S> where S: InnerSpace + Pod {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Unit<S> where S: InnerSpace + Zeroable {}
+
 impl<S> Unit<S>
 where
     S: InnerSpace,
@@ -151,10 +563,74 @@ where
     ///
     /// let unit = Unit::<R3>::try_from_inner(Basis::i()).unwrap();
     /// ```
-    pub fn try_from_inner(inner: S) -> Option<Self> {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn try_from_inner(inner: S) -> Option<Self>
+    where
+        S::Scalar: Sqrt,
+    {
         inner.normalize().map(|inner| Unit { inner })
     }
 
+    /// Creates a `Unit` from a non-zero magnitude vector.
+    ///
+    /// The given vector is normalized. If the vector's magnitude is zero, then
+    /// `Error::ZeroLength` is returned. See `try_from_inner`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn from_inner(inner: S) -> Result<Self, Error>
+    where
+        S::Scalar: Sqrt,
+    {
+        Self::try_from_inner(inner).ok_or(Error::ZeroLength)
+    }
+
+    /// Creates a `Unit` from a vector with a magnitude greater than a
+    /// tolerance.
+    ///
+    /// The given vector is normalized. If the vector's magnitude is not
+    /// greater than `epsilon`, then `None` is returned. Unlike
+    /// `try_from_inner`, this rejects vectors with very small magnitudes,
+    /// which have imprecise directions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate theon;
+    /// #
+    /// use nalgebra::Vector2;
+    /// use theon::query::Unit;
+    ///
+    /// let small = Vector2::new(1e-9, 0.0);
+    /// assert!(Unit::try_from_inner(small).is_some());
+    /// assert!(Unit::try_from_inner_with_epsilon(small, 1e-6).is_none());
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn try_from_inner_with_epsilon(inner: S, epsilon: S::Scalar) -> Option<Self>
+    where
+        S::Scalar: Sqrt,
+    {
+        let magnitude = inner.magnitude();
+        if magnitude > epsilon {
+            Some(Self::from_inner_unchecked(
+                inner * (S::Scalar::one() / magnitude),
+            ))
+        }
+        else {
+            None
+        }
+    }
+
+    /// Gets the canonical basis vector with the given index.
+    ///
+    /// Returns `None` if the index is not less than the dimensionality of the
+    /// space. See `x`, `y`, and `z`.
+    pub fn axis(index: usize) -> Option<Self>
+    where
+        S: Basis,
+    {
+        S::canonical_basis_component(index).map(Self::from_inner_unchecked)
+    }
+
     pub fn into_inner(self) -> S {
         self.inner
     }
@@ -187,8 +663,12 @@ where
         self.as_ref()
     }
 
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[must_use]
-    pub fn try_set(&mut self, inner: S) -> Option<&S> {
+    pub fn try_set(&mut self, inner: S) -> Option<&S>
+    where
+        S::Scalar: Sqrt,
+    {
         if let Some(inner) = inner.normalize() {
             self.inner = inner;
             Some(&self.inner)
@@ -204,6 +684,132 @@ where
         let Unit { inner, .. } = self;
         Self::from_inner_unchecked(-inner)
     }
+
+    /// Completes the unit vector into an orthonormal basis.
+    ///
+    /// Returns a pair of unit vectors $(\hat{t}, \hat{b})$ such that
+    /// $(\hat{t}, \hat{b}, \hat{n})$ is a right-handed orthonormal basis, where
+    /// $\hat{n}$ is the unit vector. This is useful for constructing tangent
+    /// frames about a surface normal. The basis is continuous everywhere except
+    /// where the unit vector crosses the $xy$ plane.
+    pub fn complete_basis(self) -> (Self, Self)
+    where
+        S: Basis + FiniteDimensional<N = U3>,
+    {
+        // This uses the method described by Duff et al. in "Building an
+        // Orthonormal Basis, Revisited".
+        let (x, y, z) = self.inner.into_xyz();
+        let sign = if z < Zero::zero() {
+            -S::Scalar::one()
+        }
+        else {
+            S::Scalar::one()
+        };
+        let a = -S::Scalar::one() / (sign + z);
+        let b = x * y * a;
+        (
+            Self::from_inner_unchecked(S::from_xyz(
+                S::Scalar::one() + (sign * x * x * a),
+                sign * b,
+                -sign * x,
+            )),
+            Self::from_inner_unchecked(S::from_xyz(b, sign + (y * y * a), -y)),
+        )
+    }
+
+    /// Linearly interpolates between unit vectors and normalizes the result.
+    ///
+    /// Unlike `slerp`, the angular velocity of the interpolation is not
+    /// constant. The factor `f` is clamped to $[0, 1]$. Returns `None` if the
+    /// interpolated vector has zero magnitude, which occurs when the unit
+    /// vectors are opposite and `f` is one half.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn nlerp(self, other: Self, f: R64) -> Option<Self>
+    where
+        S::Scalar: Sqrt,
+    {
+        let f = num::clamp(f, Zero::zero(), One::one());
+        let f = <S::Scalar as NumCast>::from(f).unwrap();
+        Self::try_from_inner((self.inner * (S::Scalar::one() - f)) + (other.inner * f))
+    }
+
+    /// Spherically interpolates between unit vectors.
+    ///
+    /// The interpolation follows the arc between the unit vectors with constant
+    /// angular velocity. The factor `f` is clamped to $[0, 1]$. Returns `None`
+    /// if the unit vectors are opposite, in which case the arc between them is
+    /// not unique.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn slerp(self, other: Self, f: R64) -> Option<Self>
+    where
+        S::Scalar: Sqrt + Transcendental,
+    {
+        let cosine = num::clamp(
+            self.inner.dot(other.inner),
+            -S::Scalar::one(),
+            S::Scalar::one(),
+        );
+        let angle = Transcendental::acos(cosine);
+        let sine = Transcendental::sin(angle);
+        if abs_diff_eq!(sine, Zero::zero()) {
+            // The unit vectors are parallel or opposite.
+            if cosine > Zero::zero() {
+                self.nlerp(other, f)
+            }
+            else {
+                None
+            }
+        }
+        else {
+            let f = num::clamp(f, Zero::zero(), One::one());
+            let f = <S::Scalar as NumCast>::from(f).unwrap();
+            let a = Transcendental::sin((S::Scalar::one() - f) * angle) / sine;
+            let b = Transcendental::sin(f * angle) / sine;
+            Some(Self::from_inner_unchecked(
+                (self.inner * a) + (other.inner * b),
+            ))
+        }
+    }
+
+    /// Refracts the unit vector through a surface with the given normal.
+    ///
+    /// The unit vector is the direction of incidence and `eta` is the ratio of
+    /// the refractive indices of the incident and transmitting media. The
+    /// normal should face against the direction of incidence. Returns `None` on
+    /// total internal reflection.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn refract(self, normal: Unit<S>, eta: S::Scalar) -> Option<Self>
+    where
+        S::Scalar: Sqrt,
+    {
+        let incidence = self.into_inner();
+        let normal = normal.into_inner();
+        let cosine = normal.dot(incidence);
+        let k = S::Scalar::one() - (eta * eta * (S::Scalar::one() - (cosine * cosine)));
+        if k < Zero::zero() {
+            None
+        }
+        else {
+            let transmission = (incidence * eta) + -(normal * ((eta * cosine) + Sqrt::sqrt(k)));
+            Some(Self::from_inner_unchecked(transmission))
+        }
+    }
+}
+
+impl<S> AbsDiffEq for Unit<S>
+where
+    S: FiniteDimensional + InnerSpace,
+    <S::Scalar as AbsDiffEq>::Epsilon: Copy,
+{
+    type Epsilon = <S::Scalar as AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        S::Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        all_scalar_components(&self.inner, &other.inner, |a, b| a.abs_diff_eq(b, epsilon))
+    }
 }
 
 impl<S> AsRef<S> for Unit<S>
@@ -226,27 +832,232 @@ where
     }
 }
 
-impl<S> Neg for Unit<S>
+impl<S> Deref for Unit<S>
 where
     S: InnerSpace,
 {
-    type Output = Self;
+    type Target = S;
 
-    fn neg(self) -> Self::Output {
-        self.reverse()
+    fn deref(&self) -> &Self::Target {
+        &self.inner
     }
 }
 
-/// Line.
-///
-/// Describes a line containing an _origin_ point and a _direction_. Lines
-/// extend infinitely from their origin along their direction $\hat{u}$. Unlike
-/// `Ray`, the direction component of `Line` extends in both the positive and
-/// negative.
-///
-/// This representation is typically known as the _vector form_ $P_0 +
+impl<S> Mul<S::Scalar> for Unit<S>
+where
+    S: InnerSpace,
+{
+    type Output = S;
+
+    fn mul(self, scalar: S::Scalar) -> Self::Output {
+        self.inner * scalar
+    }
+}
+
+impl<S> Neg for Unit<S>
+where
+    S: InnerSpace,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.reverse()
+    }
+}
+
+/// Reflects a unit vector across a plane or line with the given normal.
+///
+/// Reflection preserves magnitude, so the reflected vector is not
+/// normalized.
+impl<S> Reflect<Unit<S>> for Unit<S>
+where
+    S: InnerSpace,
+{
+    type Output = Self;
+
+    fn reflect(self, normal: Unit<S>) -> Self::Output {
+        Self::from_inner_unchecked(self.inner.reflect(normal))
+    }
+}
+
+/// Rotates a unit vector.
+///
+/// Rotation preserves magnitude, so the rotated vector is not normalized.
+impl<S, R> Rotate<R> for Unit<S>
+where
+    S: InnerSpace + Rotate<R, Output = S>,
+{
+    type Output = Self;
+
+    fn rotate(self, rotation: R) -> Self::Output {
+        Self::from_inner_unchecked(self.inner.rotate(rotation))
+    }
+}
+
+/// Computes the angle between unit vectors.
+///
+/// Unlike the angle between arbitrary vectors, this does not normalize the
+/// vectors nor fail.
+impl<S> Angle for Unit<S>
+where
+    S: InnerSpace,
+    S::Scalar: Transcendental,
+{
+    type Output = S::Scalar;
+
+    fn angle(self, other: Self) -> Self::Output {
+        // Rounding errors may place the cosine outside of the domain of `acos`,
+        // so it is clamped.
+        let cosine = self.inner.dot(other.inner);
+        Transcendental::acos(num::clamp(cosine, -S::Scalar::one(), S::Scalar::one()))
+    }
+}
+
+impl<S> SignedAngle for Unit<S>
+where
+    S: FiniteDimensional<N = U2> + InnerSpace,
+    S::Scalar: Transcendental,
+{
+    type Output = S::Scalar;
+
+    fn signed_angle(self, other: Self) -> Self::Output {
+        let (ax, ay) = self.inner.into_xy();
+        let (bx, by) = other.inner.into_xy();
+        Transcendental::atan2((ax * by) - (ay * bx), self.inner.dot(other.inner))
+    }
+}
+
+impl<S> Reflect<Unit<S>> for S
+where
+    S: InnerSpace,
+{
+    type Output = S;
+
+    fn reflect(self, normal: Unit<S>) -> Self::Output {
+        let normal = normal.into_inner();
+        let two = S::Scalar::one() + One::one();
+        self + -(normal * (two * self.dot(normal)))
+    }
+}
+
+impl<S> Reject<Unit<S>> for S
+where
+    S: InnerSpace,
+{
+    type Output = S;
+
+    fn reject(self, direction: Unit<S>) -> Self::Output {
+        let direction = direction.into_inner();
+        self + -(direction * self.dot(direction))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<S> ArchivedUnit<S>
+where
+    S: InnerSpace + rkyv::Archive,
+{
+    pub fn get(&self) -> &rkyv::Archived<S> {
+        &self.inner
+    }
+}
+
+impl<S> RelativeEq for Unit<S>
+where
+    S: FiniteDimensional + InnerSpace,
+    S::Scalar: RelativeEq,
+    <S::Scalar as AbsDiffEq>::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        S::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        all_scalar_components(&self.inner, &other.inner, |a, b| {
+            a.relative_eq(b, epsilon, max_relative)
+        })
+    }
+}
+
+// Deserialization normalizes vectors and so requires the `std` or `libm`
+// features.
+/// Deserializes a `Unit` from a vector, which is normalized.
+///
+/// Deserialization fails if the vector's magnitude is zero.
+#[cfg(all(feature = "serde", any(feature = "std", feature = "libm")))]
+impl<'de, S> Deserialize<'de> for Unit<S>
+where
+    S: Deserialize<'de> + InnerSpace,
+    S::Scalar: Sqrt,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Unit::try_from_inner(S::deserialize(deserializer)?)
+            .ok_or_else(|| de::Error::custom("unit vector has zero magnitude"))
+    }
+}
+
+/// Serializes a `Unit` as its vector.
+#[cfg(feature = "serde")]
+impl<S> Serialize for Unit<S>
+where
+    S: InnerSpace + Serialize,
+{
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<S> UlpsEq for Unit<S>
+where
+    S: FiniteDimensional + InnerSpace,
+    S::Scalar: UlpsEq,
+    <S::Scalar as AbsDiffEq>::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        S::Scalar::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        all_scalar_components(&self.inner, &other.inner, |a, b| {
+            a.ulps_eq(b, epsilon, max_ulps)
+        })
+    }
+}
+
+/// Line.
+///
+/// Describes a line containing an _origin_ point and a _direction_. Lines
+/// extend infinitely from their origin along their direction $\hat{u}$. Unlike
+/// `Ray`, the direction component of `Line` extends in both the positive and
+/// negative.
+///
+/// This representation is typically known as the _vector form_ $P_0 +
 /// t\hat{u}$ where $t$ is some non-zero _time of impact_.
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Vector<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
 pub struct Line<S>
 where
     S: EuclideanSpace,
@@ -257,6 +1068,22 @@ where
     pub direction: Unit<Vector<S>>,
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Pod for Line<S>
+where
+    S: CoordinateLayout + Pod,
+    Vector<S>: Pod,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Line<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Vector<S>: Zeroable,
+{
+}
+
 impl<S> Line<S>
 where
     S: EuclideanSpace,
@@ -298,6 +1125,19 @@ where
         let Line { origin, direction } = self;
         Ray { origin, direction }
     }
+
+    /// Transforms the line.
+    ///
+    /// Returns `None` if the transformation collapses the direction of the
+    /// line.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn transform<T>(self, transform: &T) -> Option<Self>
+    where
+        T: Transform<S>,
+        Scalar<S>: Sqrt,
+    {
+        self.into_ray().transform(transform).map(Ray::into_line)
+    }
 }
 
 // TODO: Provide higher dimensional intercepts, such as the xy-intercept in
@@ -333,6 +1173,8 @@ where
     }
 }
 
+impl_approx!(Line, |line| line.direction.into_inner());
+
 impl<S> Debug for Line<S>
 where
     S: Debug + EuclideanSpace,
@@ -361,6 +1203,14 @@ where
 
 /// Intersection of lines.
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Vector<S>: Serialize",
+    ))
+)]
 pub enum LineLine<S>
 where
     S: EuclideanSpace,
@@ -445,6 +1295,14 @@ where
 
 /// Intersection of a line and a plane.
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Scalar<S>: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Scalar<S>: Serialize, Vector<S>: Serialize",
+    ))
+)]
 pub enum LinePlane<S>
 where
     S: EuclideanSpace,
@@ -531,6 +1389,61 @@ where
 }
 impl_symmetrical_intersection!(Line, Plane);
 
+/// Closest point on a line to a point.
+impl<S> ClosestPoint<S> for Line<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn closest_point(&self, point: &S) -> Self::Output {
+        let direction = *self.direction.get();
+        self.origin + (direction * (*point - self.origin).dot(direction))
+    }
+}
+
+/// Closest points between lines.
+///
+/// If the lines are parallel, then the origin of `self` is chosen as the
+/// first point.
+impl<S> ClosestPoint<Line<S>> for Line<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = (S, S);
+
+    fn closest_point(&self, other: &Line<S>) -> Self::Output {
+        let d1 = *self.direction.get();
+        let d2 = *other.direction.get();
+        let r = self.origin - other.origin;
+        let b = d1.dot(d2);
+        let c = d1.dot(r);
+        let f = d2.dot(r);
+        let denominator = Scalar::<S>::one() - (b * b);
+        let s = if abs_diff_eq!(denominator, Zero::zero()) {
+            Zero::zero()
+        }
+        else {
+            ((b * f) - c) / denominator
+        };
+        let t = (b * s) + f;
+        (self.origin + (d1 * s), other.origin + (d2 * t))
+    }
+}
+
+impl<S> Reflect<Line<S>> for S
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn reflect(self, line: Line<S>) -> Self::Output {
+        let direction = line.direction.into_inner();
+        let nearest = line.origin + (direction * (self - line.origin).dot(direction));
+        nearest + (nearest - self)
+    }
+}
+
 /// Ray or half-line.
 ///
 /// Describes a decomposed line with an _origin_ or _initial point_ and a
@@ -538,6 +1451,19 @@ impl_symmetrical_intersection!(Line, Plane);
 /// the point $P_0 + \hat{u}$ (where $\hat{u}$ is the direction of the ray)
 /// form a half-line originating from $P_0$.
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Vector<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
 pub struct Ray<S>
 where
     S: EuclideanSpace,
@@ -548,6 +1474,22 @@ where
     pub direction: Unit<Vector<S>>,
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Pod for Ray<S>
+where
+    S: CoordinateLayout + Pod,
+    Vector<S>: Pod,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Ray<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Vector<S>: Zeroable,
+{
+}
+
 impl<S> Ray<S>
 where
     S: EuclideanSpace,
@@ -568,8 +1510,29 @@ where
             direction: Unit::from_inner_unchecked(-direction.into_inner()),
         }
     }
+
+    /// Transforms the ray.
+    ///
+    /// Returns `None` if the transformation collapses the direction of the
+    /// ray.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn transform<T>(self, transform: &T) -> Option<Self>
+    where
+        T: Transform<S>,
+        Scalar<S>: Sqrt,
+    {
+        let Ray { origin, direction } = self;
+        Unit::try_from_inner(transform.transform_vector(direction.into_inner())).map(|direction| {
+            Ray {
+                origin: transform.transform_point(origin),
+                direction,
+            }
+        })
+    }
 }
 
+impl_approx!(Ray, |ray| ray.direction.into_inner());
+
 impl<S> Debug for Ray<S>
 where
     S: Debug + EuclideanSpace,
@@ -607,12 +1570,156 @@ where
     }
 }
 
+/// Intersection of a ray and a solid region.
+///
+/// Describes the _times of impact_ $t_{min}$ and $t_{max}$ at which a ray enters
+/// and exits a region and classifies the intersection. Given a ray formed by an
+/// origin $P_0$ and a unit direction $\hat{u}$, the point of entry is $P_0 +
+/// t_{min}\hat{u}$.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "Scalar<S>: Deserialize<'de>",
+        serialize = "Scalar<S>: Serialize",
+    ))
+)]
+pub enum RaySolid<S>
+where
+    S: EuclideanSpace,
+{
+    /// The ray enters the region at or ahead of its origin and exits the
+    /// region later.
+    Crossing { entry: Scalar<S>, exit: Scalar<S> },
+    /// The origin of the ray is within the region.
+    ///
+    /// The time of entry is negative (behind the origin of the ray).
+    Inside { entry: Scalar<S>, exit: Scalar<S> },
+    /// The ray touches the boundary of the region at a single time of impact.
+    Tangent(Scalar<S>),
+}
+
+impl<S> RaySolid<S>
+where
+    S: EuclideanSpace,
+{
+    /// Classifies the interval $[t_{min}, t_{max}]$ of an intersection.
+    ///
+    /// The interval must be ordered and must not be entirely behind the origin
+    /// of the ray.
+    fn from_interval(entry: Scalar<S>, exit: Scalar<S>) -> Self {
+        if abs_diff_eq!(entry, exit) {
+            RaySolid::Tangent(entry)
+        }
+        else if entry < Zero::zero() {
+            RaySolid::Inside { entry, exit }
+        }
+        else {
+            RaySolid::Crossing { entry, exit }
+        }
+    }
+
+    /// Gets the time of impact at which the ray enters the region.
+    pub fn entry(&self) -> Scalar<S> {
+        match *self {
+            RaySolid::Crossing { entry, .. } | RaySolid::Inside { entry, .. } => entry,
+            RaySolid::Tangent(t) => t,
+        }
+    }
+
+    /// Gets the time of impact at which the ray exits the region.
+    pub fn exit(&self) -> Scalar<S> {
+        match *self {
+            RaySolid::Crossing { exit, .. } | RaySolid::Inside { exit, .. } => exit,
+            RaySolid::Tangent(t) => t,
+        }
+    }
+
+    /// Gets the nearest time of impact ahead of the origin of the ray.
+    ///
+    /// This is zero if the origin of the ray is within the region.
+    pub fn time_of_impact(&self) -> Scalar<S> {
+        match *self {
+            RaySolid::Inside { .. } => Zero::zero(),
+            _ => self.entry(),
+        }
+    }
+
+    /// Converts the intersection into its times of entry and exit.
+    pub fn into_interval(self) -> (Scalar<S>, Scalar<S>) {
+        (self.entry(), self.exit())
+    }
+
+    pub fn is_inside(&self) -> bool {
+        matches!(self, RaySolid::Inside { .. })
+    }
+
+    pub fn is_tangent(&self) -> bool {
+        matches!(self, RaySolid::Tangent(_))
+    }
+}
+
+impl<S> Debug for RaySolid<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            RaySolid::Crossing { entry, exit } => formatter
+                .debug_struct("Crossing")
+                .field("entry", &entry)
+                .field("exit", &exit)
+                .finish(),
+            RaySolid::Inside { entry, exit } => formatter
+                .debug_struct("Inside")
+                .field("entry", &entry)
+                .field("exit", &exit)
+                .finish(),
+            RaySolid::Tangent(t) => write!(formatter, "Tangent({:?})", t),
+        }
+    }
+}
+
+/// Closest point on a ray to a point.
+impl<S> ClosestPoint<S> for Ray<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn closest_point(&self, point: &S) -> Self::Output {
+        let direction = *self.direction.get();
+        let t = (*point - self.origin).dot(direction);
+        if t > Zero::zero() {
+            self.origin + (direction * t)
+        }
+        else {
+            self.origin
+        }
+    }
+}
+
 /// Axis-aligned bounding box.
 ///
 /// Represents an $n$-dimensional volume along each basis vector of a Euclidean
 /// space. The bounding box is defined by the region between its _origin_ and
 /// _endpoint_.
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Vector<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
 pub struct Aabb<S>
 where
     S: EuclideanSpace,
@@ -630,30 +1737,84 @@ where
     pub extent: Vector<S>,
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Pod for Aabb<S>
+where
+    S: CoordinateLayout + Pod,
+    Vector<S>: Pod,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Aabb<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Vector<S>: Zeroable,
+{
+}
+
 impl<S> Aabb<S>
 where
     S: EuclideanSpace,
 {
-    /// Creates an `Aabb` from a set of points.
+    /// Creates an `Aabb` that bounds a set of points.
     ///
     /// The bounding box is formed from the lower and upper bounds of the
-    /// points. If the set of points is empty, then the `Aabb` will sit at the
-    /// origin with zero volume.
-    pub fn from_points<I>(points: I) -> Self
+    /// points. Returns `None` if the set of points is empty.
+    pub fn from_points<I>(points: I) -> Option<Self>
     where
         I: IntoIterator<Item = S>,
         Scalar<S>: IntrinsicOrd,
     {
-        let mut min = S::origin();
-        let mut max = S::origin();
-        for point in points {
-            min = min.per_item_min_or_undefined(point);
-            max = max.per_item_max_or_undefined(point);
-        }
-        Aabb {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (
+                min.per_item_min_or_undefined(point),
+                max.per_item_max_or_undefined(point),
+            )
+        });
+        Some(Aabb {
             origin: min,
             extent: max - min,
-        }
+        })
+    }
+
+    /// Creates an `Aabb` that bounds a set of bounding boxes.
+    ///
+    /// Returns `None` if the set of bounding boxes is empty.
+    pub fn from_aabbs<I>(aabbs: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+        Scalar<S>: IntrinsicOrd,
+    {
+        let mut aabbs = aabbs.into_iter();
+        let first = aabbs.next()?;
+        Some(aabbs.fold(first, |union, aabb| union.union(&aabb)))
+    }
+
+    /// Creates an `Aabb` that bounds a set of points.
+    ///
+    /// Returns `Error::Empty` if the set of points is empty. See
+    /// `from_points`.
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+        Scalar<S>: IntrinsicOrd,
+    {
+        Self::from_points(points).ok_or(Error::Empty)
+    }
+
+    /// Creates an `Aabb` that bounds a set of bounding boxes.
+    ///
+    /// Returns `Error::Empty` if the set of bounding boxes is empty. See
+    /// `from_aabbs`.
+    pub fn try_from_aabbs<I>(aabbs: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = Self>,
+        Scalar<S>: IntrinsicOrd,
+    {
+        Self::from_aabbs(aabbs).ok_or(Error::Empty)
     }
 
     pub fn endpoint(&self) -> S {
@@ -684,21 +1845,125 @@ where
             .product()
     }
 
-    pub fn union(&self, aabb: &Self) -> Self
-    where
-        Scalar<S>: IntrinsicOrd,
-    {
-        let origin = self
-            .lower_bound()
-            .per_item_min_or_undefined(aabb.lower_bound());
+    /// Gets the ($n-1$)-dimensional measure of the boundary of the bounding box.
+    ///
+    /// This value is analogous to _perimeter_ and _surface area_ in two and
+    /// three dimensions, respectively.
+    pub fn surface_area(&self) -> Scalar<S> {
+        let two = Scalar::<S>::one() + One::one();
+        let component = |index| self.extent.scalar_component(index).unwrap().abs();
+        (0..S::dimensions())
+            .map(|face| {
+                (0..S::dimensions())
+                    .filter(|index| *index != face)
+                    .map(component)
+                    .fold(Scalar::<S>::one(), |product, x| product * x)
+            })
+            .fold(Scalar::<S>::zero(), |sum, x| sum + x)
+            * two
+    }
+
+    pub fn centroid(&self) -> S {
+        let half = Scalar::<S>::one() / (Scalar::<S>::one() + One::one());
+        self.origin + (self.extent * half)
+    }
+
+    /// Gets the index of the axis along which the bounding box is longest.
+    ///
+    /// If more than one axis has the greatest length, then the least index is
+    /// returned.
+    pub fn longest_axis(&self) -> usize {
+        (1..S::dimensions()).fold(0, |longest, index| {
+            let length = |index| self.extent.scalar_component(index).unwrap().abs();
+            if length(index) > length(longest) {
+                index
+            }
+            else {
+                longest
+            }
+        })
+    }
+
+    pub fn union(&self, aabb: &Self) -> Self
+    where
+        Scalar<S>: IntrinsicOrd,
+    {
+        let origin = self
+            .lower_bound()
+            .per_item_min_or_undefined(aabb.lower_bound());
         let extent = self
             .upper_bound()
             .per_item_max_or_undefined(aabb.upper_bound())
             - origin;
         Aabb { origin, extent }
     }
+
+    /// Expands the bounding box by a margin along each axis.
+    ///
+    /// The lower and upper bounds are moved apart by the margin, so the extent
+    /// grows by twice the margin along each axis. A negative margin shrinks the
+    /// bounding box.
+    pub fn expand(&self, margin: Scalar<S>) -> Self
+    where
+        Scalar<S>: IntrinsicOrd,
+    {
+        let margin = Vector::<S>::converged(margin);
+        let origin = self.lower_bound() + -margin;
+        let extent = (self.upper_bound() + margin) - origin;
+        Aabb { origin, extent }
+    }
+
+    /// Determines if the bounding box overlaps another bounding box.
+    ///
+    /// Bounding boxes that only touch at their boundaries are considered
+    /// overlapping. Unlike `Intersection`, this does not construct the
+    /// bounding box of the overlap.
+    pub fn overlaps(&self, aabb: &Self) -> bool
+    where
+        Scalar<S>: IntrinsicOrd,
+    {
+        let lower = self
+            .lower_bound()
+            .per_item_max_or_undefined(aabb.lower_bound());
+        let upper = self
+            .upper_bound()
+            .per_item_min_or_undefined(aabb.upper_bound());
+        (upper - lower).all(|x| x >= Zero::zero())
+    }
+
+    /// Transforms the bounding box.
+    ///
+    /// The transformed bounding box is axis-aligned and bounds the transformed
+    /// corners of the bounding box, so it may be larger than the transformed
+    /// volume.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: Transform<S>,
+        Scalar<S>: IntrinsicOrd,
+    {
+        let corners = self
+            .corners()
+            .map(|corner| transform.transform_point(corner));
+        Aabb::from_points(corners).expect("no corners")
+    }
+
+    /// Gets an iterator over the $2^n$ corners of the bounding box.
+    fn corners(&self) -> impl '_ + Iterator<Item = S> {
+        (0..(1usize << S::dimensions())).map(move |mask| {
+            let offset = Vector::<S>::canonical_basis()
+                .into_iter()
+                .enumerate()
+                .filter(|(index, _)| mask & (1 << index) != 0)
+                .fold(Vector::<S>::zero(), |offset, (index, basis)| {
+                    offset + (basis * self.extent.scalar_component(index).unwrap())
+                });
+            self.origin + offset
+        })
+    }
 }
 
+impl_approx!(Aabb, |aabb| aabb.extent);
+
 impl<S> Debug for Aabb<S>
 where
     S: Debug + EuclideanSpace,
@@ -775,17 +2040,149 @@ where
     }
 }
 
+/// Slab interval of a ray through an axis-aligned bounding box.
+///
+/// See `Aabb::slab_intersection`.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "Scalar<S>: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>",
+        serialize = "Scalar<S>: Serialize, Vector<S>: Serialize",
+    ))
+)]
+pub struct AabbRay<S>
+where
+    S: EuclideanSpace,
+{
+    /// The _time of impact_ at which the ray enters the bounding box.
+    ///
+    /// This is negative if the origin of the ray is within the bounding box.
+    pub entry: Scalar<S>,
+    /// The _time of impact_ at which the ray exits the bounding box.
+    pub exit: Scalar<S>,
+    /// The index of the axis of the face through which the ray enters the
+    /// bounding box.
+    pub axis: usize,
+    /// The normal of the face through which the ray enters the bounding box.
+    ///
+    /// The normal points out of the bounding box and so opposes the direction
+    /// of the ray.
+    pub normal: Unit<Vector<S>>,
+}
+
+impl<S> Debug for AabbRay<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Debug,
+    Vector<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("AabbRay")
+            .field("entry", &self.entry)
+            .field("exit", &self.exit)
+            .field("axis", &self.axis)
+            .field("normal", &self.normal)
+            .finish()
+    }
+}
+
+impl<S> Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded + Infinite + IntrinsicOrd + Signed,
+{
+    /// Determines the slab interval of a `Ray` intersection with an `Aabb`.
+    ///
+    /// This is like the intersection of an `Aabb` and a `Ray`, but also
+    /// determines the face through which the ray enters the bounding box. The
+    /// interval $[t_{min},t_{max}]$ is given by the _entry_ and _exit_ times of
+    /// impact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate theon;
+    /// #
+    /// use nalgebra::Point2;
+    /// use theon::query::{Aabb, Ray, Unit};
+    /// use theon::space::{EuclideanSpace, VectorSpace};
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let aabb = Aabb::<E2> {
+    ///     origin: EuclideanSpace::from_xy(1.0, -1.0),
+    ///     extent: VectorSpace::from_xy(2.0, 2.0),
+    /// };
+    /// let ray = Ray::<E2> {
+    ///     origin: EuclideanSpace::origin(),
+    ///     direction: Unit::x(),
+    /// };
+    /// let slab = aabb.slab_intersection(&ray).unwrap();
+    /// assert_eq!((1.0, 3.0), (slab.entry, slab.exit));
+    /// assert_eq!(0, slab.axis);
+    /// assert_eq!(-1.0, slab.normal.get().x);
+    /// ```
+    pub fn slab_intersection(&self, ray: &Ray<S>) -> Option<AabbRay<S>> {
+        // Avoid computing `NaN`s. Note that multiplying by the inverse (instead
+        // of dividing) avoids dividing zero by zero, but does not avoid
+        // multiplying zero by infinity.
+        let pdiv = |a: Scalar<S>, b: Scalar<S>| {
+            if abs_diff_eq!(a, Zero::zero()) {
+                a
+            }
+            else {
+                a / b
+            }
+        };
+        let direction = *ray.direction.get();
+        let origin = (self.origin - ray.origin).zip_map(direction, pdiv);
+        let endpoint = (self.endpoint() - ray.origin).zip_map(direction, pdiv);
+        let near = origin.per_item_min_or_undefined(endpoint);
+        let far = origin.per_item_max_or_undefined(endpoint);
+        let min = near.max_or_undefined();
+        let max = far.min_or_undefined();
+        // These predicates are written such that they must hold for an
+        // intersection rather than a miss, which is important for scalars
+        // that are only partially ordered (e.g., SIMD types).
+        if min <= max && max >= Zero::zero() && !min.is_undefined() && !max.is_undefined() {
+            let axis = (0..S::dimensions())
+                .find(|index| near.scalar_component(*index) == Some(min))
+                .unwrap_or(0);
+            let basis = Vector::<S>::canonical_basis_component(axis).unwrap();
+            let normal = if direction.scalar_component(axis).unwrap().is_positive() {
+                -basis
+            }
+            else {
+                basis
+            };
+            Some(AabbRay {
+                entry: min,
+                exit: max,
+                axis,
+                normal: Unit::from_inner_unchecked(normal),
+            })
+        }
+        else {
+            None
+        }
+    }
+}
+
 /// Intersection of an axis-aligned bounding box and a ray.
 impl<S> Intersection<Ray<S>> for Aabb<S>
 where
     S: EuclideanSpace,
     Scalar<S>: Bounded + Infinite + IntrinsicOrd + Signed,
 {
-    /// The minimum and maximum _times of impact_ of the intersection.
+    /// The _times of impact_ of the intersection.
     ///
     /// The times of impact $t_{min}$ and $t_{max}$ describe the distance along
     /// the half-line from the ray's origin at which the intersection occurs.
-    type Output = (Scalar<S>, Scalar<S>);
+    type Output = RaySolid<S>;
 
     /// Determines the minimum and maximum _times of impact_ of a `Ray`
     /// intersection with an `Aabb`.
@@ -802,8 +2199,8 @@ where
     /// # extern crate theon;
     /// #
     /// use nalgebra::Point2;
-    /// use theon::space::{EuclideanSpace, VectorSpace};
     /// use theon::query::{Aabb, Intersection, Ray, Unit};
+    /// use theon::space::{EuclideanSpace, VectorSpace};
     ///
     /// type E2 = Point2<f64>;
     ///
@@ -815,39 +2212,172 @@ where
     ///     origin: EuclideanSpace::origin(),
     ///     direction: Unit::x(),
     /// };
-    /// let (min, _) = ray.intersection(&aabb).unwrap();
+    /// let min = ray.intersection(&aabb).unwrap().entry();
     /// let point = ray.origin + (ray.direction.get() * min);
+    /// ```
     fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
-        // Avoid computing `NaN`s. Note that multiplying by the inverse (instead
-        // of dividing) avoids dividing zero by zero, but does not avoid
-        // multiplying zero by infinity.
-        let pdiv = |a: Scalar<S>, b: Scalar<S>| {
-            if abs_diff_eq!(a, Zero::zero()) {
-                a
+        self.slab_intersection(ray)
+            .map(|slab| RaySolid::from_interval(slab.entry, slab.exit))
+    }
+}
+impl_symmetrical_intersection!(Aabb, Ray);
+
+/// Signed distance of a point from an axis-aligned bounding box.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SignedDistance<S> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: Map<Output = Vector<S>>,
+    Scalar<S>: IntrinsicOrd + Signed + Sqrt,
+{
+    type Output = Scalar<S>;
+
+    fn signed_distance(&self, point: &S) -> Self::Output {
+        let zero = Scalar::<S>::zero();
+        let half = Scalar::<S>::one() / (Scalar::<S>::one() + One::one());
+        let lower = self.lower_bound();
+        let extent = (self.upper_bound() - lower) * half;
+        let center = lower + extent;
+        // The displacement of the point from the nearest face of the bounding
+        // box along each axis, which is negative within the bounding box.
+        let q = (*point - center).zip_map(extent, |x, e| x.abs() - e);
+        let outside = q
+            .map(|x| {
+                if x > zero {
+                    x
+                }
+                else {
+                    zero
+                }
+            })
+            .magnitude();
+        let inside = q.fold(q.scalar_component(0).unwrap(), |max, x| {
+            if x > max {
+                x
             }
             else {
-                a / b
+                max
             }
-        };
-        let aabb = self;
-        let direction = *ray.direction.get();
-        let origin = (aabb.origin - ray.origin).zip_map(direction, pdiv);
-        let endpoint = ((aabb.endpoint()) - ray.origin).zip_map(direction, pdiv);
-        let min = origin
-            .per_item_min_or_undefined(endpoint)
-            .max_or_undefined();
-        let max = origin
-            .per_item_max_or_undefined(endpoint)
-            .min_or_undefined();
-        if max.is_negative() || min > max || min.is_undefined() || max.is_undefined() {
-            None
+        });
+        outside + if inside < zero { inside } else { zero }
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_signed_distance!(Aabb);
+
+/// Closest point in an axis-aligned bounding box to a point.
+///
+/// If the point is within the bounding box, then the point itself is
+/// returned.
+impl<S> ClosestPoint<S> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    type Output = S;
+
+    fn closest_point(&self, point: &S) -> Self::Output {
+        point
+            .per_item_max_or_undefined(self.lower_bound())
+            .per_item_min_or_undefined(self.upper_bound())
+    }
+}
+
+/// Containment of a point by an axis-aligned bounding box.
+impl<S> Contains<S> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    fn contains(&self, point: &S) -> bool {
+        let point = point.into_coordinates();
+        all_scalar_components(&self.lower_bound().into_coordinates(), &point, |a, b| {
+            a <= b
+        }) && all_scalar_components(&point, &self.upper_bound().into_coordinates(), |a, b| {
+            a <= b
+        })
+    }
+}
+
+/// Containment of axis-aligned bounding boxes.
+impl<S> Contains<Aabb<S>> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    fn contains(&self, aabb: &Aabb<S>) -> bool {
+        self.contains(&aabb.lower_bound()) && self.contains(&aabb.upper_bound())
+    }
+}
+
+/// Containment of a sphere by an axis-aligned bounding box.
+impl<S> Contains<Sphere<S>> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    fn contains(&self, sphere: &Sphere<S>) -> bool {
+        let radius = Vector::<S>::canonical_basis()
+            .into_iter()
+            .fold(Vector::<S>::zero(), |radius, basis| {
+                radius + (basis * sphere.radius)
+            });
+        self.contains(&(sphere.center + -radius)) && self.contains(&(sphere.center + radius))
+    }
+}
+
+/// Swept intersection of axis-aligned bounding boxes.
+impl<S> Sweep<Aabb<S>> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded + IntrinsicOrd,
+{
+    type Displacement = Vector<S>;
+    /// The times of impact at which the bounding boxes begin and cease to
+    /// overlap.
+    ///
+    /// The entry time is negative if the bounding boxes overlap initially and
+    /// the exit time may be greater than one.
+    type Output = (Scalar<S>, Scalar<S>);
+
+    fn sweep(&self, displacement: Self::Displacement, aabb: &Aabb<S>) -> Option<Self::Output> {
+        let zero = Scalar::<S>::zero();
+        let component = |point: S, index| point.into_coordinates().scalar_component(index).unwrap();
+        let (lower, upper) = (self.lower_bound(), self.upper_bound());
+        let (other_lower, other_upper) = (aabb.lower_bound(), aabb.upper_bound());
+        let mut entry = Scalar::<S>::min_value();
+        let mut exit = Scalar::<S>::max_value();
+        for index in 0..S::dimensions() {
+            let v = displacement.scalar_component(index).unwrap();
+            // The bounding boxes overlap along the axis while the upper bound of
+            // `self` is beyond the lower bound of `aabb` and the lower bound of
+            // `self` is before the upper bound of `aabb`.
+            let near = component(other_lower, index) - component(upper, index);
+            let far = component(other_upper, index) - component(lower, index);
+            if abs_diff_eq!(v, zero) {
+                if near > zero || far < zero {
+                    return None;
+                }
+            }
+            else {
+                let (a, b) = (near / v, far / v);
+                let (a, b) = if a < b { (a, b) } else { (b, a) };
+                if a > entry {
+                    entry = a;
+                }
+                if b < exit {
+                    exit = b;
+                }
+            }
+        }
+        if entry <= exit && entry <= One::one() && exit >= zero {
+            Some((entry, exit))
         }
         else {
-            Some((min, max))
+            None
         }
     }
 }
-impl_symmetrical_intersection!(Aabb, Ray);
 
 //impl<S> PartialEq for Aabb<S>
 //where
@@ -855,7 +2385,20 @@ impl_symmetrical_intersection!(Aabb, Ray);
 //{
 //}
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Vector<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
 pub struct Plane<S>
 where
     S: EuclideanSpace,
@@ -864,6 +2407,24 @@ where
     pub normal: Unit<Vector<S>>,
 }
 
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Pod for Plane<S>
+where
+    S: CoordinateLayout + Pod,
+    Vector<S>: Pod,
+{
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Plane<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Vector<S>: Zeroable,
+{
+}
+
+impl_approx!(Plane, |plane| plane.normal.into_inner());
+
 impl<S> Copy for Plane<S>
 where
     S: EuclideanSpace,
@@ -885,8 +2446,88 @@ where
     }
 }
 
+impl<S> Plane<S>
+where
+    S: EuclideanSpace,
+{
+    /// Transforms the plane.
+    ///
+    /// The normal is transformed by the inverse transpose of the
+    /// transformation, so that it remains orthogonal to the plane. Returns
+    /// `None` if the transformation is not invertible.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn transform<T>(self, transform: &T) -> Option<Self>
+    where
+        T: Clone + Transform<S>,
+        Scalar<S>: Sqrt,
+    {
+        let Plane { origin, normal } = self;
+        let inverse = transform.clone().inverse()?;
+        let normal = Vector::<S>::canonical_basis().into_iter().fold(
+            Vector::<S>::zero(),
+            |output, basis| {
+                let component = normal.get().dot(inverse.transform_vector(basis));
+                output + (basis * component)
+            },
+        );
+        Unit::try_from_inner(normal).map(|normal| Plane {
+            origin: transform.transform_point(origin),
+            normal,
+        })
+    }
+
+    /// Converts the plane into a covector of its projective space.
+    ///
+    /// For a plane with normal $\hat{n}$ through the point $p$, the covector is
+    /// $(\hat{n}, -\hat{n}\cdot p)$. Applying the covector to the homogeneous
+    /// coordinates of a point yields the signed distance of the point from the
+    /// plane.
+    pub fn into_covector(self) -> <Projective<S> as DualSpace>::Dual
+    where
+        Vector<S>: Homogeneous + Extend<Projective<S>>,
+        Projective<S>: DualSpace + VectorSpace<Scalar = Scalar<S>>,
+    {
+        let normal = self.normal.into_inner();
+        normal
+            .extend(-normal.dot(self.origin.into_coordinates()))
+            .transpose()
+    }
+}
+
+impl<S> Reflect<Plane<S>> for S
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn reflect(self, plane: Plane<S>) -> Self::Output {
+        let two = Scalar::<S>::one() + One::one();
+        self + -(self.reject(plane) * two)
+    }
+}
+
+impl<S> Reject<Plane<S>> for S
+where
+    S: EuclideanSpace,
+{
+    type Output = Vector<S>;
+
+    fn reject(self, plane: Plane<S>) -> Self::Output {
+        let normal = plane.normal.into_inner();
+        normal * (self - plane.origin).dot(normal)
+    }
+}
+
 /// Intersection of a plane and a ray.
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Scalar<S>: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Scalar<S>: Serialize, Vector<S>: Serialize",
+    ))
+)]
 pub enum PlaneRay<S>
 where
     S: EuclideanSpace,
@@ -928,33 +2569,379 @@ where
     }
 }
 
-/// Intersection of a plane and a ray.
-impl<S> Intersection<Ray<S>> for Plane<S>
+/// Intersection of planes.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Vector<S>: Serialize",
+    ))
+)]
+pub enum PlanePlane<S>
 where
-    S: EuclideanSpace + FiniteDimensional,
-    <S as FiniteDimensional>::N: Cmp<U2, Output = Greater>,
-    Scalar<S>: Signed,
+    S: EuclideanSpace,
 {
-    /// The _time of impact_ of a point intersection or the ray if it lies
-    /// within the plane.
-    ///
-    /// The time of impact $t$ describes the distance along the half-line from
-    /// the ray's origin at which the intersection occurs.
-    type Output = PlaneRay<S>;
+    Line(Line<S>),
+    Plane(Plane<S>),
+}
 
-    /// Determines if a ray intersects a plane at a point or lies within the
-    /// plane. Computes the _time of impact_ of a `Ray` for a point
-    /// intersection.
-    ///
-    /// Given a ray formed by an origin $P_0$ and a unit direction $\hat{u}$,
-    /// the point of intersection with the plane is $P_0 + t\hat{u}$.
-    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
-        let plane = self;
-        ray.into_line()
-            .intersection(plane)
-            .and_then(|embedding| match embedding {
-                LinePlane::TimeOfImpact(t) => {
-                    if t.is_positive() {
+impl<S> PlanePlane<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn into_line(self) -> Option<Line<S>> {
+        match self {
+            PlanePlane::Line(line) => Some(line),
+            _ => None,
+        }
+    }
+
+    pub fn into_plane(self) -> Option<Plane<S>> {
+        match self {
+            PlanePlane::Plane(plane) => Some(plane),
+            _ => None,
+        }
+    }
+}
+
+impl<S> Debug for PlanePlane<S>
+where
+    S: Debug + EuclideanSpace,
+    Vector<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            PlanePlane::Line(line) => write!(formatter, "Line({:?})", line),
+            PlanePlane::Plane(plane) => write!(formatter, "Plane({:?})", plane),
+        }
+    }
+}
+
+/// Intersection of planes.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Plane<S>> for Plane<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Scalar<S>: Sqrt,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    /// The line along which the planes intersect or the plane if the planes
+    /// are coincident.
+    type Output = PlanePlane<S>;
+
+    /// Determines if planes intersect along a line or are coincident.
+    ///
+    /// Parallel planes that are not coincident do not intersect.
+    fn intersection(&self, plane: &Plane<S>) -> Option<Self::Output> {
+        let (n1, n2) = (*self.normal.get(), *plane.normal.get());
+        let direction = n1.cross(n2);
+        let square = direction.square_magnitude();
+        if abs_diff_eq!(square, Zero::zero()) {
+            if abs_diff_eq!(n1.dot(plane.origin - self.origin), Zero::zero()) {
+                Some(PlanePlane::Plane(*self))
+            }
+            else {
+                None
+            }
+        }
+        else {
+            let (d1, d2) = (
+                n1.dot(self.origin.into_coordinates()),
+                n2.dot(plane.origin.into_coordinates()),
+            );
+            let origin = ((n2.cross(direction) * d1) + (direction.cross(n1) * d2))
+                * (Scalar::<S>::one() / square);
+            Unit::try_from_inner(direction).map(|direction| {
+                PlanePlane::Line(Line {
+                    origin: S::from_coordinates(origin),
+                    direction,
+                })
+            })
+        }
+    }
+}
+
+impl<S> Plane<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+{
+    /// Determines the point at which three planes intersect.
+    ///
+    /// Returns `None` if any two of the planes are parallel or the planes
+    /// intersect along a common line.
+    pub fn triple_intersection(&self, a: &Plane<S>, b: &Plane<S>) -> Option<S> {
+        let (n1, n2, n3) = (*self.normal.get(), *a.normal.get(), *b.normal.get());
+        let denominator = n1.dot(n2.cross(n3));
+        if abs_diff_eq!(denominator, Zero::zero()) {
+            None
+        }
+        else {
+            let distance =
+                |plane: &Plane<S>| plane.normal.get().dot(plane.origin.into_coordinates());
+            let (d1, d2, d3) = (distance(self), distance(a), distance(b));
+            Some(S::from_coordinates(
+                ((n2.cross(n3) * d1) + (n3.cross(n1) * d2) + (n1.cross(n2) * d3))
+                    * (Scalar::<S>::one() / denominator),
+            ))
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Plane<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Computes a best-fit plane from a set of points without a linear
+    /// algebra library.
+    ///
+    /// The plane is fit using least squares: its origin is the centroid of the
+    /// points and its normal is the eigenvector of their covariance with the
+    /// least eigenvalue. Unlike `from_points`, this function is available
+    /// regardless of the `lapack` and `linalg-faer` features, which implement
+    /// `from_points` for spaces of any dimension but require scalars supported
+    /// by those libraries.
+    ///
+    /// Returns `None` if there are no points or the points are coincident.
+    pub fn from_points_covariance<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        let (centroid, [normal, ..]) = covariance_eigen(points.as_ref())?;
+        Some(Plane {
+            origin: centroid,
+            normal: Unit::try_from_inner(normal)?,
+        })
+    }
+
+    /// Computes a best-fit plane from a set of points without a linear
+    /// algebra library.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points_covariance`.
+    pub fn try_from_points_covariance<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        try_fit_points(points.as_ref(), |points| {
+            Self::from_points_covariance(points)
+        })
+    }
+
+    /// Computes a best-fit plane from a set of points.
+    ///
+    /// This implementation is only used if neither the `lapack` nor
+    /// `linalg-faer` features are enabled. See `from_points_covariance`.
+    #[cfg(not(any(feature = "linalg-faer", all(feature = "lapack", target_os = "linux"))))]
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        Self::from_points_covariance(points)
+    }
+
+    /// Computes a best-fit plane from a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points`.
+    #[cfg(not(any(feature = "linalg-faer", all(feature = "lapack", target_os = "linux"))))]
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        Self::try_from_points_covariance(points)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Line<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    <S as FiniteDimensional>::N: Cmp<U4, Output = Less>,
+{
+    /// Computes a best-fit line from a set of points without a linear algebra
+    /// library.
+    ///
+    /// The line is fit using total least squares: its origin is the centroid
+    /// of the points and its direction is the eigenvector of their covariance
+    /// with the greatest eigenvalue. As with
+    /// `Plane::from_points_covariance`, this function is available regardless
+    /// of the `lapack` and `linalg-faer` features and supports spaces with at
+    /// most three dimensions.
+    ///
+    /// Returns `None` if there are no points or the points are coincident.
+    pub fn from_points_covariance<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        let (centroid, [.., direction]) = covariance_eigen(points.as_ref())?;
+        Some(Line {
+            origin: centroid,
+            direction: Unit::try_from_inner(direction)?,
+        })
+    }
+
+    /// Computes a best-fit line from a set of points without a linear algebra
+    /// library.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points_covariance`.
+    pub fn try_from_points_covariance<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        try_fit_points(points.as_ref(), |points| {
+            Self::from_points_covariance(points)
+        })
+    }
+
+    /// Computes a best-fit line from a set of points.
+    ///
+    /// This implementation is only used if neither the `lapack` nor
+    /// `linalg-faer` features are enabled. See `from_points_covariance`.
+    #[cfg(not(any(feature = "linalg-faer", all(feature = "lapack", target_os = "linux"))))]
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        Self::from_points_covariance(points)
+    }
+
+    /// Computes a best-fit line from a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points are coincident. See `from_points`.
+    #[cfg(not(any(feature = "linalg-faer", all(feature = "lapack", target_os = "linux"))))]
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: AsRef<[S]>,
+    {
+        Self::try_from_points_covariance(points)
+    }
+}
+
+/// Fits a best-fit shape to a set of points.
+///
+/// Returns `Error::Empty` if there are no points and `Error::Degenerate` if
+/// the points are coincident or `f` fails. The `try_from_points` functions of
+/// `Plane` and `Line` use this function with every linear algebra backend, so
+/// these errors do not depend on which backend is enabled.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn try_fit_points<S, T, F>(points: &[S], f: F) -> Result<T, Error>
+where
+    S: EuclideanSpace,
+    F: FnOnce(&[S]) -> Option<T>,
+{
+    let (first, rest) = points.split_first().ok_or(Error::Empty)?;
+    if rest
+        .iter()
+        .all(|point| abs_diff_eq!((*point - *first).square_magnitude(), Zero::zero()))
+    {
+        Err(Error::Degenerate)
+    }
+    else {
+        f(points).ok_or(Error::Degenerate)
+    }
+}
+
+/// Computes the centroid of a set of points and the eigendecomposition of
+/// their covariance.
+///
+/// Eigenvectors are sorted by eigenvalue in nondecreasing order. Spaces with
+/// fewer than three dimensions are embedded in three dimensions, in which
+/// case the eigenvectors of the additional dimensions have zero eigenvalues
+/// and are truncated to zero vectors. Returns `None` if there are no points or
+/// the points are coincident.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn covariance_eigen<S>(points: &[S]) -> Option<(S, [Vector<S>; 3])>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Scalar<S>: Signed + Sqrt,
+    Vector<S>: FromItems,
+{
+    let n = S::dimensions();
+    let centroid = EuclideanSpace::centroid(points.iter().cloned())?;
+    let zero = Scalar::<S>::zero();
+    let mut covariance = [[zero; 3]; 3];
+    for point in points {
+        let difference = *point - centroid;
+        let mut d = [zero; 3];
+        for (i, component) in d.iter_mut().enumerate().take(n) {
+            *component = difference.scalar_component(i)?;
+        }
+        for (row, di) in covariance.iter_mut().zip(d.iter()) {
+            for (entry, dj) in row.iter_mut().zip(d.iter()) {
+                *entry = *entry + (*di * *dj);
+            }
+        }
+    }
+    if covariance
+        .iter()
+        .flatten()
+        .all(|entry| abs_diff_eq!(*entry, zero))
+    {
+        return None;
+    }
+    let (eigenvalues, eigenvectors) = jacobi_eigen(covariance, 3);
+    let eigenvector = |j: usize| {
+        Vector::<S>::from_items(eigenvectors.iter().take(n).map(|row| row[j]))
+            .unwrap_or_else(Vector::<S>::zero)
+    };
+    let mut pairs = [
+        (eigenvalues[0], eigenvector(0)),
+        (eigenvalues[1], eigenvector(1)),
+        (eigenvalues[2], eigenvector(2)),
+    ];
+    pairs.sort_unstable_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    Some((centroid, pairs.map(|(_, eigenvector)| eigenvector)))
+}
+
+/// Intersection of a plane and a ray.
+impl<S> Intersection<Ray<S>> for Plane<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    <S as FiniteDimensional>::N: Cmp<U2, Output = Greater>,
+    Scalar<S>: Signed,
+{
+    /// The _time of impact_ of a point intersection or the ray if it lies
+    /// within the plane.
+    ///
+    /// The time of impact $t$ describes the distance along the half-line from
+    /// the ray's origin at which the intersection occurs.
+    type Output = PlaneRay<S>;
+
+    /// Determines if a ray intersects a plane at a point or lies within the
+    /// plane. Computes the _time of impact_ of a `Ray` for a point
+    /// intersection.
+    ///
+    /// Given a ray formed by an origin $P_0$ and a unit direction $\hat{u}$,
+    /// the point of intersection with the plane is $P_0 + t\hat{u}$.
+    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
+        let plane = self;
+        ray.into_line()
+            .intersection(plane)
+            .and_then(|embedding| match embedding {
+                LinePlane::TimeOfImpact(t) => {
+                    if t.is_positive() {
                         Some(PlaneRay::TimeOfImpact(t))
                     }
                     else {
@@ -964,145 +2951,5228 @@ where
                 LinePlane::Line(_) => Some(PlaneRay::Ray(*ray)),
             })
     }
-}
-impl_symmetrical_intersection!(Plane, Ray);
+}
+impl_symmetrical_intersection!(Plane, Ray);
+
+/// Signed distance of a point from a plane.
+///
+/// The distance is positive in the direction of the normal of the plane.
+impl<S> SignedDistance<S> for Plane<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = Scalar<S>;
+
+    fn signed_distance(&self, point: &S) -> Self::Output {
+        self.normal.get().dot(*point - self.origin)
+    }
+}
+impl_symmetrical_signed_distance!(Plane);
+
+/// Closest point on a plane to a point.
+///
+/// This is the projection of the point onto the plane.
+impl<S> ClosestPoint<S> for Plane<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn closest_point(&self, point: &S) -> Self::Output {
+        let normal = *self.normal.get();
+        *point + -(normal * normal.dot(*point - self.origin))
+    }
+}
+
+/// Result of culling an object against a `Frustum`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Culling {
+    /// The object is entirely inside of the frustum.
+    Inside,
+    /// The object may intersect the boundary of the frustum.
+    Intersecting,
+    /// The object is entirely outside of the frustum.
+    Outside,
+}
+
+impl Culling {
+    /// Returns `true` if the object is not outside of the frustum.
+    pub fn is_visible(&self) -> bool {
+        !matches!(self, Culling::Outside)
+    }
+}
+
+/// Viewing frustum.
+///
+/// A frustum is bounded by six planes with normals that point into the
+/// frustum. The planes are ordered left, right, bottom, top, near, and far.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Unit<Vector<S>>: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Vector<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Frustum<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    pub planes: [Plane<S>; 6],
+}
+
+impl<S> Copy for Frustum<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Copy,
+{
+}
+
+impl<S> Debug for Frustum<S>
+where
+    S: Debug + EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Frustum")
+            .field("planes", &self.planes)
+            .finish()
+    }
+}
+
+impl<S> Frustum<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Gets the corners of the frustum.
+    ///
+    /// The index of each corner is a bit mask of the planes that meet at that
+    /// corner: the first bit selects the right plane (rather than the left),
+    /// the second bit selects the top plane (rather than the bottom), and the
+    /// third bit selects the far plane (rather than the near). Returns `None`
+    /// if any set of planes does not meet at a point.
+    pub fn corners(&self) -> Option<[S; 8]>
+    where
+        Vector<S>: Cross<Output = Vector<S>>,
+    {
+        let mut corners = [S::origin(); 8];
+        for (mask, corner) in corners.iter_mut().enumerate() {
+            let plane = |offset: usize, bit: usize| &self.planes[offset + ((mask >> bit) & 1)];
+            *corner = plane(0, 0).triple_intersection(plane(2, 1), plane(4, 2))?;
+        }
+        Some(corners)
+    }
+
+    /// Extracts a frustum from a view-projection matrix.
+    ///
+    /// The planes of the frustum are extracted from the rows of the matrix
+    /// (Gribb-Hartmann). The matrix must map the frustum into clip space with
+    /// all coordinates in $[-w, w]$, as with OpenGL conventions. Returns `None`
+    /// if any plane is degenerate.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn from_view_projection<M>(matrix: &M) -> Option<Self>
+    where
+        M: Matrix<Column = Projective<S>>,
+        M::Row: VectorSpace<Scalar = Scalar<S>>,
+        Scalar<S>: Sqrt,
+        Vector<S>: Homogeneous,
+    {
+        let row = |index| matrix.row_component(index).unwrap();
+        let w = row(3);
+        let plane = |row: M::Row| {
+            let normal = Vector::<S>::canonical_basis()
+                .into_iter()
+                .enumerate()
+                .fold(Vector::<S>::zero(), |normal, (index, basis)| {
+                    normal + (basis * row.scalar_component(index).unwrap())
+                });
+            let offset = row.scalar_component(3).unwrap();
+            let square = normal.square_magnitude();
+            // The point nearest to the origin on the plane $n \cdot p + d = 0$
+            // is $-(d / |n|^2)n$.
+            let origin = S::from_coordinates(normal * (-offset / square));
+            Unit::try_from_inner(normal).map(|normal| Plane { origin, normal })
+        };
+        Some(Frustum {
+            planes: [
+                plane(w + row(0))?,
+                plane(w + -row(0))?,
+                plane(w + row(1))?,
+                plane(w + -row(1))?,
+                plane(w + row(2))?,
+                plane(w + -row(2))?,
+            ],
+        })
+    }
+
+    /// Extracts a frustum from a view-projection matrix.
+    ///
+    /// Returns `Error::Degenerate` if any plane is degenerate. See
+    /// `from_view_projection`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn try_from_view_projection<M>(matrix: &M) -> Result<Self, Error>
+    where
+        M: Matrix<Column = Projective<S>>,
+        M::Row: VectorSpace<Scalar = Scalar<S>>,
+        Scalar<S>: Sqrt,
+        Vector<S>: Homogeneous,
+    {
+        Self::from_view_projection(matrix).ok_or(Error::Degenerate)
+    }
+
+    /// Culls a point against the frustum.
+    ///
+    /// Points on the boundary of the frustum are `Intersecting`.
+    pub fn cull_point(&self, point: &S) -> Culling {
+        self.cull_with(|plane| {
+            let distance = distance(plane, *point);
+            (distance, distance)
+        })
+    }
+
+    /// Culls an `Aabb` against the frustum.
+    ///
+    /// This test is conservative: an `Aabb` near an edge or corner of the
+    /// frustum may be `Intersecting` even if it is outside of the frustum.
+    pub fn cull_aabb(&self, aabb: &Aabb<S>) -> Culling
+    where
+        Scalar<S>: Signed,
+    {
+        let half = Scalar::<S>::one() / (Scalar::<S>::one() + One::one());
+        let extent = aabb.extent * half;
+        let center = aabb.origin + extent;
+        self.cull_with(|plane| {
+            let distance = distance(plane, center);
+            let radius = plane
+                .normal
+                .get()
+                .zip_map(extent, |n, e| n.abs() * e.abs())
+                .sum();
+            (distance - radius, distance + radius)
+        })
+    }
+
+    /// Culls a `Sphere` against the frustum.
+    ///
+    /// This test is conservative: a `Sphere` near an edge or corner of the
+    /// frustum may be `Intersecting` even if it is outside of the frustum.
+    pub fn cull_sphere(&self, sphere: &Sphere<S>) -> Culling {
+        self.cull_with(|plane| {
+            let distance = distance(plane, sphere.center);
+            (distance - sphere.radius, distance + sphere.radius)
+        })
+    }
+
+    /// Culls an object given the minimum and maximum signed distances of the
+    /// object from each plane of the frustum.
+    fn cull_with<F>(&self, mut f: F) -> Culling
+    where
+        F: FnMut(&Plane<S>) -> (Scalar<S>, Scalar<S>),
+    {
+        let mut culling = Culling::Inside;
+        for plane in self.planes.iter() {
+            let (min, max) = f(plane);
+            if max < Zero::zero() {
+                return Culling::Outside;
+            }
+            if min < Zero::zero() {
+                culling = Culling::Intersecting;
+            }
+        }
+        culling
+    }
+}
+
+/// Containment of a point by a frustum.
+impl<S> Contains<S> for Frustum<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn contains(&self, point: &S) -> bool {
+        self.cull_point(point) != Culling::Outside
+    }
+}
+
+/// Containment of an axis-aligned bounding box by a frustum.
+impl<S> Contains<Aabb<S>> for Frustum<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Scalar<S>: Signed,
+{
+    fn contains(&self, aabb: &Aabb<S>) -> bool {
+        self.cull_aabb(aabb) == Culling::Inside
+    }
+}
+
+/// Containment of a sphere by a frustum.
+impl<S> Contains<Sphere<S>> for Frustum<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn contains(&self, sphere: &Sphere<S>) -> bool {
+        self.cull_sphere(sphere) == Culling::Inside
+    }
+}
+
+fn distance<S>(plane: &Plane<S>, point: S) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    plane.normal.get().dot(point - plane.origin)
+}
+
+/// Principal axes of a set of points.
+///
+/// The principal axes are the eigenvectors of the covariance of a set of
+/// points and form an orthonormal basis in which the coordinates of the points
+/// are uncorrelated. The _variance_ of the points along an axis is its
+/// eigenvalue. Axes are sorted by variance in nonincreasing order, so the first
+/// axis is the direction in which the points vary the most and the last axis
+/// approximates the normal of points sampled from a surface.
+///
+/// Computing principal axes requires an eigendecomposition of the covariance
+/// matrix via `SymmetricEigen`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::Point2;
+/// use theon::query::PrincipalAxes;
+///
+/// type E2 = Point2<f64>;
+///
+/// let axes = PrincipalAxes::from_points(vec![
+///     E2::new(-2.0, 0.0),
+///     E2::new(2.0, 0.0),
+///     E2::new(0.0, -1.0),
+///     E2::new(0.0, 1.0),
+/// ])
+/// .unwrap();
+/// assert_eq!(1.0, axes.axis(0).unwrap().get().x.abs());
+/// assert_eq!(1.0, axes.axis(1).unwrap().get().y.abs());
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct PrincipalAxes<S, M>
+where
+    S: EuclideanSpace,
+{
+    /// The centroid of the points.
+    pub centroid: S,
+    /// The variance of the points along each axis.
+    pub variances: Vector<S>,
+    /// The axes, which form the columns of the matrix.
+    pub axes: M,
+}
+
+impl<S, M> PrincipalAxes<S, M>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    Vector<S>: DualSpace + FromItems + Outer<Output = M>,
+    M: FromItems + SquareMatrix<Column = Vector<S>, Scalar = Scalar<S>> + SymmetricEigen,
+    M::Row: FiniteDimensional<N = <M::Column as FiniteDimensional>::N>,
+{
+    /// Computes the principal axes of a set of points.
+    ///
+    /// Returns `None` if there are no points or the eigendecomposition fails.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let (centroid, covariance) = S::covariance(points)?;
+        let (eigenvalues, eigenvectors) = covariance.symmetric_eigen()?;
+        // Eigenvalues are sorted in nondecreasing order, so reverse the order
+        // of both eigenvalues and eigenvectors.
+        let n = S::dimensions();
+        let variances = Vector::<S>::from_items(
+            (0..n)
+                .rev()
+                .map(|i| VectorSpace::scalar_component(&eigenvalues, i).unwrap()),
+        )?;
+        let axes = M::from_items((0..n).rev().flat_map(|j| {
+            (0..n).map(move |i| Matrix::scalar_component(&eigenvectors, i, j).unwrap())
+        }))?;
+        Some(PrincipalAxes {
+            centroid,
+            variances,
+            axes,
+        })
+    }
+
+    /// Computes the principal axes of a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the eigendecomposition fails. See `from_points`.
+    pub fn try_from_points<I>(points: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut points = points.into_iter().peekable();
+        if points.peek().is_none() {
+            Err(Error::Empty)
+        }
+        else {
+            Self::from_points(points).ok_or(Error::Degenerate)
+        }
+    }
+
+    /// Gets the axis at the given index.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn axis(&self, index: usize) -> Option<Unit<Vector<S>>> {
+        self.axes
+            .column_component(index)
+            .map(Unit::from_inner_unchecked)
+    }
+
+    /// Gets the variance of the points along the axis at the given index.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn variance(&self, index: usize) -> Option<Scalar<S>> {
+        self.variances.scalar_component(index)
+    }
+}
+
+impl<S, M> Debug for PrincipalAxes<S, M>
+where
+    S: Debug + EuclideanSpace,
+    M: Debug,
+    Vector<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PrincipalAxes")
+            .field("centroid", &self.centroid)
+            .field("variances", &self.variances)
+            .field("axes", &self.axes)
+            .finish()
+    }
+}
+
+/// Oriented bounding box.
+///
+/// Represents an $n$-dimensional volume along each axis of a rotated basis.
+/// The bounding box is defined by its _center_, its _extent_ along each of its
+/// axes, and the _rotation_ of its axes. In the local space of the bounding
+/// box, its axes are the canonical basis and it is an `Aabb` centered at the
+/// origin.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, R: Deserialize<'de>, Vector<S>: Deserialize<'de>",
+        serialize = "S: Serialize, R: Serialize, Vector<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Obb<S, R>
+where
+    S: EuclideanSpace,
+{
+    pub center: S,
+    /// The half-extent of the bounding box along each of its axes.
+    ///
+    /// The components of the extent should be non-negative.
+    pub extent: Vector<S>,
+    /// The rotation from the local space of the bounding box.
+    pub rotation: R,
+}
+
+impl<S, R> Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+{
+    /// Gets an iterator over the unit axes of the bounding box.
+    pub fn axes(&self) -> impl '_ + Iterator<Item = Vector<S>> {
+        Vector::<S>::canonical_basis()
+            .into_iter()
+            .map(move |basis| self.rotation.rotate_vector(basis))
+    }
+
+    /// Gets the Lebesgue measure ($n$-dimensional volume) of the bounding box.
+    pub fn volume(&self) -> Scalar<S>
+    where
+        Scalar<S>: Signed,
+    {
+        let two = Scalar::<S>::one() + One::one();
+        self.extent
+            .fold(One::one(), |volume, x| volume * x.abs() * two)
+    }
+
+    /// Transforms the bounding box by a rigid motion.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: RigidMotion<S, Rotation = R>,
+    {
+        Obb {
+            center: transform.transform_point(self.center),
+            extent: self.extent,
+            rotation: self.rotation.clone().compose(transform.rotation()),
+        }
+    }
+
+    /// Gets the coordinates of a point in the local space of the bounding box.
+    fn local_coordinates(&self, point: S) -> Vector<S> {
+        self.rotation
+            .clone()
+            .inverse()
+            .rotate_vector(point - self.center)
+    }
+
+    /// Gets the bounding box in its local space.
+    fn local_aabb(&self) -> Aabb<S> {
+        let two = Scalar::<S>::one() + One::one();
+        Aabb {
+            origin: S::from_coordinates(-self.extent),
+            extent: self.extent * two,
+        }
+    }
+
+    /// Gets the projected radius of the bounding box along an axis.
+    fn radius(&self, axis: Vector<S>) -> Scalar<S>
+    where
+        Scalar<S>: Signed,
+    {
+        self.axes()
+            .enumerate()
+            .fold(Zero::zero(), |radius, (index, basis)| {
+                radius + (self.extent.scalar_component(index).unwrap() * basis.dot(axis).abs())
+            })
+    }
+}
+
+impl<S, R> Debug for Obb<S, R>
+where
+    S: Debug + EuclideanSpace,
+    R: Debug,
+    Vector<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Obb")
+            .field("center", &self.center)
+            .field("extent", &self.extent)
+            .field("rotation", &self.rotation)
+            .finish()
+    }
+}
+
+impl<S, R> Default for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Rotation<S>,
+{
+    fn default() -> Self {
+        Obb {
+            center: S::origin(),
+            extent: Vector::<S>::zero(),
+            rotation: R::identity(),
+        }
+    }
+}
+
+impl<S, R> From<Aabb<S>> for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Rotation<S>,
+    Scalar<S>: IntrinsicOrd,
+{
+    fn from(aabb: Aabb<S>) -> Self {
+        let half = Scalar::<S>::one() / (Scalar::<S>::one() + One::one());
+        let lower = aabb.lower_bound();
+        let extent = (aabb.upper_bound() - lower) * half;
+        Obb {
+            center: lower + extent,
+            extent,
+            rotation: R::identity(),
+        }
+    }
+}
+
+/// Converts an oriented bounding box into the `Aabb` that bounds it.
+impl<S, R> From<Obb<S, R>> for Aabb<S>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+    Scalar<S>: Signed,
+{
+    fn from(obb: Obb<S, R>) -> Self {
+        let extent = Vector::<S>::canonical_basis()
+            .into_iter()
+            .fold(Vector::<S>::zero(), |extent, basis| {
+                extent + (basis * obb.radius(basis))
+            });
+        let two = Scalar::<S>::one() + One::one();
+        Aabb {
+            origin: obb.center + -extent,
+            extent: extent * two,
+        }
+    }
+}
+
+/// Intersection of an oriented bounding box and a point.
+impl<S, R> Intersection<S> for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+    Scalar<S>: Signed,
+{
+    /// The coordinates of the point in the local space of the bounding box.
+    type Output = Vector<S>;
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        let local = self.local_coordinates(*point);
+        if (0..S::dimensions()).all(|index| {
+            local.scalar_component(index).unwrap().abs()
+                <= self.extent.scalar_component(index).unwrap().abs()
+        }) {
+            Some(local)
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Symmetrical intersection.
+impl<S, R> Intersection<Obb<S, R>> for S
+where
+    S: EuclideanSpace,
+    Obb<S, R>: Intersection<S>,
+{
+    type Output = <Obb<S, R> as Intersection<S>>::Output;
+
+    fn intersection(&self, other: &Obb<S, R>) -> Option<Self::Output> {
+        other.intersection(self)
+    }
+}
+
+/// Intersection of oriented bounding boxes.
+///
+/// Overlap is determined using the separating axis theorem. The test is exact
+/// in two and three dimensions. In higher dimensions, only the axes of the
+/// bounding boxes are tested, so boxes that do not overlap may be reported as
+/// intersecting.
+impl<S, R> Intersection<Obb<S, R>> for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+    Scalar<S>: Signed,
+{
+    type Output = ();
+
+    fn intersection(&self, other: &Obb<S, R>) -> Option<Self::Output> {
+        let offset = other.center - self.center;
+        let is_separating = |axis: Vector<S>| {
+            !abs_diff_eq!(axis.square_magnitude(), Zero::zero())
+                && offset.dot(axis).abs() > (self.radius(axis) + other.radius(axis))
+        };
+        if self.axes().chain(other.axes()).any(is_separating) {
+            return None;
+        }
+        if S::dimensions() == 3 {
+            for a in self.axes() {
+                if other.axes().any(|b| is_separating(cross(a, b))) {
+                    return None;
+                }
+            }
+        }
+        Some(())
+    }
+}
+
+/// Intersection of an oriented bounding box and a ray.
+impl<S, R> Intersection<Ray<S>> for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+    Scalar<S>: Bounded + Infinite + IntrinsicOrd + Signed,
+{
+    /// The _times of impact_ of the intersection.
+    ///
+    /// See the intersection of `Aabb` and `Ray`.
+    type Output = RaySolid<S>;
+
+    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
+        // Rotations preserve magnitude, so the direction of the ray remains a
+        // unit vector in the local space of the bounding box.
+        let ray = Ray {
+            origin: S::from_coordinates(self.local_coordinates(ray.origin)),
+            direction: Unit::from_inner_unchecked(
+                self.rotation
+                    .clone()
+                    .inverse()
+                    .rotate_vector(ray.direction.into_inner()),
+            ),
+        };
+        self.local_aabb().intersection(&ray)
+    }
+}
+
+/// Symmetrical intersection.
+impl<S, R> Intersection<Obb<S, R>> for Ray<S>
+where
+    S: EuclideanSpace,
+    Obb<S, R>: Intersection<Ray<S>>,
+{
+    type Output = <Obb<S, R> as Intersection<Ray<S>>>::Output;
+
+    fn intersection(&self, other: &Obb<S, R>) -> Option<Self::Output> {
+        other.intersection(self)
+    }
+}
+
+/// Containment of a point by an oriented bounding box.
+///
+/// The point is tested in the local space of the bounding box.
+impl<S, R> Contains<S> for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+    Scalar<S>: IntrinsicOrd,
+{
+    fn contains(&self, point: &S) -> bool {
+        self.local_aabb()
+            .contains(&S::from_coordinates(self.local_coordinates(*point)))
+    }
+}
+
+/// Containment of an axis-aligned bounding box by an oriented bounding box.
+///
+/// Because both bounding boxes are convex, the `Aabb` is contained if all of
+/// its corners are contained.
+impl<S, R> Contains<Aabb<S>> for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+    Scalar<S>: IntrinsicOrd,
+{
+    fn contains(&self, aabb: &Aabb<S>) -> bool {
+        aabb.corners().all(|corner| self.contains(&corner))
+    }
+}
+
+/// Computes the cross product of vectors in three dimensions.
+///
+/// This is computed from the components of the vectors and so does not require
+/// `Cross`, but the vectors must have exactly three dimensions.
+fn cross<V>(a: V, b: V) -> V
+where
+    V: Basis + VectorSpace,
+{
+    let component = |v: &V, index| v.scalar_component(index).unwrap();
+    let (ax, ay, az) = (component(&a, 0), component(&a, 1), component(&a, 2));
+    let (bx, by, bz) = (component(&b, 0), component(&b, 1), component(&b, 2));
+    V::canonical_basis()
+        .into_iter()
+        .zip([
+            (ay * bz) - (az * by),
+            (az * bx) - (ax * bz),
+            (ax * by) - (ay * bx),
+        ])
+        .fold(V::zero(), |output, (basis, x)| output + (basis * x))
+}
+
+/// Line segment.
+///
+/// Represents the bounded portion of a line between its _start_ and _end_
+/// points. Points along the segment are parameterized by $t\in[0,1]$, such
+/// that a point is given by $P_S + t(P_E - P_S)$.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(deserialize = "S: Deserialize<'de>", serialize = "S: Serialize"))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Segment<S>
+where
+    S: EuclideanSpace,
+{
+    pub start: S,
+    pub end: S,
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Pod for Segment<S> where S: CoordinateLayout + Pod {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Segment<S> where S: EuclideanSpace + Zeroable {}
+
+impl<S> Segment<S>
+where
+    S: EuclideanSpace,
+{
+    /// Gets the vector from the start to the end of the segment.
+    pub fn extent(&self) -> Vector<S> {
+        self.end - self.start
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn length(&self) -> Scalar<S>
+    where
+        Scalar<S>: Sqrt,
+    {
+        self.extent().magnitude()
+    }
+
+    pub fn midpoint(&self) -> S {
+        let two = Scalar::<S>::one() + One::one();
+        self.start + (self.extent() * (Scalar::<S>::one() / two))
+    }
+
+    /// Gets the point along the segment at the parameter $t$.
+    pub fn point(&self, t: Scalar<S>) -> S {
+        self.start + (self.extent() * t)
+    }
+
+    /// Gets the parameter $t$ of the point along the segment nearest to the
+    /// given point.
+    ///
+    /// The parameter is zero if the segment is degenerate.
+    pub fn closest_parameter(&self, point: S) -> Scalar<S> {
+        let extent = self.extent();
+        let square_length = extent.square_magnitude();
+        if abs_diff_eq!(square_length, Zero::zero()) {
+            Zero::zero()
+        }
+        else {
+            num::clamp(
+                (point - self.start).dot(extent) / square_length,
+                Zero::zero(),
+                One::one(),
+            )
+        }
+    }
+
+    /// Gets the point along the segment nearest to the given point.
+    pub fn closest_point(&self, point: S) -> S {
+        self.point(self.closest_parameter(point))
+    }
+
+    /// Gets the nearest pair of points between segments.
+    ///
+    /// The first point lies along `self` and the second point lies along
+    /// `other`. If the segments are parallel, then one of many nearest pairs is
+    /// chosen.
+    pub fn closest_points(&self, other: &Self) -> (S, S) {
+        // See Ericson, Real-Time Collision Detection, section 5.1.9.
+        let d1 = self.extent();
+        let d2 = other.extent();
+        let r = self.start - other.start;
+        let a = d1.square_magnitude();
+        let e = d2.square_magnitude();
+        let f = d2.dot(r);
+        let zero = Scalar::<S>::zero();
+        let one = Scalar::<S>::one();
+        let (s, t) = if abs_diff_eq!(a, zero) && abs_diff_eq!(e, zero) {
+            (zero, zero)
+        }
+        else if abs_diff_eq!(a, zero) {
+            (zero, num::clamp(f / e, zero, one))
+        }
+        else {
+            let c = d1.dot(r);
+            if abs_diff_eq!(e, zero) {
+                (num::clamp(-c / a, zero, one), zero)
+            }
+            else {
+                let b = d1.dot(d2);
+                let denominator = (a * e) - (b * b);
+                let s = if abs_diff_eq!(denominator, zero) {
+                    zero
+                }
+                else {
+                    num::clamp(((b * f) - (c * e)) / denominator, zero, one)
+                };
+                let t = ((b * s) + f) / e;
+                if t < zero {
+                    (num::clamp(-c / a, zero, one), zero)
+                }
+                else if t > one {
+                    (num::clamp((b - c) / a, zero, one), one)
+                }
+                else {
+                    (s, t)
+                }
+            }
+        };
+        (self.point(s), other.point(t))
+    }
+
+    /// Gets the distance between the given point and the segment.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn distance_to_point(&self, point: S) -> Scalar<S>
+    where
+        Scalar<S>: Sqrt,
+    {
+        (point - self.closest_point(point)).magnitude()
+    }
+
+    /// Gets the distance between segments.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn distance_to_segment(&self, other: &Self) -> Scalar<S>
+    where
+        Scalar<S>: Sqrt,
+    {
+        let (a, b) = self.closest_points(other);
+        (b - a).magnitude()
+    }
+
+    /// Transforms the segment.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: Transform<S>,
+    {
+        Segment {
+            start: transform.transform_point(self.start),
+            end: transform.transform_point(self.end),
+        }
+    }
+}
+
+impl_approx_points!(Segment, |segment| [segment.start, segment.end]);
+
+impl<S> Debug for Segment<S>
+where
+    S: Debug + EuclideanSpace,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Segment")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+/// Intersection of an axis-aligned bounding box and a segment.
+impl<S> Intersection<Segment<S>> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded + Infinite + IntrinsicOrd + Signed,
+{
+    /// The minimum and maximum parameters of the intersection.
+    ///
+    /// The parameters $t_{min}$ and $t_{max}$ are in $[0, 1]$ and describe the
+    /// portion of the segment within the bounding box (see `Segment::point`).
+    type Output = (Scalar<S>, Scalar<S>);
+
+    fn intersection(&self, segment: &Segment<S>) -> Option<Self::Output> {
+        // Avoid computing `NaN`s. See the intersection of `Aabb` and `Ray`.
+        let pdiv = |a: Scalar<S>, b: Scalar<S>| {
+            if abs_diff_eq!(a, Zero::zero()) {
+                a
+            }
+            else {
+                a / b
+            }
+        };
+        let aabb = self;
+        let extent = segment.extent();
+        let origin = (aabb.origin - segment.start).zip_map(extent, pdiv);
+        let endpoint = (aabb.endpoint() - segment.start).zip_map(extent, pdiv);
+        let min = origin
+            .per_item_min_or_undefined(endpoint)
+            .max_or_undefined()
+            .max_or_undefined(&Zero::zero());
+        let max = origin
+            .per_item_max_or_undefined(endpoint)
+            .min_or_undefined()
+            .min_or_undefined(&One::one());
+        if min <= max && !min.is_undefined() && !max.is_undefined() {
+            Some((min, max))
+        }
+        else {
+            None
+        }
+    }
+}
+impl_symmetrical_intersection!(Aabb, Segment);
+
+impl<S> Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded + IntrinsicOrd,
+{
+    /// Clips a line to the bounding box.
+    ///
+    /// Returns the segment of the line within the bounding box or `None` if
+    /// the line misses the bounding box. The segment is directed along the
+    /// line.
+    pub fn clip_line(&self, line: &Line<S>) -> Option<Segment<S>> {
+        let Line { origin, direction } = *line;
+        clip_line(origin, *direction.get(), self.half_spaces()).map(|(min, max)| Segment {
+            start: origin + (*direction.get() * min),
+            end: origin + (*direction.get() * max),
+        })
+    }
+
+    /// Clips a ray to the bounding box.
+    ///
+    /// Returns the segment of the ray within the bounding box or `None` if the
+    /// ray misses the bounding box. If the origin of the ray is within the
+    /// bounding box, then the segment starts at the origin.
+    pub fn clip_ray(&self, ray: &Ray<S>) -> Option<Segment<S>> {
+        let Ray { origin, direction } = *ray;
+        clip_ray(ray, self.half_spaces()).map(|(min, max)| Segment {
+            start: origin + (*direction.get() * min.max_or_undefined(&Zero::zero())),
+            end: origin + (*direction.get() * max),
+        })
+    }
+
+    /// Clips a segment to the bounding box.
+    ///
+    /// Returns the portion of the segment within the bounding box or `None` if
+    /// the segment misses the bounding box.
+    pub fn clip_segment(&self, segment: &Segment<S>) -> Option<Segment<S>> {
+        clip_line(segment.start, segment.extent(), self.half_spaces())
+            .map(|(min, max)| {
+                (
+                    min.max_or_undefined(&Zero::zero()),
+                    max.min_or_undefined(&One::one()),
+                )
+            })
+            .filter(|(min, max)| min <= max)
+            .map(|(min, max)| Segment {
+                start: segment.point(min),
+                end: segment.point(max),
+            })
+    }
+
+    fn half_spaces(&self) -> impl Iterator<Item = (S, Vector<S>)> {
+        let lower = self.lower_bound();
+        let upper = self.upper_bound();
+        Vector::<S>::canonical_basis()
+            .into_iter()
+            .flat_map(move |basis| IntoIterator::into_iter([(lower, basis), (upper, -basis)]))
+    }
+}
+
+/// Intersection of an axis-aligned bounding box and a line.
+impl<S> Intersection<Line<S>> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded + IntrinsicOrd,
+{
+    /// The minimum and maximum parameters of the intersection.
+    ///
+    /// The parameters $t_{min}$ and $t_{max}$ describe the portion of the line
+    /// $P_0 + t\hat{u}$ within the bounding box, where $P_0$ is the origin
+    /// and $\hat{u}$ is the direction of the line.
+    type Output = (Scalar<S>, Scalar<S>);
+
+    fn intersection(&self, line: &Line<S>) -> Option<Self::Output> {
+        clip_line(line.origin, *line.direction.get(), self.half_spaces())
+    }
+}
+impl_symmetrical_intersection!(Aabb, Line);
+
+/// Intersection of a plane and a segment.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Scalar<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Scalar<S>: Serialize",
+    ))
+)]
+pub enum PlaneSegment<S>
+where
+    S: EuclideanSpace,
+{
+    /// The parameter along the segment at which it crosses the plane.
+    Parameter(Scalar<S>),
+    /// The segment lies within the plane.
+    Segment(Segment<S>),
+}
+
+impl<S> PlaneSegment<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn into_parameter(self) -> Option<Scalar<S>> {
+        match self {
+            PlaneSegment::Parameter(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    pub fn into_segment(self) -> Option<Segment<S>> {
+        match self {
+            PlaneSegment::Segment(segment) => Some(segment),
+            _ => None,
+        }
+    }
+}
+
+impl<S> Debug for PlaneSegment<S>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            PlaneSegment::Parameter(x) => write!(formatter, "Parameter({:?})", x),
+            PlaneSegment::Segment(segment) => write!(formatter, "Segment({:?})", segment),
+        }
+    }
+}
+
+/// Intersection of a plane and a segment.
+impl<S> Intersection<Segment<S>> for Plane<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Signed,
+{
+    type Output = PlaneSegment<S>;
+
+    fn intersection(&self, segment: &Segment<S>) -> Option<Self::Output> {
+        let normal = self.normal.into_inner();
+        let start = (segment.start - self.origin).dot(normal);
+        let end = (segment.end - self.origin).dot(normal);
+        let is_zero = |x: Scalar<S>| abs_diff_eq!(x, Zero::zero());
+        if is_zero(start) && is_zero(end) {
+            Some(PlaneSegment::Segment(*segment))
+        }
+        else if (start.is_positive() && end.is_positive())
+            || (start.is_negative() && end.is_negative())
+        {
+            None
+        }
+        else {
+            Some(PlaneSegment::Parameter(start / (start - end)))
+        }
+    }
+}
+impl_symmetrical_intersection!(Plane, Segment);
+
+/// Intersection of segments.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(deserialize = "S: Deserialize<'de>", serialize = "S: Serialize",))
+)]
+pub enum SegmentSegment<S>
+where
+    S: EuclideanSpace,
+{
+    Point(S),
+    /// The overlap of collinear segments.
+    ///
+    /// The overlap is directed like the segment on which the intersection is
+    /// queried (the receiver).
+    Segment(Segment<S>),
+}
+
+impl<S> SegmentSegment<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn into_point(self) -> Option<S> {
+        match self {
+            SegmentSegment::Point(point) => Some(point),
+            _ => None,
+        }
+    }
+
+    pub fn into_segment(self) -> Option<Segment<S>> {
+        match self {
+            SegmentSegment::Segment(segment) => Some(segment),
+            _ => None,
+        }
+    }
+}
+
+impl<S> Debug for SegmentSegment<S>
+where
+    S: Debug + EuclideanSpace,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            SegmentSegment::Point(point) => write!(formatter, "Point({:?})", point),
+            SegmentSegment::Segment(segment) => write!(formatter, "Segment({:?})", segment),
+        }
+    }
+}
+
+/// Intersection of segments in two dimensions.
+///
+/// Segments intersect at a point unless they are collinear, in which case
+/// their overlap may be a segment. Degenerate segments with coincident
+/// endpoints are treated as points.
+///
+/// Parallelism and collinearity are determined by perp dot products normalized
+/// by the product of the lengths of the segments, so these tests do not depend
+/// on the scale of the segments.
+impl<S> Intersection<Segment<S>> for Segment<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    Scalar<S>: IntrinsicOrd,
+{
+    type Output = SegmentSegment<S>;
+
+    fn intersection(&self, other: &Segment<S>) -> Option<Self::Output> {
+        let zero = Scalar::<S>::zero();
+        let one = Scalar::<S>::one();
+        let within = |t: Scalar<S>| t >= zero && t <= one;
+        // Determines if a perp dot product is negligible relative to a product
+        // of squared magnitudes, which must be non-zero.
+        let is_negligible = |perp_dot: Scalar<S>, magnitudes: Scalar<S>| {
+            abs_diff_eq!((perp_dot * perp_dot) / magnitudes, zero)
+        };
+        let r = self.extent();
+        let s = other.extent();
+        let rr = r.square_magnitude();
+        let ss = s.square_magnitude();
+        let qp = other.start - self.start;
+        if rr.is_zero() {
+            // This segment is degenerate. Intersect its point with the other
+            // segment.
+            let qq = qp.square_magnitude();
+            return if ss.is_zero() {
+                qq.is_zero().then_some(SegmentSegment::Point(self.start))
+            }
+            else {
+                let t = -qp.dot(s) / ss;
+                (within(t) && (qq.is_zero() || is_negligible(s.perp_dot(qp), ss * qq)))
+                    .then_some(SegmentSegment::Point(self.start))
+            };
+        }
+        if ss.is_zero() {
+            return other
+                .intersection(self)
+                .map(|_| SegmentSegment::Point(other.start));
+        }
+        let denominator = r.perp_dot(s);
+        if is_negligible(denominator, rr * ss) {
+            if !is_negligible(qp.perp_dot(r), rr * ss) {
+                // The segments are parallel but not collinear.
+                return None;
+            }
+            // The segments are collinear. Project the other segment onto this
+            // segment and clamp its parameters to this segment.
+            let t0 = qp.dot(r) / rr;
+            let t1 = t0 + (s.dot(r) / rr);
+            let (min, max) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+            let min = min.max_or_undefined(&zero);
+            let max = max.min_or_undefined(&one);
+            if min < max {
+                Some(SegmentSegment::Segment(Segment {
+                    start: self.point(min),
+                    end: self.point(max),
+                }))
+            }
+            else if min == max {
+                Some(SegmentSegment::Point(self.point(min)))
+            }
+            else {
+                None
+            }
+        }
+        else {
+            let t = qp.perp_dot(s) / denominator;
+            let u = qp.perp_dot(r) / denominator;
+            (within(t) && within(u)).then_some(SegmentSegment::Point(self.point(t)))
+        }
+    }
+}
+
+/// Distance of a point from a segment.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SignedDistance<S> for Segment<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Output = Scalar<S>;
+
+    fn signed_distance(&self, point: &S) -> Self::Output {
+        self.distance_to_point(*point)
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_signed_distance!(Segment);
+
+/// Distance between segments.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SignedDistance<Segment<S>> for Segment<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Output = Scalar<S>;
+
+    fn signed_distance(&self, other: &Segment<S>) -> Self::Output {
+        self.distance_to_segment(other)
+    }
+}
+
+/// Closest point on a segment to a point.
+impl<S> ClosestPoint<S> for Segment<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn closest_point(&self, point: &S) -> Self::Output {
+        Segment::closest_point(self, *point)
+    }
+}
+
+/// Closest points between segments.
+///
+/// See `Segment::closest_points`.
+impl<S> ClosestPoint<Segment<S>> for Segment<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = (S, S);
+
+    fn closest_point(&self, other: &Segment<S>) -> Self::Output {
+        self.closest_points(other)
+    }
+}
+
+/// Sphere (ball).
+///
+/// Represents the $n$-dimensional region within a distance of its _radius_
+/// from its _center_. Intersections consider the region enclosed by the
+/// sphere, such that a sphere contains the points within it.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Scalar<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Scalar<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Sphere<S>
+where
+    S: EuclideanSpace,
+{
+    pub center: S,
+    /// The radius of the sphere.
+    ///
+    /// The radius should be non-negative.
+    pub radius: Scalar<S>,
+}
+
+// `Sphere` does not implement `Pod`, because `CoordinateLayout` does not
+// preclude padding between its center and radius (e.g., `glam::Vec3A`).
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Sphere<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Scalar<S>: Zeroable,
+{
+}
+
+impl<S> Sphere<S>
+where
+    S: EuclideanSpace,
+{
+    /// Transforms the sphere by a rigid motion.
+    ///
+    /// Rigid motions preserve distances, so the radius of the sphere is
+    /// unchanged.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: RigidMotion<S>,
+    {
+        Sphere {
+            center: transform.transform_point(self.center),
+            radius: self.radius,
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Sphere<S>
+where
+    S: EuclideanSpace + FiniteDimensional,
+    <S as FiniteDimensional>::N: Cmp<U4, Output = Less>,
+{
+    /// Computes a best-fit sphere from a set of points.
+    ///
+    /// The sphere is fit using algebraic least squares, which minimizes the
+    /// error of the squared distances of the points from its center. In two
+    /// dimensions, this fits a circle. Produces the sphere and the residual,
+    /// which is the root mean square of the distances of the points from the
+    /// surface of the sphere.
+    ///
+    /// Returns `None` if there are no points or the points lie on a
+    /// hyperplane, such as collinear points in two dimensions, in which case
+    /// no unique sphere fits the points.
+    pub fn from_points<I>(points: I) -> Option<(Self, Scalar<S>)>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: IntoIterator<Item = S>,
+        I::IntoIter: Clone,
+    {
+        // The points are centered on their centroid, which decouples the
+        // offset of the sphere from the centroid and the square of its radius
+        // in the normal equations.
+        let points = points.into_iter();
+        let n = S::dimensions();
+        let centroid = EuclideanSpace::centroid(points.clone())?;
+        let (zero, one) = (Scalar::<S>::zero(), Scalar::<S>::one());
+        let half = one / (one + one);
+        let mut a = [[zero; 3]; 3];
+        let mut b = [zero; 3];
+        let (mut count, mut k) = (zero, zero);
+        for point in points.clone() {
+            let difference = point - centroid;
+            let square = difference.square_magnitude();
+            let mut d = [zero; 3];
+            for (i, component) in d.iter_mut().enumerate().take(n) {
+                *component = difference.scalar_component(i)?;
+            }
+            for ((row, bi), di) in a.iter_mut().zip(b.iter_mut()).zip(d.iter()) {
+                for (entry, dj) in row.iter_mut().zip(d.iter()) {
+                    *entry = *entry + (*di * *dj);
+                }
+                *bi = *bi + (*di * square * half);
+            }
+            count = count + one;
+            k = k + square;
+        }
+        let offset = solve(a, b, n)?;
+        let offset = Vector::<S>::from_items(offset.iter().take(n).cloned())?;
+        let center = centroid + offset;
+        let radius = Sqrt::sqrt((k / count) + offset.square_magnitude());
+        let residual = points.fold(zero, |sum, point| {
+            let error = Sqrt::sqrt((point - center).square_magnitude()) - radius;
+            sum + (error * error)
+        });
+        Some((Sphere { center, radius }, Sqrt::sqrt(residual / count)))
+    }
+
+    /// Computes a best-fit sphere from a set of points.
+    ///
+    /// Returns `Error::Empty` if there are no points and `Error::Degenerate`
+    /// if the points lie on a hyperplane. See `from_points`.
+    pub fn try_from_points<I>(points: I) -> Result<(Self, Scalar<S>), Error>
+    where
+        Scalar<S>: Signed + Sqrt,
+        Vector<S>: FromItems,
+        I: IntoIterator<Item = S>,
+        I::IntoIter: Clone,
+    {
+        let points = points.into_iter();
+        if points.clone().next().is_none() {
+            Err(Error::Empty)
+        }
+        else {
+            Self::from_points(points).ok_or(Error::Degenerate)
+        }
+    }
+}
+
+/// Solves the linear system formed by the leading $n\times n$ block of a
+/// matrix.
+///
+/// The system is solved via Gaussian elimination with partial pivoting.
+/// Returns `None` if the system is singular.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn solve<T>(mut a: [[T; 3]; 3], mut b: [T; 3], n: usize) -> Option<[T; 3]>
+where
+    T: AbsDiffEq + Copy + PartialOrd + Signed,
+{
+    for i in 0..n {
+        let pivot = (i..n).fold(i, |pivot, j| {
+            if a[j][i].abs() > a[pivot][i].abs() {
+                j
+            }
+            else {
+                pivot
+            }
+        });
+        if abs_diff_eq!(a[pivot][i], T::zero()) {
+            return None;
+        }
+        a.swap(i, pivot);
+        b.swap(i, pivot);
+        for j in (i + 1)..n {
+            let (head, tail) = a.split_at_mut(j);
+            let (pivot, row) = (&head[i], &mut tail[0]);
+            let factor = row[i] / pivot[i];
+            for (x, y) in row[i..n].iter_mut().zip(pivot[i..n].iter()) {
+                *x = *x - (factor * *y);
+            }
+            b[j] = b[j] - (factor * b[i]);
+        }
+    }
+    let mut x = [T::zero(); 3];
+    for i in (0..n).rev() {
+        let sum = ((i + 1)..n).fold(b[i], |sum, j| sum - (a[i][j] * x[j]));
+        x[i] = sum / a[i][i];
+    }
+    Some(x)
+}
+
+impl_approx_points!(Sphere, |sphere| [sphere.center], |sphere| [sphere.radius]);
+
+impl<S> Debug for Sphere<S>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Sphere")
+            .field("center", &self.center)
+            .field("radius", &self.radius)
+            .finish()
+    }
+}
+
+impl<S> Default for Sphere<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Sphere {
+            center: S::origin(),
+            radius: Zero::zero(),
+        }
+    }
+}
+
+/// Intersection of an axis-aligned bounding box and a sphere.
+impl<S> Intersection<Sphere<S>> for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    /// The point in the bounding box nearest to the center of the sphere.
+    type Output = S;
+
+    fn intersection(&self, sphere: &Sphere<S>) -> Option<Self::Output> {
+        let point = sphere
+            .center
+            .per_item_max_or_undefined(self.lower_bound())
+            .per_item_min_or_undefined(self.upper_bound());
+        if (point - sphere.center).square_magnitude() <= sphere.radius * sphere.radius {
+            Some(point)
+        }
+        else {
+            None
+        }
+    }
+}
+impl_symmetrical_intersection!(Aabb, Sphere);
+
+/// Intersection of a ray and a sphere.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Sphere<S>> for Ray<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    /// The _times of impact_ of the intersection.
+    ///
+    /// The times of impact $t_{min}$ and $t_{max}$ describe the distance along
+    /// the half-line from the ray's origin at which the ray enters and exits
+    /// the sphere. $t_{min}$ is negative if the ray's origin is within the
+    /// sphere.
+    type Output = RaySolid<S>;
+
+    fn intersection(&self, sphere: &Sphere<S>) -> Option<Self::Output> {
+        let offset = self.origin - sphere.center;
+        let b = offset.dot(*self.direction.get());
+        let c = offset.square_magnitude() - (sphere.radius * sphere.radius);
+        // Exit if the ray's origin is outside of the sphere and the ray points
+        // away from the sphere.
+        if c > Zero::zero() && b > Zero::zero() {
+            return None;
+        }
+        let discriminant = (b * b) - c;
+        if discriminant < Zero::zero() {
+            None
+        }
+        else {
+            let root = Sqrt::sqrt(discriminant);
+            Some(RaySolid::from_interval(-b - root, -b + root))
+        }
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_intersection!(Ray, Sphere);
+
+/// Intersection of a sphere and a point.
+impl<S> Intersection<S> for Sphere<S>
+where
+    S: EuclideanSpace,
+{
+    /// The displacement of the point from the center of the sphere.
+    type Output = Vector<S>;
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        let displacement = *point - self.center;
+        if displacement.square_magnitude() <= self.radius * self.radius {
+            Some(displacement)
+        }
+        else {
+            None
+        }
+    }
+}
+impl_symmetrical_intersection!(Sphere);
+
+/// Intersection of spheres.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Sphere<S>> for Sphere<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    /// The depth of penetration of the spheres.
+    ///
+    /// This is the sum of the radii less the distance between the centers of
+    /// the spheres, which is zero if the spheres are tangent.
+    type Output = Scalar<S>;
+
+    fn intersection(&self, other: &Sphere<S>) -> Option<Self::Output> {
+        let radius = self.radius + other.radius;
+        let distance = (other.center - self.center).square_magnitude();
+        if distance <= radius * radius {
+            Some(radius - Sqrt::sqrt(distance))
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Signed distance of a point from a sphere.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SignedDistance<S> for Sphere<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Output = Scalar<S>;
+
+    fn signed_distance(&self, point: &S) -> Self::Output {
+        (*point - self.center).magnitude() - self.radius
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_signed_distance!(Sphere);
+
+/// Signed distance between spheres.
+///
+/// The negation of the signed distance is the depth of penetration of the
+/// spheres.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SignedDistance<Sphere<S>> for Sphere<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Output = Scalar<S>;
+
+    fn signed_distance(&self, other: &Sphere<S>) -> Self::Output {
+        (other.center - self.center).magnitude() - (self.radius + other.radius)
+    }
+}
+
+/// Containment of a point by a sphere.
+impl<S> Contains<S> for Sphere<S>
+where
+    S: EuclideanSpace,
+{
+    fn contains(&self, point: &S) -> bool {
+        (*point - self.center).square_magnitude() <= self.radius * self.radius
+    }
+}
+
+/// Containment of an axis-aligned bounding box by a sphere.
+impl<S> Contains<Aabb<S>> for Sphere<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd + Signed,
+{
+    fn contains(&self, aabb: &Aabb<S>) -> bool {
+        // The farthest corner of the bounding box from the center of the
+        // sphere is contained if and only if the bounding box is contained.
+        let lower = aabb.lower_bound() - self.center;
+        let upper = aabb.upper_bound() - self.center;
+        let corner = lower.zip_map(upper, |a, b| {
+            let (a, b) = (a.abs(), b.abs());
+            if a > b {
+                a
+            }
+            else {
+                b
+            }
+        });
+        corner.square_magnitude() <= self.radius * self.radius
+    }
+}
+
+/// Containment of spheres.
+impl<S> Contains<Sphere<S>> for Sphere<S>
+where
+    S: EuclideanSpace,
+{
+    fn contains(&self, sphere: &Sphere<S>) -> bool {
+        let difference = self.radius - sphere.radius;
+        difference >= Zero::zero()
+            && (sphere.center - self.center).square_magnitude() <= difference * difference
+    }
+}
+
+/// Swept intersection of a sphere and a plane.
+impl<S> Sweep<Plane<S>> for Sphere<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Signed,
+{
+    type Displacement = Vector<S>;
+    /// The time of impact and the point of contact on the plane.
+    ///
+    /// The time of impact is zero if the sphere intersects the plane
+    /// initially.
+    type Output = (Scalar<S>, S);
+
+    fn sweep(&self, displacement: Self::Displacement, plane: &Plane<S>) -> Option<Self::Output> {
+        let zero = Scalar::<S>::zero();
+        let normal = *plane.normal.get();
+        let distance = normal.dot(self.center - plane.origin);
+        if distance.abs() <= self.radius {
+            return Some((zero, plane.closest_point(&self.center)));
+        }
+        // Orient the normal toward the sphere.
+        let (normal, distance) = if distance < zero {
+            (-normal, -distance)
+        }
+        else {
+            (normal, distance)
+        };
+        let rate = normal.dot(displacement);
+        if rate >= zero {
+            return None;
+        }
+        let t = (distance - self.radius) / -rate;
+        if t <= One::one() {
+            Some((
+                t,
+                self.center + (displacement * t) + -(normal * self.radius),
+            ))
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Swept intersection of spheres.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Sweep<Sphere<S>> for Sphere<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Displacement = Vector<S>;
+    /// The time of impact and a point of contact between the spheres.
+    ///
+    /// The time of impact is zero if the spheres intersect initially.
+    type Output = (Scalar<S>, S);
+
+    fn sweep(&self, displacement: Self::Displacement, sphere: &Sphere<S>) -> Option<Self::Output> {
+        // The motion of the center of `self` is tested against a sphere
+        // with the sum of the radii.
+        let zero = Scalar::<S>::zero();
+        let radius = self.radius + sphere.radius;
+        let offset = self.center - sphere.center;
+        let contact = |center: S| {
+            let direction = center - sphere.center;
+            sphere.center + (direction * (sphere.radius / radius))
+        };
+        let c = offset.square_magnitude() - (radius * radius);
+        if c <= zero {
+            return Some((zero, contact(self.center)));
+        }
+        let a = displacement.square_magnitude();
+        let b = offset.dot(displacement);
+        if abs_diff_eq!(a, zero) || b >= zero {
+            return None;
+        }
+        let discriminant = (b * b) - (a * c);
+        if discriminant < zero {
+            return None;
+        }
+        let t = (-b - Sqrt::sqrt(discriminant)) / a;
+        if t <= One::one() {
+            Some((t, contact(self.center + (displacement * t))))
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Swept intersection of a sphere and a triangle.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Sweep<Triangle<S>> for Sphere<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+    Scalar<S>: Signed + Sqrt,
+{
+    type Displacement = Vector<S>;
+    /// The time of impact and the point of contact on the triangle.
+    ///
+    /// The time of impact is zero if the sphere intersects the triangle
+    /// initially.
+    type Output = (Scalar<S>, S);
+
+    fn sweep(
+        &self,
+        displacement: Self::Displacement,
+        triangle: &Triangle<S>,
+    ) -> Option<Self::Output> {
+        let zero = Scalar::<S>::zero();
+        let closest = triangle.closest_point(&self.center);
+        if (closest - self.center).square_magnitude() <= self.radius * self.radius {
+            return Some((zero, closest));
+        }
+        let length = displacement.square_magnitude();
+        if abs_diff_eq!(length, zero) {
+            return None;
+        }
+        let length = Sqrt::sqrt(length);
+        // The center of the sphere is cast as a ray against the triangle
+        // expanded by the radius of the sphere: the face offset toward the
+        // sphere and capsules about each edge.
+        let ray = Ray {
+            origin: self.center,
+            direction: Unit::from_inner_unchecked(displacement * (Scalar::<S>::one() / length)),
+        };
+        let direction = *ray.direction.get();
+        let face = triangle.normal().and_then(|normal| {
+            let normal = *normal.get();
+            let distance = normal.dot(self.center - triangle.a);
+            let facing = if distance < zero { -normal } else { normal };
+            let rate = facing.dot(direction);
+            if rate >= zero {
+                return None;
+            }
+            let t = (distance.abs() - self.radius) / -rate;
+            let point = self.center + (direction * t) + -(facing * self.radius);
+            let Triangle { a, b, c } = *triangle;
+            let is_inside = [(a, b), (b, c), (c, a)]
+                .iter()
+                .all(|(start, end)| (*end - *start).cross(point - *start).dot(normal) >= zero);
+            if is_inside {
+                Some(t)
+            }
+            else {
+                None
+            }
+        });
+        let Triangle { a, b, c } = *triangle;
+        let edges = IntoIterator::into_iter([(a, b), (b, c), (c, a)]).filter_map(|(start, end)| {
+            Capsule {
+                segment: Segment { start, end },
+                radius: self.radius,
+            }
+            .intersection(&ray)
+            .map(|hit| hit.entry())
+        });
+        let t = face.into_iter().chain(edges).filter(|t| *t >= zero).fold(
+            None,
+            |min: Option<Scalar<S>>, t| match min {
+                Some(min) if min < t => Some(min),
+                _ => Some(t),
+            },
+        )? / length;
+        if t <= One::one() {
+            let center = self.center + (displacement * t);
+            Some((t, triangle.closest_point(&center)))
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Capsule.
+///
+/// Represents the $n$-dimensional region within a distance of its _radius_
+/// from its _segment_ (its axis). A capsule is the union of a cylinder and two
+/// hemispherical caps, or equivalently the set of spheres swept along the
+/// segment. Intersections consider the region enclosed by the capsule.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Scalar<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Scalar<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Capsule<S>
+where
+    S: EuclideanSpace,
+{
+    pub segment: Segment<S>,
+    pub radius: Scalar<S>,
+}
+
+// `Capsule` does not implement `Pod`, because `CoordinateLayout` does not
+// preclude padding between its segment and radius (e.g., `glam::Vec3A`).
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Capsule<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Scalar<S>: Zeroable,
+{
+}
+
+impl<S> Capsule<S>
+where
+    S: EuclideanSpace,
+{
+    /// Gets the point in the capsule nearest to the given point.
+    ///
+    /// If the point is within the capsule, then the point itself is returned.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn closest_point(&self, point: S) -> S
+    where
+        Scalar<S>: Sqrt,
+    {
+        let axis = self.segment.closest_point(point);
+        let displacement = point - axis;
+        let square_distance = displacement.square_magnitude();
+        if square_distance <= self.radius * self.radius {
+            point
+        }
+        else {
+            axis + (displacement * (self.radius / Sqrt::sqrt(square_distance)))
+        }
+    }
+
+    /// Transforms the capsule by a rigid motion.
+    ///
+    /// Rigid motions preserve distances, so the radius of the capsule is
+    /// unchanged.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: RigidMotion<S>,
+    {
+        Capsule {
+            segment: self.segment.transform(transform),
+            radius: self.radius,
+        }
+    }
+}
+
+impl_approx_points!(
+    Capsule,
+    |capsule| [capsule.segment.start, capsule.segment.end],
+    |capsule| [capsule.radius],
+);
+
+impl<S> Debug for Capsule<S>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Capsule")
+            .field("segment", &self.segment)
+            .field("radius", &self.radius)
+            .finish()
+    }
+}
+
+impl<S> Default for Capsule<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Capsule {
+            segment: Segment {
+                start: S::origin(),
+                end: S::origin(),
+            },
+            radius: Zero::zero(),
+        }
+    }
+}
+
+/// Intersection of a capsule and a point.
+impl<S> Intersection<S> for Capsule<S>
+where
+    S: EuclideanSpace,
+{
+    /// The displacement of the point from the nearest point on the segment of
+    /// the capsule.
+    type Output = Vector<S>;
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        let displacement = *point - self.segment.closest_point(*point);
+        if displacement.square_magnitude() <= self.radius * self.radius {
+            Some(displacement)
+        }
+        else {
+            None
+        }
+    }
+}
+impl_symmetrical_intersection!(Capsule);
+
+/// Intersection of capsules.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Capsule<S>> for Capsule<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    /// The depth of penetration of the capsules.
+    ///
+    /// This is the sum of the radii less the distance between the segments of
+    /// the capsules, which is zero if the capsules are tangent.
+    type Output = Scalar<S>;
+
+    fn intersection(&self, other: &Capsule<S>) -> Option<Self::Output> {
+        let (a, b) = self.segment.closest_points(&other.segment);
+        let radius = self.radius + other.radius;
+        let distance = (b - a).square_magnitude();
+        if distance <= radius * radius {
+            Some(radius - Sqrt::sqrt(distance))
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Intersection of a capsule and a ray.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Ray<S>> for Capsule<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    /// The _times of impact_ of the intersection.
+    ///
+    /// See the intersection of `Ray` and `Sphere`.
+    type Output = RaySolid<S>;
+
+    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
+        // A capsule is convex and is the union of a cylinder and a sphere at
+        // each endpoint of its segment, so the intersection is the union of the
+        // intersections with each of these regions.
+        let zero = Scalar::<S>::zero();
+        let direction = *ray.direction.get();
+        let sphere = |center: S| {
+            let offset = ray.origin - center;
+            let b = offset.dot(direction);
+            let c = offset.square_magnitude() - (self.radius * self.radius);
+            let discriminant = (b * b) - c;
+            if discriminant < zero {
+                None
+            }
+            else {
+                let root = Sqrt::sqrt(discriminant);
+                Some((-b - root, -b + root))
+            }
+        };
+        let cylinder = || {
+            // See Ericson, Real-Time Collision Detection, section 5.3.7.
+            let d = self.segment.extent();
+            let m = ray.origin - self.segment.start;
+            let dd = d.square_magnitude();
+            let md = m.dot(d);
+            let nd = direction.dot(d);
+            let a = dd - (nd * nd);
+            // The cylinder need not be considered if the ray is parallel to the
+            // segment, because the ray then intersects both spheres or neither.
+            if abs_diff_eq!(a, zero) {
+                return None;
+            }
+            let b = (dd * m.dot(direction)) - (nd * md);
+            let c = (dd * (m.square_magnitude() - (self.radius * self.radius))) - (md * md);
+            let discriminant = (b * b) - (a * c);
+            if discriminant < zero {
+                return None;
+            }
+            let root = Sqrt::sqrt(discriminant);
+            let (min, max) = ((-b - root) / a, (-b + root) / a);
+            // Clip the intersection to the slab between the endpoints.
+            let (lower, upper) = if abs_diff_eq!(nd, zero) {
+                if md < zero || md > dd {
+                    return None;
+                }
+                (min, max)
+            }
+            else {
+                let (p, q) = (-md / nd, (dd - md) / nd);
+                let (p, q) = if p < q { (p, q) } else { (q, p) };
+                (if p > min { p } else { min }, if q < max { q } else { max })
+            };
+            if lower > upper {
+                None
+            }
+            else {
+                Some((lower, upper))
+            }
+        };
+        let (min, max) = IntoIterator::into_iter([
+            sphere(self.segment.start),
+            sphere(self.segment.end),
+            cylinder(),
+        ])
+        .flatten()
+        .reduce(|(amin, amax), (bmin, bmax)| {
+            (
+                if bmin < amin { bmin } else { amin },
+                if bmax > amax { bmax } else { amax },
+            )
+        })?;
+        if max < zero {
+            None
+        }
+        else {
+            Some(RaySolid::from_interval(min, max))
+        }
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_intersection!(Capsule, Ray);
+
+/// Intersection of a capsule and a sphere.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Sphere<S>> for Capsule<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    /// The depth of penetration of the capsule and sphere.
+    ///
+    /// See the intersection of `Capsule`s.
+    type Output = Scalar<S>;
+
+    fn intersection(&self, sphere: &Sphere<S>) -> Option<Self::Output> {
+        Capsule {
+            segment: Segment {
+                start: sphere.center,
+                end: sphere.center,
+            },
+            radius: sphere.radius,
+        }
+        .intersection(self)
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_intersection!(Capsule, Sphere);
+
+/// Polygon.
+///
+/// Represents the two-dimensional region bounded by the closed chain of its
+/// _vertices_. Unlike `ConvexPolygon`, the polygon need not be convex and its
+/// vertices may be in either order. The vertices may be stored in any
+/// collection that can be viewed as a slice, such as an array or `Vec`.
+///
+/// Intersections consider the region enclosed by the polygon per the non-zero
+/// winding rule. See `Polygon::winding_number` and `Polygon::crossing_number`
+/// for the even-odd rule. Containment of points on the boundary of the polygon
+/// is unspecified.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(deserialize = "V: Deserialize<'de>", serialize = "V: Serialize",))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+pub struct Polygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    pub vertices: V,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S, V> Polygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    pub fn from_vertices(vertices: V) -> Self {
+        Polygon {
+            vertices,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gets an iterator over the edges of the polygon.
+    ///
+    /// The last edge joins the last vertex to the first vertex.
+    pub fn edges(&self) -> impl '_ + Iterator<Item = Segment<S>> {
+        let vertices = self.vertices.as_ref();
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(start, end)| Segment {
+                start: *start,
+                end: *end,
+            })
+    }
+
+    /// Gets the signed area of the polygon.
+    ///
+    /// The area is positive if the vertices are in counterclockwise order.
+    /// Regions of self-intersecting polygons contribute to the area in
+    /// proportion to their winding number.
+    pub fn area(&self) -> Scalar<S> {
+        let two = Scalar::<S>::one() + One::one();
+        self.edges().fold(Scalar::<S>::zero(), |area, edge| {
+            area + edge
+                .start
+                .into_coordinates()
+                .perp_dot(edge.end.into_coordinates())
+        }) / two
+    }
+
+    /// Gets the winding order of the vertices of the polygon.
+    ///
+    /// Returns `None` if the area of the polygon is zero. See
+    /// `winding::winding`.
+    pub fn winding(&self) -> Option<Winding> {
+        winding::winding(self.vertices.as_ref().iter().copied())
+    }
+
+    /// Determines if the polygon is convex.
+    ///
+    /// See `winding::is_convex`.
+    pub fn is_convex(&self) -> bool {
+        winding::is_convex(self.vertices.as_ref().iter().copied())
+    }
+
+    /// Reverses the winding order of the vertices of the polygon in place.
+    ///
+    /// The first vertex is unchanged. See `winding::reverse_winding`.
+    pub fn reverse_winding(&mut self)
+    where
+        V: AsMut<[S]>,
+    {
+        if let Some((_, vertices)) = self.vertices.as_mut().split_first_mut() {
+            vertices.reverse();
+        }
+    }
+
+    /// Gets the centroid of the region enclosed by the polygon.
+    ///
+    /// Returns `None` if the area of the polygon is zero.
+    pub fn centroid(&self) -> Option<S> {
+        let origin = *self.vertices.as_ref().first()?;
+        let (area, moment) = self.edges().fold(
+            (Scalar::<S>::zero(), Vector::<S>::zero()),
+            |(area, moment), edge| {
+                let start = edge.start - origin;
+                let end = edge.end - origin;
+                let cross = start.perp_dot(end);
+                (area + cross, moment + ((start + end) * cross))
+            },
+        );
+        if abs_diff_eq!(area, Zero::zero()) {
+            None
+        }
+        else {
+            let three = Scalar::<S>::one() + One::one() + One::one();
+            Some(origin + (moment * (Scalar::<S>::one() / (area * three))))
+        }
+    }
+
+    /// Gets the winding number of the polygon about a point.
+    ///
+    /// The winding number is the number of times that the boundary of the
+    /// polygon travels counterclockwise around the point. It is zero if the
+    /// point is outside of the polygon.
+    pub fn winding_number(&self, point: &S) -> isize {
+        let (_, y) = point.into_coordinates().into_xy();
+        self.edges().fold(0, |winding, edge| {
+            let (_, y0) = edge.start.into_coordinates().into_xy();
+            let (_, y1) = edge.end.into_coordinates().into_xy();
+            let side = edge.extent().perp_dot(*point - edge.start);
+            if y0 <= y {
+                if y1 > y && side > Zero::zero() {
+                    return winding + 1;
+                }
+            }
+            else if y1 <= y && side < Zero::zero() {
+                return winding - 1;
+            }
+            winding
+        })
+    }
+
+    /// Gets the number of times that a ray from a point in the direction of
+    /// the $x$ axis crosses the boundary of the polygon.
+    ///
+    /// The point is within the polygon per the even-odd rule if the crossing
+    /// number is odd.
+    pub fn crossing_number(&self, point: &S) -> usize {
+        let (x, y) = point.into_coordinates().into_xy();
+        self.edges()
+            .filter(|edge| {
+                let (x0, y0) = edge.start.into_coordinates().into_xy();
+                let (x1, y1) = edge.end.into_coordinates().into_xy();
+                ((y0 <= y) != (y1 <= y)) && (x < x0 + (((y - y0) / (y1 - y0)) * (x1 - x0)))
+            })
+            .count()
+    }
+}
+
+impl<S, V> Debug for Polygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]> + Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Polygon")
+            .field("vertices", &self.vertices)
+            .finish()
+    }
+}
+
+/// Intersection of a polygon and a point.
+///
+/// The point intersects the polygon if the winding number of the polygon about
+/// the point is non-zero.
+impl<S, V> Intersection<S> for Polygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    type Output = ();
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        if self.winding_number(point) != 0 {
+            Some(())
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Symmetrical intersection.
+impl<S, V> Intersection<Polygon<S, V>> for S
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+    Polygon<S, V>: Intersection<S>,
+{
+    type Output = <Polygon<S, V> as Intersection<S>>::Output;
+
+    fn intersection(&self, other: &Polygon<S, V>) -> Option<Self::Output> {
+        other.intersection(self)
+    }
+}
+
+impl<S, V> From<ConvexPolygon<S, V>> for Polygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    fn from(polygon: ConvexPolygon<S, V>) -> Self {
+        Polygon::from_vertices(polygon.vertices)
+    }
+}
+
+/// Convex polygon.
+///
+/// Represents the two-dimensional region bounded by its _vertices_, which are
+/// in counterclockwise order. The vertices may be stored in any collection
+/// that can be viewed as a slice, such as an array or `Vec`.
+///
+/// Intersections consider the region enclosed by the polygon. The polygon must
+/// be convex and have at least three vertices for intersections to be
+/// meaningful.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(deserialize = "V: Deserialize<'de>", serialize = "V: Serialize",))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+pub struct ConvexPolygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    pub vertices: V,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S, V> ConvexPolygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    pub fn from_vertices(vertices: V) -> Self {
+        ConvexPolygon {
+            vertices,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Gets an iterator over the edges of the polygon.
+    ///
+    /// Edges are in counterclockwise order and the last edge joins the last
+    /// vertex to the first vertex.
+    pub fn edges(&self) -> impl '_ + Iterator<Item = Segment<S>> {
+        let vertices = self.vertices.as_ref();
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(start, end)| Segment {
+                start: *start,
+                end: *end,
+            })
+    }
+
+    /// Gets an iterator over the planes (lines) that bound the polygon.
+    ///
+    /// The normals of the planes point into the polygon. Degenerate edges are
+    /// skipped. The planes can be collected into a `ConvexPolytope`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn planes(&self) -> impl '_ + Iterator<Item = Plane<S>>
+    where
+        Scalar<S>: Sqrt,
+    {
+        self.edges().filter_map(|edge| {
+            Unit::try_from_inner(edge.extent().perp()).map(|normal| Plane {
+                origin: edge.start,
+                normal,
+            })
+        })
+    }
+
+    /// Gets the signed area of the polygon.
+    ///
+    /// The area is positive if the vertices are in counterclockwise order.
+    pub fn area(&self) -> Scalar<S> {
+        let two = Scalar::<S>::one() + One::one();
+        self.edges().fold(Scalar::<S>::zero(), |area, edge| {
+            area + edge
+                .start
+                .into_coordinates()
+                .perp_dot(edge.end.into_coordinates())
+        }) / two
+    }
+
+    fn half_spaces(&self) -> impl '_ + Iterator<Item = (S, Vector<S>)> {
+        self.edges().map(|edge| (edge.start, edge.extent().perp()))
+    }
+}
+
+impl<S, V> Debug for ConvexPolygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]> + Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("ConvexPolygon")
+            .field("vertices", &self.vertices)
+            .finish()
+    }
+}
+
+/// Intersection of a convex polygon and a point.
+impl<S, V> Intersection<S> for ConvexPolygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    type Output = ();
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        if self.vertices.as_ref().len() >= 3
+            && self
+                .half_spaces()
+                .all(|(origin, normal)| normal.dot(*point - origin) >= Zero::zero())
+        {
+            Some(())
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Symmetrical intersection.
+impl<S, V> Intersection<ConvexPolygon<S, V>> for S
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+    ConvexPolygon<S, V>: Intersection<S>,
+{
+    type Output = <ConvexPolygon<S, V> as Intersection<S>>::Output;
+
+    fn intersection(&self, other: &ConvexPolygon<S, V>) -> Option<Self::Output> {
+        other.intersection(self)
+    }
+}
+
+/// Intersection of a convex polygon and a ray.
+impl<S, V> Intersection<Ray<S>> for ConvexPolygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+    Scalar<S>: Bounded,
+{
+    /// The _times of impact_ of the intersection.
+    ///
+    /// See the intersection of `ConvexPolytope` and `Ray`.
+    type Output = RaySolid<S>;
+
+    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
+        if self.vertices.as_ref().len() >= 3 {
+            clip_ray(ray, self.half_spaces()).map(|(min, max)| RaySolid::from_interval(min, max))
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Symmetrical intersection.
+impl<S, V> Intersection<ConvexPolygon<S, V>> for Ray<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+    ConvexPolygon<S, V>: Intersection<Ray<S>>,
+{
+    type Output = <ConvexPolygon<S, V> as Intersection<Ray<S>>>::Output;
+
+    fn intersection(&self, other: &ConvexPolygon<S, V>) -> Option<Self::Output> {
+        other.intersection(self)
+    }
+}
+
+/// Convex polytope.
+///
+/// Represents the $n$-dimensional region formed by the intersection of the
+/// half-spaces bounded by its _planes_. The normals of the planes point into
+/// the polytope. The planes may be stored in any collection that can be viewed
+/// as a slice, such as an array or `Vec`.
+///
+/// A polytope need not be bounded. For example, a polytope with a single plane
+/// is a half-space.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(deserialize = "P: Deserialize<'de>", serialize = "P: Serialize",))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+pub struct ConvexPolytope<S, P>
+where
+    S: EuclideanSpace,
+    P: AsRef<[Plane<S>]>,
+{
+    pub planes: P,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S, P> ConvexPolytope<S, P>
+where
+    S: EuclideanSpace,
+    P: AsRef<[Plane<S>]>,
+{
+    pub fn from_planes(planes: P) -> Self {
+        ConvexPolytope {
+            planes,
+            phantom: PhantomData,
+        }
+    }
+
+    fn half_spaces(&self) -> impl '_ + Iterator<Item = (S, Vector<S>)> {
+        self.planes
+            .as_ref()
+            .iter()
+            .map(|plane| (plane.origin, *plane.normal.get()))
+    }
+}
+
+impl<S, P> Debug for ConvexPolytope<S, P>
+where
+    S: EuclideanSpace,
+    P: AsRef<[Plane<S>]> + Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("ConvexPolytope")
+            .field("planes", &self.planes)
+            .finish()
+    }
+}
+
+impl<S> From<Frustum<S>> for ConvexPolytope<S, [Plane<S>; 6]>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn from(frustum: Frustum<S>) -> Self {
+        ConvexPolytope::from_planes(frustum.planes)
+    }
+}
+
+/// Intersection of a convex polytope and a point.
+impl<S, P> Intersection<S> for ConvexPolytope<S, P>
+where
+    S: EuclideanSpace,
+    P: AsRef<[Plane<S>]>,
+    Scalar<S>: Bounded,
+{
+    /// The depth of the point in the polytope.
+    ///
+    /// This is the minimum signed distance of the point from the planes of the
+    /// polytope, which is zero if the point is on the boundary of the
+    /// polytope. If the polytope has no planes, then this is the maximum value
+    /// of the scalar type.
+    type Output = Scalar<S>;
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        self.half_spaces()
+            .map(|(origin, normal)| normal.dot(*point - origin))
+            .try_fold(Scalar::<S>::max_value(), |depth, distance| {
+                if distance < Zero::zero() {
+                    None
+                }
+                else if distance < depth {
+                    Some(distance)
+                }
+                else {
+                    Some(depth)
+                }
+            })
+    }
+}
+
+/// Symmetrical intersection.
+impl<S, P> Intersection<ConvexPolytope<S, P>> for S
+where
+    S: EuclideanSpace,
+    P: AsRef<[Plane<S>]>,
+    ConvexPolytope<S, P>: Intersection<S>,
+{
+    type Output = <ConvexPolytope<S, P> as Intersection<S>>::Output;
+
+    fn intersection(&self, other: &ConvexPolytope<S, P>) -> Option<Self::Output> {
+        other.intersection(self)
+    }
+}
+
+/// Intersection of a convex polytope and a ray.
+impl<S, P> Intersection<Ray<S>> for ConvexPolytope<S, P>
+where
+    S: EuclideanSpace,
+    P: AsRef<[Plane<S>]>,
+    Scalar<S>: Bounded,
+{
+    /// The _times of impact_ of the intersection.
+    ///
+    /// The ray is clipped by each plane of the polytope (Cyrus-Beck). $t_{min}$
+    /// is negative if the ray's origin is within the polytope. If the polytope
+    /// is unbounded, then the times of impact may be the minimum or maximum
+    /// value of the scalar type.
+    type Output = RaySolid<S>;
+
+    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
+        clip_ray(ray, self.half_spaces()).map(|(min, max)| RaySolid::from_interval(min, max))
+    }
+}
+
+/// Symmetrical intersection.
+impl<S, P> Intersection<ConvexPolytope<S, P>> for Ray<S>
+where
+    S: EuclideanSpace,
+    P: AsRef<[Plane<S>]>,
+    ConvexPolytope<S, P>: Intersection<Ray<S>>,
+{
+    type Output = <ConvexPolytope<S, P> as Intersection<Ray<S>>>::Output;
+
+    fn intersection(&self, other: &ConvexPolytope<S, P>) -> Option<Self::Output> {
+        other.intersection(self)
+    }
+}
+
+/// Clips a ray by half-spaces.
+///
+/// Each half-space is given by a point on its boundary and a normal that points
+/// into the half-space. The normals need not be unit vectors.
+fn clip_ray<S, I>(ray: &Ray<S>, half_spaces: I) -> Option<(Scalar<S>, Scalar<S>)>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded,
+    I: IntoIterator<Item = (S, Vector<S>)>,
+{
+    clip_line(ray.origin, *ray.direction.get(), half_spaces).filter(|(_, max)| *max >= Zero::zero())
+}
+
+/// Clips the line $P_0 + t\vec{v}$ by half-spaces (Liang-Barsky).
+///
+/// Returns the range of the parameter $t$ within all of the half-spaces. See
+/// `clip_ray`.
+fn clip_line<S, I>(
+    origin: S,
+    direction: Vector<S>,
+    half_spaces: I,
+) -> Option<(Scalar<S>, Scalar<S>)>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded,
+    I: IntoIterator<Item = (S, Vector<S>)>,
+{
+    let zero = Scalar::<S>::zero();
+    let mut min = Scalar::<S>::min_value();
+    let mut max = Scalar::<S>::max_value();
+    for (boundary, normal) in half_spaces {
+        let distance = normal.dot(origin - boundary);
+        let rate = normal.dot(direction);
+        if abs_diff_eq!(rate, zero) {
+            // The line is parallel to the boundary of the half-space.
+            if distance < zero {
+                return None;
+            }
+        }
+        else {
+            let t = -distance / rate;
+            if rate > zero {
+                if t > min {
+                    min = t;
+                }
+            }
+            else if t < max {
+                max = t;
+            }
+            if min > max {
+                return None;
+            }
+        }
+    }
+    Some((min, max))
+}
+
+/// Cylinder.
+///
+/// Represents the finite $n$-dimensional region within a distance of its
+/// _radius_ from the axis formed by its _segment_. The cylinder is capped at
+/// the endpoints of its segment by planes orthogonal to the segment.
+/// Intersections consider the region enclosed by the cylinder, including its
+/// caps.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Scalar<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Scalar<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Cylinder<S>
+where
+    S: EuclideanSpace,
+{
+    pub segment: Segment<S>,
+    pub radius: Scalar<S>,
+}
+
+// `Cylinder` does not implement `Pod`, because `CoordinateLayout` does not
+// preclude padding between its segment and radius (e.g., `glam::Vec3A`).
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Cylinder<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Scalar<S>: Zeroable,
+{
+}
+
+impl<S> Cylinder<S>
+where
+    S: EuclideanSpace,
+{
+    /// Transforms the cylinder by a rigid motion.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: RigidMotion<S>,
+    {
+        Cylinder {
+            segment: self.segment.transform(transform),
+            radius: self.radius,
+        }
+    }
+}
+
+impl_approx_points!(
+    Cylinder,
+    |cylinder| [cylinder.segment.start, cylinder.segment.end],
+    |cylinder| [cylinder.radius],
+);
+
+impl<S> Debug for Cylinder<S>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Cylinder")
+            .field("segment", &self.segment)
+            .field("radius", &self.radius)
+            .finish()
+    }
+}
+
+/// Intersection of a cylinder and a point.
+impl<S> Intersection<S> for Cylinder<S>
+where
+    S: EuclideanSpace,
+{
+    /// The displacement of the point from the axis of the cylinder.
+    type Output = Vector<S>;
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        let extent = self.segment.extent();
+        let square_length = extent.square_magnitude();
+        let offset = *point - self.segment.start;
+        let s = offset.dot(extent);
+        if abs_diff_eq!(square_length, Zero::zero()) || s < Zero::zero() || s > square_length {
+            return None;
+        }
+        let displacement = offset + -(extent * (s / square_length));
+        if displacement.square_magnitude() <= self.radius * self.radius {
+            Some(displacement)
+        }
+        else {
+            None
+        }
+    }
+}
+impl_symmetrical_intersection!(Cylinder);
+
+/// Intersection of a cylinder and a ray.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Ray<S>> for Cylinder<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded + Sqrt,
+{
+    /// The _times of impact_ of the intersection.
+    ///
+    /// See the intersection of `Ray` and `Sphere`.
+    type Output = RaySolid<S>;
+
+    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
+        // See Ericson, Real-Time Collision Detection, section 5.3.7.
+        let zero = Scalar::<S>::zero();
+        let direction = *ray.direction.get();
+        let d = self.segment.extent();
+        let m = ray.origin - self.segment.start;
+        let dd = d.square_magnitude();
+        if abs_diff_eq!(dd, zero) {
+            return None;
+        }
+        let md = m.dot(d);
+        let nd = direction.dot(d);
+        let slab = slab_interval::<S>(md, nd, dd)?;
+        // The lateral surface is an infinite cylinder, which is intersected
+        // with the slab between the caps.
+        let a = dd - (nd * nd);
+        let c = (dd * (m.square_magnitude() - (self.radius * self.radius))) - (md * md);
+        let lateral = if abs_diff_eq!(a, zero) {
+            // The ray is parallel to the axis.
+            if c > zero {
+                return None;
+            }
+            unbounded_interval::<S>()
+        }
+        else {
+            let b = (dd * m.dot(direction)) - (nd * md);
+            let discriminant = (b * b) - (a * c);
+            if discriminant < zero {
+                return None;
+            }
+            let root = Sqrt::sqrt(discriminant);
+            ((-b - root) / a, (-b + root) / a)
+        };
+        intersect_intervals::<S>(lateral, slab)
+            .filter(|(_, max)| *max >= zero)
+            .map(|(min, max)| RaySolid::from_interval(min, max))
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_intersection!(Cylinder, Ray);
+
+/// Cone.
+///
+/// Represents the finite $n$-dimensional region swept from its _apex_ to a
+/// base centered at _base_ with a _radius_ orthogonal to the axis of the
+/// cone. Intersections consider the region enclosed by the cone, including its
+/// base.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(
+        deserialize = "S: Deserialize<'de>, Scalar<S>: Deserialize<'de>",
+        serialize = "S: Serialize, Scalar<S>: Serialize",
+    ))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Cone<S>
+where
+    S: EuclideanSpace,
+{
+    pub apex: S,
+    pub base: S,
+    pub radius: Scalar<S>,
+}
+
+// `Cone` does not implement `Pod`, because `CoordinateLayout` does not
+// preclude padding between its base and radius (e.g., `glam::Vec3A`).
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Cone<S>
+where
+    S: EuclideanSpace + Zeroable,
+    Scalar<S>: Zeroable,
+{
+}
+
+impl<S> Cone<S>
+where
+    S: EuclideanSpace,
+{
+    /// Gets the vector from the apex to the center of the base of the cone.
+    pub fn axis(&self) -> Vector<S> {
+        self.base - self.apex
+    }
+
+    /// Transforms the cone by a rigid motion.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: RigidMotion<S>,
+    {
+        Cone {
+            apex: transform.transform_point(self.apex),
+            base: transform.transform_point(self.base),
+            radius: self.radius,
+        }
+    }
+}
+
+impl_approx_points!(Cone, |cone| [cone.apex, cone.base], |cone| [cone.radius]);
+
+impl<S> Debug for Cone<S>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Cone")
+            .field("apex", &self.apex)
+            .field("base", &self.base)
+            .field("radius", &self.radius)
+            .finish()
+    }
+}
+
+/// Intersection of a cone and a point.
+impl<S> Intersection<S> for Cone<S>
+where
+    S: EuclideanSpace,
+{
+    /// The displacement of the point from the axis of the cone.
+    type Output = Vector<S>;
+
+    fn intersection(&self, point: &S) -> Option<Self::Output> {
+        let axis = self.axis();
+        let square_height = axis.square_magnitude();
+        let offset = *point - self.apex;
+        let s = offset.dot(axis);
+        if abs_diff_eq!(square_height, Zero::zero()) || s < Zero::zero() || s > square_height {
+            return None;
+        }
+        // The radius of the cone at the point is proportional to the distance
+        // of the point along the axis.
+        let t = s / square_height;
+        let displacement = offset + -(axis * t);
+        let radius = self.radius * t;
+        if displacement.square_magnitude() <= radius * radius {
+            Some(displacement)
+        }
+        else {
+            None
+        }
+    }
+}
+impl_symmetrical_intersection!(Cone);
+
+/// Intersection of a cone and a ray.
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> Intersection<Ray<S>> for Cone<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded + Sqrt,
+{
+    /// The _times of impact_ of the intersection.
+    ///
+    /// See the intersection of `Ray` and `Sphere`.
+    type Output = RaySolid<S>;
+
+    fn intersection(&self, ray: &Ray<S>) -> Option<Self::Output> {
+        let zero = Scalar::<S>::zero();
+        let direction = *ray.direction.get();
+        let h = self.axis();
+        let m = ray.origin - self.apex;
+        let hh = h.square_magnitude();
+        if abs_diff_eq!(hh, zero) {
+            return None;
+        }
+        let md = m.dot(h);
+        let nd = direction.dot(h);
+        let slab = slab_interval::<S>(md, nd, hh)?;
+        // A point $p$ at a distance $s = (p - a) \cdot h$ along the axis is
+        // within the (double) cone if $|h|^4|p - a|^2 - (|h|^2 + r^2)s^2 \leq
+        // 0$. Along the ray, this is a quadratic $At^2 + 2Bt + C \leq 0$.
+        let k = hh + (self.radius * self.radius);
+        let hhhh = hh * hh;
+        let a = hhhh - (k * nd * nd);
+        let b = (hhhh * m.dot(direction)) - (k * md * nd);
+        let c = (hhhh * m.square_magnitude()) - (k * md * md);
+        let (lower, upper) = unbounded_interval::<S>();
+        let (first, second) = if abs_diff_eq!(a, zero) {
+            // The ray is parallel to the lateral surface.
+            if abs_diff_eq!(b, zero) {
+                if c > zero {
+                    return None;
+                }
+                ((lower, upper), None)
+            }
+            else {
+                let t = -c / (b + b);
+                if b > zero {
+                    ((lower, t), None)
+                }
+                else {
+                    ((t, upper), None)
+                }
+            }
+        }
+        else {
+            let discriminant = (b * b) - (a * c);
+            if discriminant < zero {
+                if a > zero {
+                    return None;
+                }
+                ((lower, upper), None)
+            }
+            else {
+                let root = Sqrt::sqrt(discriminant);
+                let (t0, t1) = ((-b - root) / a, (-b + root) / a);
+                let (t0, t1) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+                if a > zero {
+                    ((t0, t1), None)
+                }
+                else {
+                    // The ray passes through both nappes of the double cone.
+                    ((lower, t0), Some((t1, upper)))
+                }
+            }
+        };
+        // The finite cone is convex, so the intersections with the slab are
+        // merged into a single interval.
+        IntoIterator::into_iter([Some(first), second])
+            .flatten()
+            .filter_map(|interval| intersect_intervals::<S>(interval, slab))
+            .reduce(|(amin, amax), (bmin, bmax)| {
+                (
+                    if bmin < amin { bmin } else { amin },
+                    if bmax > amax { bmax } else { amax },
+                )
+            })
+            .filter(|(_, max)| *max >= zero)
+            .map(|(min, max)| RaySolid::from_interval(min, max))
+    }
+}
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_symmetrical_intersection!(Cone, Ray);
+
+/// Gets the interval of times of impact along a ray within a slab.
+///
+/// The slab is bounded by planes orthogonal to an axis $d$. The arguments are
+/// the dot products $m \cdot d$ and $n \cdot d$ of the offset $m$ of the
+/// origin of the ray and the direction $n$ of the ray with the axis, and $d
+/// \cdot d$.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn slab_interval<S>(md: Scalar<S>, nd: Scalar<S>, dd: Scalar<S>) -> Option<(Scalar<S>, Scalar<S>)>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded,
+{
+    if abs_diff_eq!(nd, Zero::zero()) {
+        if md < Zero::zero() || md > dd {
+            None
+        }
+        else {
+            Some(unbounded_interval::<S>())
+        }
+    }
+    else {
+        let (p, q) = (-md / nd, (dd - md) / nd);
+        Some(if p < q { (p, q) } else { (q, p) })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn unbounded_interval<S>() -> (Scalar<S>, Scalar<S>)
+where
+    S: EuclideanSpace,
+    Scalar<S>: Bounded,
+{
+    (Bounded::min_value(), Bounded::max_value())
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn intersect_intervals<S>(
+    a: (Scalar<S>, Scalar<S>),
+    b: (Scalar<S>, Scalar<S>),
+) -> Option<(Scalar<S>, Scalar<S>)>
+where
+    S: EuclideanSpace,
+{
+    let min = if a.0 > b.0 { a.0 } else { b.0 };
+    let max = if a.1 < b.1 { a.1 } else { b.1 };
+    if min > max {
+        None
+    }
+    else {
+        Some((min, max))
+    }
+}
+
+/// Triangle.
+///
+/// Represents the region bounded by three points. Triangles in three
+/// dimensions are two-sided: intersections do not depend on the winding order
+/// of the points.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(bound(deserialize = "S: Deserialize<'de>", serialize = "S: Serialize"))
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
+)]
+#[repr(C)]
+pub struct Triangle<S>
+where
+    S: EuclideanSpace,
+{
+    pub a: S,
+    pub b: S,
+    pub c: S,
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Pod for Triangle<S> where S: CoordinateLayout + Pod {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> Zeroable for Triangle<S> where S: EuclideanSpace + Zeroable {}
+
+impl<S> Triangle<S>
+where
+    S: EuclideanSpace,
+{
+    /// Gets the centroid of the triangle.
+    pub fn centroid(&self) -> S {
+        EuclideanSpace::centroid([self.a, self.b, self.c]).unwrap()
+    }
+
+    /// Gets the area of the triangle.
+    ///
+    /// The area is computed from the Gram determinant of the edges of the
+    /// triangle, so it is supported in any number of dimensions. The area is
+    /// zero if the triangle is degenerate.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn area(&self) -> Scalar<S>
+    where
+        Scalar<S>: Signed + Sqrt,
+    {
+        let u = self.b - self.a;
+        let v = self.c - self.a;
+        let uv = u.dot(v);
+        let gram = (u.square_magnitude() * v.square_magnitude()) - (uv * uv);
+        let two = Scalar::<S>::one() + One::one();
+        // The Gram determinant may be slightly negative due to rounding.
+        if gram.is_positive() {
+            Sqrt::sqrt(gram) / two
+        }
+        else {
+            Zero::zero()
+        }
+    }
+
+    /// Gets the normal of a triangle in three dimensions.
+    ///
+    /// The normal is oriented such that the points of the triangle appear in
+    /// counterclockwise order when viewed from the direction of the normal.
+    /// Returns `None` if the triangle is degenerate.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn normal(&self) -> Option<Unit<Vector<S>>>
+    where
+        S: FiniteDimensional<N = U3>,
+        Vector<S>: Cross<Output = Vector<S>>,
+        Scalar<S>: Sqrt,
+    {
+        Unit::try_from_inner((self.b - self.a).cross(self.c - self.a))
+    }
+
+    /// Transforms the triangle.
+    pub fn transform<T>(&self, transform: &T) -> Self
+    where
+        T: Transform<S>,
+    {
+        Triangle {
+            a: transform.transform_point(self.a),
+            b: transform.transform_point(self.b),
+            c: transform.transform_point(self.c),
+        }
+    }
+}
+
+impl_approx_points!(Triangle, |triangle| [triangle.a, triangle.b, triangle.c]);
+
+impl<S> Debug for Triangle<S>
+where
+    S: Debug + EuclideanSpace,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Triangle")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("c", &self.c)
+            .finish()
+    }
+}
+
+/// Intersection of a ray and a triangle in three dimensions.
+impl<S> Intersection<Triangle<S>> for Ray<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+    Vector<S>: Cross<Output = Vector<S>>,
+    Scalar<S>: Signed,
+{
+    /// The _time of impact_ and barycentric coordinates of the intersection.
+    ///
+    /// The time of impact $t$ describes the distance along the half-line from
+    /// the ray's origin at which the intersection occurs. The barycentric
+    /// coordinates $(u, v, w)$ weight the points $(a, b, c)$ of the triangle.
+    type Output = (Scalar<S>, (Scalar<S>, Scalar<S>, Scalar<S>));
+
+    /// Determines the _time of impact_ and barycentric coordinates of a `Ray`
+    /// intersection with a `Triangle` using the Möller–Trumbore algorithm.
+    ///
+    /// Returns `None` if the ray is parallel to the plane of the triangle.
+    fn intersection(&self, triangle: &Triangle<S>) -> Option<Self::Output> {
+        let direction = *self.direction.get();
+        let ab = triangle.b - triangle.a;
+        let ac = triangle.c - triangle.a;
+        let p = direction.cross(ac);
+        let determinant = ab.dot(p);
+        if abs_diff_eq!(determinant, Zero::zero()) {
+            return None;
+        }
+        let inverse = Scalar::<S>::one() / determinant;
+        let offset = self.origin - triangle.a;
+        let v = offset.dot(p) * inverse;
+        if v < Zero::zero() || v > One::one() {
+            return None;
+        }
+        let q = offset.cross(ab);
+        let w = direction.dot(q) * inverse;
+        if w < Zero::zero() || (v + w) > One::one() {
+            return None;
+        }
+        let t = ac.dot(q) * inverse;
+        if t.is_positive() {
+            Some((t, (Scalar::<S>::one() - v - w, v, w)))
+        }
+        else {
+            None
+        }
+    }
+}
+impl_symmetrical_intersection!(Ray, Triangle);
+
+/// Closest point on a triangle to a point.
+impl<S> ClosestPoint<S> for Triangle<S>
+where
+    S: EuclideanSpace,
+{
+    type Output = S;
+
+    fn closest_point(&self, point: &S) -> Self::Output {
+        // See Ericson, Real-Time Collision Detection, section 5.1.5. The
+        // Voronoi regions of the vertices and edges are tested before the face.
+        let zero = Scalar::<S>::zero();
+        let Triangle { a, b, c } = *self;
+        let (ab, ac) = (b - a, c - a);
+        // The barycentric denominator `va + vb + vc` is the Gram determinant
+        // of the edges `ab` and `ac`, which is zero if the triangle is
+        // degenerate. In that case, the closest point lies on the longest edge.
+        let (ab2, ac2) = (ab.square_magnitude(), ac.square_magnitude());
+        let abac = ab.dot(ac);
+        if abs_diff_eq!((ab2 * ac2) - (abac * abac), zero) {
+            let bc2 = (c - b).square_magnitude();
+            let (start, end) = if ab2 >= ac2 && ab2 >= bc2 {
+                (a, b)
+            }
+            else if ac2 >= bc2 {
+                (a, c)
+            }
+            else {
+                (b, c)
+            };
+            return Segment { start, end }.closest_point(*point);
+        }
+        let ap = *point - a;
+        let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+        let bp = *point - b;
+        let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+        let vc = (d1 * d4) - (d3 * d2);
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + (ab * (d1 / (d1 - d3)));
+        }
+        let cp = *point - c;
+        let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+        let vb = (d5 * d2) - (d1 * d6);
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + (ac * (d2 / (d2 - d6)));
+        }
+        let va = (d3 * d6) - (d5 * d4);
+        if va <= zero && (d4 - d3) >= zero && (d5 - d6) >= zero {
+            return b + ((c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6))));
+        }
+        let denominator = va + vb + vc;
+        a + (ab * (vb / denominator)) + (ac * (vc / denominator))
+    }
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use approx::{assert_relative_eq, assert_relative_ne};
+    use decorum::N64;
+    use nalgebra::{
+        Isometry2, Matrix3, Perspective3, Point2, Point3, Rotation2, Rotation3, Vector2, Vector3,
+    };
+    use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4, PI};
+
+    use crate::adjunct::Converged;
+    use crate::ops::{Angle, Reflect, Reject, Rotate, SignedAngle};
+    use crate::query::winding::Winding;
+    use crate::query::{
+        Aabb, Capsule, ClosestPoint, Cone, Contains, ConvexPolygon, ConvexPolytope, Culling,
+        Cylinder, Frustum, Intersection, Line, LineLine, Obb, Plane, PlanePlane, PlaneRay,
+        PlaneSegment, Polygon, PrincipalAxes, Ray, RaySolid, Segment, SegmentSegment,
+        SignedDistance, Sphere, Sweep, Triangle, Unit,
+    };
+    use crate::space::{DualSpace, EuclideanSpace, Vector, VectorSpace};
+    use crate::Error;
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn aabb_aabb_intersection_e2() {
+        let aabb1 = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(2.0),
+        };
+        let aabb2 = Aabb::<E2> {
+            origin: Converged::converged(1.0),
+            extent: Converged::converged(2.0),
+        };
+        assert_eq!(Some(aabb1), aabb1.intersection(&aabb1));
+        assert_eq!(
+            Some(Aabb::<E2> {
+                origin: Converged::converged(1.0),
+                extent: Converged::converged(1.0),
+            }),
+            aabb1.intersection(&aabb2),
+        );
+        let aabb2 = Aabb::<E2> {
+            origin: Converged::converged(-3.0),
+            extent: Converged::converged(2.0),
+        };
+        assert_eq!(None, aabb1.intersection(&aabb2));
+    }
+
+    #[test]
+    fn aabb_clip_e2() {
+        let aabb = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(2.0),
+        };
+        let line = Line::<E2> {
+            origin: EuclideanSpace::from_xy(-1.0, 1.0),
+            direction: Unit::x(),
+        };
+        assert_eq!(Some((1.0, 3.0)), aabb.intersection(&line));
+        assert_eq!(
+            Some(Segment {
+                start: E2::new(0.0, 1.0),
+                end: E2::new(2.0, 1.0),
+            }),
+            aabb.clip_line(&line),
+        );
+        assert_eq!(
+            None,
+            aabb.clip_line(&Line {
+                origin: EuclideanSpace::from_xy(0.0, 3.0),
+                ..line
+            }),
+        );
+
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(1.0, 1.0),
+            direction: Unit::x(),
+        };
+        assert_eq!(
+            Some(Segment {
+                start: E2::new(1.0, 1.0),
+                end: E2::new(2.0, 1.0),
+            }),
+            aabb.clip_ray(&ray),
+        );
+        assert_eq!(
+            None,
+            aabb.clip_ray(&Ray {
+                origin: EuclideanSpace::from_xy(3.0, 1.0),
+                ..ray
+            }),
+        );
+
+        let segment = Segment::<E2> {
+            start: EuclideanSpace::from_xy(-1.0, -1.0),
+            end: EuclideanSpace::from_xy(1.0, 3.0),
+        };
+        assert_eq!(
+            Some(Segment {
+                start: E2::new(0.0, 1.0),
+                end: E2::new(0.5, 2.0),
+            }),
+            aabb.clip_segment(&segment),
+        );
+        assert_eq!(
+            None,
+            aabb.clip_segment(&Segment {
+                start: EuclideanSpace::from_xy(-2.0, 0.0),
+                end: EuclideanSpace::from_xy(-1.0, 2.0),
+            }),
+        );
+    }
+
+    #[test]
+    fn aabb_from_points_e2() {
+        let aabb = Aabb::<E2>::from_points(vec![
+            E2::new(1.0, 3.0),
+            E2::new(4.0, 2.0),
+            E2::new(2.0, 5.0),
+        ])
+        .unwrap();
+        assert_eq!(
+            Aabb::<E2> {
+                origin: EuclideanSpace::from_xy(1.0, 2.0),
+                extent: Vector2::new(3.0, 3.0),
+            },
+            aabb,
+        );
+        assert!(Aabb::<E2>::from_points(vec![]).is_none());
+
+        let other = Aabb::<E2> {
+            origin: EuclideanSpace::from_xy(6.0, 0.0),
+            extent: Vector2::new(-1.0, 1.0),
+        };
+        assert_eq!(
+            Some(Aabb::<E2> {
+                origin: EuclideanSpace::from_xy(1.0, 0.0),
+                extent: Vector2::new(5.0, 5.0),
+            }),
+            Aabb::from_aabbs(vec![aabb, other]),
+        );
+        assert!(Aabb::<E2>::from_aabbs(vec![]).is_none());
+    }
+
+    #[test]
+    fn aabb_measures_e3() {
+        let aabb = Aabb::<E3> {
+            origin: EuclideanSpace::from_xyz(1.0, 2.0, 3.0),
+            extent: Vector3::new(-1.0, 3.0, 2.0),
+        };
+        assert_eq!(6.0, aabb.volume());
+        assert_eq!(22.0, aabb.surface_area());
+        assert_eq!(1, aabb.longest_axis());
+        assert_eq!(E3::new(0.5, 3.5, 4.0), aabb.centroid());
+        assert_eq!(
+            Aabb::<E3> {
+                origin: EuclideanSpace::from_xyz(-1.0, 1.0, 2.0),
+                extent: Vector3::new(3.0, 5.0, 4.0),
+            },
+            aabb.expand(1.0),
+        );
+    }
+
+    #[test]
+    fn aabb_overlaps_e2() {
+        let aabb1 = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(2.0),
+        };
+        let aabb2 = Aabb::<E2> {
+            origin: EuclideanSpace::from_xy(4.0, 0.0),
+            extent: Vector2::new(-2.0, 1.0),
+        };
+        assert!(aabb1.overlaps(&aabb2));
+        assert!(!aabb1.overlaps(&aabb2.expand(-0.5)));
+        assert_eq!(
+            Aabb::<E2> {
+                origin: EuclideanSpace::origin(),
+                extent: Vector2::new(4.0, 2.0),
+            },
+            aabb1.union(&aabb2),
+        );
+    }
+
+    #[test]
+    fn aabb_point_intersection_e2() {
+        let aabb = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(2.0),
+        };
+        let point = E2::converged(1.0);
+        assert_eq!(
+            Some(Vector::<E2>::converged(1.0)),
+            aabb.intersection(&point),
+        );
+        let point = E2::converged(3.0);
+        assert_eq!(None, aabb.intersection(&point));
+    }
+
+    #[test]
+    fn aabb_ray_intersection_e2() {
+        let aabb = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(1.0),
+        };
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(-1.0, 0.5),
+            direction: Unit::x(),
+        };
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 1.0,
+                exit: 2.0
+            }),
+            ray.intersection(&aabb)
+        );
+        assert_eq!(None, ray.reverse().intersection(&aabb));
+    }
+
+    #[test]
+    fn aabb_ray_intersection_e3() {
+        let aabb = Aabb::<E3> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(1.0),
+        };
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(-1.0, 0.5, 0.5),
+            direction: Unit::x(),
+        };
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 1.0,
+                exit: 2.0
+            }),
+            ray.intersection(&aabb)
+        );
+        assert_eq!(None, ray.reverse().intersection(&aabb));
+    }
+
+    // Ensure that certain values do not produce `NaN`s when querying the
+    // intersection of `Aabb` and `Ray`.
+    #[test]
+    fn aabb_ray_intersection_nan() {
+        let aabb = Aabb::<Point2<N64>> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(1.0.into()),
+        };
+        let ray = Ray::<Point2<N64>> {
+            origin: EuclideanSpace::origin(),
+            direction: Unit::x(),
+        };
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 0.0.into(),
+                exit: 1.0.into()
+            }),
+            ray.intersection(&aabb)
+        );
+    }
+
+    #[test]
+    fn aabb_ray_slab_intersection_e3() {
+        let aabb = Aabb::<E3> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(1.0),
+        };
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(0.5, 2.0, 0.25),
+            direction: Unit::y(),
+        }
+        .reverse();
+        let slab = aabb.slab_intersection(&ray).unwrap();
+        assert_eq!((1.0, 2.0), (slab.entry, slab.exit));
+        assert_eq!(1, slab.axis);
+        assert_eq!(Vector3::y(), slab.normal.into_inner());
+
+        // The ray enters the bounding box behind its origin.
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(0.5, 0.5, 0.5),
+            direction: Unit::x(),
+        };
+        let slab = aabb.slab_intersection(&ray).unwrap();
+        assert_eq!((-0.5, 0.5), (slab.entry, slab.exit));
+        assert_eq!(0, slab.axis);
+        assert_eq!(-Vector3::x(), slab.normal.into_inner());
+        assert!(aabb
+            .slab_intersection(&Ray {
+                origin: EuclideanSpace::from_xyz(2.0, 0.5, 0.5),
+                ..ray
+            })
+            .is_none());
+    }
+
+    #[test]
+    fn aabb_relative_eq_e2() {
+        let aabb1 = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(0.3),
+        };
+        let aabb2 = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Converged::converged(0.1 + 0.2),
+        };
+        assert_relative_eq!(aabb1, aabb2);
+        let aabb2 = Aabb::<E2> {
+            origin: Converged::converged(0.1),
+            extent: Converged::converged(0.3),
+        };
+        assert_relative_ne!(aabb1, aabb2);
+    }
+
+    #[test]
+    fn aabb_transform_e2() {
+        let aabb = Aabb::<E2> {
+            origin: EuclideanSpace::from_xy(1.0, 0.0),
+            extent: Vector::<E2>::from_xy(2.0, 1.0),
+        };
+        let rotation = Isometry2::rotation(std::f64::consts::FRAC_PI_2);
+        assert_relative_eq!(
+            Aabb::<E2> {
+                origin: EuclideanSpace::from_xy(-1.0, 1.0),
+                extent: Vector::<E2>::from_xy(1.0, 2.0),
+            },
+            aabb.transform(&rotation),
+            epsilon = 1e-9,
+        );
+    }
+
+    #[test]
+    fn capsule_intersection_e3() {
+        let capsule = Capsule::<E3> {
+            segment: Segment {
+                start: EuclideanSpace::origin(),
+                end: EuclideanSpace::from_xyz(4.0, 0.0, 0.0),
+            },
+            radius: 1.0,
+        };
+        assert_eq!(
+            Some(Vector3::new(0.0, 0.5, 0.0)),
+            capsule.intersection(&E3::new(2.0, 0.5, 0.0)),
+        );
+        assert!(E3::new(5.5, 0.0, 0.0).intersection(&capsule).is_none());
+        assert_eq!(
+            E3::new(2.0, 1.0, 0.0),
+            capsule.closest_point(E3::new(2.0, 3.0, 0.0))
+        );
+        assert_eq!(
+            E3::new(5.0, 0.0, 0.0),
+            capsule.closest_point(E3::new(6.0, 0.0, 0.0))
+        );
+        assert_eq!(
+            E3::new(1.0, 0.5, 0.0),
+            capsule.closest_point(E3::new(1.0, 0.5, 0.0))
+        );
+
+        let ray = |origin: E3, direction| Ray { origin, direction };
+        let (min, max) = capsule
+            .intersection(&ray(E3::new(2.0, -5.0, 0.0), Unit::y()))
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(4.0, min);
+        assert_relative_eq!(6.0, max);
+        let (min, max) = ray(E3::new(-5.0, 0.0, 0.0), Unit::x())
+            .intersection(&capsule)
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(4.0, min);
+        assert_relative_eq!(10.0, max);
+        let (min, max) = capsule
+            .intersection(&ray(E3::new(2.0, 0.0, 0.0), Unit::y()))
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(-1.0, min);
+        assert_relative_eq!(1.0, max);
+        // The ray intersects the cap at the end of the capsule.
+        let (min, max) = capsule
+            .intersection(&ray(E3::new(4.5, -5.0, 0.0), Unit::y()))
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(5.0 - 0.75f64.sqrt(), min);
+        assert_relative_eq!(5.0 + 0.75f64.sqrt(), max);
+        assert!(capsule
+            .intersection(&ray(E3::new(-2.0, 2.0, 0.0), Unit::x()))
+            .is_none());
+        assert!(capsule
+            .intersection(&ray(E3::new(2.0, 5.0, 0.0), Unit::y()))
+            .is_none());
+
+        let other = Capsule::<E3> {
+            segment: Segment {
+                start: EuclideanSpace::from_xyz(2.0, 1.5, -1.0),
+                end: EuclideanSpace::from_xyz(2.0, 1.5, 1.0),
+            },
+            radius: 1.0,
+        };
+        assert_relative_eq!(0.5, capsule.intersection(&other).unwrap());
+        let other = Capsule {
+            segment: Segment {
+                start: EuclideanSpace::from_xyz(2.0, 2.5, -1.0),
+                end: EuclideanSpace::from_xyz(2.0, 2.5, 1.0),
+            },
+            ..other
+        };
+        assert!(capsule.intersection(&other).is_none());
+        let sphere = Sphere::<E3> {
+            center: EuclideanSpace::from_xyz(5.5, 0.0, 0.0),
+            radius: 1.0,
+        };
+        assert_relative_eq!(0.5, sphere.intersection(&capsule).unwrap());
+    }
+
+    #[test]
+    fn contains_e3() {
+        let aabb = Aabb::<E3> {
+            origin: EuclideanSpace::from_xyz(4.0, 4.0, 4.0),
+            extent: Vector3::new(-4.0, -4.0, -4.0),
+        };
+        assert!(aabb.contains(&E3::new(4.0, 2.0, 0.0)));
+        assert!(!aabb.contains(&E3::new(5.0, 2.0, 0.0)));
+        assert!(aabb.contains(&Aabb {
+            origin: EuclideanSpace::from_xyz(1.0, 1.0, 1.0),
+            extent: Vector3::new(3.0, 2.0, 1.0),
+        }));
+        assert!(!aabb.contains(&Aabb {
+            origin: EuclideanSpace::from_xyz(1.0, 1.0, 1.0),
+            extent: Vector3::new(4.0, 2.0, 1.0),
+        }));
+        let sphere = |center: E3, radius| Sphere { center, radius };
+        assert!(aabb.contains(&sphere(E3::new(2.0, 2.0, 2.0), 2.0)));
+        assert!(!aabb.contains(&sphere(E3::new(2.0, 2.0, 3.0), 2.0)));
+
+        let outer = sphere(E3::origin(), 4.0);
+        assert!(outer.contains(&E3::new(0.0, 4.0, 0.0)));
+        assert!(!outer.contains(&E3::new(3.0, 3.0, 0.0)));
+        assert!(outer.contains(&sphere(E3::new(1.0, 0.0, 0.0), 3.0)));
+        assert!(!outer.contains(&sphere(E3::new(1.5, 0.0, 0.0), 3.0)));
+        assert!(!sphere(E3::origin(), 1.0).contains(&outer));
+        assert!(outer.contains(&Aabb {
+            origin: EuclideanSpace::from_xyz(-2.0, -2.0, -2.0),
+            extent: Vector3::new(4.0, 4.0, 4.0),
+        }));
+        assert!(!outer.contains(&aabb));
+
+        let frustum = Frustum::<E3>::from_view_projection(
+            &Perspective3::new(1.0, FRAC_PI_2, 1.0, 10.0).into_inner(),
+        )
+        .unwrap();
+        assert!(frustum.contains(&E3::new(0.0, 0.0, -5.0)));
+        assert!(frustum.contains(&Aabb {
+            origin: EuclideanSpace::from_xyz(-1.0, -1.0, -6.0),
+            extent: Vector3::new(2.0, 2.0, 2.0),
+        }));
+        assert!(!frustum.contains(&Aabb {
+            origin: EuclideanSpace::from_xyz(-1.0, -1.0, -2.0),
+            extent: Vector3::new(2.0, 2.0, 2.0),
+        }));
+        assert!(!frustum.contains(&sphere(E3::new(0.0, 0.0, -10.0), 1.0)));
+    }
+
+    #[test]
+    fn convex_polygon_intersection_e2() {
+        let polygon = ConvexPolygon::from_vertices([
+            E2::new(0.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(2.0, 2.0),
+            E2::new(0.0, 2.0),
+        ]);
+        assert_eq!(4.0, polygon.area());
+        assert_eq!(4, polygon.edges().count());
+        assert!(polygon.intersection(&E2::new(1.0, 1.0)).is_some());
+        assert!(polygon.intersection(&E2::new(2.0, 1.0)).is_some());
+        assert!(E2::new(3.0, 1.0).intersection(&polygon).is_none());
+
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(-1.0, 1.0),
+            direction: Unit::x(),
+        };
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 1.0,
+                exit: 3.0
+            }),
+            polygon.intersection(&ray)
+        );
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(-1.0, 3.0),
+            ..ray
+        };
+        assert!(ray.intersection(&polygon).is_none());
+
+        let polytope = ConvexPolytope::from_planes(polygon.planes().collect::<Vec<_>>());
+        assert_eq!(Some(1.0), polytope.intersection(&E2::new(1.0, 1.0)));
+        assert_eq!(Some(0.5), E2::new(1.5, 1.0).intersection(&polytope));
+        assert!(polytope.intersection(&E2::new(1.0, -1.0)).is_none());
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(1.0, 1.0),
+            direction: Unit::y(),
+        };
+        assert_eq!(
+            Some(RaySolid::Inside {
+                entry: -1.0,
+                exit: 1.0
+            }),
+            polytope.intersection(&ray)
+        );
+    }
+
+    #[test]
+    fn convex_polytope_intersection_e3() {
+        // A half-space is unbounded.
+        let polytope = ConvexPolytope::from_planes([Plane::<E3> {
+            origin: EuclideanSpace::origin(),
+            normal: Unit::z(),
+        }]);
+        assert!(polytope.intersection(&E3::new(0.0, 0.0, 1.0)).is_some());
+        let (min, max) = polytope
+            .intersection(&Ray::<E3> {
+                origin: EuclideanSpace::from_xyz(0.0, 0.0, -1.0),
+                direction: Unit::z(),
+            })
+            .unwrap()
+            .into_interval();
+        assert_eq!(1.0, min);
+        assert_eq!(f64::MAX, max);
+
+        let frustum = Frustum::<E3>::from_view_projection(
+            &Perspective3::new(1.0, FRAC_PI_2, 1.0, 10.0).into_inner(),
+        )
+        .unwrap();
+        let polytope = ConvexPolytope::from(frustum);
+        assert_relative_eq!(
+            1.0,
+            polytope.intersection(&E3::new(0.0, 0.0, -2.0)).unwrap(),
+            epsilon = 1e-9,
+        );
+        let (min, max) = polytope
+            .intersection(&Ray::<E3> {
+                origin: EuclideanSpace::origin(),
+                direction: -Unit::z(),
+            })
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(1.0, min, epsilon = 1e-9);
+        assert_relative_eq!(10.0, max, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn closest_point_e3() {
+        let line = Line::<E3> {
+            origin: EuclideanSpace::origin(),
+            direction: Unit::x(),
+        };
+        assert_eq!(
+            E3::new(2.0, 0.0, 0.0),
+            line.closest_point(&E3::new(2.0, 1.0, 1.0))
+        );
+        let other = Line::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 1.0, 2.0),
+            direction: Unit::y(),
+        };
+        assert_eq!(
+            (E3::new(0.0, 0.0, 0.0), E3::new(0.0, 0.0, 2.0)),
+            line.closest_point(&other),
+        );
+
+        let ray = line.into_ray();
+        assert_eq!(E3::origin(), ray.closest_point(&E3::new(-2.0, 1.0, 0.0)));
+        assert_eq!(
+            E3::new(2.0, 0.0, 0.0),
+            ray.closest_point(&E3::new(2.0, 1.0, 0.0))
+        );
+
+        let plane = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            normal: Unit::z(),
+        };
+        assert_eq!(
+            E3::new(3.0, 2.0, 1.0),
+            plane.closest_point(&E3::new(3.0, 2.0, -4.0))
+        );
+
+        let aabb = Aabb::<E3> {
+            origin: EuclideanSpace::origin(),
+            extent: Vector3::new(1.0, 1.0, 1.0),
+        };
+        assert_eq!(
+            E3::new(1.0, 0.5, 0.0),
+            aabb.closest_point(&E3::new(2.0, 0.5, -1.0))
+        );
+
+        let segment = Segment::<E3> {
+            start: EuclideanSpace::origin(),
+            end: EuclideanSpace::from_xyz(2.0, 0.0, 0.0),
+        };
+        assert_eq!(
+            E3::new(2.0, 0.0, 0.0),
+            ClosestPoint::closest_point(&segment, &E3::new(3.0, 1.0, 0.0)),
+        );
+        let other = Segment::<E3> {
+            start: EuclideanSpace::from_xyz(1.0, -1.0, 1.0),
+            end: EuclideanSpace::from_xyz(1.0, 1.0, 1.0),
+        };
+        assert_eq!(
+            (E3::new(1.0, 0.0, 0.0), E3::new(1.0, 0.0, 1.0)),
+            ClosestPoint::closest_point(&segment, &other),
+        );
+
+        let triangle = Triangle::<E3> {
+            a: EuclideanSpace::origin(),
+            b: EuclideanSpace::from_xyz(2.0, 0.0, 0.0),
+            c: EuclideanSpace::from_xyz(0.0, 2.0, 0.0),
+        };
+        // Face, vertex, and edge regions.
+        assert_eq!(
+            E3::new(0.5, 0.5, 0.0),
+            triangle.closest_point(&E3::new(0.5, 0.5, 3.0)),
+        );
+        assert_eq!(
+            E3::new(2.0, 0.0, 0.0),
+            triangle.closest_point(&E3::new(3.0, -1.0, 0.0))
+        );
+        assert_eq!(
+            E3::new(1.0, 0.0, 0.0),
+            triangle.closest_point(&E3::new(1.0, -1.0, 1.0))
+        );
+        assert_eq!(
+            E3::new(1.0, 1.0, 0.0),
+            triangle.closest_point(&E3::new(2.0, 2.0, 0.0))
+        );
+        assert_eq!(
+            E3::new(0.0, 1.0, 0.0),
+            triangle.closest_point(&E3::new(-1.0, 1.0, 0.0))
+        );
+
+        // Degenerate triangles, for which the closest point lies on the
+        // longest edge.
+        let triangle = Triangle::<E3> {
+            a: EuclideanSpace::origin(),
+            b: EuclideanSpace::from_xyz(1.0, 0.0, 0.0),
+            c: EuclideanSpace::from_xyz(2.0, 0.0, 0.0),
+        };
+        assert_eq!(
+            E3::new(1.5, 0.0, 0.0),
+            triangle.closest_point(&E3::new(1.5, 1.0, 0.0))
+        );
+        let triangle = Triangle::<E3> {
+            a: EuclideanSpace::origin(),
+            b: EuclideanSpace::origin(),
+            c: EuclideanSpace::from_xyz(0.0, 2.0, 0.0),
+        };
+        assert_eq!(
+            E3::new(0.0, 1.0, 0.0),
+            triangle.closest_point(&E3::new(1.0, 1.0, 0.0))
+        );
+        let triangle = Triangle::<E3> {
+            a: EuclideanSpace::from_xyz(1.0, 1.0, 1.0),
+            b: EuclideanSpace::from_xyz(1.0, 1.0, 1.0),
+            c: EuclideanSpace::from_xyz(1.0, 1.0, 1.0),
+        };
+        assert_eq!(
+            E3::new(1.0, 1.0, 1.0),
+            triangle.closest_point(&E3::new(0.0, 2.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn cylinder_cone_intersection_e3() {
+        let ray = |origin: E3, direction| Ray { origin, direction };
+        let cylinder = Cylinder::<E3> {
+            segment: Segment {
+                start: EuclideanSpace::origin(),
+                end: EuclideanSpace::from_xyz(0.0, 0.0, 2.0),
+            },
+            radius: 1.0,
+        };
+        assert_eq!(
+            Some(Vector3::new(0.5, 0.0, 0.0)),
+            cylinder.intersection(&E3::new(0.5, 0.0, 1.0)),
+        );
+        assert!(E3::new(0.0, 0.0, 2.5).intersection(&cylinder).is_none());
+        assert!(E3::new(1.0, 1.0, 1.0).intersection(&cylinder).is_none());
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 5.0,
+                exit: 7.0,
+            }),
+            cylinder.intersection(&ray(E3::new(0.0, 0.0, -5.0), Unit::z())),
+        );
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 3.0,
+                exit: 5.0,
+            }),
+            cylinder.intersection(&ray(E3::new(0.5, 0.0, 5.0), -Unit::z())),
+        );
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 4.0,
+                exit: 6.0
+            }),
+            ray(E3::new(-5.0, 0.0, 1.0), Unit::x()).intersection(&cylinder),
+        );
+        assert!(cylinder
+            .intersection(&ray(E3::new(-5.0, 0.0, 3.0), Unit::x()))
+            .is_none());
+        assert!(cylinder
+            .intersection(&ray(E3::new(2.0, 0.0, -5.0), Unit::z()))
+            .is_none());
+
+        let cone = Cone::<E3> {
+            apex: EuclideanSpace::from_xyz(0.0, 0.0, 2.0),
+            base: EuclideanSpace::origin(),
+            radius: 1.0,
+        };
+        assert!(cone.intersection(&E3::new(0.2, 0.0, 1.0)).is_some());
+        assert!(E3::new(0.6, 0.0, 1.0).intersection(&cone).is_none());
+        assert!(E3::new(0.0, 0.0, 3.0).intersection(&cone).is_none());
+        let (min, max) = cone
+            .intersection(&ray(E3::new(0.0, 0.0, -5.0), Unit::z()))
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(5.0, min);
+        assert_relative_eq!(7.0, max);
+        let (min, max) = cone
+            .intersection(&ray(E3::new(0.0, 0.0, 5.0), -Unit::z()))
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(3.0, min);
+        assert_relative_eq!(5.0, max);
+        let (min, max) = ray(E3::new(-5.0, 0.0, 1.0), Unit::x())
+            .intersection(&cone)
+            .unwrap()
+            .into_interval();
+        assert_relative_eq!(4.5, min);
+        assert_relative_eq!(5.5, max);
+        // The ray intersects the other nappe of the double cone.
+        assert!(cone
+            .intersection(&ray(E3::new(-5.0, 0.0, 3.0), Unit::x()))
+            .is_none());
+    }
+
+    #[test]
+    fn frustum_corners_e3() {
+        let frustum = Frustum::<E3>::from_view_projection(
+            &Perspective3::new(1.0, FRAC_PI_2, 1.0, 10.0).into_inner(),
+        )
+        .unwrap();
+        let corners = frustum.corners().unwrap();
+        for (corner, expected) in corners.iter().zip([
+            E3::new(-1.0, -1.0, -1.0),
+            E3::new(1.0, -1.0, -1.0),
+            E3::new(-1.0, 1.0, -1.0),
+            E3::new(1.0, 1.0, -1.0),
+            E3::new(-10.0, -10.0, -10.0),
+            E3::new(10.0, -10.0, -10.0),
+            E3::new(-10.0, 10.0, -10.0),
+            E3::new(10.0, 10.0, -10.0),
+        ]) {
+            for (a, b) in expected.iter().zip(corner.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn frustum_culling_e3() {
+        let frustum = Frustum::<E3>::from_view_projection(
+            &Perspective3::new(1.0, FRAC_PI_2, 1.0, 10.0).into_inner(),
+        )
+        .unwrap();
+        assert_eq!(
+            Culling::Inside,
+            frustum.cull_point(&E3::new(0.0, 0.0, -5.0))
+        );
+        assert_eq!(
+            Culling::Outside,
+            frustum.cull_point(&E3::new(0.0, 0.0, 5.0))
+        );
+        assert_eq!(
+            Culling::Outside,
+            frustum.cull_point(&E3::new(0.0, 0.0, -11.0))
+        );
+        assert_eq!(
+            Culling::Outside,
+            frustum.cull_point(&E3::new(6.0, 0.0, -5.0))
+        );
+
+        let aabb = |origin: E3, extent: Vector<E3>| Aabb { origin, extent };
+        assert_eq!(
+            Culling::Inside,
+            frustum.cull_aabb(&aabb(
+                E3::new(-1.0, -1.0, -6.0),
+                Vector3::new(2.0, 2.0, 2.0)
+            )),
+        );
+        assert_eq!(
+            Culling::Intersecting,
+            frustum.cull_aabb(&aabb(
+                E3::new(-1.0, -1.0, -2.0),
+                Vector3::new(2.0, 2.0, 2.0)
+            )),
+        );
+        assert!(!frustum
+            .cull_aabb(&aabb(E3::new(-1.0, -1.0, 1.0), Vector3::new(2.0, 2.0, 2.0)))
+            .is_visible());
+
+        let sphere = |center: E3, radius| Sphere { center, radius };
+        assert_eq!(
+            Culling::Inside,
+            frustum.cull_sphere(&sphere(E3::new(0.0, 0.0, -5.0), 1.0))
+        );
+        assert_eq!(
+            Culling::Intersecting,
+            frustum.cull_sphere(&sphere(E3::new(0.0, 0.0, -10.0), 1.0)),
+        );
+        assert_eq!(
+            Culling::Outside,
+            frustum.cull_sphere(&sphere(E3::new(0.0, 0.0, -12.0), 1.0))
+        );
+    }
+
+    #[test]
+    fn line_line_intersection_e2() {
+        let line = Line::<E2>::x();
+        assert_eq!(
+            Some(LineLine::Point(E2::origin())),
+            line.intersection(&Line::y()),
+        );
+        assert_eq!(Some(LineLine::Line(line)), line.intersection(&Line::x()));
+
+        let line1 = Line::<E2> {
+            origin: E2::origin(),
+            direction: Unit::try_from_inner(Converged::converged(1.0)).unwrap(),
+        };
+        let line2 = Line::<E2> {
+            origin: E2::from_xy(2.0, 0.0),
+            direction: Unit::try_from_inner(Vector::<E2>::from_xy(-1.0, 1.0)).unwrap(),
+        };
+        assert_eq!(
+            Some(LineLine::Point(Converged::converged(1.0))),
+            line1.intersection(&line2),
+        );
+
+        let line1 = Line::<E2>::x();
+        let line2 = Line::<E2> {
+            origin: E2::from_xy(0.0, 1.0),
+            direction: Unit::x(),
+        };
+        assert_eq!(None, line1.intersection(&line2));
+    }
+
+    #[test]
+    fn plane_from_points_e3() {
+        // Points on the plane `x + y + z = 1`.
+        let plane = Plane::<E3>::from_points(vec![
+            E3::from_xyz(1.0, 0.0, 0.0),
+            E3::from_xyz(0.0, 1.0, 0.0),
+            E3::from_xyz(0.0, 0.0, 1.0),
+            E3::from_xyz(2.0, 2.0, -3.0),
+            E3::from_xyz(-1.0, 3.0, -1.0),
+        ])
+        .unwrap();
+        let normal = plane.normal.get() * plane.normal.get().x.signum();
+        let expected = 1.0 / 3.0f64.sqrt();
+        assert_relative_eq!(expected, normal.x, epsilon = 1e-9);
+        assert_relative_eq!(expected, normal.y, epsilon = 1e-9);
+        assert_relative_eq!(expected, normal.z, epsilon = 1e-9);
+        assert_relative_eq!(
+            0.0,
+            plane.signed_distance(&E3::from_xyz(1.0, 0.0, 0.0)),
+            epsilon = 1e-9
+        );
+
+        // The covariance fit is available regardless of the linear algebra
+        // backend.
+        let plane = Plane::<E3>::from_points_covariance(vec![
+            E3::from_xyz(1.0, 0.0, 2.0),
+            E3::from_xyz(0.0, 1.0, 2.0),
+            E3::from_xyz(0.0, 0.0, 2.0),
+        ])
+        .unwrap();
+        assert_relative_eq!(1.0, plane.normal.get().z.abs(), epsilon = 1e-9);
+        assert_eq!(
+            Err(Error::Degenerate),
+            Line::<E3>::try_from_points_covariance(vec![E3::origin(); 2])
+        );
+    }
+
+    #[test]
+    fn line_from_points() {
+        // Points about the line `y = x` in two dimensions.
+        let line = Line::<E2>::from_points(vec![
+            E2::from_xy(-0.1, 0.1),
+            E2::from_xy(0.1, -0.1),
+            E2::from_xy(1.9, 2.1),
+            E2::from_xy(2.1, 1.9),
+        ])
+        .unwrap();
+        let direction = line.direction.get() * line.direction.get().x.signum();
+        assert_relative_eq!(FRAC_1_SQRT_2, direction.x, epsilon = 1e-9);
+        assert_relative_eq!(FRAC_1_SQRT_2, direction.y, epsilon = 1e-9);
+        assert_relative_eq!(1.0, line.origin.x, epsilon = 1e-9);
+        assert_relative_eq!(1.0, line.origin.y, epsilon = 1e-9);
+
+        // Points along the `z` axis in three dimensions.
+        let line = Line::<E3>::from_points(vec![
+            E3::from_xyz(0.0, 0.0, -1.0),
+            E3::from_xyz(0.0, 0.0, 2.0),
+            E3::from_xyz(0.0, 0.0, 5.0),
+        ])
+        .unwrap();
+        assert_relative_eq!(0.0, line.direction.get().x, epsilon = 1e-9);
+        assert_relative_eq!(0.0, line.direction.get().y, epsilon = 1e-9);
+        assert_relative_eq!(1.0, line.direction.get().z.abs(), epsilon = 1e-9);
+        assert_relative_eq!(2.0, line.origin.z, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn principal_axes_e3() {
+        // Points spread along `x` and `y` in the plane `z = 1` and far from
+        // the origin.
+        let axes = PrincipalAxes::from_points(
+            [(-3.0, 0.0), (3.0, 0.0), (0.0, -1.0), (0.0, 1.0)]
+                .iter()
+                .map(|(x, y)| E3::from_xyz(1000.0 + x, y - 1000.0, 1.0)),
+        )
+        .unwrap();
+        assert_relative_eq!(1000.0, axes.centroid.x, epsilon = 1e-9);
+        assert_relative_eq!(-1000.0, axes.centroid.y, epsilon = 1e-9);
+        assert_relative_eq!(1.0, axes.centroid.z, epsilon = 1e-9);
+        assert_relative_eq!(4.5, axes.variance(0).unwrap(), epsilon = 1e-9);
+        assert_relative_eq!(0.5, axes.variance(1).unwrap(), epsilon = 1e-9);
+        assert_relative_eq!(0.0, axes.variance(2).unwrap(), epsilon = 1e-9);
+        assert_relative_eq!(1.0, axes.axis(0).unwrap().get().x.abs(), epsilon = 1e-9);
+        assert_relative_eq!(1.0, axes.axis(1).unwrap().get().y.abs(), epsilon = 1e-9);
+        assert_relative_eq!(1.0, axes.axis(2).unwrap().get().z.abs(), epsilon = 1e-9);
+        assert!(axes.axis(3).is_none());
+    }
+
+    #[test]
+    fn sphere_from_points() {
+        // Points on a circle in two dimensions.
+        let (circle, residual) = Sphere::<E2>::from_points(
+            [(3.0, 0.0), (-3.0, 0.0), (0.0, 3.0), (0.0, -3.0), (2.4, 1.8)]
+                .iter()
+                .map(|(x, y)| E2::from_xy(x + 1.0, y - 2.0)),
+        )
+        .unwrap();
+        assert_relative_eq!(1.0, circle.center.x, epsilon = 1e-9);
+        assert_relative_eq!(-2.0, circle.center.y, epsilon = 1e-9);
+        assert_relative_eq!(3.0, circle.radius, epsilon = 1e-9);
+        assert_relative_eq!(0.0, residual, epsilon = 1e-9);
+
+        // Points near a sphere in three dimensions.
+        let (sphere, residual) = Sphere::<E3>::from_points(vec![
+            E3::from_xyz(1.1, 0.0, 0.0),
+            E3::from_xyz(-1.1, 0.0, 0.0),
+            E3::from_xyz(0.0, 0.9, 0.0),
+            E3::from_xyz(0.0, -0.9, 0.0),
+            E3::from_xyz(0.0, 0.0, 1.0),
+            E3::from_xyz(0.0, 0.0, -1.0),
+        ])
+        .unwrap();
+        assert_relative_eq!(0.0, sphere.center.x, epsilon = 1e-9);
+        assert_relative_eq!(0.0, sphere.center.y, epsilon = 1e-9);
+        assert_relative_eq!(0.0, sphere.center.z, epsilon = 1e-9);
+        assert!(sphere.radius > 0.9 && sphere.radius < 1.1);
+        assert!(residual > 0.0);
+
+        // Collinear points do not determine a circle.
+        assert!(Sphere::<E2>::from_points(vec![
+            E2::from_xy(0.0, 0.0),
+            E2::from_xy(1.0, 1.0),
+            E2::from_xy(2.0, 2.0),
+        ])
+        .is_none());
+    }
+
+    #[test]
+    fn from_points_errors() {
+        assert_eq!(Err(Error::Empty), Aabb::<E2>::try_from_points(vec![]));
+        assert_eq!(Err(Error::Empty), Plane::<E3>::try_from_points(vec![]));
+        assert_eq!(
+            Err(Error::Degenerate),
+            Line::<E3>::try_from_points(vec![E3::origin(), E3::origin()])
+        );
+        assert_eq!(
+            Err(Error::Degenerate),
+            Plane::<E3>::try_from_points(vec![E3::from_xyz(1.0, 2.0, 3.0); 3])
+        );
+        assert_eq!(
+            Err(Error::Degenerate),
+            Sphere::<E2>::try_from_points(vec![
+                E2::from_xy(0.0, 0.0),
+                E2::from_xy(1.0, 1.0),
+                E2::from_xy(2.0, 2.0),
+            ])
+        );
+        assert_eq!(
+            Err(Error::ZeroLength),
+            Unit::from_inner(Vector3::<f64>::zeros())
+        );
+    }
+
+    #[test]
+    fn plane_plane_intersection_e3() {
+        let a = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            normal: Unit::z(),
+        };
+        let b = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(2.0, 0.0, 0.0),
+            normal: Unit::x(),
+        };
+        let line = a.intersection(&b).unwrap().into_line().unwrap();
+        assert_eq!(E3::new(2.0, 0.0, 1.0), line.origin);
+        assert_eq!(Vector3::y(), line.direction.into_inner());
+        assert_eq!(Some(PlanePlane::Plane(a)), a.intersection(&a));
+        assert_eq!(
+            None,
+            a.intersection(&Plane {
+                origin: EuclideanSpace::origin(),
+                ..a
+            })
+        );
+
+        let c = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, -3.0, 0.0),
+            normal: Unit::y(),
+        };
+        assert_eq!(Some(E3::new(2.0, -3.0, 1.0)), a.triple_intersection(&b, &c));
+        assert_eq!(None, a.triple_intersection(&b, &a));
+    }
+
+    #[test]
+    fn plane_ray_intersection_e3() {
+        let plane = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            normal: Unit::z(),
+        };
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::origin(),
+            direction: Unit::z(),
+        };
+        assert_eq!(Some(PlaneRay::TimeOfImpact(1.0)), ray.intersection(&plane));
+        assert_eq!(None, ray.reverse().intersection(&plane));
+    }
+
+    #[test]
+    fn line_transform_e2() {
+        let line = Line::<E2> {
+            origin: EuclideanSpace::from_xy(1.0, 0.0),
+            direction: Unit::x(),
+        };
+        let rotation = Isometry2::new(Vector2::new(0.0, 1.0), FRAC_PI_2);
+        let line = line.transform(&rotation).unwrap();
+        assert_relative_eq!(0.0, line.origin.x, epsilon = 1e-9);
+        assert_relative_eq!(2.0, line.origin.y, epsilon = 1e-9);
+        assert_relative_eq!(Unit::y(), line.direction, epsilon = 1e-9);
+        assert!(Line::<E2>::y()
+            .transform(&Matrix3::new_nonuniform_scaling(&Vector2::new(1.0, 0.0)))
+            .is_none());
+    }
+
+    #[test]
+    fn plane_transform_e2() {
+        let plane = Plane::<E2> {
+            origin: EuclideanSpace::origin(),
+            normal: Unit::try_from_inner(Vector::<E2>::from_xy(1.0, 1.0)).unwrap(),
+        };
+        let scale = Matrix3::new_nonuniform_scaling(&Vector2::new(2.0, 1.0));
+        let plane = plane.transform(&scale).unwrap();
+        assert_relative_eq!(
+            Unit::try_from_inner(Vector::<E2>::from_xy(1.0, 2.0)).unwrap(),
+            plane.normal,
+            epsilon = 1e-9,
+        );
+        assert!(plane
+            .transform(&Matrix3::new_nonuniform_scaling(&Vector2::new(0.0, 1.0)))
+            .is_none());
+    }
+
+    #[test]
+    fn point_reflect_line_e2() {
+        let line = Line::<E2> {
+            origin: EuclideanSpace::from_xy(0.0, 1.0),
+            direction: Unit::x(),
+        };
+        assert_eq!(E2::new(2.0, -1.0), E2::new(2.0, 3.0).reflect(line));
+    }
+
+    #[test]
+    fn obb_aabb_conversion_e2() {
+        let aabb = Aabb::<E2> {
+            origin: EuclideanSpace::from_xy(1.0, 2.0),
+            extent: Vector::<E2>::new(2.0, 4.0),
+        };
+        let obb = Obb::<E2, Rotation2<f64>>::from(aabb);
+        assert_eq!(E2::new(2.0, 4.0), obb.center);
+        assert_eq!(Vector::<E2>::new(1.0, 2.0), obb.extent);
+        assert_relative_eq!(8.0, obb.volume());
+        let aabb = Aabb::from(Obb {
+            rotation: Rotation2::new(FRAC_PI_2),
+            ..obb
+        });
+        assert_relative_eq!(0.0, aabb.origin.x, epsilon = 1e-9);
+        assert_relative_eq!(3.0, aabb.origin.y, epsilon = 1e-9);
+        assert_relative_eq!(4.0, aabb.extent.x, epsilon = 1e-9);
+        assert_relative_eq!(2.0, aabb.extent.y, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn obb_point_ray_intersection_e2() {
+        // A unit square rotated into a diamond.
+        let obb = Obb::<E2, _> {
+            center: EuclideanSpace::origin(),
+            extent: Vector::<E2>::new(1.0, 1.0),
+            rotation: Rotation2::new(FRAC_PI_4),
+        };
+        assert!(obb.intersection(&E2::new(0.0, 1.4)).is_some());
+        assert!(E2::new(1.0, 1.0).intersection(&obb).is_none());
+
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(-3.0, 0.0),
+            direction: Unit::x(),
+        };
+        let (min, max) = obb.intersection(&ray).unwrap().into_interval();
+        assert_relative_eq!(3.0 - 2.0f64.sqrt(), min, epsilon = 1e-9);
+        assert_relative_eq!(3.0 + 2.0f64.sqrt(), max, epsilon = 1e-9);
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(-3.0, 1.5),
+            direction: Unit::x(),
+        };
+        assert!(ray.intersection(&obb).is_none());
+    }
 
-#[cfg(all(test, feature = "geometry-nalgebra"))]
-mod tests {
-    use decorum::N64;
-    use nalgebra::{Point2, Point3};
+    #[test]
+    fn obb_contains_e2() {
+        // A unit square rotated into a diamond.
+        let obb = Obb::<E2, _> {
+            center: EuclideanSpace::from_xy(1.0, 1.0),
+            extent: Vector::<E2>::new(1.0, 1.0),
+            rotation: Rotation2::new(FRAC_PI_4),
+        };
+        assert!(obb.contains(&E2::new(1.0, 1.0)));
+        assert!(obb.contains(&E2::new(1.0, 2.4)));
+        assert!(!obb.contains(&E2::new(2.0, 2.0)));
+        assert!(!obb.contains(&E2::new(1.0, 2.5)));
 
-    use crate::adjunct::Converged;
-    use crate::query::{Aabb, Intersection, Line, LineLine, Plane, PlaneRay, Ray, Unit};
-    use crate::space::{EuclideanSpace, Vector, VectorSpace};
+        let aabb = |origin: E2, extent: Vector2<f64>| Aabb { origin, extent };
+        assert!(obb.contains(&aabb(E2::new(0.5, 0.5), Vector2::new(1.0, 1.0))));
+        assert!(!obb.contains(&aabb(E2::new(0.0, 0.0), Vector2::new(2.0, 2.0))));
+        // Wide boxes are contained only if their corners are within the faces
+        // of the diamond.
+        assert!(obb.contains(&aabb(E2::new(0.0, 0.6), Vector2::new(2.0, 0.8))));
+        assert!(!obb.contains(&aabb(E2::new(0.0, 0.5), Vector2::new(2.0, 1.0))));
+    }
 
-    type E2 = Point2<f64>;
-    type E3 = Point3<f64>;
+    #[test]
+    fn obb_obb_intersection_e3() {
+        let a = Obb::<E3, _> {
+            center: EuclideanSpace::origin(),
+            extent: Vector::<E3>::new(1.0, 1.0, 1.0),
+            rotation: Rotation3::identity(),
+        };
+        let b = Obb::<E3, _> {
+            center: EuclideanSpace::from_xyz(2.3, 0.0, 0.0),
+            extent: Vector::<E3>::new(1.0, 1.0, 1.0),
+            rotation: Rotation3::from_axis_angle(&Vector3::z_axis(), FRAC_PI_4),
+        };
+        // The rotated box reaches $\sqrt{2}$ along the X axis.
+        assert!(a.intersection(&b).is_some());
+        let b = Obb {
+            center: EuclideanSpace::from_xyz(2.5, 0.0, 0.0),
+            ..b
+        };
+        assert!(a.intersection(&b).is_none());
+        // These boxes are only separated by an axis formed from their edges.
+        let b = Obb::<E3, _> {
+            center: EuclideanSpace::from_xyz(0.0, 1.8, 2.3),
+            extent: Vector::<E3>::new(1.0, 1.0, 1.0),
+            rotation: Rotation3::from_axis_angle(&Vector3::z_axis(), FRAC_PI_4)
+                * Rotation3::from_axis_angle(&Vector3::x_axis(), FRAC_PI_4),
+        };
+        assert!(a.intersection(&b).is_none());
+        assert!(a.intersection(&a).is_some());
+    }
 
     #[test]
-    fn aabb_aabb_intersection_e2() {
-        let aabb1 = Aabb::<E2> {
+    fn plane_covector_e3() {
+        let plane = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            normal: Unit::z(),
+        };
+        let covector = plane.into_covector();
+        assert_eq!(
+            3.0,
+            covector.apply(E3::new(1.0, 2.0, 4.0).into_homogeneous())
+        );
+        assert_eq!(
+            -1.0,
+            covector.apply(E3::new(0.0, 0.0, 0.0).into_homogeneous())
+        );
+        // Vectors are not affected by the offset of the plane.
+        assert_eq!(
+            2.0,
+            covector.apply(Vector3::new(0.0, 0.0, 2.0).into_homogeneous())
+        );
+    }
+
+    #[test]
+    fn point_reflect_plane_e3() {
+        let plane = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            normal: Unit::z(),
+        };
+        let point = E3::new(1.0, 2.0, 4.0);
+        assert_eq!(Vector::<E3>::new(0.0, 0.0, 3.0), point.reject(plane));
+        assert_eq!(E3::new(1.0, 2.0, -2.0), point.reflect(plane));
+    }
+
+    #[test]
+    fn polygon_winding_e2() {
+        // A "U" shape in clockwise order.
+        let polygon = Polygon::from_vertices([
+            E2::new(0.0, 0.0),
+            E2::new(0.0, 3.0),
+            E2::new(1.0, 3.0),
+            E2::new(1.0, 1.0),
+            E2::new(2.0, 1.0),
+            E2::new(2.0, 3.0),
+            E2::new(3.0, 3.0),
+            E2::new(3.0, 0.0),
+        ]);
+        assert_eq!(-7.0, polygon.area());
+        assert_eq!(Some(Winding::Clockwise), polygon.winding());
+        assert!(!polygon.is_convex());
+        let centroid = polygon.centroid().unwrap();
+        assert_relative_eq!(1.5, centroid.x, epsilon = 1e-9);
+        assert_relative_eq!(19.0 / 14.0, centroid.y, epsilon = 1e-9);
+
+        let inside = E2::new(0.5, 2.0);
+        let notch = E2::new(1.5, 2.0);
+        assert_eq!(-1, polygon.winding_number(&inside));
+        assert_eq!(3, polygon.crossing_number(&inside));
+        assert!(polygon.intersection(&inside).is_some());
+        assert_eq!(0, polygon.winding_number(&notch));
+        assert_eq!(2, polygon.crossing_number(&notch));
+        assert!(notch.intersection(&polygon).is_none());
+
+        // A pentagram winds twice about its center.
+        let pentagram = Polygon::from_vertices(
+            (0..5)
+                .map(|n| {
+                    let angle = FRAC_PI_2 + (f64::from(n * 2) * 2.0 * PI / 5.0);
+                    E2::new(angle.cos(), angle.sin())
+                })
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(2, pentagram.winding_number(&E2::origin()));
+        assert!(!pentagram.is_convex());
+        assert_eq!(0, pentagram.crossing_number(&E2::origin()) % 2);
+        assert!(Polygon::<E2, [E2; 0]>::from_vertices([])
+            .centroid()
+            .is_none());
+    }
+
+    #[test]
+    fn ray_solid_classification_e3() {
+        let sphere = Sphere::<E3> {
+            center: EuclideanSpace::from_xyz(4.0, 0.0, 0.0),
+            radius: 1.0,
+        };
+        let tangent = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 1.0, 0.0),
+            direction: Unit::x(),
+        };
+        assert_eq!(Some(RaySolid::Tangent(4.0)), tangent.intersection(&sphere));
+
+        let aabb = Aabb::<E3> {
             origin: EuclideanSpace::origin(),
             extent: Converged::converged(2.0),
         };
-        let aabb2 = Aabb::<E2> {
+        let inside = Ray::<E3> {
             origin: Converged::converged(1.0),
-            extent: Converged::converged(2.0),
+            direction: Unit::x(),
+        };
+        let hit = inside.intersection(&aabb).unwrap();
+        assert!(hit.is_inside());
+        assert_eq!((-1.0, 1.0), hit.into_interval());
+        assert_eq!(0.0, hit.time_of_impact());
+    }
+
+    #[test]
+    fn ray_sphere_intersection_e3() {
+        let sphere = Sphere::<E3> {
+            center: EuclideanSpace::from_xyz(4.0, 0.0, 0.0),
+            radius: 1.0,
+        };
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::origin(),
+            direction: Unit::x(),
         };
-        assert_eq!(Some(aabb1), aabb1.intersection(&aabb1));
         assert_eq!(
-            Some(Aabb::<E2> {
-                origin: Converged::converged(1.0),
-                extent: Converged::converged(1.0),
+            Some(RaySolid::Crossing {
+                entry: 3.0,
+                exit: 5.0
             }),
-            aabb1.intersection(&aabb2),
+            ray.intersection(&sphere)
         );
-        let aabb2 = Aabb::<E2> {
-            origin: Converged::converged(-3.0),
-            extent: Converged::converged(2.0),
+        assert_eq!(
+            Some(RaySolid::Crossing {
+                entry: 3.0,
+                exit: 5.0
+            }),
+            sphere.intersection(&ray)
+        );
+        assert_eq!(None, ray.reverse().intersection(&sphere));
+        // Origin within the sphere.
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(4.0, 0.0, 0.0),
+            direction: Unit::y(),
         };
-        assert_eq!(None, aabb1.intersection(&aabb2));
+        assert_eq!(
+            Some(RaySolid::Inside {
+                entry: -1.0,
+                exit: 1.0
+            }),
+            ray.intersection(&sphere)
+        );
+        // Miss.
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 2.0, 0.0),
+            direction: Unit::x(),
+        };
+        assert_eq!(None, ray.intersection(&sphere));
     }
 
     #[test]
-    fn aabb_point_intersection_e2() {
-        let aabb = Aabb::<E2> {
-            origin: EuclideanSpace::origin(),
-            extent: Converged::converged(2.0),
+    fn segment_segment_intersection_e2() {
+        let segment = |start: (f64, f64), end: (f64, f64)| Segment::<E2> {
+            start: E2::new(start.0, start.1),
+            end: E2::new(end.0, end.1),
         };
-        let point = E2::converged(1.0);
+        let a = segment((0.0, 0.0), (2.0, 2.0));
         assert_eq!(
-            Some(Vector::<E2>::converged(1.0)),
-            aabb.intersection(&point),
+            Some(SegmentSegment::Point(E2::new(1.0, 1.0))),
+            a.intersection(&segment((0.0, 2.0), (2.0, 0.0))),
         );
-        let point = E2::converged(3.0);
-        assert_eq!(None, aabb.intersection(&point));
+        assert_eq!(None, a.intersection(&segment((0.0, 2.0), (0.5, 1.5))));
+        // Parallel.
+        assert_eq!(None, a.intersection(&segment((1.0, 0.0), (3.0, 2.0))));
+        // Collinear.
+        assert_eq!(
+            Some(SegmentSegment::Segment(segment((1.0, 1.0), (2.0, 2.0)))),
+            a.intersection(&segment((3.0, 3.0), (1.0, 1.0))),
+        );
+        assert_eq!(
+            Some(SegmentSegment::Point(E2::new(2.0, 2.0))),
+            a.intersection(&segment((2.0, 2.0), (3.0, 3.0))),
+        );
+        assert_eq!(None, a.intersection(&segment((3.0, 3.0), (4.0, 4.0))));
+        // Degenerate.
+        assert_eq!(
+            Some(SegmentSegment::Point(E2::new(1.0, 1.0))),
+            a.intersection(&segment((1.0, 1.0), (1.0, 1.0))),
+        );
+        assert_eq!(
+            Some(SegmentSegment::Point(E2::new(1.0, 1.0))),
+            segment((1.0, 1.0), (1.0, 1.0)).intersection(&a),
+        );
+        assert_eq!(None, a.intersection(&segment((1.0, 0.0), (1.0, 0.0))));
     }
 
     #[test]
-    fn aabb_ray_intersection_e2() {
+    fn segment_segment_intersection_scale_e2() {
+        let segment = |start: (f64, f64), end: (f64, f64), scale: f64| Segment::<E2> {
+            start: E2::new(start.0 * scale, start.1 * scale),
+            end: E2::new(end.0 * scale, end.1 * scale),
+        };
+        // Whether segments intersect does not depend on their scale, even when
+        // their perp dot products are far from one.
+        for scale in [1e-9, 1.0, 1e9] {
+            let a = segment((0.0, 0.0), (2.0, 2.0), scale);
+            assert_eq!(
+                Some(SegmentSegment::Point(E2::new(scale, scale))),
+                a.intersection(&segment((0.0, 2.0), (2.0, 0.0), scale)),
+            );
+            // Parallel.
+            assert_eq!(
+                None,
+                a.intersection(&segment((1.0, 0.0), (3.0, 2.0), scale))
+            );
+            // Collinear.
+            assert_eq!(
+                Some(SegmentSegment::Segment(segment(
+                    (1.0, 1.0),
+                    (2.0, 2.0),
+                    scale
+                ))),
+                a.intersection(&segment((3.0, 3.0), (1.0, 1.0), scale)),
+            );
+        }
+    }
+
+    #[test]
+    fn segment_aabb_intersection_e2() {
         let aabb = Aabb::<E2> {
-            origin: EuclideanSpace::origin(),
-            extent: Converged::converged(1.0),
+            origin: EuclideanSpace::from_xy(1.0, -1.0),
+            extent: VectorSpace::from_xy(2.0, 2.0),
         };
-        let ray = Ray::<E2> {
-            origin: EuclideanSpace::from_xy(-1.0, 0.5),
-            direction: Unit::x(),
+        let segment = Segment::<E2> {
+            start: EuclideanSpace::origin(),
+            end: EuclideanSpace::from_xy(4.0, 0.0),
         };
-        assert_eq!(Some((1.0, 2.0)), ray.intersection(&aabb));
-        assert_eq!(None, ray.reverse().intersection(&aabb));
+        assert_eq!(Some((0.25, 0.75)), segment.intersection(&aabb));
+        assert_eq!(Some((0.25, 0.75)), aabb.intersection(&segment));
+        // The segment ends within the bounding box.
+        let segment = Segment::<E2> {
+            start: EuclideanSpace::origin(),
+            end: EuclideanSpace::from_xy(2.0, 0.0),
+        };
+        assert_eq!(Some((0.5, 1.0)), segment.intersection(&aabb));
+        // The segment ends before the bounding box.
+        let segment = Segment::<E2> {
+            start: EuclideanSpace::origin(),
+            end: EuclideanSpace::from_xy(0.5, 0.0),
+        };
+        assert_eq!(None, segment.intersection(&aabb));
     }
 
     #[test]
-    fn aabb_ray_intersection_e3() {
-        let aabb = Aabb::<E3> {
-            origin: EuclideanSpace::origin(),
-            extent: Converged::converged(1.0),
+    fn segment_closest_points_e3() {
+        let segment = Segment::<E3> {
+            start: EuclideanSpace::origin(),
+            end: EuclideanSpace::from_xyz(2.0, 0.0, 0.0),
         };
-        let ray = Ray::<E3> {
-            origin: EuclideanSpace::from_xyz(-1.0, 0.5, 0.5),
-            direction: Unit::x(),
+        assert_eq!(2.0, segment.length());
+        assert_eq!(E3::new(1.0, 0.0, 0.0), segment.midpoint());
+        assert_eq!(
+            E3::new(1.5, 0.0, 0.0),
+            segment.closest_point(E3::new(1.5, 1.0, 0.0))
+        );
+        assert_eq!(
+            E3::new(2.0, 0.0, 0.0),
+            segment.closest_point(E3::new(3.0, 1.0, 0.0))
+        );
+        let other = Segment::<E3> {
+            start: EuclideanSpace::from_xyz(1.0, -1.0, 1.0),
+            end: EuclideanSpace::from_xyz(1.0, 1.0, 1.0),
         };
-        assert_eq!(Some((1.0, 2.0)), ray.intersection(&aabb));
-        assert_eq!(None, ray.reverse().intersection(&aabb));
+        assert_eq!(
+            (E3::new(1.0, 0.0, 0.0), E3::new(1.0, 0.0, 1.0)),
+            segment.closest_points(&other)
+        );
+        assert_relative_eq!(1.0, segment.distance_to_segment(&other));
+        // Parallel segments.
+        let other = Segment::<E3> {
+            start: EuclideanSpace::from_xyz(3.0, 1.0, 0.0),
+            end: EuclideanSpace::from_xyz(5.0, 1.0, 0.0),
+        };
+        assert_relative_eq!(2.0f64.sqrt(), segment.distance_to_segment(&other));
     }
 
-    // Ensure that certain values do not produce `NaN`s when querying the
-    // intersection of `Aabb` and `Ray`.
     #[test]
-    fn aabb_ray_intersection_nan() {
-        let aabb = Aabb::<Point2<N64>> {
-            origin: EuclideanSpace::origin(),
-            extent: Converged::converged(1.0.into()),
+    fn segment_plane_intersection_e3() {
+        let plane = Plane::<E3> {
+            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            normal: Unit::z(),
         };
-        let ray = Ray::<Point2<N64>> {
+        let segment = Segment::<E3> {
+            start: EuclideanSpace::origin(),
+            end: EuclideanSpace::from_xyz(0.0, 0.0, 4.0),
+        };
+        assert_eq!(
+            Some(0.25),
+            segment
+                .intersection(&plane)
+                .and_then(PlaneSegment::into_parameter)
+        );
+        let segment = Segment::<E3> {
+            start: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            end: EuclideanSpace::from_xyz(1.0, 0.0, 1.0),
+        };
+        assert_eq!(
+            Some(segment),
+            plane
+                .intersection(&segment)
+                .and_then(PlaneSegment::into_segment)
+        );
+        let segment = Segment::<E3> {
+            start: EuclideanSpace::from_xyz(0.0, 0.0, 2.0),
+            end: EuclideanSpace::from_xyz(0.0, 0.0, 3.0),
+        };
+        assert!(plane.intersection(&segment).is_none());
+    }
+
+    #[test]
+    fn signed_distance_e2() {
+        let aabb = Aabb::<E2> {
+            origin: EuclideanSpace::from_xy(2.0, 2.0),
+            extent: Vector::<E2>::new(-2.0, -2.0),
+        };
+        assert_relative_eq!(-0.5, aabb.signed_distance(&E2::new(1.5, 1.0)));
+        assert_relative_eq!(0.0, aabb.signed_distance(&E2::new(2.0, 1.0)));
+        assert_relative_eq!(1.0, E2::new(1.0, 3.0).signed_distance(&aabb));
+        assert_relative_eq!(5.0, aabb.signed_distance(&E2::new(5.0, 6.0)));
+
+        let plane = Plane::<E2> {
+            origin: EuclideanSpace::from_xy(0.0, 1.0),
+            normal: Unit::y(),
+        };
+        assert_eq!(-1.0, E2::new(3.0, 0.0).signed_distance(&plane));
+
+        let sphere = Sphere::<E2> {
+            center: EuclideanSpace::origin(),
+            radius: 1.0,
+        };
+        assert_relative_eq!(-1.0, sphere.signed_distance(&E2::origin()));
+        assert_relative_eq!(4.0, sphere.signed_distance(&E2::new(3.0, 4.0)));
+        let other = Sphere::<E2> {
+            center: EuclideanSpace::from_xy(1.5, 0.0),
+            radius: 1.0,
+        };
+        assert_relative_eq!(-0.5, sphere.signed_distance(&other));
+
+        let segment = Segment::<E2> {
+            start: EuclideanSpace::origin(),
+            end: EuclideanSpace::from_xy(2.0, 0.0),
+        };
+        assert_relative_eq!(0.0, segment.signed_distance(&E2::new(1.0, 0.0)));
+        assert_relative_eq!(2.0, E2::new(1.0, -2.0).signed_distance(&segment));
+        let other = Segment::<E2> {
+            start: EuclideanSpace::from_xy(3.0, 1.0),
+            end: EuclideanSpace::from_xy(3.0, 2.0),
+        };
+        assert_relative_eq!(2.0f64.sqrt(), segment.signed_distance(&other));
+    }
+
+    #[test]
+    fn sphere_aabb_intersection_e2() {
+        let aabb = Aabb::<E2> {
             origin: EuclideanSpace::origin(),
-            direction: Unit::x(),
+            extent: Converged::converged(2.0),
         };
-        assert_eq!(Some((0.0.into(), 1.0.into())), ray.intersection(&aabb));
+        let sphere = Sphere::<E2> {
+            center: EuclideanSpace::from_xy(3.0, 1.0),
+            radius: 1.5,
+        };
+        assert_eq!(Some(E2::new(2.0, 1.0)), sphere.intersection(&aabb));
+        assert_eq!(Some(E2::new(2.0, 1.0)), aabb.intersection(&sphere));
+        let sphere = Sphere::<E2> {
+            center: EuclideanSpace::from_xy(3.0, 3.0),
+            radius: 1.0,
+        };
+        assert_eq!(None, sphere.intersection(&aabb));
+        let sphere = Sphere::<E2> {
+            center: EuclideanSpace::from_xy(1.0, 1.0),
+            radius: 0.5,
+        };
+        assert_eq!(Some(E2::new(1.0, 1.0)), sphere.intersection(&aabb));
     }
 
     #[test]
-    fn line_line_intersection_e2() {
-        let line = Line::<E2>::x();
+    fn sphere_point_intersection_e2() {
+        let sphere = Sphere::<E2> {
+            center: EuclideanSpace::from_xy(1.0, 1.0),
+            radius: 1.0,
+        };
         assert_eq!(
-            Some(LineLine::Point(E2::origin())),
-            line.intersection(&Line::y()),
+            Some(Vector2::new(1.0, 0.0)),
+            sphere.intersection(&E2::new(2.0, 1.0))
         );
-        assert_eq!(Some(LineLine::Line(line)), line.intersection(&Line::x()));
+        assert_eq!(
+            Some(Vector2::new(0.0, 0.0)),
+            E2::new(1.0, 1.0).intersection(&sphere)
+        );
+        assert_eq!(None, sphere.intersection(&E2::new(2.0, 2.0)));
+    }
 
-        let line1 = Line::<E2> {
-            origin: E2::origin(),
-            direction: Unit::try_from_inner(Converged::converged(1.0)).unwrap(),
+    #[test]
+    fn sphere_sphere_intersection_e2() {
+        let sphere1 = Sphere::<E2> {
+            center: EuclideanSpace::origin(),
+            radius: 2.0,
         };
-        let line2 = Line::<E2> {
-            origin: E2::from_xy(2.0, 0.0),
-            direction: Unit::try_from_inner(Vector::<E2>::from_xy(-1.0, 1.0)).unwrap(),
+        let sphere2 = Sphere::<E2> {
+            center: EuclideanSpace::from_xy(3.0, 0.0),
+            radius: 1.5,
         };
-        assert_eq!(
-            Some(LineLine::Point(Converged::converged(1.0))),
-            line1.intersection(&line2),
-        );
+        assert_relative_eq!(0.5, sphere1.intersection(&sphere2).unwrap());
+        assert_relative_eq!(0.5, sphere2.intersection(&sphere1).unwrap());
+        let sphere2 = Sphere::<E2> {
+            center: EuclideanSpace::from_xy(3.0, 3.0),
+            radius: 1.5,
+        };
+        assert_eq!(None, sphere1.intersection(&sphere2));
+    }
 
-        let line1 = Line::<E2>::x();
-        let line2 = Line::<E2> {
-            origin: E2::from_xy(0.0, 1.0),
-            direction: Unit::x(),
+    #[test]
+    fn ray_triangle_intersection_e3() {
+        let triangle = Triangle::<E3> {
+            a: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            b: EuclideanSpace::from_xyz(2.0, 0.0, 1.0),
+            c: EuclideanSpace::from_xyz(0.0, 2.0, 1.0),
         };
-        assert_eq!(None, line1.intersection(&line2));
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(0.5, 1.0, 0.0),
+            direction: Unit::z(),
+        };
+        let (t, (u, v, w)) = ray.intersection(&triangle).unwrap();
+        assert_relative_eq!(1.0, t);
+        assert_relative_eq!(0.25, u);
+        assert_relative_eq!(0.25, v);
+        assert_relative_eq!(0.5, w);
+        // Triangles are two-sided.
+        let reversed = Triangle::<E3> {
+            b: triangle.c,
+            c: triangle.b,
+            ..triangle
+        };
+        assert!(ray.intersection(&reversed).is_some());
+        assert!(ray.reverse().intersection(&triangle).is_none());
+        let ray = Ray::<E3> {
+            origin: EuclideanSpace::from_xyz(2.0, 2.0, 0.0),
+            direction: Unit::z(),
+        };
+        assert!(triangle.intersection(&ray).is_none());
     }
 
     #[test]
-    fn plane_ray_intersection_e3() {
+    fn sweep_aabb_e2() {
+        let aabb = Aabb::<E2> {
+            origin: EuclideanSpace::origin(),
+            extent: Vector2::new(1.0, 1.0),
+        };
+        let other = Aabb::<E2> {
+            origin: EuclideanSpace::from_xy(3.0, 0.5),
+            extent: Vector2::new(1.0, 1.0),
+        };
+        assert_eq!(Some((0.5, 1.0)), aabb.sweep(Vector2::new(4.0, 0.0), &other));
+        assert!(aabb.sweep(Vector2::new(1.0, 0.0), &other).is_none());
+        assert!(aabb.sweep(Vector2::new(4.0, 4.0), &other).is_none());
+        assert!(aabb.sweep(Vector2::new(-4.0, 0.0), &other).is_none());
+        let (entry, _) = other.sweep(Vector2::new(0.0, 1.0), &other).unwrap();
+        assert!(entry < 0.0);
+    }
+
+    #[test]
+    fn sweep_sphere_e3() {
+        let sphere = Sphere::<E3> {
+            center: EuclideanSpace::from_xyz(0.0, 0.0, 5.0),
+            radius: 1.0,
+        };
         let plane = Plane::<E3> {
-            origin: EuclideanSpace::from_xyz(0.0, 0.0, 1.0),
+            origin: EuclideanSpace::origin(),
             normal: Unit::z(),
         };
-        let ray = Ray::<E3> {
-            origin: EuclideanSpace::origin(),
-            direction: Unit::z(),
+        let (t, point) = sphere.sweep(Vector3::new(2.0, 0.0, -8.0), &plane).unwrap();
+        assert_relative_eq!(0.5, t);
+        assert_relative_eq!(1.0, point.x);
+        assert_relative_eq!(0.0, point.z);
+        assert!(sphere.sweep(Vector3::new(0.0, 0.0, -2.0), &plane).is_none());
+        assert!(sphere.sweep(Vector3::new(0.0, 0.0, 8.0), &plane).is_none());
+
+        let other = Sphere::<E3> {
+            center: EuclideanSpace::origin(),
+            radius: 2.0,
         };
-        assert_eq!(Some(PlaneRay::TimeOfImpact(1.0)), ray.intersection(&plane));
-        assert_eq!(None, ray.reverse().intersection(&plane));
+        let (t, point) = sphere.sweep(Vector3::new(0.0, 0.0, -4.0), &other).unwrap();
+        assert_relative_eq!(0.5, t);
+        assert_relative_eq!(2.0, point.z);
+        assert!(sphere.sweep(Vector3::new(4.0, 0.0, 0.0), &other).is_none());
+
+        // A fast sphere passes through the triangle within a single step.
+        let triangle = Triangle::<E3> {
+            a: EuclideanSpace::from_xyz(-2.0, -2.0, 0.0),
+            b: EuclideanSpace::from_xyz(2.0, -2.0, 0.0),
+            c: EuclideanSpace::from_xyz(0.0, 2.0, 0.0),
+        };
+        let (t, point) = sphere
+            .sweep(Vector3::new(0.0, 0.0, -20.0), &triangle)
+            .unwrap();
+        assert_relative_eq!(0.2, t);
+        assert_eq!(E3::origin(), point);
+        // The sphere grazes an edge of the triangle.
+        let sphere = Sphere {
+            center: EuclideanSpace::from_xyz(0.0, -2.5, 5.0),
+            ..sphere
+        };
+        let (t, point) = sphere
+            .sweep(Vector3::new(0.0, 0.0, -10.0), &triangle)
+            .unwrap();
+        assert_relative_eq!(0.5 - (0.75f64.sqrt() / 10.0), t, epsilon = 1e-9);
+        assert_relative_eq!(-2.0, point.y, epsilon = 1e-9);
+        assert_relative_eq!(0.0, point.z, epsilon = 1e-9);
+        let sphere = Sphere {
+            center: EuclideanSpace::from_xyz(0.0, -3.5, 5.0),
+            ..sphere
+        };
+        assert!(sphere
+            .sweep(Vector3::new(0.0, 0.0, -10.0), &triangle)
+            .is_none());
+    }
+
+    #[test]
+    fn triangle_area_normal_e3() {
+        let triangle = Triangle::<E3> {
+            a: EuclideanSpace::origin(),
+            b: EuclideanSpace::from_xyz(2.0, 0.0, 0.0),
+            c: EuclideanSpace::from_xyz(0.0, 3.0, 0.0),
+        };
+        assert_relative_eq!(3.0, triangle.area());
+        assert_relative_eq!(Unit::z(), triangle.normal().unwrap());
+        let centroid = triangle.centroid();
+        assert_relative_eq!(2.0 / 3.0, centroid.x);
+        assert_relative_eq!(1.0, centroid.y);
+        let degenerate = Triangle::<E3> {
+            c: EuclideanSpace::from_xyz(4.0, 0.0, 0.0),
+            ..triangle
+        };
+        assert_eq!(0.0, degenerate.area());
+        assert!(degenerate.normal().is_none());
+    }
+
+    #[test]
+    fn unit_angle_e2() {
+        let x = Unit::<Vector<E2>>::x();
+        let y = Unit::<Vector<E2>>::y();
+        assert_relative_eq!(FRAC_PI_2, x.angle(y), epsilon = 1e-9);
+        assert_relative_eq!(FRAC_PI_2, x.signed_angle(y), epsilon = 1e-9);
+        assert_relative_eq!(-FRAC_PI_2, y.signed_angle(x), epsilon = 1e-9);
+        assert_relative_eq!(0.0, x.angle(x));
+        assert_relative_eq!(PI, x.angle(-x), epsilon = 1e-9);
+        assert_relative_eq!(
+            FRAC_PI_4,
+            Vector::<E2>::new(2.0, 0.0)
+                .angle(Vector::<E2>::new(3.0, 3.0))
+                .unwrap(),
+            epsilon = 1e-9,
+        );
+        assert!(Vector::<E2>::zeros().angle(Vector::<E2>::x()).is_none());
+    }
+
+    #[test]
+    fn unit_complete_basis_e3() {
+        for normal in [
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(1.0, -2.0, 3.0),
+            Vector3::new(-1.0, 2.0, -3.0),
+        ] {
+            let normal = Unit::try_from_inner(normal).unwrap();
+            let (tangent, bitangent) = normal.complete_basis();
+            let (t, b, n) = (
+                tangent.into_inner(),
+                bitangent.into_inner(),
+                normal.into_inner(),
+            );
+            assert_relative_eq!(1.0, t.norm(), epsilon = 1e-9);
+            assert_relative_eq!(1.0, b.norm(), epsilon = 1e-9);
+            assert_relative_eq!(0.0, t.dot(&b), epsilon = 1e-9);
+            assert_relative_eq!(0.0, t.dot(&n), epsilon = 1e-9);
+            assert_relative_eq!(1.0, t.cross(&b).dot(&n), epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn unit_ops_e3() {
+        let x = Unit::<Vector3<f64>>::x();
+        assert_eq!(Some(Unit::z()), Unit::<Vector3<f64>>::axis(2));
+        assert!(Unit::<Vector3<f64>>::axis(3).is_none());
+        assert_eq!(1.0, x.x);
+        assert_eq!(Vector3::new(2.0, 0.0, 0.0), x * 2.0);
+        assert_eq!(-x, x.reflect(Unit::x()));
+        assert_relative_eq!(
+            Unit::<Vector3<f64>>::y(),
+            x.rotate(Rotation3::from_axis_angle(
+                &nalgebra::Unit::new_normalize(Vector3::z()),
+                FRAC_PI_2
+            )),
+            epsilon = 1e-9
+        );
+        assert!(Unit::try_from_inner_with_epsilon(Vector3::new(0.0, 1e-9, 0.0), 1e-6).is_none());
+        assert_eq!(
+            Some(Unit::y()),
+            Unit::try_from_inner_with_epsilon(Vector3::new(0.0, 2.0, 0.0), 1e-6)
+        );
+    }
+
+    #[test]
+    fn unit_refract_e2() {
+        let normal = Unit::<Vector<E2>>::y();
+        let incidence = Unit::try_from_inner(Vector::<E2>::new(1.0, -1.0)).unwrap();
+        assert_relative_eq!(
+            incidence,
+            incidence.refract(normal, 1.0).unwrap(),
+            epsilon = 1e-9
+        );
+        let transmission = incidence.refract(normal, 1.0 / 1.5).unwrap();
+        let (sine, _) = transmission.into_inner().into_xy();
+        assert_relative_eq!(
+            (1.0 / 1.5) * std::f64::consts::FRAC_1_SQRT_2,
+            sine,
+            epsilon = 1e-9,
+        );
+        assert!(incidence.refract(normal, 1.5).is_none());
+    }
+
+    #[test]
+    fn unit_slerp_e2() {
+        let x = Unit::<Vector<E2>>::x();
+        let y = Unit::<Vector<E2>>::y();
+        let unit = x.slerp(y, (1.0 / 3.0).into()).unwrap();
+        let (cosine, sine) = unit.into_inner().into_xy();
+        assert_relative_eq!(
+            std::f64::consts::FRAC_PI_6,
+            sine.atan2(cosine),
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(x, x.slerp(x, 0.5.into()).unwrap(), epsilon = 1e-9);
+        assert!(x.slerp(-x, 0.5.into()).is_none());
+        assert!(x.nlerp(-x, 0.5.into()).is_none());
+    }
+
+    #[test]
+    fn vector_reject_reflect_e2() {
+        let vector = Vector::<E2>::new(1.0, -1.0);
+        assert_eq!(
+            Vector::<E2>::new(0.0, -1.0),
+            vector.reject(Vector::<E2>::x()),
+        );
+        assert_eq!(Vector::<E2>::new(1.0, 0.0), vector.reject(Unit::y()));
+        assert_eq!(Vector::<E2>::new(1.0, 1.0), vector.reflect(Unit::y()));
     }
 }
@@ -0,0 +1,652 @@
+//! Bounding volume hierarchy.
+//!
+//! This module provides `Bvh`, an acceleration structure over items with an
+//! axis-aligned bounding box (see `AsAabb`). A `Bvh` partitions its items into
+//! a binary tree of nested `Aabb`s, such that queries need only consider the
+//! items within the bounding boxes that they intersect.
+//!
+//! Traversals produce _candidate_ items with bounding boxes that satisfy a
+//! query. Exact queries against the items themselves are left to the caller,
+//! typically via `Intersection`.
+//!
+//! # Examples
+//!
+//! Finding the spheres along a ray:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::query::bvh::Bvh;
+//! use theon::query::{Intersection, Ray, Sphere, Unit};
+//! use theon::space::EuclideanSpace;
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let bvh = Bvh::from_items(
+//!     (0..16)
+//!         .map(|n| Sphere::<E2> {
+//!             center: EuclideanSpace::from_xy(n as f64 * 4.0, 0.0),
+//!             radius: 1.0,
+//!         })
+//!         .collect(),
+//! );
+//! let ray = Ray::<E2> {
+//!     origin: EuclideanSpace::from_xy(-2.0, 0.0),
+//!     direction: Unit::x(),
+//! };
+//! let (index, t) = bvh
+//!     .cast_ray(&ray, |sphere| {
+//!         ray.intersection(sphere).map(|hit| hit.entry())
+//!     })
+//!     .unwrap();
+//! assert_eq!(0, index);
+//! ```
+
+#![cfg(feature = "std")]
+
+use decorum::cmp::IntrinsicOrd;
+use decorum::Infinite;
+use num::{Bounded, One, Signed, Zero};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter};
+
+use crate::query::{Aabb, Capsule, Culling, Frustum, Intersection, Ray, Segment, Sphere, Triangle};
+use crate::space::{Basis, EuclideanSpace, FiniteDimensional, Scalar, Vector, VectorSpace};
+
+/// The maximum number of items in a leaf of a `Bvh`.
+const LEAF_SIZE: usize = 4;
+
+/// Types with an axis-aligned bounding box.
+pub trait AsAabb {
+    type Space: EuclideanSpace;
+
+    fn aabb(&self) -> Aabb<Self::Space>;
+}
+
+impl<S> AsAabb for Aabb<S>
+where
+    S: EuclideanSpace,
+{
+    type Space = S;
+
+    fn aabb(&self) -> Aabb<S> {
+        *self
+    }
+}
+
+impl<S> AsAabb for Capsule<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    type Space = S;
+
+    fn aabb(&self) -> Aabb<S> {
+        let Segment { start, end } = self.segment;
+        let start = Sphere {
+            center: start,
+            radius: self.radius,
+        };
+        let end = Sphere {
+            center: end,
+            radius: self.radius,
+        };
+        start.aabb().union(&end.aabb())
+    }
+}
+
+impl<S> AsAabb for Segment<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    type Space = S;
+
+    fn aabb(&self) -> Aabb<S> {
+        point_aabb(self.start).union(&point_aabb(self.end))
+    }
+}
+
+impl<S> AsAabb for Sphere<S>
+where
+    S: EuclideanSpace,
+{
+    type Space = S;
+
+    fn aabb(&self) -> Aabb<S> {
+        let radius = Vector::<S>::canonical_basis()
+            .into_iter()
+            .fold(Vector::<S>::zero(), |radius, basis| {
+                radius + (basis * self.radius)
+            });
+        Aabb {
+            origin: self.center + -radius,
+            extent: radius * (Scalar::<S>::one() + One::one()),
+        }
+    }
+}
+
+impl<S> AsAabb for Triangle<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    type Space = S;
+
+    fn aabb(&self) -> Aabb<S> {
+        point_aabb(self.a)
+            .union(&point_aabb(self.b))
+            .union(&point_aabb(self.c))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    Branch { left: usize, right: usize },
+    Leaf { start: usize, end: usize },
+}
+
+#[derive(Clone)]
+struct Node<S>
+where
+    S: EuclideanSpace,
+{
+    aabb: Aabb<S>,
+    kind: Kind,
+}
+
+/// Partitions indices about their median along the longest axis of the bounds
+/// of the centroids of their boxes.
+///
+/// Returns the index of the median, which splits the indices into two
+/// non-empty halves.
+fn split<S>(boxes: &[Aabb<S>], indices: &mut [usize]) -> usize
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd + Signed,
+{
+    let centroid = |index: &usize| boxes[*index].centroid();
+    let bounds = Aabb::from_points(indices.iter().map(centroid)).expect("empty node");
+    let axis = bounds.longest_axis();
+    let component = |index: &usize| {
+        centroid(index)
+            .into_coordinates()
+            .scalar_component(axis)
+            .unwrap()
+    };
+    let middle = indices.len() / 2;
+    indices.select_nth_unstable_by(middle, |a, b| {
+        component(a)
+            .partial_cmp(&component(b))
+            .unwrap_or(Ordering::Equal)
+    });
+    middle
+}
+
+/// Builds the nodes of a subtree in parallel.
+///
+/// Nodes are ordered as they are by `Bvh::build`, with the indices of child
+/// nodes relative to the root of the subtree. `start` is the offset of
+/// `indices` into the indices of the `Bvh`.
+#[cfg(feature = "rayon")]
+fn par_build<S>(boxes: &[Aabb<S>], indices: &mut [usize], start: usize) -> Vec<Node<S>>
+where
+    S: EuclideanSpace,
+    Aabb<S>: Send + Sync,
+    Scalar<S>: IntrinsicOrd + Signed,
+{
+    let end = start + indices.len();
+    let aabb = Aabb::from_aabbs(indices.iter().map(|index| boxes[*index])).expect("empty node");
+    let mut nodes = vec![Node {
+        aabb,
+        kind: Kind::Leaf { start, end },
+    }];
+    if indices.len() <= LEAF_SIZE {
+        return nodes;
+    }
+    let middle = split(boxes, indices);
+    let (left, right) = indices.split_at_mut(middle);
+    let (left, right) = rayon::join(
+        || par_build(boxes, left, start),
+        || par_build(boxes, right, start + middle),
+    );
+    let offset = |offset: usize| {
+        move |mut node: Node<S>| {
+            if let Kind::Branch { left, right } = &mut node.kind {
+                *left += offset;
+                *right += offset;
+            }
+            node
+        }
+    };
+    nodes[0].kind = Kind::Branch {
+        left: 1,
+        right: 1 + left.len(),
+    };
+    let n = left.len();
+    nodes.extend(left.into_iter().map(offset(1)));
+    nodes.extend(right.into_iter().map(offset(1 + n)));
+    nodes
+}
+
+/// Bounding volume hierarchy.
+///
+/// A binary tree of `Aabb`s over items. The tree is built by recursively
+/// splitting items at the median of their centroids along the longest axis of
+/// the bounds of those centroids.
+///
+/// Items are identified by their index in the collection from which the `Bvh`
+/// is built. The order of items is never changed by a `Bvh`.
+#[derive(Clone)]
+pub struct Bvh<T>
+where
+    T: AsAabb,
+{
+    items: Vec<T>,
+    indices: Vec<usize>,
+    nodes: Vec<Node<T::Space>>,
+}
+
+impl<T> Bvh<T>
+where
+    T: AsAabb,
+    Scalar<T::Space>: IntrinsicOrd + Signed,
+{
+    /// Builds a `Bvh` over items.
+    pub fn from_items(items: Vec<T>) -> Self {
+        let mut bvh = Bvh {
+            indices: (0..items.len()).collect(),
+            items,
+            nodes: Vec::new(),
+        };
+        if !bvh.items.is_empty() {
+            let boxes: Vec<_> = bvh.items.iter().map(AsAabb::aabb).collect();
+            bvh.build(&boxes, 0, boxes.len());
+        }
+        bvh
+    }
+
+    /// Builds a `Bvh` over items in parallel.
+    ///
+    /// The resulting hierarchy is the same as that built by `from_items`, but
+    /// the bounding boxes of items are computed and subtrees are built on the
+    /// `rayon` thread pool. This requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn par_from_items(items: Vec<T>) -> Self
+    where
+        T: Send + Sync,
+        Aabb<T::Space>: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        let mut indices: Vec<_> = (0..items.len()).collect();
+        let nodes = if items.is_empty() {
+            Vec::new()
+        }
+        else {
+            let boxes: Vec<_> = items.par_iter().map(AsAabb::aabb).collect();
+            par_build(&boxes, &mut indices, 0)
+        };
+        Bvh {
+            items,
+            indices,
+            nodes,
+        }
+    }
+
+    fn build(&mut self, boxes: &[Aabb<T::Space>], start: usize, end: usize) -> usize {
+        let indices = &mut self.indices[start..end];
+        let aabb = Aabb::from_aabbs(indices.iter().map(|index| boxes[*index])).expect("empty node");
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            aabb,
+            kind: Kind::Leaf { start, end },
+        });
+        if indices.len() <= LEAF_SIZE {
+            return node;
+        }
+        let middle = split(boxes, indices);
+        let left = self.build(boxes, start, start + middle);
+        let right = self.build(boxes, start + middle, end);
+        self.nodes[node].kind = Kind::Branch { left, right };
+        node
+    }
+
+    /// Recomputes the bounding boxes of the hierarchy.
+    ///
+    /// This should be called after items are modified via `items_mut`. The
+    /// structure of the tree is not changed, so queries remain correct but may
+    /// become less efficient if items move far from their original positions.
+    /// In that case, rebuild the `Bvh` instead.
+    pub fn refit(&mut self) {
+        // Children are always pushed after their parents, so visiting nodes in
+        // reverse order refits children before their parents.
+        for node in (0..self.nodes.len()).rev() {
+            let aabb = match self.nodes[node].kind {
+                Kind::Branch { left, right } => {
+                    self.nodes[left].aabb.union(&self.nodes[right].aabb)
+                }
+                Kind::Leaf { start, end } => Aabb::from_aabbs(
+                    self.indices[start..end]
+                        .iter()
+                        .map(|index| self.items[*index].aabb()),
+                )
+                .expect("empty node"),
+            };
+            self.nodes[node].aabb = aabb;
+        }
+    }
+
+    /// Gets the bounding box of all items, if any.
+    pub fn aabb(&self) -> Option<Aabb<T::Space>> {
+        self.nodes.first().map(|node| node.aabb)
+    }
+
+    /// Traverses the items with bounding boxes that satisfy a predicate.
+    ///
+    /// The predicate is applied to the bounding boxes of both nodes and items.
+    /// If the predicate is not satisfied by the bounding box of a node, then
+    /// none of the items within that node are visited. Items are visited in
+    /// no particular order.
+    pub fn traverse<F>(&self, predicate: F) -> Traversal<'_, T, F>
+    where
+        F: FnMut(&Aabb<T::Space>) -> bool,
+    {
+        Traversal {
+            bvh: self,
+            stack: if self.nodes.is_empty() {
+                Vec::new()
+            }
+            else {
+                vec![0]
+            },
+            leaf: [].iter(),
+            predicate,
+        }
+    }
+
+    /// Traverses the items with bounding boxes that intersect a ray.
+    pub fn traverse_ray<'a>(
+        &'a self,
+        ray: &'a Ray<T::Space>,
+    ) -> Traversal<'a, T, impl 'a + FnMut(&Aabb<T::Space>) -> bool>
+    where
+        Scalar<T::Space>: Bounded + Infinite,
+    {
+        self.traverse(move |aabb| aabb.intersection(ray).is_some())
+    }
+
+    /// Traverses the items with bounding boxes that are not outside of a
+    /// frustum.
+    pub fn traverse_frustum<'a>(
+        &'a self,
+        frustum: &'a Frustum<T::Space>,
+    ) -> Traversal<'a, T, impl 'a + FnMut(&Aabb<T::Space>) -> bool>
+    where
+        T::Space: FiniteDimensional<N = typenum::U3>,
+    {
+        self.traverse(move |aabb| frustum.cull_aabb(aabb) != Culling::Outside)
+    }
+
+    /// Finds the nearest item along a ray.
+    ///
+    /// The function `f` computes the time of impact of the ray with an item,
+    /// if any. Nodes beyond the nearest time of impact found so far are not
+    /// visited. Returns the index of the nearest item and its time of impact.
+    pub fn cast_ray<F>(&self, ray: &Ray<T::Space>, mut f: F) -> Option<(usize, Scalar<T::Space>)>
+    where
+        Scalar<T::Space>: Bounded + Infinite,
+        F: FnMut(&T) -> Option<Scalar<T::Space>>,
+    {
+        let mut nearest: Option<(usize, Scalar<T::Space>)> = None;
+        let mut stack = if self.nodes.is_empty() {
+            Vec::new()
+        }
+        else {
+            vec![0]
+        };
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            match node.aabb.intersection(ray) {
+                Some(hit) if nearest.is_none_or(|(_, t)| hit.entry() <= t) => {}
+                _ => continue,
+            }
+            match node.kind {
+                Kind::Branch { left, right } => {
+                    stack.push(left);
+                    stack.push(right);
+                }
+                Kind::Leaf { start, end } => {
+                    for index in self.indices[start..end].iter() {
+                        if let Some(t) = f(&self.items[*index]) {
+                            if t >= Zero::zero() && nearest.is_none_or(|(_, nearest)| t < nearest) {
+                                nearest = Some((*index, t));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        nearest
+    }
+
+    /// Gets the pairs of items with overlapping bounding boxes.
+    ///
+    /// Each pair $(i, j)$ of indices is reported once with $i < j$.
+    pub fn overlapping_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (i, item) in self.items.iter().enumerate() {
+            let aabb = item.aabb();
+            pairs.extend(
+                self.traverse(|other| aabb.intersection(other).is_some())
+                    .map(|(j, _)| j)
+                    .filter(|j| i < *j)
+                    .map(|j| (i, j)),
+            );
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+}
+
+impl<T> Bvh<T>
+where
+    T: AsAabb,
+{
+    pub fn items(&self) -> &[T] {
+        self.items.as_slice()
+    }
+
+    /// Gets the items of the `Bvh` mutably.
+    ///
+    /// After modifying items, call `refit` to update the bounding boxes of the
+    /// hierarchy.
+    pub fn items_mut(&mut self) -> &mut [T] {
+        self.items.as_mut_slice()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> Debug for Bvh<T>
+where
+    T: AsAabb + Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Bvh")
+            .field("items", &self.items)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Traversal of a `Bvh`.
+///
+/// Produces the index and a reference to each visited item. See
+/// `Bvh::traverse`.
+pub struct Traversal<'a, T, F>
+where
+    T: AsAabb,
+{
+    bvh: &'a Bvh<T>,
+    stack: Vec<usize>,
+    leaf: std::slice::Iter<'a, usize>,
+    predicate: F,
+}
+
+impl<'a, T, F> Iterator for Traversal<'a, T, F>
+where
+    T: AsAabb,
+    F: FnMut(&Aabb<T::Space>) -> bool,
+{
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for index in self.leaf.by_ref() {
+                let item = &self.bvh.items[*index];
+                if (self.predicate)(&item.aabb()) {
+                    return Some((*index, item));
+                }
+            }
+            let node = &self.bvh.nodes[self.stack.pop()?];
+            if !(self.predicate)(&node.aabb) {
+                continue;
+            }
+            match node.kind {
+                Kind::Branch { left, right } => {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+                Kind::Leaf { start, end } => {
+                    self.leaf = self.bvh.indices[start..end].iter();
+                }
+            }
+        }
+    }
+}
+
+fn point_aabb<S>(point: S) -> Aabb<S>
+where
+    S: EuclideanSpace,
+{
+    Aabb {
+        origin: point,
+        extent: Vector::<S>::zero(),
+    }
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Perspective3, Point2, Point3, Vector2};
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::query::bvh::Bvh;
+    use crate::query::{Aabb, Frustum, Intersection, Ray, Sphere, Unit};
+    use crate::space::EuclideanSpace;
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    fn grid() -> Bvh<Aabb<E2>> {
+        Bvh::from_items(
+            (0..10)
+                .flat_map(|x| {
+                    (0..10).map(move |y| Aabb {
+                        origin: E2::new(x as f64 * 2.0, y as f64 * 2.0),
+                        extent: Vector2::new(1.0, 1.0),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn bvh_traverse_ray() {
+        let bvh = grid();
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(-1.0, 4.5),
+            direction: Unit::x(),
+        };
+        let mut indices: Vec<_> = bvh.traverse_ray(&ray).map(|(index, _)| index).collect();
+        indices.sort_unstable();
+        assert_eq!((0..10).map(|x| (x * 10) + 2).collect::<Vec<_>>(), indices);
+        let (index, t) = bvh
+            .cast_ray(&ray, |aabb| ray.intersection(aabb).map(|hit| hit.entry()))
+            .unwrap();
+        assert_eq!(2, index);
+        assert_eq!(1.0, t);
+    }
+
+    #[test]
+    fn bvh_overlapping_pairs_refit() {
+        let mut bvh = grid();
+        assert!(bvh.overlapping_pairs().is_empty());
+        bvh.items_mut()[0].extent = Vector2::new(2.5, 1.0);
+        bvh.refit();
+        assert_eq!(vec![(0, 10)], bvh.overlapping_pairs());
+        assert_eq!(E2::new(0.0, 0.0), bvh.aabb().unwrap().origin);
+        // The refit bounding boxes must enclose moved items.
+        bvh.items_mut()[99].origin = E2::new(40.0, 40.0);
+        bvh.refit();
+        assert_eq!(Vector2::new(41.0, 41.0), bvh.aabb().unwrap().extent);
+        let point = E2::new(40.5, 40.5);
+        assert_eq!(
+            vec![99],
+            bvh.traverse(|aabb| aabb.intersection(&point).is_some())
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn bvh_traverse_frustum() {
+        let bvh = Bvh::from_items(
+            (0..20)
+                .map(|z| Sphere::<E3> {
+                    center: E3::new(0.0, 0.0, -(z as f64)),
+                    radius: 0.25,
+                })
+                .collect(),
+        );
+        let frustum = Frustum::<E3>::from_view_projection(
+            &Perspective3::new(1.0, FRAC_PI_2, 1.0, 10.0).into_inner(),
+        )
+        .unwrap();
+        let mut indices: Vec<_> = bvh
+            .traverse_frustum(&frustum)
+            .map(|(index, _)| index)
+            .collect();
+        indices.sort_unstable();
+        assert_eq!((1..=10).collect::<Vec<_>>(), indices);
+        assert!(Bvh::<Sphere<E3>>::from_items(vec![])
+            .traverse_frustum(&frustum)
+            .next()
+            .is_none());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn bvh_par_from_items() {
+        let bvh = grid();
+        let par = Bvh::par_from_items(bvh.items().to_vec());
+        assert_eq!(bvh.indices, par.indices);
+        assert_eq!(bvh.aabb(), par.aabb());
+        let ray = Ray::<E2> {
+            origin: EuclideanSpace::from_xy(-1.0, -1.0),
+            direction: Unit::try_from_inner(Vector2::new(1.0, 1.0)).unwrap(),
+        };
+        assert_eq!(
+            bvh.traverse_ray(&ray)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+            par.traverse_ray(&ray)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+        );
+    }
+}
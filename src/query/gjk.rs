@@ -0,0 +1,1004 @@
+//! Support mappings and the GJK and EPA algorithms.
+//!
+//! This module provides `SupportFunction`, which describes a convex shape by
+//! its _support mapping_: a function that gets the point of the shape that is
+//! furthest along a given direction. Any pair of shapes with support mappings
+//! can be queried using the Gilbert-Johnson-Keerthi (GJK) algorithm, which
+//! determines if the shapes intersect and, if they do not, their closest
+//! points. When shapes intersect, the expanding polytope algorithm (EPA)
+//! determines their penetration depth and contact normal.
+//!
+//! Both algorithms operate on the Minkowski difference of shapes and are
+//! implemented for spaces with no more than three dimensions. Support mappings
+//! can also be combined into Minkowski sums and differences (see `MinkowskiSum`
+//! and `MinkowskiDifference`).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::{Point2, Vector2};
+//! use theon::query::gjk;
+//! use theon::query::{Aabb, Sphere};
+//! use theon::space::EuclideanSpace;
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let aabb = Aabb::<E2> {
+//!     origin: EuclideanSpace::origin(),
+//!     extent: Vector2::new(2.0, 2.0),
+//! };
+//! let sphere = Sphere::<E2> {
+//!     center: EuclideanSpace::from_xy(4.0, 1.0),
+//!     radius: 1.0,
+//! };
+//! assert!(!gjk::intersects(&aabb, &sphere));
+//!
+//! let (a, b) = gjk::closest_points(&aabb, &sphere).unwrap();
+//! ```
+
+use arrayvec::ArrayVec;
+#[cfg(feature = "std")]
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::fmt::{self, Debug, Formatter};
+use decorum::cmp::IntrinsicOrd;
+use decorum::Encoding;
+use num::{NumCast, One, Signed, Zero};
+use typenum::type_operators::Cmp;
+use typenum::{Less, U2, U4};
+
+use crate::adjunct::ZipMap;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::ops::Sqrt;
+use crate::ops::{Dot, Real};
+#[cfg(feature = "std")]
+use crate::query::Unit;
+use crate::query::{Aabb, ConvexPolygon, Obb, Segment, Triangle};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::query::{Capsule, Cone, Cylinder, Sphere};
+use crate::space::{
+    Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Rotation, Scalar, Vector, VectorSpace,
+};
+
+/// The maximum number of iterations performed by GJK and EPA.
+///
+/// Shapes with curved boundaries may require many iterations to converge
+/// exactly. Queries produce their best approximation after this many
+/// iterations.
+const MAX_ITERATIONS: usize = 64;
+
+/// Convex shapes with a support mapping.
+///
+/// See the module documentation.
+pub trait SupportFunction {
+    type Space: EuclideanSpace;
+
+    /// Gets the point of the shape that is furthest along a direction.
+    ///
+    /// The direction need not be normalized. If more than one point is furthest
+    /// along the direction, then any such point may be returned.
+    fn support(&self, direction: &Vector<Self::Space>) -> Self::Space;
+}
+
+impl<S> SupportFunction for S
+where
+    S: EuclideanSpace,
+{
+    type Space = S;
+
+    fn support(&self, _: &Vector<S>) -> S {
+        *self
+    }
+}
+
+impl<S> SupportFunction for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        let lower = self.lower_bound();
+        let extent = self.upper_bound() - lower;
+        lower
+            + direction.zip_map(extent, |x, extent| {
+                if x >= Zero::zero() {
+                    extent
+                }
+                else {
+                    Zero::zero()
+                }
+            })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SupportFunction for Capsule<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        offset(self.segment.support(direction), direction, self.radius)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SupportFunction for Cone<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        let rim = offset(
+            self.base,
+            &radial(self.base - self.apex, *direction),
+            self.radius,
+        );
+        furthest(direction, [self.apex, rim])
+    }
+}
+
+impl<S, V> SupportFunction for ConvexPolygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    type Space = S;
+
+    /// Gets the vertex of the polygon that is furthest along a direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polygon has no vertices.
+    fn support(&self, direction: &Vector<S>) -> S {
+        furthest(direction, self.vertices.as_ref().iter().copied())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SupportFunction for Cylinder<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        let axis = self.segment.end - self.segment.start;
+        offset(
+            self.segment.support(direction),
+            &radial(axis, *direction),
+            self.radius,
+        )
+    }
+}
+
+impl<S, R> SupportFunction for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        self.axes()
+            .enumerate()
+            .fold(self.center, |support, (index, axis)| {
+                let extent = self.extent.scalar_component(index).unwrap();
+                if axis.dot(*direction) >= Zero::zero() {
+                    support + (axis * extent)
+                }
+                else {
+                    support + -(axis * extent)
+                }
+            })
+    }
+}
+
+impl<S> SupportFunction for Segment<S>
+where
+    S: EuclideanSpace,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        furthest(direction, [self.start, self.end])
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<S> SupportFunction for Sphere<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        offset(self.center, direction, self.radius)
+    }
+}
+
+impl<S> SupportFunction for Triangle<S>
+where
+    S: EuclideanSpace,
+{
+    type Space = S;
+
+    fn support(&self, direction: &Vector<S>) -> S {
+        furthest(direction, [self.a, self.b, self.c])
+    }
+}
+
+/// Convex hull of a set of points.
+impl<S> SupportFunction for [S]
+where
+    S: EuclideanSpace,
+{
+    type Space = S;
+
+    /// Gets the point that is furthest along a direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the set of points is empty.
+    fn support(&self, direction: &Vector<S>) -> S {
+        furthest(direction, self.iter().copied())
+    }
+}
+
+/// Minkowski sum of convex shapes.
+///
+/// The Minkowski sum $A \oplus B$ is the set of points $a + b$ for all $a \in A$
+/// and $b \in B$. For example, the sum of a shape and a sphere inflates the
+/// shape by the radius of the sphere. The sum is translated by the position of
+/// the origin of `b`, so `b` is typically centered at the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinkowskiSum<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> SupportFunction for MinkowskiSum<A, B>
+where
+    A: SupportFunction,
+    B: SupportFunction<Space = A::Space>,
+{
+    type Space = A::Space;
+
+    fn support(&self, direction: &Vector<Self::Space>) -> Self::Space {
+        self.a.support(direction) + self.b.support(direction).into_coordinates()
+    }
+}
+
+/// Minkowski difference of convex shapes.
+///
+/// The Minkowski difference $A \ominus B$ is the set of points $a - b$ for all
+/// $a \in A$ and $b \in B$. The difference contains the origin if and only if
+/// the shapes intersect.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinkowskiDifference<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> SupportFunction for MinkowskiDifference<A, B>
+where
+    A: SupportFunction,
+    B: SupportFunction<Space = A::Space>,
+{
+    type Space = A::Space;
+
+    fn support(&self, direction: &Vector<Self::Space>) -> Self::Space {
+        Self::Space::from_coordinates(self.a.support(direction) - self.b.support(&-*direction))
+    }
+}
+
+/// Gets the axis-aligned bounding box of a convex shape.
+///
+/// The bounding box is formed from the support points along each axis in both
+/// directions and so is tight for any shape with an exact support mapping.
+pub fn aabb<T>(shape: &T) -> Aabb<T::Space>
+where
+    T: ?Sized + SupportFunction,
+    Scalar<T::Space>: IntrinsicOrd,
+{
+    Aabb::from_points(
+        Vector::<T::Space>::canonical_basis()
+            .into_iter()
+            .flat_map(|basis| {
+                IntoIterator::into_iter([shape.support(&basis), shape.support(&-basis)])
+            }),
+    )
+    .expect("no dimensions")
+}
+
+/// Penetration of intersecting shapes.
+///
+/// Translating the second shape by the normal scaled by the depth separates
+/// the shapes such that they are touching.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Penetration<S>
+where
+    S: EuclideanSpace,
+{
+    /// The penetration depth.
+    pub depth: Scalar<S>,
+    /// The contact normal, which points from the first shape into the second.
+    pub normal: Unit<Vector<S>>,
+    /// The deepest points of the first and second shapes.
+    pub points: (S, S),
+}
+
+#[cfg(feature = "std")]
+impl<S> Debug for Penetration<S>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: Debug,
+    Vector<S>: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("Penetration")
+            .field("depth", &self.depth)
+            .field("normal", &self.normal)
+            .field("points", &self.points)
+            .finish()
+    }
+}
+
+/// Determines if a pair of convex shapes intersects.
+pub fn intersects<A, B>(a: &A, b: &B) -> bool
+where
+    A: ?Sized + SupportFunction,
+    B: ?Sized + SupportFunction<Space = A::Space>,
+    Scalar<A::Space>: Encoding,
+    <A::Space as FiniteDimensional>::N: Cmp<U4, Output = Less>,
+{
+    matches!(gjk(a, b), Gjk::Intersecting(_))
+}
+
+/// Gets the closest points of a pair of convex shapes.
+///
+/// Returns the point of the first and second shape, respectively, or `None` if
+/// the shapes intersect.
+pub fn closest_points<A, B>(a: &A, b: &B) -> Option<(A::Space, A::Space)>
+where
+    A: ?Sized + SupportFunction,
+    B: ?Sized + SupportFunction<Space = A::Space>,
+    Scalar<A::Space>: Encoding,
+    <A::Space as FiniteDimensional>::N: Cmp<U4, Output = Less>,
+{
+    match gjk(a, b) {
+        Gjk::Separated(simplex, weights) => Some((
+            combine_points(simplex.iter().map(|vertex| vertex.a), &weights),
+            combine_points(simplex.iter().map(|vertex| vertex.b), &weights),
+        )),
+        Gjk::Intersecting(_) => None,
+    }
+}
+
+/// Gets the penetration of a pair of convex shapes.
+///
+/// Returns `None` if the shapes do not intersect or their Minkowski difference
+/// is degenerate (has no volume).
+#[cfg(feature = "std")]
+pub fn penetration<A, B>(a: &A, b: &B) -> Option<Penetration<A::Space>>
+where
+    A: ?Sized + SupportFunction,
+    B: ?Sized + SupportFunction<Space = A::Space>,
+    Scalar<A::Space>: Encoding + Sqrt,
+    <A::Space as FiniteDimensional>::N: Cmp<U4, Output = Less>,
+{
+    let n = A::Space::dimensions();
+    let mut vertices: Vec<_> = match gjk(a, b) {
+        Gjk::Separated(..) => {
+            return None;
+        }
+        Gjk::Intersecting(simplex) => simplex.into_iter().collect(),
+    };
+    // GJK may terminate with a degenerate simplex when the origin lies on its
+    // boundary. Expand such a simplex along the basis vectors.
+    for basis in Vector::<A::Space>::canonical_basis() {
+        for direction in IntoIterator::into_iter([basis, -basis]) {
+            if vertices.len() > n {
+                break;
+            }
+            let vertex = minkowski_support(a, b, &direction);
+            let mut vectors: ArrayVec<[_; 4]> = vertices.iter().map(|vertex| vertex.w).collect();
+            vectors.push(vertex.w);
+            if affine_nearest(&vectors).is_some() {
+                vertices.push(vertex);
+            }
+        }
+    }
+    if vertices.len() <= n {
+        return None;
+    }
+    // Facet normals are computed relative to a point in the interior of the
+    // polytope, which remains in its interior as it expands.
+    let interior = vertices
+        .iter()
+        .fold(Vector::<A::Space>::zero(), |sum, vertex| sum + vertex.w)
+        * (Scalar::<A::Space>::one() / NumCast::from(n + 1).unwrap());
+    let mut facets = (0..=n)
+        .map(|excluded| {
+            facet(
+                &vertices,
+                &interior,
+                (0..=n).filter(|index| *index != excluded).collect(),
+            )
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let convergence = Sqrt::sqrt(Scalar::<A::Space>::EPSILON);
+    let mut nearest = nearest_facet(&facets).clone();
+    for _ in 0..MAX_ITERATIONS {
+        let vertex = minkowski_support(a, b, &nearest.normal);
+        let support = nearest.normal.dot(vertex.w);
+        if support - nearest.distance <= convergence * support.abs() {
+            break;
+        }
+        // Remove the facets that are visible from the new vertex. The ridges
+        // of these facets that are not shared are the horizon. Facets that are
+        // coplanar with the new vertex are considered visible, which prevents
+        // degenerate facets.
+        let threshold = -(tolerance::<Scalar<A::Space>>() * support.abs());
+        let mut horizon: Vec<ArrayVec<[usize; 3]>> = Vec::new();
+        facets.retain(|facet| {
+            let visible = facet.normal.dot(vertex.w + -vertices[facet.indices[0]].w) >= threshold;
+            if visible {
+                for excluded in 0..facet.indices.len() {
+                    let mut ridge: ArrayVec<[usize; 3]> = facet
+                        .indices
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, _)| *index != excluded)
+                        .map(|(_, index)| *index)
+                        .collect();
+                    ridge.sort_unstable();
+                    if let Some(index) = horizon.iter().position(|other| *other == ridge) {
+                        horizon.swap_remove(index);
+                    }
+                    else {
+                        horizon.push(ridge);
+                    }
+                }
+            }
+            !visible
+        });
+        let index = vertices.len();
+        vertices.push(vertex);
+        // Stop with the current nearest facet if the polytope cannot be
+        // expanded, which may occur due to numeric error.
+        match horizon
+            .into_iter()
+            .map(|mut ridge| {
+                ridge.push(index);
+                facet(&vertices, &interior, ridge)
+            })
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(expansion) => {
+                facets.extend(expansion);
+            }
+            None => {
+                break;
+            }
+        }
+        nearest = nearest_facet(&facets).clone();
+    }
+    let point = nearest.normal * nearest.distance;
+    let weights = affine_nearest(
+        &nearest
+            .indices
+            .iter()
+            .map(|index| vertices[*index].w + -point)
+            .collect::<ArrayVec<[_; 4]>>(),
+    )?;
+    Some(Penetration {
+        depth: nearest.distance,
+        normal: Unit::from_inner_unchecked(nearest.normal),
+        points: (
+            combine_points(
+                nearest.indices.iter().map(|index| vertices[*index].a),
+                &weights,
+            ),
+            combine_points(
+                nearest.indices.iter().map(|index| vertices[*index].b),
+                &weights,
+            ),
+        ),
+    })
+}
+
+/// A point in the Minkowski difference of a pair of shapes.
+#[derive(Clone, Copy)]
+struct Vertex<S>
+where
+    S: EuclideanSpace,
+{
+    w: Vector<S>,
+    // The supporting points of the shapes from which `w` is derived.
+    a: S,
+    b: S,
+}
+
+type Simplex<S> = ArrayVec<[Vertex<S>; 4]>;
+
+type Weights<S> = ArrayVec<[Scalar<S>; 4]>;
+
+enum Gjk<S>
+where
+    S: EuclideanSpace,
+{
+    // The simplex and weights of the point nearest to the origin.
+    Separated(Simplex<S>, Weights<S>),
+    // The simplex that contains the origin. This simplex may be degenerate.
+    Intersecting(Simplex<S>),
+}
+
+fn gjk<A, B>(a: &A, b: &B) -> Gjk<A::Space>
+where
+    A: ?Sized + SupportFunction,
+    B: ?Sized + SupportFunction<Space = A::Space>,
+    Scalar<A::Space>: Encoding,
+    <A::Space as FiniteDimensional>::N: Cmp<U4, Output = Less>,
+{
+    let tolerance = tolerance::<Scalar<A::Space>>();
+    let mut simplex = Simplex::new();
+    simplex.push(minkowski_support(
+        a,
+        b,
+        &Vector::<A::Space>::canonical_basis_component(0).unwrap(),
+    ));
+    let mut weights = Weights::<A::Space>::new();
+    weights.push(One::one());
+    for _ in 0..MAX_ITERATIONS {
+        let v = combine(simplex.iter().map(|vertex| vertex.w), &weights);
+        let square = v.square_magnitude();
+        let scale = simplex
+            .iter()
+            .map(|vertex| vertex.w.square_magnitude())
+            .fold(Zero::zero(), |max, square| {
+                if square > max {
+                    square
+                }
+                else {
+                    max
+                }
+            });
+        if square <= tolerance * scale {
+            return Gjk::Intersecting(simplex);
+        }
+        let vertex = minkowski_support(a, b, &-v);
+        // Stop when the new vertex makes no progress toward the origin.
+        if (square - v.dot(vertex.w) <= tolerance * square)
+            || simplex.iter().any(|other| other.w == vertex.w)
+        {
+            return Gjk::Separated(simplex, weights);
+        }
+        simplex.push(vertex);
+        weights = reduce(&mut simplex);
+        if simplex.len() > A::Space::dimensions() {
+            return Gjk::Intersecting(simplex);
+        }
+    }
+    Gjk::Separated(simplex, weights)
+}
+
+/// Reduces a simplex to the vertices of the face nearest to the origin.
+///
+/// Returns the weights of the point nearest to the origin in the reduced
+/// simplex.
+fn reduce<S>(simplex: &mut Simplex<S>) -> Weights<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Encoding,
+{
+    // The nearest point lies in the relative interior of exactly one face, so
+    // consider every face (subset of vertices) and choose the nearest point in
+    // the interior of any face.
+    let mut nearest: Option<(Scalar<S>, usize, Weights<S>)> = None;
+    for mask in 1usize..(1 << simplex.len()) {
+        let vectors: ArrayVec<[Vector<S>; 4]> = simplex
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| mask & (1 << index) != 0)
+            .map(|(_, vertex)| vertex.w)
+            .collect();
+        if let Some(weights) = affine_nearest(&vectors) {
+            if weights.len() == 1 || weights.iter().all(|weight| *weight > Zero::zero()) {
+                let square = combine(vectors, &weights).square_magnitude();
+                if nearest
+                    .as_ref()
+                    .is_none_or(|(nearest, _, _)| square < *nearest)
+                {
+                    nearest = Some((square, mask, weights));
+                }
+            }
+        }
+    }
+    // Faces with one vertex always have a valid nearest point.
+    let (_, mask, weights) = nearest.unwrap();
+    let mut index = 0;
+    simplex.retain(|_| {
+        let is_retained = mask & (1 << index) != 0;
+        index += 1;
+        is_retained
+    });
+    weights
+}
+
+/// Gets the affine weights of the point nearest to the origin in the affine
+/// hull of vectors.
+///
+/// Returns `None` if the vectors are affinely dependent.
+fn affine_nearest<V>(vectors: &[V]) -> Option<ArrayVec<[V::Scalar; 4]>>
+where
+    V: InnerSpace,
+    V::Scalar: Encoding,
+{
+    let zero = V::Scalar::zero();
+    let n = vectors.len() - 1;
+    let origin = vectors[0];
+    let edges: ArrayVec<[V; 3]> = vectors[1..]
+        .iter()
+        .map(|vector| *vector + -origin)
+        .collect();
+    // Solve the normal equations of the edges as an augmented matrix.
+    let mut rows = [[zero; 4]; 3];
+    let mut scale = zero;
+    for (row, edge) in rows.iter_mut().zip(edges.iter()) {
+        for (entry, other) in row.iter_mut().zip(edges.iter()) {
+            *entry = edge.dot(*other);
+        }
+        row[n] = -origin.dot(*edge);
+        scale = scale + edge.square_magnitude();
+    }
+    let tolerance = tolerance::<V::Scalar>() * scale;
+    for column in 0..n {
+        let pivot = (column..n).fold(column, |pivot, row| {
+            if rows[row][column].abs() > rows[pivot][column].abs() {
+                row
+            }
+            else {
+                pivot
+            }
+        });
+        if rows[pivot][column].abs() <= tolerance {
+            return None;
+        }
+        rows.swap(column, pivot);
+        let pivot = rows[column];
+        for row in rows[(column + 1)..n].iter_mut() {
+            let factor = row[column] / pivot[column];
+            for (entry, x) in row.iter_mut().zip(pivot.iter()).skip(column) {
+                *entry = *entry - (factor * *x);
+            }
+        }
+    }
+    let mut solution = [zero; 3];
+    for row in (0..n).rev() {
+        let sum = ((row + 1)..n).fold(rows[row][n], |sum, column| {
+            sum - (rows[row][column] * solution[column])
+        });
+        solution[row] = sum / rows[row][row];
+    }
+    let mut weights = ArrayVec::new();
+    weights.push(
+        solution[..n]
+            .iter()
+            .fold(V::Scalar::one(), |weight, x| weight - *x),
+    );
+    weights.extend(solution[..n].iter().copied());
+    Some(weights)
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct Facet<S>
+where
+    S: EuclideanSpace,
+{
+    indices: ArrayVec<[usize; 3]>,
+    normal: Vector<S>,
+    // The distance from the origin to the affine hull of the facet.
+    distance: Scalar<S>,
+}
+
+#[cfg(feature = "std")]
+fn facet<S>(
+    vertices: &[Vertex<S>],
+    interior: &Vector<S>,
+    indices: ArrayVec<[usize; 3]>,
+) -> Option<Facet<S>>
+where
+    S: EuclideanSpace,
+    Scalar<S>: Encoding + Sqrt,
+{
+    // The normal is the direction from the interior point to the nearest point
+    // in the affine hull of the facet.
+    let vectors: ArrayVec<[Vector<S>; 4]> = indices
+        .iter()
+        .map(|index| vertices[*index].w + -*interior)
+        .collect();
+    let weights = affine_nearest(&vectors)?;
+    let normal = combine(vectors, &weights).normalize()?;
+    let distance = normal.dot(vertices[indices[0]].w);
+    Some(Facet {
+        indices,
+        normal,
+        distance,
+    })
+}
+
+#[cfg(feature = "std")]
+fn nearest_facet<S>(facets: &[Facet<S>]) -> &Facet<S>
+where
+    S: EuclideanSpace,
+{
+    facets
+        .iter()
+        .min_by(|a, b| {
+            a.distance
+                .partial_cmp(&b.distance)
+                .unwrap_or(Ordering::Equal)
+        })
+        .unwrap()
+}
+
+fn minkowski_support<A, B>(a: &A, b: &B, direction: &Vector<A::Space>) -> Vertex<A::Space>
+where
+    A: ?Sized + SupportFunction,
+    B: ?Sized + SupportFunction<Space = A::Space>,
+{
+    let (a, b) = (a.support(direction), b.support(&-*direction));
+    Vertex { w: a - b, a, b }
+}
+
+fn combine<V, I>(vectors: I, weights: &[V::Scalar]) -> V
+where
+    V: VectorSpace,
+    I: IntoIterator<Item = V>,
+{
+    vectors
+        .into_iter()
+        .zip(weights.iter().copied())
+        .fold(V::zero(), |sum, (vector, weight)| sum + (vector * weight))
+}
+
+// Weights must sum to one.
+fn combine_points<S, I>(points: I, weights: &[Scalar<S>]) -> S
+where
+    S: EuclideanSpace,
+    I: IntoIterator<Item = S>,
+{
+    S::from_coordinates(combine(
+        points.into_iter().map(|point| point.into_coordinates()),
+        weights,
+    ))
+}
+
+fn furthest<S, I>(direction: &Vector<S>, points: I) -> S
+where
+    S: EuclideanSpace,
+    I: IntoIterator<Item = S>,
+{
+    points
+        .into_iter()
+        .map(|point| (point, point.into_coordinates().dot(*direction)))
+        .reduce(|furthest, candidate| {
+            if candidate.1 > furthest.1 {
+                candidate
+            }
+            else {
+                furthest
+            }
+        })
+        .map(|(point, _)| point)
+        .expect("no points")
+}
+
+// Offsets a point along a direction by a distance. The point is not offset if
+// the direction has zero magnitude.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn offset<S>(point: S, direction: &Vector<S>, distance: Scalar<S>) -> S
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+{
+    match direction.normalize() {
+        Some(direction) => point + (direction * distance),
+        None => point,
+    }
+}
+
+// Gets the component of a direction that is orthogonal to an axis.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn radial<V>(axis: V, direction: V) -> V
+where
+    V: InnerSpace,
+{
+    let square = axis.square_magnitude();
+    if square > Zero::zero() {
+        direction + -(axis * (axis.dot(direction) / square))
+    }
+    else {
+        direction
+    }
+}
+
+fn tolerance<T>() -> T
+where
+    T: Encoding + NumCast + Real,
+{
+    T::EPSILON * NumCast::from(1024).unwrap()
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{Point2, Point3, Rotation2, Vector2, Vector3};
+    use std::f64::consts::FRAC_PI_4;
+
+    use crate::query::gjk::{self, MinkowskiDifference, MinkowskiSum, SupportFunction};
+    use crate::query::{Aabb, Capsule, ConvexPolygon, Obb, Segment, Sphere, Triangle};
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn gjk_closest_points_e2() {
+        let aabb = Aabb::<E2> {
+            origin: E2::new(-1.0, -1.0),
+            extent: Vector2::new(2.0, 2.0),
+        };
+        let sphere = Sphere::<E2> {
+            center: E2::new(3.0, 0.5),
+            radius: 1.0,
+        };
+        assert!(!gjk::intersects(&aabb, &sphere));
+        let (a, b) = gjk::closest_points(&aabb, &sphere).unwrap();
+        assert_abs_diff_eq!(1.0, a.x, epsilon = 1e-6);
+        assert_abs_diff_eq!(0.5, a.y, epsilon = 1e-6);
+        assert_abs_diff_eq!(2.0, b.x, epsilon = 1e-6);
+        assert_abs_diff_eq!(0.5, b.y, epsilon = 1e-6);
+
+        let obb = Obb::<E2, Rotation2<f64>> {
+            center: E2::new(0.0, 3.0),
+            extent: Vector2::new(1.0, 1.0),
+            rotation: Rotation2::new(FRAC_PI_4),
+        };
+        // The corner of the rotated box is nearest to the bounding box.
+        let (a, b) = gjk::closest_points(&aabb, &obb).unwrap();
+        assert_abs_diff_eq!(1.0, a.y, epsilon = 1e-6);
+        assert_abs_diff_eq!(0.0, b.x, epsilon = 1e-6);
+        assert_abs_diff_eq!(3.0 - 2.0f64.sqrt(), b.y, epsilon = 1e-6);
+
+        let polygon = ConvexPolygon::<E2, _>::from_vertices(vec![
+            E2::new(0.5, 0.5),
+            E2::new(4.0, 0.0),
+            E2::new(4.0, 4.0),
+        ]);
+        assert!(gjk::intersects(&aabb, &polygon));
+        assert!(gjk::closest_points(&aabb, &polygon).is_none());
+        assert!(gjk::intersects(&E2::new(3.0, 0.5), &sphere));
+    }
+
+    #[test]
+    fn gjk_closest_points_e3() {
+        let triangle = Triangle::<E3> {
+            a: E3::new(0.0, 0.0, 0.0),
+            b: E3::new(2.0, 0.0, 0.0),
+            c: E3::new(0.0, 2.0, 0.0),
+        };
+        let segment = Segment::<E3> {
+            start: E3::new(0.5, 0.5, 1.0),
+            end: E3::new(0.5, 0.5, 3.0),
+        };
+        let (a, b) = gjk::closest_points(&triangle, &segment).unwrap();
+        assert_eq!(E3::new(0.5, 0.5, 0.0), a);
+        assert_eq!(E3::new(0.5, 0.5, 1.0), b);
+
+        let capsule = Capsule::<E3> {
+            segment,
+            radius: 0.5,
+        };
+        assert!(!gjk::intersects(&triangle, &capsule));
+        let capsule = Capsule::<E3> {
+            segment,
+            radius: 1.5,
+        };
+        assert!(gjk::intersects(&triangle, &capsule));
+    }
+
+    #[test]
+    fn epa_penetration() {
+        let a = Aabb::<E3> {
+            origin: E3::new(0.0, 0.0, 0.0),
+            extent: Vector3::new(1.0, 1.0, 1.0),
+        };
+        let b = Aabb::<E3> {
+            origin: E3::new(0.75, 0.1, 0.2),
+            extent: Vector3::new(1.0, 1.0, 1.0),
+        };
+        let penetration = gjk::penetration(&a, &b).unwrap();
+        assert_abs_diff_eq!(0.25, penetration.depth, epsilon = 1e-6);
+        let normal = penetration.normal.into_inner();
+        assert_abs_diff_eq!(1.0, normal.x, epsilon = 1e-6);
+        assert_abs_diff_eq!(0.0, normal.y, epsilon = 1e-6);
+        assert_abs_diff_eq!(0.0, normal.z, epsilon = 1e-6);
+
+        let a = Sphere::<E3> {
+            center: E3::new(0.0, 0.0, 0.0),
+            radius: 1.0,
+        };
+        let b = Sphere::<E3> {
+            center: E3::new(0.0, 1.5, 0.0),
+            radius: 1.0,
+        };
+        let penetration = gjk::penetration(&a, &b).unwrap();
+        assert_abs_diff_eq!(0.5, penetration.depth, epsilon = 1e-3);
+        let normal = penetration.normal.into_inner();
+        assert_abs_diff_eq!(1.0, normal.y, epsilon = 1e-3);
+
+        let a = Sphere::<E2> {
+            center: E2::new(0.0, 0.0),
+            radius: 1.0,
+        };
+        let b = Aabb::<E2> {
+            origin: E2::new(-2.0, 0.5),
+            extent: Vector2::new(4.0, 1.0),
+        };
+        let penetration = gjk::penetration(&a, &b).unwrap();
+        assert_abs_diff_eq!(0.5, penetration.depth, epsilon = 1e-3);
+        let normal = penetration.normal.into_inner();
+        assert_abs_diff_eq!(1.0, normal.y, epsilon = 1e-3);
+        assert!(gjk::penetration(&E2::new(0.0, 3.0), &b).is_none());
+    }
+
+    #[test]
+    fn minkowski_support_e2() {
+        let points = [
+            E2::new(0.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(1.0, 1.0),
+            E2::new(1.0, 3.0),
+        ];
+        assert_eq!(E2::new(1.0, 3.0), points.support(&Vector2::new(0.0, 1.0)));
+
+        let sphere = Sphere::<E2> {
+            center: E2::origin(),
+            radius: 1.0,
+        };
+        let sum = MinkowskiSum {
+            a: Segment {
+                start: E2::new(0.0, 0.0),
+                end: E2::new(2.0, 0.0),
+            },
+            b: sphere,
+        };
+        let aabb = gjk::aabb(&sum);
+        assert_abs_diff_eq!(-1.0, aabb.lower_bound().x);
+        assert_abs_diff_eq!(-1.0, aabb.lower_bound().y);
+        assert_abs_diff_eq!(3.0, aabb.upper_bound().x);
+        assert_abs_diff_eq!(1.0, aabb.upper_bound().y);
+
+        let point = E2::new(1.0, 0.5);
+        assert!(!gjk::intersects(&points[..2], &point));
+        assert!(gjk::intersects(&sum, &point));
+        assert!(gjk::intersects(&points[..], &point));
+        let difference = MinkowskiDifference { a: sum, b: point };
+        assert!(gjk::intersects(&difference, &E2::origin()));
+    }
+}
@@ -0,0 +1,699 @@
+//! Uniform spatial hash grid.
+//!
+//! This module provides `HashGrid`, a broad-phase structure that partitions
+//! space into cubic cells of uniform size. Items are inserted with an
+//! axis-aligned bounding box (or a position) and are associated with every
+//! cell that their bounding box overlaps. Cells are keyed by their integer
+//! coordinates, which are given by an adjunct of `i64` items of the same shape
+//! as the vector of the space (see `Cell`).
+//!
+//! For items of roughly uniform size, a `HashGrid` is cheaper to build and
+//! query than a `Bvh`. Items that span many cells degrade performance, so the
+//! cell size should be similar to the size of typical items. Items with
+//! bounding boxes that are not finite or that span more than `MAX_CELLS` cells
+//! cannot be inserted.
+//!
+//! Like `Bvh`, queries produce _candidate_ items with bounding boxes that
+//! overlap a region. Exact queries against the items themselves are left to
+//! the caller.
+//!
+//! This module also provides functions for voxel and tile workflows that relate
+//! points and bounding boxes to cells without a `HashGrid`, such as `cell`,
+//! `cell_aabb`, and `cells_overlapping`. `lattice_points` enumerates the integer
+//! lattice points contained in a bounding box.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::query::grid::HashGrid;
+//! use theon::query::Sphere;
+//! use theon::space::EuclideanSpace;
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let grid = HashGrid::from_items(
+//!     2.0,
+//!     vec![
+//!         Sphere::<E2> {
+//!             center: EuclideanSpace::from_xy(0.0, 0.0),
+//!             radius: 1.0,
+//!         },
+//!         Sphere::<E2> {
+//!             center: EuclideanSpace::from_xy(1.5, 0.0),
+//!             radius: 1.0,
+//!         },
+//!         Sphere::<E2> {
+//!             center: EuclideanSpace::from_xy(8.0, 8.0),
+//!             radius: 1.0,
+//!         },
+//!     ],
+//! )
+//! .unwrap();
+//! assert_eq!(vec![(0, 1)], grid.overlapping_pairs());
+//! ```
+
+#![cfg(feature = "std")]
+
+use decorum::cmp::IntrinsicOrd;
+use num::{NumCast, Zero};
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+use crate::adjunct::{Adjunct, Converged, FromItems, IntoItems, Map};
+use crate::ops::Real;
+use crate::query::bvh::AsAabb;
+use crate::query::Aabb;
+use crate::space::{Basis, EuclideanSpace, Scalar, Vector, VectorSpace};
+
+/// Integer coordinates of a cell in a `HashGrid`.
+///
+/// A cell is an adjunct of `i64` items with the same shape as the vector of the
+/// space. For example, the cells of a space with `Vector2<f64>` vectors are
+/// `Vector2<i64>` when using `nalgebra`.
+pub type Cell<S> = <Vector<S> as Map<i64>>::Output;
+
+/// Maximum number of cells or lattice points that may be enumerated for a
+/// bounding box.
+///
+/// This bounds the number of cells that an item in a `HashGrid` may overlap as
+/// well as the number of items produced by `cells_overlapping` and
+/// `lattice_points`.
+pub const MAX_CELLS: u64 = 1 << 32;
+
+/// Uniform spatial hash grid.
+///
+/// Items are identified by the index at which they are inserted into the grid.
+/// Indices are stable until the grid is cleared.
+#[derive(Clone)]
+pub struct HashGrid<S, T>
+where
+    S: EuclideanSpace,
+    Vector<S>: Map<i64>,
+    Cell<S>: Clone + Eq + Hash,
+{
+    cell_size: Scalar<S>,
+    items: Vec<(Aabb<S>, T)>,
+    cells: HashMap<Cell<S>, Vec<usize>>,
+}
+
+impl<S, T> HashGrid<S, T>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+    Vector<S>: Map<i64>,
+    Cell<S>: Clone + Eq + FromItems + Hash,
+{
+    /// Constructs an empty grid with the given cell size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell size is not positive.
+    pub fn new(cell_size: Scalar<S>) -> Self {
+        assert!(cell_size > Zero::zero(), "non-positive cell size");
+        HashGrid {
+            cell_size,
+            items: Vec::new(),
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Constructs a grid with the given cell size from items with bounding
+    /// boxes.
+    ///
+    /// Returns `None` if any item cannot be inserted. See `insert`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell size is not positive.
+    pub fn from_items(cell_size: Scalar<S>, items: Vec<T>) -> Option<Self>
+    where
+        T: AsAabb<Space = S>,
+    {
+        let mut grid = HashGrid::new(cell_size);
+        for item in items {
+            grid.insert(item.aabb(), item)?;
+        }
+        Some(grid)
+    }
+
+    /// Inserts an item with the given bounding box and returns its index.
+    ///
+    /// Returns `None` and does not insert the item if its bounding box is not
+    /// finite or overlaps more than `MAX_CELLS` cells.
+    pub fn insert(&mut self, aabb: Aabb<S>, item: T) -> Option<usize> {
+        let index = self.items.len();
+        for cell in self.cells_overlapping(&aabb)? {
+            self.cells.entry(cell).or_default().push(index);
+        }
+        self.items.push((aabb, item));
+        Some(index)
+    }
+
+    /// Inserts an item at the given position and returns its index.
+    ///
+    /// Returns `None` and does not insert the item if the position is not
+    /// finite.
+    pub fn insert_at(&mut self, position: S, item: T) -> Option<usize> {
+        self.insert(
+            Aabb {
+                origin: position,
+                extent: Vector::<S>::zero(),
+            },
+            item,
+        )
+    }
+
+    /// Removes all items from the grid.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.cells.clear();
+    }
+
+    /// Gets the cell that contains a point.
+    ///
+    /// See the `cell` function.
+    pub fn cell(&self, point: &S) -> Cell<S> {
+        cell(point, self.cell_size)
+    }
+
+    pub fn cell_size(&self) -> Scalar<S> {
+        self.cell_size
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index).map(|(_, item)| item)
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.items.iter().map(|(_, item)| item).enumerate()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Gets the items with bounding boxes that overlap a region.
+    ///
+    /// Items are ordered by their index. Bounding boxes that touch the region
+    /// are considered overlapping. If the region is not finite or overlaps
+    /// more than `MAX_CELLS` cells, then every item is tested.
+    pub fn query_aabb(&self, aabb: &Aabb<S>) -> Vec<(usize, &T)> {
+        let cells = match self.cells_overlapping(aabb) {
+            Some(cells) => cells,
+            _ => {
+                return self
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (item, _))| item.overlaps(aabb))
+                    .map(|(index, (_, item))| (index, item))
+                    .collect();
+            }
+        };
+        let mut indices: Vec<_> = cells
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .cloned()
+            .filter(|index| self.items[*index].0.overlaps(aabb))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+            .into_iter()
+            .map(|index| (index, &self.items[index].1))
+            .collect()
+    }
+
+    /// Gets the items with bounding boxes that contain a point.
+    ///
+    /// Items are ordered by their index.
+    pub fn query_point(&self, point: &S) -> Vec<(usize, &T)> {
+        self.query_aabb(&Aabb {
+            origin: *point,
+            extent: Vector::<S>::zero(),
+        })
+    }
+
+    /// Gets the pairs of items with overlapping bounding boxes.
+    ///
+    /// Each pair is ordered such that the lesser index is first and pairs are
+    /// sorted.
+    pub fn overlapping_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = HashSet::new();
+        for indices in self.cells.values() {
+            for (n, i) in indices.iter().enumerate() {
+                for j in indices.iter().skip(n + 1) {
+                    let (i, j) = if i < j { (*i, *j) } else { (*j, *i) };
+                    if !pairs.contains(&(i, j)) && self.items[i].0.overlaps(&self.items[j].0) {
+                        pairs.insert((i, j));
+                    }
+                }
+            }
+        }
+        let mut pairs: Vec<_> = pairs.into_iter().collect();
+        pairs.sort_unstable();
+        pairs
+    }
+
+    fn cells_overlapping(&self, aabb: &Aabb<S>) -> Option<impl Iterator<Item = Cell<S>>> {
+        cells_overlapping(aabb, self.cell_size)
+    }
+}
+
+impl<S, T> Debug for HashGrid<S, T>
+where
+    S: Debug + EuclideanSpace,
+    Scalar<S>: Debug,
+    Vector<S>: Map<i64>,
+    Cell<S>: Clone + Eq + Hash,
+    T: Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("HashGrid")
+            .field("cell_size", &self.cell_size)
+            .field(
+                "items",
+                &self.items.iter().map(|(_, item)| item).collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+/// Gets the cell that contains a point in a grid with the given cell size.
+///
+/// Cells are half-open: a cell contains the points on its lower bound but not
+/// its upper bound. Cell coordinates saturate at the bounds of `i64`.
+pub fn cell<S>(point: &S, cell_size: Scalar<S>) -> Cell<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: Map<i64>,
+{
+    point.into_coordinates().map(|x| floor(x / cell_size))
+}
+
+/// Gets the bounding box of a cell in a grid with the given cell size.
+///
+/// The bounding box of a cell contains the points in the cell (see `cell`) and
+/// the points on its upper bound.
+pub fn cell_aabb<S>(cell: Cell<S>, cell_size: Scalar<S>) -> Aabb<S>
+where
+    S: EuclideanSpace,
+    Vector<S>: Map<i64>,
+    Cell<S>: IntoItems,
+{
+    let origin = Vector::<S>::canonical_basis()
+        .into_iter()
+        .zip(cell.into_items())
+        .fold(Vector::<S>::zero(), |origin, (basis, x)| {
+            origin + (basis * (scalar::<Scalar<S>>(x) * cell_size))
+        });
+    Aabb {
+        origin: S::from_coordinates(origin),
+        extent: Converged::converged(cell_size),
+    }
+}
+
+/// Gets an iterator over the cells that overlap a bounding box in a grid with
+/// the given cell size.
+///
+/// Cells that touch the bounding box are considered overlapping. Cells are
+/// ordered like an odometer, such that the first axis increments fastest.
+///
+/// Returns `None` if the bounding box is not finite or overlaps more than
+/// `MAX_CELLS` cells.
+pub fn cells_overlapping<S>(
+    aabb: &Aabb<S>,
+    cell_size: Scalar<S>,
+) -> Option<impl Iterator<Item = Cell<S>>>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+    Vector<S>: Map<i64>,
+    Cell<S>: FromItems,
+{
+    let lower = aabb.lower_bound().into_coordinates();
+    let upper = aabb.upper_bound().into_coordinates();
+    lattice(
+        (0..S::dimensions())
+            .map(|axis| {
+                (
+                    try_floor(lower.scalar_component(axis).unwrap() / cell_size),
+                    try_floor(upper.scalar_component(axis).unwrap() / cell_size),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Gets an iterator over the integer lattice points contained in a bounding
+/// box.
+///
+/// Lattice points on the boundary of the bounding box are contained. Points
+/// are ordered like an odometer, such that the first axis increments fastest.
+/// The iterator is empty if the bounding box contains no lattice points.
+///
+/// Returns `None` if the bounding box is not finite or contains more than
+/// `MAX_CELLS` lattice points.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::{Point2, Vector2};
+/// use theon::query::grid;
+/// use theon::query::Aabb;
+///
+/// let aabb = Aabb {
+///     origin: Point2::new(-0.5, 0.0),
+///     extent: Vector2::new(2.0, 1.5),
+/// };
+/// assert_eq!(
+///     vec![
+///         Vector2::new(0, 0),
+///         Vector2::new(1, 0),
+///         Vector2::new(0, 1),
+///         Vector2::new(1, 1),
+///     ],
+///     grid::lattice_points(&aabb).unwrap().collect::<Vec<_>>(),
+/// );
+/// ```
+pub fn lattice_points<S>(aabb: &Aabb<S>) -> Option<impl Iterator<Item = Cell<S>>>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+    Vector<S>: Map<i64>,
+    Cell<S>: FromItems,
+{
+    let lower = aabb.lower_bound().into_coordinates();
+    let upper = aabb.upper_bound().into_coordinates();
+    lattice(
+        (0..S::dimensions())
+            .map(|axis| {
+                (
+                    try_ceil(lower.scalar_component(axis).unwrap()),
+                    try_floor(upper.scalar_component(axis).unwrap()),
+                )
+            })
+            .collect(),
+    )
+}
+
+// Enumerates the integer coordinates in the inclusive range between the given
+// bounds like an odometer, incrementing the first axis fastest.
+//
+// Returns `None` if any bound is not representable by `i64` (such as the
+// bounds of a bounding box that is not finite) or if there are more than
+// `MAX_CELLS` coordinates. Otherwise, enumerating the coordinates may not
+// terminate.
+fn lattice<C>(bounds: Vec<(Option<i64>, Option<i64>)>) -> Option<impl Iterator<Item = C>>
+where
+    C: Adjunct<Item = i64> + FromItems,
+{
+    let (lower, upper): (Vec<_>, Vec<_>) = bounds
+        .into_iter()
+        .map(|(lower, upper)| Some((lower?, upper?)))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+    let count = lower
+        .iter()
+        .zip(upper.iter())
+        .fold(1u128, |count, (lower, upper)| {
+            count.saturating_mul(cmp::max(0, (*upper as i128) - (*lower as i128) + 1) as u128)
+        });
+    if count > MAX_CELLS as u128 {
+        return None;
+    }
+    let mut cell = if lower
+        .iter()
+        .zip(upper.iter())
+        .all(|(lower, upper)| lower <= upper)
+    {
+        Some(lower.clone())
+    }
+    else {
+        None
+    };
+    let cells = std::iter::from_fn(move || {
+        let current = cell.take()?;
+        let mut next = current.clone();
+        for axis in 0..next.len() {
+            if next[axis] < upper[axis] {
+                next[axis] += 1;
+                cell = Some(next);
+                break;
+            }
+            next[axis] = lower[axis];
+        }
+        Some(current)
+    });
+    Some(cells.map(|cell| C::from_items(cell).expect("cell dimensions")))
+}
+
+// Gets the greatest integer less than or equal to `x`, saturating at the bounds
+// of `i64`.
+fn floor<T>(x: T) -> i64
+where
+    T: NumCast + Real + PartialOrd + Zero,
+{
+    try_floor(x).unwrap_or(if x > Zero::zero() { i64::MAX } else { i64::MIN })
+}
+
+// Gets the greatest integer less than or equal to `x` or `None` if it is not
+// representable by `i64`, such as if `x` is not finite.
+fn try_floor<T>(x: T) -> Option<i64>
+where
+    T: NumCast + Real,
+{
+    <i64 as NumCast>::from(x.floor())
+}
+
+// Gets the least integer greater than or equal to `x` or `None` if it is not
+// representable by `i64`, such as if `x` is not finite.
+fn try_ceil<T>(x: T) -> Option<i64>
+where
+    T: NumCast + Real,
+{
+    <i64 as NumCast>::from(-(-x).floor())
+}
+
+fn scalar<T>(x: i64) -> T
+where
+    T: NumCast,
+{
+    <T as NumCast>::from(x).expect("cell not representable by scalar")
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+    use crate::query::grid::{self, HashGrid};
+    use crate::query::{Aabb, Intersection, Sphere};
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn grid_cells_e2() {
+        let mut grid = HashGrid::<E2, ()>::new(2.0);
+        assert_eq!(Vector2::new(0, 0), grid.cell(&E2::new(0.0, 1.9)));
+        assert_eq!(Vector2::new(-1, 2), grid.cell(&E2::new(-0.5, 4.0)));
+
+        let index = grid
+            .insert(
+                Aabb {
+                    origin: E2::new(-1.0, -1.0),
+                    extent: Vector2::new(4.0, 2.0),
+                },
+                (),
+            )
+            .unwrap();
+        // The bounding box overlaps cells on [-1, 1] and [-1, 0].
+        assert_eq!(6, grid.cells.len());
+        assert_eq!(
+            vec![index],
+            grid.query_point(&E2::new(2.5, 0.5))
+                .into_iter()
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+        );
+        assert!(grid.query_point(&E2::new(3.5, 0.5)).is_empty());
+    }
+
+    #[test]
+    fn grid_insert_unbounded_e2() {
+        let mut grid = HashGrid::<E2, ()>::new(1.0);
+        let index = grid.insert_at(E2::new(0.5, 0.5), ()).unwrap();
+
+        // Bounding boxes that are not finite or that span too many cells are
+        // rejected.
+        let unbounded = Aabb {
+            origin: E2::new(0.0, 0.0),
+            extent: Vector2::new(f64::INFINITY, 1.0),
+        };
+        assert_eq!(None, grid.insert(unbounded, ()));
+        assert_eq!(None, grid.insert_at(E2::new(f64::NAN, 0.0), ()));
+        assert_eq!(
+            None,
+            grid.insert(
+                Aabb {
+                    origin: E2::new(0.0, 0.0),
+                    extent: Vector2::new(1.0e300, 1.0e300),
+                },
+                (),
+            )
+        );
+        assert_eq!(1, grid.len());
+
+        // Queries of such regions test every item.
+        assert_eq!(
+            vec![index],
+            grid.query_aabb(&unbounded)
+                .into_iter()
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    #[test]
+    fn grid_lattice_e2() {
+        let point = E2::new(-0.5, 4.0);
+        let cell = grid::cell(&point, 2.0);
+        assert_eq!(Vector2::new(-1, 2), cell);
+        let aabb = grid::cell_aabb::<E2>(cell, 2.0);
+        assert_eq!(E2::new(-2.0, 4.0), aabb.origin);
+        assert_eq!(Vector2::new(2.0, 2.0), aabb.extent);
+        assert!(aabb.intersection(&point).is_some());
+        assert_eq!(
+            vec![cell],
+            grid::cells_overlapping(&grid::cell_aabb::<E2>(cell, 2.0).expand(-0.5), 2.0)
+                .unwrap()
+                .collect::<Vec<_>>(),
+        );
+
+        let aabb = Aabb::<E2> {
+            origin: E2::new(-1.0, 0.5),
+            extent: Vector2::new(2.0, 1.0),
+        };
+        assert_eq!(
+            vec![Vector2::new(-1, 1), Vector2::new(0, 1), Vector2::new(1, 1),],
+            grid::lattice_points(&aabb).unwrap().collect::<Vec<_>>(),
+        );
+        // Bounding boxes between lattice points contain no lattice points.
+        assert_eq!(
+            0,
+            grid::lattice_points(&Aabb::<E2> {
+                origin: E2::new(0.25, 0.25),
+                extent: Vector2::new(0.5, 0.5),
+            })
+            .unwrap()
+            .count()
+        );
+        assert_eq!(
+            27,
+            grid::lattice_points(&Aabb::<E3> {
+                origin: E3::new(-1.0, -1.0, -1.0),
+                extent: Vector3::new(2.0, 2.0, 2.0),
+            })
+            .unwrap()
+            .count()
+        );
+    }
+
+    #[test]
+    fn grid_lattice_unbounded_e2() {
+        let aabb = Aabb::<E2> {
+            origin: E2::new(0.0, 0.0),
+            extent: Vector2::new(f64::INFINITY, 1.0),
+        };
+        assert!(grid::lattice_points(&aabb).is_none());
+        assert!(grid::cells_overlapping(&aabb, 1.0).is_none());
+        let aabb = Aabb::<E2> {
+            origin: E2::new(f64::NAN, 0.0),
+            extent: Vector2::new(1.0, 1.0),
+        };
+        assert!(grid::lattice_points(&aabb).is_none());
+        // Finite bounding boxes with too many lattice points are rejected.
+        let aabb = Aabb::<E2> {
+            origin: E2::new(0.0, 0.0),
+            extent: Vector2::new(1.0e300, 1.0e300),
+        };
+        assert!(grid::lattice_points(&aabb).is_none());
+        assert_eq!(
+            121,
+            grid::cells_overlapping(&aabb, 1.0e299).unwrap().count()
+        );
+    }
+
+    #[test]
+    fn grid_query_e3() {
+        let spheres: Vec<_> = (0..64u32)
+            .map(|n| Sphere::<E3> {
+                center: E3::new(
+                    f64::from(n % 4) * 1.5,
+                    f64::from((n / 4) % 4) * 1.5,
+                    f64::from(n / 16) * 1.5,
+                ),
+                radius: 0.5 + f64::from(n % 3) * 0.2,
+            })
+            .collect();
+        let grid = HashGrid::from_items(1.0, spheres.clone()).unwrap();
+
+        // Compare against exhaustive queries of the bounding boxes.
+        let mut expected = Vec::new();
+        for i in 0..spheres.len() {
+            for j in (i + 1)..spheres.len() {
+                let (a, b) = (&spheres[i], &spheres[j]);
+                let overlap = (0..3)
+                    .all(|axis| (a.center[axis] - b.center[axis]).abs() <= a.radius + b.radius);
+                if overlap {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert!(!expected.is_empty());
+        assert_eq!(expected, grid.overlapping_pairs());
+
+        let region = Aabb::<E3> {
+            origin: E3::new(1.0, 1.0, 1.0),
+            extent: Vector3::new(1.0, 1.0, 1.0),
+        };
+        let expected: Vec<_> = spheres
+            .iter()
+            .enumerate()
+            .filter(|(_, sphere)| {
+                (0..3).all(|axis| {
+                    sphere.center[axis] + sphere.radius >= 1.0
+                        && sphere.center[axis] - sphere.radius <= 2.0
+                })
+            })
+            .map(|(index, _)| index)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(
+            expected,
+            grid.query_aabb(&region)
+                .into_iter()
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+        );
+    }
+}
@@ -0,0 +1,368 @@
+//! $k$-d tree.
+//!
+//! This module provides `KdTree`, a container that partitions items with a
+//! position in a `EuclideanSpace` (see `AsPosition`) for nearest neighbor and
+//! range queries.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::query::kdtree::KdTree;
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let tree = KdTree::from_items(vec![
+//!     E2::new(0.0, 0.0),
+//!     E2::new(1.0, 2.0),
+//!     E2::new(4.0, 1.0),
+//! ]);
+//! let (index, _) = tree.nearest(&E2::new(3.0, 1.0)).unwrap();
+//! assert_eq!(2, index);
+//! ```
+
+#![cfg(feature = "std")]
+
+use decorum::cmp::IntrinsicOrd;
+use num::Zero;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter};
+
+use crate::query::{Aabb, Contains};
+use crate::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, VectorSpace};
+use crate::AsPosition;
+
+/// $k$-d tree.
+///
+/// A balanced binary tree over items that splits space at the median position
+/// of its items along each axis in turn. Items are identified by their index in
+/// the collection from which the tree is built.
+///
+/// The tree is static: to insert, remove, or move items, rebuild the tree.
+#[derive(Clone)]
+pub struct KdTree<T>
+where
+    T: AsPosition,
+{
+    items: Vec<T>,
+    // Each range of indices in the tree is ordered such that the median index
+    // is the splitting item of that subtree.
+    indices: Vec<usize>,
+}
+
+impl<T> KdTree<T>
+where
+    T: AsPosition,
+{
+    /// Builds a tree over items.
+    pub fn from_items(items: Vec<T>) -> Self {
+        let mut tree = KdTree {
+            indices: (0..items.len()).collect(),
+            items,
+        };
+        let len = tree.indices.len();
+        tree.build(0, len, 0);
+        tree
+    }
+
+    fn build(&mut self, start: usize, end: usize, depth: usize) {
+        if end - start <= 1 {
+            return;
+        }
+        let axis = axis::<T::Position>(depth);
+        let items = &self.items;
+        let middle = (end - start) / 2;
+        self.indices[start..end].select_nth_unstable_by(middle, |a, b| {
+            let a = component(items[*a].as_position(), axis);
+            let b = component(items[*b].as_position(), axis);
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        });
+        self.build(start, start + middle, depth + 1);
+        self.build(start + middle + 1, end, depth + 1);
+    }
+
+    pub fn items(&self) -> &[T] {
+        self.items.as_slice()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Gets the item nearest to a point.
+    ///
+    /// Returns `None` if the tree is empty.
+    pub fn nearest(&self, point: &T::Position) -> Option<(usize, &T)> {
+        self.k_nearest(point, 1).into_iter().next()
+    }
+
+    /// Gets the $k$ items nearest to a point.
+    ///
+    /// Items are ordered by their distance from the point, nearest first.
+    /// Fewer than $k$ items are returned if the tree has fewer than $k$ items.
+    pub fn k_nearest(&self, point: &T::Position, k: usize) -> Vec<(usize, &T)> {
+        let mut nearest = Vec::with_capacity(k);
+        if k > 0 {
+            self.visit_nearest(point, k, 0, self.indices.len(), 0, &mut nearest);
+        }
+        nearest
+            .into_iter()
+            .map(|(index, _)| (index, &self.items[index]))
+            .collect()
+    }
+
+    fn visit_nearest(
+        &self,
+        point: &T::Position,
+        k: usize,
+        start: usize,
+        end: usize,
+        depth: usize,
+        nearest: &mut Vec<(usize, Scalar<T::Position>)>,
+    ) {
+        if start >= end {
+            return;
+        }
+        let middle = start + ((end - start) / 2);
+        let index = self.indices[middle];
+        let position = self.items[index].as_position();
+        let distance = (*position - *point).square_magnitude();
+        // Insert the item into the sorted neighbors if it is nearer than the
+        // farthest neighbor or there are fewer than `k` neighbors.
+        if nearest.len() < k || distance < nearest[nearest.len() - 1].1 {
+            let insertion = nearest
+                .iter()
+                .position(|(_, other)| distance < *other)
+                .unwrap_or(nearest.len());
+            nearest.insert(insertion, (index, distance));
+            nearest.truncate(k);
+        }
+        let axis = axis::<T::Position>(depth);
+        let offset = component(point, axis) - component(position, axis);
+        let (near, far) = if offset < Zero::zero() {
+            ((start, middle), (middle + 1, end))
+        }
+        else {
+            ((middle + 1, end), (start, middle))
+        };
+        self.visit_nearest(point, k, near.0, near.1, depth + 1, nearest);
+        // The far subtree can only contain nearer items if the splitting plane
+        // is nearer than the farthest neighbor.
+        if nearest.len() < k || (offset * offset) < nearest[nearest.len() - 1].1 {
+            self.visit_nearest(point, k, far.0, far.1, depth + 1, nearest);
+        }
+    }
+
+    /// Gets the items within a distance of a point.
+    ///
+    /// Items are visited in no particular order. Items at exactly the given
+    /// distance are included.
+    pub fn within_radius(
+        &self,
+        point: &T::Position,
+        radius: Scalar<T::Position>,
+    ) -> Vec<(usize, &T)> {
+        let square_radius = radius * radius;
+        let mut items = Vec::new();
+        self.visit_range(
+            0,
+            self.indices.len(),
+            0,
+            &mut |position| (*position - *point).square_magnitude() <= square_radius,
+            &mut |axis, split| {
+                let offset = component(point, axis) - split;
+                (offset <= radius, -offset <= radius)
+            },
+            &mut items,
+        );
+        items
+    }
+
+    /// Gets the items within an axis-aligned bounding box.
+    ///
+    /// Items are visited in no particular order. Items on the boundary of the
+    /// bounding box are included.
+    pub fn within_aabb(&self, aabb: &Aabb<T::Position>) -> Vec<(usize, &T)>
+    where
+        Scalar<T::Position>: IntrinsicOrd,
+    {
+        let lower = aabb.lower_bound();
+        let upper = aabb.upper_bound();
+        let mut items = Vec::new();
+        self.visit_range(
+            0,
+            self.indices.len(),
+            0,
+            &mut |position| aabb.contains(position),
+            &mut |axis, split| {
+                (
+                    component(&lower, axis) <= split,
+                    component(&upper, axis) >= split,
+                )
+            },
+            &mut items,
+        );
+        items
+    }
+
+    // The `overlaps` function determines if the query region overlaps the
+    // lower and upper half-spaces of a split.
+    fn visit_range<'a, F, G>(
+        &'a self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        contains: &mut F,
+        overlaps: &mut G,
+        items: &mut Vec<(usize, &'a T)>,
+    ) where
+        F: FnMut(&T::Position) -> bool,
+        G: FnMut(usize, Scalar<T::Position>) -> (bool, bool),
+    {
+        if start >= end {
+            return;
+        }
+        let middle = start + ((end - start) / 2);
+        let index = self.indices[middle];
+        let item = &self.items[index];
+        let position = item.as_position();
+        if contains(position) {
+            items.push((index, item));
+        }
+        let axis = axis::<T::Position>(depth);
+        let (lower, upper) = overlaps(axis, component(position, axis));
+        if lower {
+            self.visit_range(start, middle, depth + 1, contains, overlaps, items);
+        }
+        if upper {
+            self.visit_range(middle + 1, end, depth + 1, contains, overlaps, items);
+        }
+    }
+}
+
+impl<T> Debug for KdTree<T>
+where
+    T: AsPosition + Debug,
+{
+    fn fmt(&self, formatter: &mut Formatter) -> Result<(), fmt::Error> {
+        formatter
+            .debug_struct("KdTree")
+            .field("items", &self.items)
+            .finish_non_exhaustive()
+    }
+}
+
+fn axis<S>(depth: usize) -> usize
+where
+    S: FiniteDimensional,
+{
+    depth % S::dimensions()
+}
+
+fn component<S>(point: &S, axis: usize) -> Scalar<S>
+where
+    S: EuclideanSpace,
+{
+    point.into_coordinates().scalar_component(axis).unwrap()
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Point2, Point3, Vector2};
+
+    use crate::query::kdtree::KdTree;
+    use crate::query::Aabb;
+    use crate::space::InnerSpace;
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    fn points() -> Vec<E2> {
+        // A deterministic scattering of points.
+        (0..200u32)
+            .map(|n| {
+                let x = f64::from((n * 37) % 101) / 10.0;
+                let y = f64::from((n * 59) % 97) / 10.0;
+                E2::new(x, y)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn kdtree_nearest() {
+        let points = points();
+        let tree = KdTree::from_items(points.clone());
+        for query in [E2::new(0.0, 0.0), E2::new(5.05, 3.3), E2::new(-3.0, 12.0)] {
+            let mut expected: Vec<_> = points
+                .iter()
+                .map(|point| (point - query).square_magnitude())
+                .collect();
+            expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let (_, nearest) = tree.nearest(&query).unwrap();
+            assert_eq!(expected[0], (nearest - query).square_magnitude());
+            let nearest: Vec<_> = tree
+                .k_nearest(&query, 5)
+                .into_iter()
+                .map(|(_, point)| (point - query).square_magnitude())
+                .collect();
+            assert_eq!(&expected[..5], nearest.as_slice());
+        }
+        assert!(KdTree::<E3>::from_items(vec![])
+            .nearest(&E3::origin())
+            .is_none());
+        assert_eq!(
+            2,
+            KdTree::from_items(vec![E2::origin(); 2])
+                .k_nearest(&E2::origin(), 3)
+                .len()
+        );
+    }
+
+    #[test]
+    fn kdtree_range() {
+        let points = points();
+        let tree = KdTree::from_items(points.clone());
+        let query = E2::new(4.0, 6.0);
+        let mut indices: Vec<_> = tree
+            .within_radius(&query, 2.0)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        indices.sort_unstable();
+        let expected: Vec<_> = (0..points.len())
+            .filter(|index| (points[*index] - query).magnitude() <= 2.0)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(expected, indices);
+
+        let aabb = Aabb::<E2> {
+            origin: E2::new(2.0, 7.0),
+            extent: Vector2::new(3.0, -4.0),
+        };
+        let mut indices: Vec<_> = tree
+            .within_aabb(&aabb)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        indices.sort_unstable();
+        let expected: Vec<_> = (0..points.len())
+            .filter(|index| {
+                let point = points[*index];
+                (2.0..=5.0).contains(&point.x) && (3.0..=7.0).contains(&point.y)
+            })
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(expected, indices);
+    }
+}
@@ -0,0 +1,332 @@
+//! Batched ray queries.
+//!
+//! This module provides `RayPacket` and `AabbBundle`, which query several rays
+//! against one bounding box or one ray against several bounding boxes in a
+//! single call. Such batches are common when traversing bounding volume
+//! hierarchies with coherent rays or when testing the children of a wide node.
+//!
+//! The slab test is computed for each _lane_ of a batch one axis at a time:
+//! the components of each axis are gathered into arrays and each lane is then
+//! computed in a scalar loop. When the `simd` feature is enabled, batches of
+//! four or eight lanes of `f32` and two or four lanes of `f64` instead compute
+//! each axis using the SIMD types of `wide`. Unlike the SIMD scalars of the
+//! `scalar-wide` feature, each lane produces its own result, which is the same
+//! as the corresponding query of `Aabb` and `Ray` in either case.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::{Point2, Vector2};
+//! use theon::query::packet::RayPacket;
+//! use theon::query::{Aabb, Ray, Unit};
+//! use theon::space::EuclideanSpace;
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let aabb = Aabb::<E2> {
+//!     origin: E2::new(1.0, -1.0),
+//!     extent: Vector2::new(2.0, 2.0),
+//! };
+//! let packet = RayPacket::from_rays([
+//!     Ray::<E2> {
+//!         origin: E2::origin(),
+//!         direction: Unit::x(),
+//!     },
+//!     Ray::<E2> {
+//!         origin: E2::origin(),
+//!         direction: Unit::y(),
+//!     },
+//! ]);
+//! let [x, y] = packet.aabb_intersections(&aabb);
+//! assert_eq!(Some(1.0), x.map(|hit| hit.entry()));
+//! assert!(y.is_none());
+//! ```
+
+use approx::abs_diff_eq;
+use core::array;
+use decorum::cmp::IntrinsicOrd;
+use num::{Bounded, Signed, Zero};
+use typenum::type_operators::Cmp;
+use typenum::{Greater, U2};
+
+use crate::query::{Aabb, Intersection, Plane, PlaneRay, Ray, RaySolid};
+use crate::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector, VectorSpace};
+use crate::RealScalar;
+
+mod simd;
+
+/// Packet of rays that are queried together.
+///
+/// See the module documentation.
+#[derive(Clone, Copy, PartialEq)]
+pub struct RayPacket<S, const N: usize>
+where
+    S: EuclideanSpace,
+{
+    pub rays: [Ray<S>; N],
+}
+
+impl<S, const N: usize> RayPacket<S, N>
+where
+    S: EuclideanSpace,
+{
+    pub fn from_rays(rays: [Ray<S>; N]) -> Self {
+        RayPacket { rays }
+    }
+
+    /// Intersects each ray in the packet with a bounding box.
+    ///
+    /// The intersections are in the same order as the rays. See the
+    /// intersection of `Aabb` and `Ray`.
+    pub fn aabb_intersections(&self, aabb: &Aabb<S>) -> [Option<RaySolid<S>>; N]
+    where
+        Scalar<S>: 'static + Bounded + IntrinsicOrd + Signed,
+    {
+        slab(
+            self.rays.map(|ray| ray.origin),
+            self.rays.map(|ray| *ray.direction.get()),
+            [aabb.lower_bound(); N],
+            [aabb.upper_bound(); N],
+        )
+    }
+
+    /// Intersects each ray in the packet with a plane.
+    ///
+    /// The intersections are in the same order as the rays. See the
+    /// intersection of `Plane` and `Ray`.
+    pub fn plane_intersections(&self, plane: &Plane<S>) -> [Option<PlaneRay<S>>; N]
+    where
+        S: FiniteDimensional,
+        <S as FiniteDimensional>::N: Cmp<U2, Output = Greater>,
+        Scalar<S>: Signed,
+    {
+        self.rays.map(|ray| plane.intersection(&ray))
+    }
+}
+
+/// Bundle of axis-aligned bounding boxes that are queried together.
+///
+/// See the module documentation.
+#[derive(Clone, Copy, PartialEq)]
+pub struct AabbBundle<S, const N: usize>
+where
+    S: EuclideanSpace,
+{
+    pub aabbs: [Aabb<S>; N],
+}
+
+impl<S, const N: usize> AabbBundle<S, N>
+where
+    S: EuclideanSpace,
+{
+    pub fn from_aabbs(aabbs: [Aabb<S>; N]) -> Self {
+        AabbBundle { aabbs }
+    }
+
+    /// Intersects a ray with each bounding box in the bundle.
+    ///
+    /// The intersections are in the same order as the bounding boxes. See the
+    /// intersection of `Aabb` and `Ray`.
+    pub fn ray_intersections(&self, ray: &Ray<S>) -> [Option<RaySolid<S>>; N]
+    where
+        Scalar<S>: 'static + Bounded + IntrinsicOrd + Signed,
+    {
+        slab(
+            [ray.origin; N],
+            [*ray.direction.get(); N],
+            self.aabbs.map(|aabb| aabb.lower_bound()),
+            self.aabbs.map(|aabb| aabb.upper_bound()),
+        )
+    }
+}
+
+// Computes the slab test for each lane. This is the same test as
+// `Aabb::slab_intersection`, computed lane by lane for each axis.
+fn slab<S, const N: usize>(
+    origins: [S; N],
+    directions: [Vector<S>; N],
+    lowers: [S; N],
+    uppers: [S; N],
+) -> [Option<RaySolid<S>>; N]
+where
+    S: EuclideanSpace,
+    Scalar<S>: 'static + Bounded + IntrinsicOrd + Signed,
+{
+    let mut entry = [Scalar::<S>::min_value(); N];
+    let mut exit = [Scalar::<S>::max_value(); N];
+    for axis in 0..S::dimensions() {
+        let lanes = |vectors: [Vector<S>; N]| -> [Scalar<S>; N] {
+            vectors.map(|vector| vector.scalar_component(axis).unwrap())
+        };
+        let origin = lanes(origins.map(|origin| origin.into_coordinates()));
+        let direction = lanes(directions);
+        let lower = lanes(lowers.map(|lower| lower.into_coordinates()));
+        let upper = lanes(uppers.map(|upper| upper.into_coordinates()));
+        #[cfg(feature = "simd")]
+        if simd::slab(&mut entry, &mut exit, &origin, &direction, &lower, &upper) {
+            continue;
+        }
+        slab_axis(&mut entry, &mut exit, origin, direction, lower, upper);
+    }
+    array::from_fn(|lane| {
+        let (min, max) = (entry[lane], exit[lane]);
+        // See `Aabb::slab_intersection`.
+        if min <= max && max >= Zero::zero() && !min.is_undefined() && !max.is_undefined() {
+            Some(RaySolid::from_interval(min, max))
+        }
+        else {
+            None
+        }
+    })
+}
+
+// Updates the entry and exit times of each lane for an axis of the slab test.
+fn slab_axis<T, const N: usize>(
+    entry: &mut [T; N],
+    exit: &mut [T; N],
+    origin: [T; N],
+    direction: [T; N],
+    lower: [T; N],
+    upper: [T; N],
+) where
+    T: IntrinsicOrd + RealScalar,
+{
+    // Avoid computing `NaN`s. See `Aabb::slab_intersection`.
+    let pdiv = |a: T, b: T| {
+        if abs_diff_eq!(a, Zero::zero()) {
+            a
+        }
+        else {
+            a / b
+        }
+    };
+    let near: [T; N] = array::from_fn(|lane| pdiv(lower[lane] - origin[lane], direction[lane]));
+    let far: [T; N] = array::from_fn(|lane| pdiv(upper[lane] - origin[lane], direction[lane]));
+    for (((entry, exit), near), far) in entry
+        .iter_mut()
+        .zip(exit.iter_mut())
+        .zip(near.iter())
+        .zip(far.iter())
+    {
+        *entry = entry.max_or_undefined(&near.min_or_undefined(far));
+        *exit = exit.min_or_undefined(&near.max_or_undefined(far));
+    }
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Point3, Vector3};
+
+    use crate::query::packet::{AabbBundle, RayPacket};
+    use crate::query::{Aabb, Intersection, Plane, Ray, Unit};
+
+    type E3 = Point3<f64>;
+
+    fn aabbs() -> [Aabb<E3>; 4] {
+        [
+            Aabb {
+                origin: E3::new(2.0, -1.0, -1.0),
+                extent: Vector3::new(2.0, 2.0, 2.0),
+            },
+            Aabb {
+                origin: E3::new(-1.0, -1.0, -1.0),
+                extent: Vector3::new(2.0, 2.0, 2.0),
+            },
+            Aabb {
+                origin: E3::new(2.0, 2.0, 2.0),
+                extent: Vector3::new(1.0, 1.0, 1.0),
+            },
+            Aabb {
+                origin: E3::new(-4.0, -1.0, -1.0),
+                extent: Vector3::new(2.0, 2.0, 2.0),
+            },
+        ]
+    }
+
+    #[test]
+    fn aabb_bundle_ray_intersections() {
+        let ray = Ray::<E3> {
+            origin: E3::origin(),
+            direction: Unit::x(),
+        };
+        let bundle = AabbBundle::from_aabbs(aabbs());
+        for (aabb, hit) in aabbs().iter().zip(bundle.ray_intersections(&ray)) {
+            assert_eq!(ray.intersection(aabb), hit);
+        }
+    }
+
+    #[test]
+    fn ray_packet_intersections() {
+        let rays = [
+            Ray::<E3> {
+                origin: E3::new(0.0, 0.0, -5.0),
+                direction: Unit::z(),
+            },
+            Ray::<E3> {
+                origin: E3::new(0.0, 0.0, 5.0),
+                direction: Unit::z(),
+            },
+            Ray::<E3> {
+                origin: E3::new(0.0, 5.0, 0.0),
+                direction: Unit::x(),
+            },
+            Ray::<E3> {
+                origin: E3::new(0.5, 0.5, 0.5),
+                direction: Unit::y(),
+            },
+        ];
+        let packet = RayPacket::from_rays(rays);
+        for aabb in aabbs().iter() {
+            for (ray, hit) in rays.iter().zip(packet.aabb_intersections(aabb)) {
+                assert_eq!(ray.intersection(aabb), hit);
+            }
+        }
+        let plane = Plane::<E3> {
+            origin: E3::origin(),
+            normal: Unit::z(),
+        };
+        for (ray, hit) in rays.iter().zip(packet.plane_intersections(&plane)) {
+            assert_eq!(ray.intersection(&plane), hit);
+        }
+    }
+
+    // Packets of eight `f32` rays are computed using SIMD types when the `simd`
+    // feature is enabled.
+    #[test]
+    fn ray_packet_intersections_f32() {
+        let aabb = Aabb::<Point3<f32>> {
+            origin: Point3::new(-1.0, -1.0, -1.0),
+            extent: Vector3::new(2.0, 2.0, 2.0),
+        };
+        let rays: [_; 8] = core::array::from_fn(|n| {
+            let n = n as f32;
+            if n < 4.0 {
+                Ray::<Point3<f32>> {
+                    origin: Point3::new(n - 2.0, 0.5 * n - 1.0, -5.0),
+                    direction: Unit::z(),
+                }
+            }
+            else {
+                Ray::<Point3<f32>> {
+                    origin: Point3::new(n - 6.0, 0.5 * n - 2.5, 0.0),
+                    direction: Unit::x(),
+                }
+            }
+        });
+        let packet = RayPacket::from_rays(rays);
+        for (ray, hit) in rays.iter().zip(packet.aabb_intersections(&aabb)) {
+            assert_eq!(ray.intersection(&aabb), hit);
+        }
+        assert_eq!(
+            6,
+            packet
+                .aabb_intersections(&aabb)
+                .iter()
+                .filter(|hit| hit.is_some())
+                .count()
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! SIMD fast path of the slab test.
+//!
+//! When the `simd` feature is enabled, the slab test of packets and bundles
+//! first attempts the function of this module, which computes each axis using
+//! the SIMD types of `wide` for four or eight lanes of `f32` and two or four
+//! lanes of `f64`. Types are matched using `Any`, so this dispatch is resolved
+//! at compile time and other types are unaffected.
+//!
+//! The comparisons propagate `NaN`s like `IntrinsicOrd`, so this path produces
+//! the same results as the scalar loop.
+
+#![cfg(feature = "simd")]
+
+use core::any::Any;
+use wide::{f32x4, f32x8, f64x2, f64x4, CmpLe};
+
+/// Lanes that can be packed into a SIMD type.
+trait Packed: Any + Copy {
+    /// Updates the entry and exit times of each lane for an axis of the slab
+    /// test.
+    fn slab(
+        entry: &mut Self,
+        exit: &mut Self,
+        origin: Self,
+        direction: Self,
+        lower: Self,
+        upper: Self,
+    );
+
+    fn packed_slab<T>(
+        entry: &mut T,
+        exit: &mut T,
+        [origin, direction, lower, upper]: [&T; 4],
+    ) -> Option<()>
+    where
+        T: Any,
+    {
+        let origin = *downcast_ref::<_, Self>(origin)?;
+        let direction = *downcast_ref::<_, Self>(direction)?;
+        let lower = *downcast_ref::<_, Self>(lower)?;
+        let upper = *downcast_ref::<_, Self>(upper)?;
+        Self::slab(
+            downcast_mut::<_, Self>(entry)?,
+            downcast_mut::<_, Self>(exit)?,
+            origin,
+            direction,
+            lower,
+            upper,
+        );
+        Some(())
+    }
+}
+
+macro_rules! impl_packed {
+    ([$s:ident; $n:literal] => $w:ident $(,)?) => {
+        impl Packed for [$s; $n] {
+            fn slab(
+                entry: &mut Self,
+                exit: &mut Self,
+                origin: Self,
+                direction: Self,
+                lower: Self,
+                upper: Self,
+            ) {
+                // Avoid computing `NaN`s. See `Aabb::slab_intersection`.
+                let pdiv = |a: $w, b: $w| a.abs().cmp_le($w::splat($s::EPSILON)).blend(a, a / b);
+                // Gets the pairwise minimum and maximum of lanes, which are
+                // `NaN` if either lane is `NaN`.
+                let min_max = |a: $w, b: $w| {
+                    let nan = $w::splat($s::NAN);
+                    let is_undefined = a.is_nan() | b.is_nan();
+                    let is_ordered = a.cmp_le(b);
+                    (
+                        is_undefined.blend(nan, is_ordered.blend(a, b)),
+                        is_undefined.blend(nan, is_ordered.blend(b, a)),
+                    )
+                };
+                let (origin, direction) = ($w::new(origin), $w::new(direction));
+                let near = pdiv($w::new(lower) - origin, direction);
+                let far = pdiv($w::new(upper) - origin, direction);
+                let (near, far) = min_max(near, far);
+                *entry = min_max($w::new(*entry), near).1.to_array();
+                *exit = min_max($w::new(*exit), far).0.to_array();
+            }
+        }
+    };
+}
+impl_packed!([f32; 4] => f32x4);
+impl_packed!([f32; 8] => f32x8);
+impl_packed!([f64; 2] => f64x2);
+impl_packed!([f64; 4] => f64x4);
+
+/// Updates the entry and exit times of each lane for an axis of the slab test,
+/// if the lanes can be packed.
+///
+/// Returns `true` if the lanes have been packed and updated.
+pub fn slab<T>(entry: &mut T, exit: &mut T, origin: &T, direction: &T, lower: &T, upper: &T) -> bool
+where
+    T: Any,
+{
+    let lanes = [origin, direction, lower, upper];
+    <[f32; 4] as Packed>::packed_slab(entry, exit, lanes).is_some()
+        || <[f32; 8] as Packed>::packed_slab(entry, exit, lanes).is_some()
+        || <[f64; 2] as Packed>::packed_slab(entry, exit, lanes).is_some()
+        || <[f64; 4] as Packed>::packed_slab(entry, exit, lanes).is_some()
+}
+
+fn downcast_ref<T, U>(value: &T) -> Option<&U>
+where
+    T: Any,
+    U: Any,
+{
+    (value as &dyn Any).downcast_ref()
+}
+
+fn downcast_mut<T, U>(value: &mut T) -> Option<&mut U>
+where
+    T: Any,
+    U: Any,
+{
+    (value as &mut dyn Any).downcast_mut()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::packet::{self, simd};
+
+    // Compares the SIMD and scalar paths for an axis of the slab test,
+    // including lanes with zero and `NaN` components.
+    #[test]
+    fn simd_slab_agreement() {
+        let origin = [0.0f32, -1.0, 2.5, 0.0, f32::NAN, 1.0, -3.0, 0.5];
+        let direction = [1.0f32, 0.0, -0.5, 1.0e-9, 1.0, 0.0, 2.0, -1.0];
+        let lower = [1.0f32, -2.0, 0.0, -1.0, 0.0, 2.0, -1.0, 0.5];
+        let upper = [3.0f32, 0.0, 1.0, 1.0, 1.0, 3.0, 1.0, 1.5];
+        let (mut entry, mut exit) = ([-1.0f32; 8], [f32::MAX; 8]);
+        let (mut expected_entry, mut expected_exit) = (entry, exit);
+        assert!(simd::slab(
+            &mut entry, &mut exit, &origin, &direction, &lower, &upper
+        ));
+        packet::slab_axis(
+            &mut expected_entry,
+            &mut expected_exit,
+            origin,
+            direction,
+            lower,
+            upper,
+        );
+        for (lane, (entry, expected)) in entry.iter().zip(expected_entry.iter()).enumerate() {
+            assert_eq!(
+                expected.to_bits(),
+                entry.to_bits(),
+                "entry of lane {}",
+                lane
+            );
+        }
+        for (lane, (exit, expected)) in exit.iter().zip(expected_exit.iter()).enumerate() {
+            assert_eq!(expected.to_bits(), exit.to_bits(), "exit of lane {}", lane);
+        }
+
+        // Other types are not packed.
+        let lanes = [0.0f32; 3];
+        assert!(!simd::slab(
+            &mut [0.0f32; 3],
+            &mut [0.0f32; 3],
+            &lanes,
+            &lanes,
+            &lanes,
+            &lanes
+        ));
+    }
+}
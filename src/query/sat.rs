@@ -0,0 +1,374 @@
+//! Separating axis tests.
+//!
+//! This module provides overlap tests for convex polytopes based on the
+//! separating axis theorem (SAT). Convex shapes do not overlap if and only if
+//! there is an axis onto which their projections do not overlap. For polytopes
+//! in two dimensions, it is sufficient to test the face normals of both shapes.
+//! In three dimensions, the cross products of the edges of both shapes must
+//! also be tested. Shapes expose these candidate axes via `SeparatingAxes`.
+//!
+//! Shapes are projected onto axes via their `SupportFunction`. SAT is often
+//! cheaper than GJK for simple polytopes and also produces the _minimum
+//! translation vector_ (MTV) that separates overlapping shapes.
+//!
+//! These tests are exact in two and three dimensions. In higher dimensions,
+//! only face normals are tested, so shapes that do not overlap may be reported
+//! as overlapping.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::{Point2, Rotation2, Vector2};
+//! use theon::query::{sat, Obb, Triangle};
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let obb = Obb::<E2, Rotation2<f64>> {
+//!     center: E2::new(0.0, 0.0),
+//!     extent: Vector2::new(1.0, 1.0),
+//!     rotation: Rotation2::new(0.0),
+//! };
+//! let triangle = Triangle::<E2> {
+//!     a: E2::new(0.5, 0.0),
+//!     b: E2::new(3.0, 0.0),
+//!     c: E2::new(3.0, 2.0),
+//! };
+//! let mtv = sat::minimum_translation(&obb, &triangle).unwrap();
+//! assert_eq!(Vector2::new(0.5, 0.0), mtv);
+//! ```
+
+use approx::abs_diff_eq;
+use decorum::cmp::IntrinsicOrd;
+use num::{Signed, Zero};
+use typenum::U2;
+
+use crate::ops::Dot;
+use crate::query::gjk::SupportFunction;
+use crate::query::{cross, Aabb, ConvexPolygon, Obb, Triangle};
+use crate::space::{
+    Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Rotation, Scalar, Vector, VectorSpace,
+};
+
+/// Convex polytopes with a finite set of candidate separating axes.
+///
+/// See the module documentation.
+pub trait SeparatingAxes: SupportFunction {
+    /// Calls a function with each face normal of the shape.
+    ///
+    /// Normals need not be normalized nor unique.
+    fn for_each_normal<F>(&self, f: F)
+    where
+        F: FnMut(Vector<Self::Space>);
+
+    /// Calls a function with each edge direction of the shape.
+    ///
+    /// Directions need not be normalized nor unique. Edges are only used in
+    /// three dimensions.
+    fn for_each_edge<F>(&self, f: F)
+    where
+        F: FnMut(Vector<Self::Space>);
+}
+
+impl<S> SeparatingAxes for Aabb<S>
+where
+    S: EuclideanSpace,
+    Scalar<S>: IntrinsicOrd,
+{
+    fn for_each_normal<F>(&self, f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        Vector::<S>::canonical_basis().into_iter().for_each(f)
+    }
+
+    fn for_each_edge<F>(&self, f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        self.for_each_normal(f)
+    }
+}
+
+impl<S, V> SeparatingAxes for ConvexPolygon<S, V>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    V: AsRef<[S]>,
+{
+    fn for_each_normal<F>(&self, f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        self.edges().map(|edge| perp(edge.extent())).for_each(f)
+    }
+
+    fn for_each_edge<F>(&self, f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        self.edges().map(|edge| edge.extent()).for_each(f)
+    }
+}
+
+impl<S, R> SeparatingAxes for Obb<S, R>
+where
+    S: EuclideanSpace,
+    R: Clone + Rotation<S>,
+{
+    fn for_each_normal<F>(&self, f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        self.axes().for_each(f)
+    }
+
+    fn for_each_edge<F>(&self, f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        self.axes().for_each(f)
+    }
+}
+
+impl<S> SeparatingAxes for Triangle<S>
+where
+    S: EuclideanSpace,
+{
+    fn for_each_normal<F>(&self, mut f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        match S::dimensions() {
+            2 => self.for_each_edge(|edge| f(perp(edge))),
+            3 => f(cross(self.b - self.a, self.c - self.a)),
+            _ => {}
+        }
+    }
+
+    fn for_each_edge<F>(&self, f: F)
+    where
+        F: FnMut(Vector<S>),
+    {
+        IntoIterator::into_iter([self.b - self.a, self.c - self.b, self.a - self.c]).for_each(f)
+    }
+}
+
+/// Determines if a pair of convex polytopes overlaps.
+///
+/// Polytopes that touch are considered overlapping.
+pub fn overlaps<A, B>(a: &A, b: &B) -> bool
+where
+    A: SeparatingAxes,
+    B: SeparatingAxes<Space = A::Space>,
+    Scalar<A::Space>: Signed,
+{
+    let mut is_separated = false;
+    for_each_axis(a, b, |axis| {
+        if !is_separated {
+            is_separated = project(a, b, axis).is_none();
+        }
+    });
+    !is_separated
+}
+
+/// Gets the minimum translation vector of a pair of convex polytopes.
+///
+/// Translating the second polytope by the minimum translation vector separates
+/// the polytopes such that they are touching. Returns `None` if the polytopes
+/// do not overlap.
+pub fn minimum_translation<A, B>(a: &A, b: &B) -> Option<Vector<A::Space>>
+where
+    A: SeparatingAxes,
+    B: SeparatingAxes<Space = A::Space>,
+    Scalar<A::Space>: Signed,
+{
+    let mut is_separated = false;
+    let mut minimum: Option<Vector<A::Space>> = None;
+    for_each_axis(a, b, |axis| {
+        if !is_separated {
+            match project(a, b, axis) {
+                Some(translation) => {
+                    if minimum.is_none_or(|minimum| {
+                        translation.square_magnitude() < minimum.square_magnitude()
+                    }) {
+                        minimum = Some(translation);
+                    }
+                }
+                None => {
+                    is_separated = true;
+                }
+            }
+        }
+    });
+    if is_separated {
+        None
+    }
+    else {
+        minimum
+    }
+}
+
+// Calls a function with each non-degenerate candidate separating axis.
+fn for_each_axis<A, B, F>(a: &A, b: &B, mut f: F)
+where
+    A: SeparatingAxes,
+    B: SeparatingAxes<Space = A::Space>,
+    F: FnMut(Vector<A::Space>),
+{
+    let mut f = move |axis: Vector<A::Space>| {
+        if !abs_diff_eq!(axis.square_magnitude(), Zero::zero()) {
+            f(axis)
+        }
+    };
+    a.for_each_normal(&mut f);
+    b.for_each_normal(&mut f);
+    if A::Space::dimensions() == 3 {
+        a.for_each_edge(|edge| b.for_each_edge(|other| f(cross(edge, other))));
+    }
+}
+
+// Projects shapes onto an axis. Returns `None` if the axis separates the
+// shapes. Otherwise, returns the translation of the second shape along the
+// axis that separates the shapes.
+fn project<A, B>(a: &A, b: &B, axis: Vector<A::Space>) -> Option<Vector<A::Space>>
+where
+    A: SeparatingAxes,
+    B: SeparatingAxes<Space = A::Space>,
+{
+    let (a_min, a_max) = interval(a, &axis);
+    let (b_min, b_max) = interval(b, &axis);
+    let positive = a_max - b_min;
+    let negative = b_max - a_min;
+    // The projections are scaled by the magnitude of the axis, so dividing by
+    // its square magnitude yields a translation of the correct magnitude.
+    let square = axis.square_magnitude();
+    if positive < Zero::zero() || negative < Zero::zero() {
+        None
+    }
+    else if positive <= negative {
+        Some(axis * (positive / square))
+    }
+    else {
+        Some(-axis * (negative / square))
+    }
+}
+
+// Gets the interval of the projection of a shape onto an axis.
+fn interval<T>(shape: &T, axis: &Vector<T::Space>) -> (Scalar<T::Space>, Scalar<T::Space>)
+where
+    T: SupportFunction,
+{
+    let project = |point: T::Space| point.into_coordinates().dot(*axis);
+    (
+        project(shape.support(&-*axis)),
+        project(shape.support(axis)),
+    )
+}
+
+/// Computes a vector perpendicular to a vector in two dimensions.
+///
+/// This is computed from the components of the vector and so does not require
+/// `Perp`, but the vector must have exactly two dimensions.
+fn perp<V>(v: V) -> V
+where
+    V: Basis + VectorSpace,
+{
+    let component = |index| v.scalar_component(index).unwrap();
+    let (x, y) = (component(0), component(1));
+    V::canonical_basis()
+        .into_iter()
+        .zip([-y, x])
+        .fold(V::zero(), |output, (basis, x)| output + (basis * x))
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{Point2, Point3, Rotation3, Vector2, Vector3};
+    use std::f64::consts::FRAC_PI_4;
+
+    use crate::query::{gjk, sat};
+    use crate::query::{Aabb, ConvexPolygon, Obb, Triangle};
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn sat_polygon_e2() {
+        let square = ConvexPolygon::<E2, _>::from_vertices([
+            E2::new(0.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(2.0, 2.0),
+            E2::new(0.0, 2.0),
+        ]);
+        let diamond = ConvexPolygon::<E2, _>::from_vertices([
+            E2::new(2.5, 0.5),
+            E2::new(3.5, 1.5),
+            E2::new(2.5, 2.5),
+            E2::new(1.5, 1.5),
+        ]);
+        assert!(sat::overlaps(&square, &diamond));
+        assert!(gjk::intersects(&square, &diamond));
+        let translation = sat::minimum_translation(&square, &diamond).unwrap();
+        assert_abs_diff_eq!(0.5, translation.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(0.0, translation.y, epsilon = 1e-9);
+
+        // The translated polygon touches the square.
+        let diamond = ConvexPolygon::<E2, _>::from_vertices(
+            diamond.vertices.map(|vertex| vertex + translation),
+        );
+        assert!(sat::overlaps(&square, &diamond));
+        assert_abs_diff_eq!(
+            0.0,
+            sat::minimum_translation(&square, &diamond)
+                .unwrap()
+                .magnitude(),
+            epsilon = 1e-9
+        );
+
+        let aabb = Aabb::<E2> {
+            origin: E2::new(3.0, -1.0),
+            extent: Vector2::new(1.0, 1.0),
+        };
+        let triangle = Triangle::<E2> {
+            a: E2::new(2.0, -1.0),
+            b: E2::new(3.0, 2.0),
+            c: E2::new(1.0, 3.0),
+        };
+        assert!(!sat::overlaps(&square, &aabb));
+        assert!(sat::minimum_translation(&square, &aabb).is_none());
+        assert!(sat::overlaps(&square, &triangle));
+        // Only the normal of an edge of the triangle separates these shapes.
+        assert!(!sat::overlaps(&aabb, &triangle));
+    }
+
+    #[test]
+    fn sat_obb_triangle_e3() {
+        let a = Obb::<E3, _> {
+            center: E3::origin(),
+            extent: Vector3::new(1.0, 1.0, 1.0),
+            rotation: Rotation3::identity(),
+        };
+        // These boxes are only separated by an axis formed from their edges.
+        let b = Obb::<E3, _> {
+            center: E3::new(0.0, 1.8, 2.3),
+            extent: Vector3::new(1.0, 1.0, 1.0),
+            rotation: Rotation3::from_axis_angle(&Vector3::z_axis(), FRAC_PI_4)
+                * Rotation3::from_axis_angle(&Vector3::x_axis(), FRAC_PI_4),
+        };
+        assert!(!sat::overlaps(&a, &b));
+        assert!(sat::overlaps(&a, &a));
+
+        let triangle = Triangle::<E3> {
+            a: E3::new(-4.0, -4.0, 0.8),
+            b: E3::new(4.0, -4.0, 0.8),
+            c: E3::new(0.0, 4.0, 0.8),
+        };
+        let translation = sat::minimum_translation(&a, &triangle).unwrap();
+        assert_abs_diff_eq!(0.0, translation.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(0.0, translation.y, epsilon = 1e-9);
+        assert_abs_diff_eq!(0.2, translation.z, epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,280 @@
+//! Winding order and convexity of polygons.
+//!
+//! This module provides functions over sequences of points in two dimensions
+//! that form the closed chain of a polygon, where the last point is joined to
+//! the first point. Unlike `Polygon`, these functions accept any iterator of
+//! points and do not require a collection, which is convenient when processing
+//! meshes and paths.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::query::winding::{self, Winding};
+//!
+//! let points = [
+//!     Point2::new(0.0, 0.0),
+//!     Point2::new(0.0, 1.0),
+//!     Point2::new(1.0, 1.0),
+//!     Point2::new(1.0, 0.0),
+//! ];
+//! assert_eq!(Some(Winding::Clockwise), winding::winding(points));
+//! assert_eq!(
+//!     Some(Winding::Counterclockwise),
+//!     winding::winding(winding::reverse_winding(points)),
+//! );
+//! assert!(winding::is_convex(points));
+//! ```
+
+use core::iter;
+use num::{One, Zero};
+use typenum::U2;
+
+use crate::ops::Dot;
+use crate::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector, VectorSpace};
+
+/// Winding order of the points of a polygon.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Winding {
+    Clockwise,
+    Counterclockwise,
+}
+
+impl Winding {
+    pub fn reverse(self) -> Self {
+        match self {
+            Winding::Clockwise => Winding::Counterclockwise,
+            Winding::Counterclockwise => Winding::Clockwise,
+        }
+    }
+}
+
+/// Computes the signed area of the polygon formed by points.
+///
+/// The area is positive if the points are in counterclockwise order. Regions
+/// of self-intersecting polygons contribute to the area in proportion to
+/// their winding number. See `Polygon::area`.
+pub fn signed_area<S, I>(points: I) -> Scalar<S>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    I: IntoIterator<Item = S>,
+{
+    let mut points = points.into_iter();
+    let origin = match points.next() {
+        Some(origin) => origin,
+        None => return Zero::zero(),
+    };
+    // Points are relative to the first point, which improves precision. The
+    // edges incident to the first point do not contribute to the area.
+    let (area, _) = points.fold(
+        (Scalar::<S>::zero(), Vector::<S>::zero()),
+        |(area, start), point| {
+            let end = point - origin;
+            (area + start.perp_dot(end), end)
+        },
+    );
+    area / (Scalar::<S>::one() + One::one())
+}
+
+/// Determines the winding order of the polygon formed by points.
+///
+/// Returns `None` if the signed area of the polygon is zero, such as if there
+/// are fewer than three points or the points are collinear.
+pub fn winding<S, I>(points: I) -> Option<Winding>
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    I: IntoIterator<Item = S>,
+{
+    let area = signed_area(points);
+    if area > Zero::zero() {
+        Some(Winding::Counterclockwise)
+    }
+    else if area < Zero::zero() {
+        Some(Winding::Clockwise)
+    }
+    else {
+        None
+    }
+}
+
+/// Determines if the polygon formed by points is convex.
+///
+/// The points may be in either order, and consecutive collinear or coincident
+/// points are permitted. Returns `false` if the polygon is self-intersecting
+/// or if its area is zero, such as if there are fewer than three points.
+pub fn is_convex<S, I>(points: I) -> bool
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+    I: IntoIterator<Item = S>,
+{
+    let mut points = points.into_iter();
+    let (first, second) = match (points.next(), points.next()) {
+        (Some(first), Some(second)) => (first, second),
+        _ => return false,
+    };
+    let sign = |x: Scalar<S>| {
+        if x > Zero::zero() {
+            1
+        }
+        else if x < Zero::zero() {
+            -1
+        }
+        else {
+            0
+        }
+    };
+    // The polygon is convex if every turn is in the same direction and the
+    // direction of its edges along the $x$ axis changes at most twice. The
+    // latter excludes self-intersecting polygons that wind more than once,
+    // such as pentagrams.
+    let mut turn = 0;
+    let mut changes = 0;
+    let mut edge = second - first;
+    let mut direction = sign(edge.x());
+    let mut initial = direction;
+    let mut start = second;
+    // Visit each pair of adjacent edges, including the pair that joins the
+    // last edge to the first edge.
+    for end in points.chain(iter::once(first)).chain(iter::once(second)) {
+        let next = end - start;
+        match sign(edge.perp_dot(next)) {
+            // Edges that reverse direction form a spike.
+            0 if edge.dot(next) < Zero::zero() => return false,
+            0 => {}
+            x if turn == 0 => turn = x,
+            x if x != turn => return false,
+            _ => {}
+        }
+        match sign(next.x()) {
+            0 => {}
+            x if direction == 0 => {
+                direction = x;
+                initial = x;
+            }
+            x if x != direction => {
+                direction = x;
+                changes += 1;
+            }
+            _ => {}
+        }
+        edge = next;
+        start = end;
+    }
+    // Count the change between the last and first edges if the direction of
+    // the first edge along the $x$ axis is zero.
+    if direction != initial {
+        changes += 1;
+    }
+    turn != 0 && changes <= 2
+}
+
+/// Reverses the winding order of points.
+///
+/// The first point is unchanged and the remaining points are reversed, so the
+/// polygon formed by the points begins at the same point but travels in the
+/// opposite direction. The points of each edge are reversed, such that the
+/// edge from the $i^{th}$ point to the next point corresponds to the edge from
+/// the next point to the $i^{th}$ point in the output.
+pub fn reverse_winding<I>(points: I) -> impl Iterator<Item = I::Item>
+where
+    I: IntoIterator,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut points = points.into_iter();
+    points.next().into_iter().chain(points.rev())
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::Point2;
+
+    use crate::query::winding::{self, Winding};
+    use crate::query::Polygon;
+
+    type E2 = Point2<f64>;
+
+    fn square() -> [E2; 4] {
+        [
+            E2::new(0.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(2.0, 2.0),
+            E2::new(0.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn winding_square() {
+        let square = square();
+        assert_eq!(4.0, winding::signed_area(square));
+        assert_eq!(Some(Winding::Counterclockwise), winding::winding(square));
+        let reversed: Vec<_> = winding::reverse_winding(square).collect();
+        assert_eq!(square[0], reversed[0]);
+        assert_eq!(-4.0, winding::signed_area(reversed.iter().copied()));
+        assert_eq!(
+            Some(Winding::Clockwise),
+            winding::winding(reversed.iter().copied())
+        );
+
+        let mut polygon = Polygon::from_vertices(square);
+        assert_eq!(polygon.area(), winding::signed_area(square));
+        polygon.reverse_winding();
+        assert_eq!(reversed, polygon.vertices);
+        assert_eq!(
+            Some(Winding::Clockwise.reverse()),
+            Polygon::from_vertices(square).winding()
+        );
+
+        assert_eq!(None, winding::winding(square[..2].iter().copied()));
+        assert_eq!(0.0, winding::signed_area(Vec::<E2>::new()));
+    }
+
+    #[test]
+    fn convexity() {
+        let square = square();
+        assert!(winding::is_convex(square));
+        assert!(winding::is_convex(winding::reverse_winding(square)));
+        // Collinear and coincident points.
+        assert!(winding::is_convex([
+            E2::new(0.0, 0.0),
+            E2::new(1.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(2.0, 2.0),
+            E2::new(0.0, 2.0),
+        ]));
+        // A reflex vertex.
+        assert!(!winding::is_convex([
+            E2::new(0.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(1.0, 1.0),
+            E2::new(2.0, 2.0),
+            E2::new(0.0, 2.0),
+        ]));
+        // A spike.
+        assert!(!winding::is_convex([
+            E2::new(0.0, 0.0),
+            E2::new(3.0, 0.0),
+            E2::new(2.0, 0.0),
+            E2::new(2.0, 2.0),
+            E2::new(0.0, 2.0),
+        ]));
+        // A pentagram, which turns in the same direction at each vertex.
+        let pentagram = [
+            E2::new(0.0, 1.0),
+            E2::new(0.588, -0.809),
+            E2::new(-0.951, 0.309),
+            E2::new(0.951, 0.309),
+            E2::new(-0.588, -0.809),
+        ];
+        assert!(!winding::is_convex(pentagram));
+        assert!(!winding::is_convex(square[..2].iter().copied()));
+        assert!(!winding::is_convex([
+            E2::new(0.0, 0.0),
+            E2::new(1.0, 0.0),
+            E2::new(2.0, 0.0),
+        ]));
+    }
+}
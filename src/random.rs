@@ -0,0 +1,233 @@
+//! Uniform sampling of geometric distributions.
+//!
+//! This module provides functions that sample points and vectors uniformly
+//! from geometric regions, such as the interior of an `Aabb` or the surface of
+//! a `Sphere`, using a random number generator from `rand`. These functions
+//! are generic over `EuclideanSpace` and `InnerSpace`, so the same code can be
+//! used with any integrated crate.
+//!
+//! Samples are computed using `f64` and are converted to the scalar type of
+//! the space.
+//!
+//! This module requires the `rand` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate rand;
+//! # extern crate theon;
+//! #
+//! use nalgebra::{Point3, Vector3};
+//! use rand::rngs::SmallRng;
+//! use rand::SeedableRng;
+//! use theon::query::Aabb;
+//! use theon::random;
+//!
+//! let mut rng = SmallRng::seed_from_u64(0);
+//! let aabb = Aabb {
+//!     origin: Point3::new(0.0, 0.0, 0.0),
+//!     extent: Vector3::new(1.0, 2.0, 3.0),
+//! };
+//! let point = random::point_in_aabb(&aabb, &mut rng);
+//! assert!(point.z >= 0.0 && point.z < 3.0);
+//! ```
+
+#![cfg(feature = "rand")]
+
+use num::{NumCast, One};
+use rand::{Rng, RngExt};
+use std::f64::consts::TAU;
+
+use crate::adjunct::ZipMap;
+use crate::ops::Sqrt;
+use crate::query::{Aabb, Sphere, Triangle, Unit};
+use crate::space::{Basis, EuclideanSpace, InnerSpace, Scalar, Vector};
+
+/// Samples a point uniformly from the interior of an `Aabb`.
+pub fn point_in_aabb<S, R>(aabb: &Aabb<S>, rng: &mut R) -> S
+where
+    S: EuclideanSpace,
+    R: Rng + ?Sized,
+{
+    let offset = vector_with::<Vector<S>, _>(|| uniform(rng));
+    aabb.origin + aabb.extent.zip_map(offset, |extent, t| extent * t)
+}
+
+/// Samples a unit vector uniformly from the surface of the unit $n$-sphere.
+pub fn unit_vector<V, R>(rng: &mut R) -> Unit<V>
+where
+    V: Basis + InnerSpace,
+    V::Scalar: Sqrt,
+    R: Rng + ?Sized,
+{
+    // The normal distribution is spherically symmetric, so normalizing a vector
+    // of normally distributed components yields a uniformly distributed
+    // direction.
+    loop {
+        if let Some(unit) = Unit::try_from_inner(vector_with(|| normal(rng))) {
+            return unit;
+        }
+    }
+}
+
+/// Samples a point uniformly from the surface of a `Sphere`.
+pub fn point_on_sphere<S, R>(sphere: &Sphere<S>, rng: &mut R) -> S
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+    R: Rng + ?Sized,
+{
+    sphere.center + (unit_vector::<Vector<S>, _>(rng).into_inner() * sphere.radius)
+}
+
+/// Samples a point uniformly from the interior of a `Sphere`.
+pub fn point_in_sphere<S, R>(sphere: &Sphere<S>, rng: &mut R) -> S
+where
+    S: EuclideanSpace,
+    Scalar<S>: Sqrt,
+    R: Rng + ?Sized,
+{
+    let direction = unit_vector::<Vector<S>, _>(rng).into_inner();
+    // The volume of a ball is proportional to the $n^{th}$ power of its radius,
+    // so the distance from the center is the $n^{th}$ root of a uniform
+    // sample.
+    let distance = uniform::<f64, _>(rng).powf(1.0 / S::dimensions() as f64);
+    sphere.center + (direction * (sphere.radius * scalar(distance)))
+}
+
+/// Samples a point uniformly from the interior of a `Triangle`.
+pub fn point_in_triangle<S, R>(triangle: &Triangle<S>, rng: &mut R) -> S
+where
+    S: EuclideanSpace,
+    R: Rng + ?Sized,
+{
+    let mut u: Scalar<S> = uniform(rng);
+    let mut v: Scalar<S> = uniform(rng);
+    // Samples in the other half of the unit square are reflected into the
+    // triangle.
+    if u + v > One::one() {
+        u = Scalar::<S>::one() - u;
+        v = Scalar::<S>::one() - v;
+    }
+    let Triangle { a, b, c } = *triangle;
+    a + ((b - a) * u) + ((c - a) * v)
+}
+
+/// Constructs a vector with components produced by a function.
+fn vector_with<V, F>(mut f: F) -> V
+where
+    V: Basis + InnerSpace,
+    F: FnMut() -> V::Scalar,
+{
+    V::canonical_basis()
+        .into_iter()
+        .fold(V::zero(), |vector, basis| vector + (basis * f()))
+}
+
+/// Samples a scalar uniformly from $[0, 1)$.
+fn uniform<T, R>(rng: &mut R) -> T
+where
+    T: NumCast,
+    R: Rng + ?Sized,
+{
+    scalar(rng.random::<f64>())
+}
+
+/// Samples a scalar from the standard normal distribution.
+fn normal<T, R>(rng: &mut R) -> T
+where
+    T: NumCast,
+    R: Rng + ?Sized,
+{
+    // Box-Muller transform. The first sample is in $(0, 1]$ to avoid the
+    // logarithm of zero.
+    let u = 1.0 - rng.random::<f64>();
+    let v = rng.random::<f64>();
+    scalar((-2.0 * u.ln()).sqrt() * (TAU * v).cos())
+}
+
+fn scalar<T>(value: f64) -> T
+where
+    T: NumCast,
+{
+    T::from(value).expect("sample not representable by scalar")
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use nalgebra::{Point2, Point3, Vector3};
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use crate::query::{Aabb, Intersection, Sphere, Triangle};
+    use crate::random;
+    use crate::space::InnerSpace;
+
+    type E2 = Point2<f64>;
+    type E3 = Point3<f64>;
+
+    const SAMPLES: usize = 1000;
+
+    #[test]
+    fn sample_aabb_triangle() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let aabb = Aabb::<E3> {
+            origin: E3::new(-1.0, 2.0, 0.0),
+            extent: Vector3::new(2.0, 1.0, 4.0),
+        };
+        let triangle = Triangle::<E2> {
+            a: E2::new(0.0, 0.0),
+            b: E2::new(4.0, 0.0),
+            c: E2::new(0.0, 2.0),
+        };
+        let mut below = 0;
+        for _ in 0..SAMPLES {
+            assert!(aabb
+                .intersection(&random::point_in_aabb(&aabb, &mut rng))
+                .is_some());
+            let point = random::point_in_triangle(&triangle, &mut rng);
+            assert!(point.x >= 0.0 && point.y >= 0.0 && point.x + (2.0 * point.y) <= 4.0);
+            // The lower half of the triangle (below $y = 1$) has three quarters
+            // of its area.
+            if point.y < 1.0 {
+                below += 1;
+            }
+        }
+        assert!((below as f64 / SAMPLES as f64 - 0.75).abs() < 0.05);
+    }
+
+    #[test]
+    fn sample_sphere() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let sphere = Sphere::<E3> {
+            center: E3::new(1.0, -1.0, 2.0),
+            radius: 2.0,
+        };
+        let mut inner = 0;
+        let mut mean = Vector3::zeros();
+        for _ in 0..SAMPLES {
+            let unit = random::unit_vector::<Vector3<f64>, _>(&mut rng);
+            assert!((unit.get().magnitude() - 1.0).abs() < 1e-9);
+            mean += *unit.get() / SAMPLES as f64;
+
+            let point = random::point_on_sphere(&sphere, &mut rng);
+            assert!(((point - sphere.center).magnitude() - 2.0).abs() < 1e-9);
+
+            let point = random::point_in_sphere(&sphere, &mut rng);
+            let distance = (point - sphere.center).magnitude();
+            assert!(distance <= 2.0);
+            // The inner ball with half of the radius has one eighth of the
+            // volume.
+            if distance < 1.0 {
+                inner += 1;
+            }
+        }
+        assert!(mean.magnitude() < 0.1);
+        assert!((inner as f64 / SAMPLES as f64 - 0.125).abs() < 0.05);
+        assert_eq!(
+            random::point_in_sphere(&sphere, &mut SmallRng::seed_from_u64(1)),
+            random::point_in_sphere(&sphere, &mut SmallRng::seed_from_u64(1)),
+        );
+    }
+}
@@ -1,16 +1,24 @@
 //! Vector and affine spaces.
 
+#[cfg(any(feature = "std", feature = "libm"))]
 use approx::AbsDiffEq;
-use decorum::Real;
-use num::{NumCast, One, Zero};
-use std::ops::{Add, Mul, Neg, Sub};
-use typenum::consts::{U0, U1, U2, U3};
+use arrayvec::ArrayVec;
+use core::array;
+use core::ops::{Add, Mul, Neg, Sub};
+use decorum::R64;
+use num::{One, Signed, Zero};
+use typenum::consts::{U0, U1, U2, U3, U4};
 use typenum::type_operators::Cmp;
-use typenum::{Greater, NonZero, Unsigned};
+use typenum::{Const, Greater, NonZero, ToUInt, Unsigned};
 
 use crate::adjunct::{Adjunct, Converged, Extend, Fold, Truncate, ZipMap};
-use crate::ops::{Dot, Project};
-use crate::AsPosition;
+use crate::ops::{
+    Angle, Barycentric, Dot, Interpolate, Outer, Project, Real, Reject, SignedAngle, Sqrt,
+    Transcendental,
+};
+use crate::{AsPosition, RealScalar};
+
+pub mod consts;
 
 /// The scalar of a `EuclideanSpace`.
 pub type Scalar<S> = <Vector<S> as VectorSpace>::Scalar;
@@ -29,6 +37,26 @@ pub trait FiniteDimensional {
     }
 }
 
+impl<T> FiniteDimensional for (T, T) {
+    type N = U2;
+}
+
+impl<T> FiniteDimensional for (T, T, T) {
+    type N = U3;
+}
+
+impl<T> FiniteDimensional for (T, T, T, T) {
+    type N = U4;
+}
+
+impl<T, const N: usize> FiniteDimensional for [T; N]
+where
+    Const<N>: ToUInt,
+    typenum::U<N>: NonZero + Unsigned,
+{
+    type N = typenum::U<N>;
+}
+
 /// Describes the basis of a vector space.
 pub trait Basis: FiniteDimensional + Sized {
     type Bases: IntoIterator<Item = Self>;
@@ -84,6 +112,73 @@ pub trait Basis: FiniteDimensional + Sized {
     }
 }
 
+impl<T, const N: usize> Basis for [T; N]
+where
+    Self: FiniteDimensional,
+    T: One + Zero,
+{
+    type Bases = [Self; N];
+
+    fn canonical_basis() -> Self::Bases {
+        array::from_fn(|i| {
+            array::from_fn(|j| {
+                if i == j {
+                    One::one()
+                }
+                else {
+                    Zero::zero()
+                }
+            })
+        })
+    }
+}
+
+impl<T> Basis for (T, T)
+where
+    T: One + Zero,
+{
+    type Bases = [Self; 2];
+
+    fn canonical_basis() -> Self::Bases {
+        [(One::one(), Zero::zero()), (Zero::zero(), One::one())]
+    }
+}
+
+impl<T> Basis for (T, T, T)
+where
+    T: One + Zero,
+{
+    type Bases = [Self; 3];
+
+    fn canonical_basis() -> Self::Bases {
+        [
+            (One::one(), Zero::zero(), Zero::zero()),
+            (Zero::zero(), One::one(), Zero::zero()),
+            (Zero::zero(), Zero::zero(), One::one()),
+        ]
+    }
+}
+
+impl<T> Basis for (T, T, T, T)
+where
+    T: One + Zero,
+{
+    type Bases = [Self; 4];
+
+    fn canonical_basis() -> Self::Bases {
+        [
+            (One::one(), Zero::zero(), Zero::zero(), Zero::zero()),
+            (Zero::zero(), One::one(), Zero::zero(), Zero::zero()),
+            (Zero::zero(), Zero::zero(), One::one(), Zero::zero()),
+            (Zero::zero(), Zero::zero(), Zero::zero(), One::one()),
+        ]
+    }
+}
+
+// Arrays and tuples cannot implement `VectorSpace`, because it requires
+// operators like `Add` and `Neg` that cannot be implemented for foreign types.
+// Use the adjunct traits (and `Dot` for arrays) with these types instead.
+
 pub trait VectorSpace:
     Add<Output = Self>
     + Adjunct<Item = <Self as VectorSpace>::Scalar>
@@ -95,7 +190,7 @@ pub trait VectorSpace:
     + Neg<Output = Self>
     + ZipMap<<Self as VectorSpace>::Scalar, Output = Self>
 {
-    type Scalar: AbsDiffEq + NumCast + Real;
+    type Scalar: RealScalar;
 
     fn scalar_component(&self, index: usize) -> Option<Self::Scalar>;
 
@@ -141,6 +236,32 @@ pub trait VectorSpace:
         (self.x(), self.y(), self.z())
     }
 
+    /// Rotates a two-dimensional vector a quarter turn counterclockwise.
+    ///
+    /// For a vector $(x, y)$, this is the perpendicular vector $(-y, x)$.
+    fn perp(self) -> Self
+    where
+        Self: Basis + FiniteDimensional<N = U2>,
+    {
+        let (x, y) = self.into_xy();
+        Self::from_xy(-y, x)
+    }
+
+    /// Computes the perp-dot product of two-dimensional vectors.
+    ///
+    /// This is the dot product of the perpendicular of `self` and `other`, which
+    /// is the scalar two-dimensional cross product (see `Wedge`). It is positive
+    /// if `other` is counterclockwise from `self` and zero if the vectors are
+    /// parallel.
+    fn perp_dot(self, other: Self) -> Self::Scalar
+    where
+        Self: FiniteDimensional<N = U2>,
+    {
+        let (ax, ay) = self.into_xy();
+        let (bx, by) = other.into_xy();
+        (ax * by) - (ay * bx)
+    }
+
     fn x(&self) -> Self::Scalar
     where
         Self: FiniteDimensional,
@@ -173,6 +294,44 @@ pub trait VectorSpace:
         self.all(|x| x.is_zero())
     }
 
+    /// Computes the $L^1$ (Manhattan or taxicab) norm.
+    ///
+    /// This is the sum of the absolute values of the components.
+    fn l1_norm(self) -> Self::Scalar {
+        self.fold(Zero::zero(), |sum, x| sum + Signed::abs(&x))
+    }
+
+    /// Computes the $L^\infty$ (Chebyshev or maximum) norm.
+    ///
+    /// This is the greatest absolute value of the components.
+    fn linf_norm(self) -> Self::Scalar {
+        self.fold(Zero::zero(), |max, x| {
+            let x = Signed::abs(&x);
+            if x > max {
+                x
+            }
+            else {
+                max
+            }
+        })
+    }
+
+    /// Computes the $L^p$ norm.
+    ///
+    /// This is a norm only if `p` is greater than or equal to one. The $L^2$
+    /// norm is the Euclidean norm (magnitude).
+    fn p_norm(self, p: Self::Scalar) -> Self::Scalar
+    where
+        Self::Scalar: Transcendental,
+    {
+        Transcendental::powf(
+            self.fold(Zero::zero(), |sum, x| {
+                sum + Transcendental::powf(Signed::abs(&x), p)
+            }),
+            Self::Scalar::one() / p,
+        )
+    }
+
     fn from_homogeneous(vector: Self::ProjectiveSpace) -> Option<Self>
     where
         Self: Homogeneous,
@@ -195,27 +354,85 @@ pub trait VectorSpace:
         self.extend(Zero::zero())
     }
 
+    /// Computes the arithmetic mean of vectors.
+    ///
+    /// The mean is computed incrementally, which avoids the overflow and loss
+    /// of precision of summing many vectors. Returns `None` if there are no
+    /// vectors.
     fn mean<I>(vectors: I) -> Option<Self>
     where
         I: IntoIterator<Item = Self>,
     {
-        let mut vectors = vectors.into_iter();
-        if let Some(mut sum) = vectors.next() {
-            let mut n = 1usize;
-            for vector in vectors {
-                n += 1;
-                sum = sum + vector;
+        Self::weighted_mean(
+            vectors
+                .into_iter()
+                .map(|vector| (vector, Self::Scalar::one())),
+        )
+    }
+
+    /// Computes the weighted arithmetic mean of vectors.
+    ///
+    /// Weights should be non-negative. The mean is computed incrementally.
+    /// Returns `None` if the sum of the weights is zero, including when there
+    /// are no vectors.
+    fn weighted_mean<I>(vectors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Self, Self::Scalar)>,
+    {
+        let mut mean = Self::zero();
+        let mut total = Self::Scalar::zero();
+        for (vector, weight) in vectors {
+            total = total + weight;
+            if !total.is_zero() {
+                mean = mean + ((vector + -mean) * (weight / total));
             }
-            NumCast::from(n).map(move |n| sum * (Self::Scalar::one() / n))
+        }
+        if total.is_zero() {
+            None
         }
         else {
+            Some(mean)
+        }
+    }
+
+    /// Computes the arithmetic mean and covariance of vectors.
+    ///
+    /// The covariance is the (population) covariance matrix: the mean of the
+    /// outer products of the deviations of the vectors from their mean. Both
+    /// are computed incrementally in a single pass using Welford's algorithm,
+    /// which avoids the catastrophic cancellation of summing outer products
+    /// when the vectors are far from the origin. Returns `None` if there are no
+    /// vectors.
+    fn covariance<I, M>(vectors: I) -> Option<(Self, M)>
+    where
+        Self: Outer<Output = M>,
+        M: VectorSpace<Scalar = Self::Scalar>,
+        I: IntoIterator<Item = Self>,
+    {
+        let mut mean = Self::zero();
+        let mut sum = M::zero();
+        let mut count = Self::Scalar::zero();
+        for vector in vectors {
+            let previous = count;
+            count = count + One::one();
+            let deviation = vector + -mean;
+            mean = mean + (deviation * (Self::Scalar::one() / count));
+            sum = sum + (deviation.outer(deviation) * (previous / count));
+        }
+        if count.is_zero() {
             None
         }
+        else {
+            Some((mean, sum * (Self::Scalar::one() / count)))
+        }
     }
 }
 
 pub trait InnerSpace: Dot<Output = <Self as VectorSpace>::Scalar> + VectorSpace {
-    fn normalize(self) -> Option<Self> {
+    fn normalize(self) -> Option<Self>
+    where
+        Self::Scalar: Sqrt,
+    {
         let magnitude = self.magnitude();
         if magnitude != Zero::zero() {
             Some(self * (Self::Scalar::one() / magnitude))
@@ -229,8 +446,11 @@ pub trait InnerSpace: Dot<Output = <Self as VectorSpace>::Scalar> + VectorSpace
         Dot::dot(self, self)
     }
 
-    fn magnitude(self) -> Self::Scalar {
-        Real::sqrt(self.square_magnitude())
+    fn magnitude(self) -> Self::Scalar
+    where
+        Self::Scalar: Sqrt,
+    {
+        Sqrt::sqrt(self.square_magnitude())
     }
 }
 
@@ -247,10 +467,268 @@ where
     }
 }
 
+impl<T> Reject<T> for T
+where
+    T: InnerSpace,
+{
+    type Output = T;
+
+    fn reject(self, other: T) -> Self::Output {
+        self + -other.project(self)
+    }
+}
+
+/// Computes the angle between vectors.
+///
+/// Returns `None` if either vector has zero magnitude.
+impl<T> Angle<T> for T
+where
+    T: InnerSpace,
+    T::Scalar: Sqrt + Transcendental,
+{
+    type Output = Option<T::Scalar>;
+
+    fn angle(self, other: T) -> Self::Output {
+        let magnitudes = self.square_magnitude() * other.square_magnitude();
+        if magnitudes.is_zero() {
+            None
+        }
+        else {
+            // Rounding errors may place the cosine outside of the domain of
+            // `acos`, so it is clamped.
+            let cosine = self.dot(other) / Sqrt::sqrt(magnitudes);
+            Some(Transcendental::acos(num::clamp(
+                cosine,
+                -T::Scalar::one(),
+                T::Scalar::one(),
+            )))
+        }
+    }
+}
+
+/// Computes the signed angle between vectors.
+///
+/// Returns `None` if either vector has zero magnitude.
+impl<T> SignedAngle<T> for T
+where
+    T: FiniteDimensional<N = U2> + InnerSpace,
+    T::Scalar: Transcendental,
+{
+    type Output = Option<T::Scalar>;
+
+    fn signed_angle(self, other: T) -> Self::Output {
+        if self.square_magnitude().is_zero() || other.square_magnitude().is_zero() {
+            None
+        }
+        else {
+            let (ax, ay) = self.into_xy();
+            let (bx, by) = other.into_xy();
+            Some(Transcendental::atan2(
+                (ax * by) - (ay * bx),
+                self.dot(other),
+            ))
+        }
+    }
+}
+
+/// Orthonormalizes vectors using the modified Gram-Schmidt process.
+///
+/// The vectors are orthonormalized in place and in order, such that the first
+/// vector retains its direction and each subsequent vector is orthogonal to all
+/// vectors before it. Returns `None` if the vectors are linearly dependent (or
+/// nearly so), in which case the vectors are only partially orthonormalized.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate theon;
+/// #
+/// use nalgebra::Vector3;
+/// use theon::space::orthonormalize;
+///
+/// let mut vectors = [Vector3::new(1.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0)];
+/// let basis = orthonormalize(&mut vectors).unwrap();
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[must_use]
+pub fn orthonormalize<S>(vectors: &mut [S]) -> Option<&[S]>
+where
+    S: InnerSpace,
+    S::Scalar: Sqrt,
+{
+    for i in 0..vectors.len() {
+        let (basis, remaining) = vectors.split_at_mut(i + 1);
+        let vector = &mut basis[i];
+        if vector
+            .square_magnitude()
+            .abs_diff_eq(&Zero::zero(), S::Scalar::default_epsilon())
+        {
+            return None;
+        }
+        *vector = vector.normalize()?;
+        for other in remaining {
+            *other = *other + -(*vector * other.dot(*vector));
+        }
+    }
+    Some(vectors)
+}
+
+/// Type with a dimension that is only known at runtime.
+///
+/// This is the runtime counterpart of `FiniteDimensional`. See
+/// `DynVectorSpace`.
+pub trait DynDimensional {
+    fn dimensions(&self) -> usize;
+}
+
+/// Vector space with a dimension that is only known at runtime.
+///
+/// This is the runtime counterpart of `VectorSpace` and `InnerSpace` for types
+/// like `DVector` and `Vec`. Because the dimensions of vectors may differ,
+/// operations on more than one vector are fallible and return `None` if the
+/// dimensions of the vectors differ.
+///
+/// # Examples
+///
+/// ```rust
+/// use theon::space::{DynDimensional, DynVectorSpace};
+///
+/// let a = vec![1.0, 2.0, 3.0];
+/// let b = vec![1.0, 1.0, 1.0];
+/// assert_eq!(Some(6.0), a.checked_dot(&b));
+/// assert_eq!(None, a.checked_add(vec![1.0]));
+/// assert_eq!(3, Vec::<f64>::zero(3).dimensions());
+/// ```
+pub trait DynVectorSpace: Clone + DynDimensional + Sized {
+    type Scalar: RealScalar;
+
+    /// Constructs the zero vector with the given dimensions.
+    fn zero(dimensions: usize) -> Self;
+
+    fn scalar_component(&self, index: usize) -> Option<Self::Scalar>;
+
+    fn map_scalars<F>(self, f: F) -> Self
+    where
+        F: FnMut(Self::Scalar) -> Self::Scalar;
+
+    /// Zips and maps the scalars of two vectors.
+    ///
+    /// Returns `None` if the dimensions of the vectors differ.
+    fn checked_zip_map<F>(self, other: Self, f: F) -> Option<Self>
+    where
+        F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar;
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        self.checked_zip_map(other, |a, b| a + b)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_zip_map(other, |a, b| a - b)
+    }
+
+    fn scale(self, factor: Self::Scalar) -> Self {
+        self.map_scalars(|a| a * factor)
+    }
+
+    /// Computes the dot product of two vectors.
+    ///
+    /// Returns `None` if the dimensions of the vectors differ.
+    fn checked_dot(&self, other: &Self) -> Option<Self::Scalar> {
+        if self.dimensions() == other.dimensions() {
+            Some((0..self.dimensions()).fold(Zero::zero(), |dot, index| {
+                dot + (self.scalar_component(index).unwrap()
+                    * other.scalar_component(index).unwrap())
+            }))
+        }
+        else {
+            None
+        }
+    }
+
+    fn square_magnitude(&self) -> Self::Scalar {
+        self.checked_dot(self).unwrap()
+    }
+
+    fn magnitude(&self) -> Self::Scalar
+    where
+        Self::Scalar: Sqrt,
+    {
+        Sqrt::sqrt(self.square_magnitude())
+    }
+
+    fn normalize(self) -> Option<Self>
+    where
+        Self::Scalar: Sqrt,
+    {
+        let magnitude = self.magnitude();
+        if magnitude != Zero::zero() {
+            Some(self.scale(Self::Scalar::one() / magnitude))
+        }
+        else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> DynDimensional for Vec<T> {
+    fn dimensions(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> DynVectorSpace for Vec<T>
+where
+    T: RealScalar,
+{
+    type Scalar = T;
+
+    fn zero(dimensions: usize) -> Self {
+        vec![Zero::zero(); dimensions]
+    }
+
+    fn scalar_component(&self, index: usize) -> Option<Self::Scalar> {
+        self.get(index).copied()
+    }
+
+    fn map_scalars<F>(self, f: F) -> Self
+    where
+        F: FnMut(Self::Scalar) -> Self::Scalar,
+    {
+        self.into_iter().map(f).collect()
+    }
+
+    fn checked_zip_map<F>(self, other: Self, mut f: F) -> Option<Self>
+    where
+        F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar,
+    {
+        if self.len() == other.len() {
+            Some(self.into_iter().zip(other).map(|(a, b)| f(a, b)).collect())
+        }
+        else {
+            None
+        }
+    }
+}
+
 pub trait DualSpace: FiniteDimensional + VectorSpace {
     type Dual: DualSpace + FiniteDimensional<N = Self::N> + VectorSpace<Scalar = Self::Scalar>;
 
     fn transpose(self) -> Self::Dual;
+
+    /// Applies a covector to a vector of its dual space.
+    ///
+    /// This pairs a dual (row) vector with a (column) vector and yields the
+    /// sum of the products of their components. For example, applying the
+    /// covector of a plane to the homogeneous coordinates of a point yields the
+    /// signed distance of the point from the plane (see `Plane::into_covector`).
+    fn apply(self, vector: Self::Dual) -> Self::Scalar {
+        (0..Self::dimensions()).fold(Zero::zero(), |sum, index| {
+            sum + (self.scalar_component(index).unwrap() * vector.scalar_component(index).unwrap())
+        })
+    }
 }
 
 pub trait Matrix: VectorSpace {
@@ -289,6 +767,168 @@ where
     Self::Row: FiniteDimensional<N = <Self::Column as FiniteDimensional>::N>,
 {
     fn multiplicative_identity() -> Self;
+
+    /// Computes the trace of the matrix (the sum of its diagonal).
+    fn trace(&self) -> Self::Scalar {
+        (0..Self::row_count()).fold(Zero::zero(), |trace, index| {
+            trace + Matrix::scalar_component(self, index, index).unwrap()
+        })
+    }
+
+    /// Computes the determinant of the matrix.
+    ///
+    /// The default implementation uses cofactor expansion and supports
+    /// matrices with no more than five columns.
+    fn determinant(&self) -> Self::Scalar {
+        let indices = (0..Self::row_count()).collect::<ArrayVec<[usize; 5]>>();
+        minor_determinant(self, &indices, &indices)
+    }
+
+    /// Computes the inverse of the matrix.
+    ///
+    /// Returns `None` if the matrix is singular. The default implementation
+    /// divides the adjugate by the determinant, so it supports the same
+    /// matrices as `determinant`.
+    fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant.is_zero() {
+            return None;
+        }
+        let n = Self::row_count();
+        let indices = (0..n).collect::<ArrayVec<[usize; 5]>>();
+        let cofactor = |row: usize, column: usize| {
+            let rows = minor_indices(&indices, row);
+            let columns = minor_indices(&indices, column);
+            let minor = minor_determinant(self, &rows, &columns);
+            if (row + column).is_multiple_of(2) {
+                minor
+            }
+            else {
+                -minor
+            }
+        };
+        // Items are in column-major order, as in `Matrix::scalar_component`.
+        let mut index = 0usize;
+        let identity = Self::multiplicative_identity();
+        Some(identity.zip_map(identity, |_, _| {
+            let (row, column) = (index % n, index / n);
+            index += 1;
+            cofactor(column, row) / determinant
+        }))
+    }
+
+    /// Inverts the matrix in place.
+    ///
+    /// Returns `None` and leaves the matrix unchanged if it is singular.
+    #[must_use]
+    fn try_invert(&mut self) -> Option<&Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        }
+        else {
+            None
+        }
+    }
+}
+
+/// Extension of a square matrix into a square matrix of one additional
+/// dimension.
+///
+/// The extended matrix contains `self` in its upper-left block and the last
+/// row and column of the identity matrix. For example, extending a `3x3`
+/// linear transformation yields the equivalent `4x4` homogeneous
+/// transformation. This is the matrix analog of `Extend`.
+pub trait ExtendMatrix<M>: Matrix
+where
+    M: Matrix<Scalar = Self::Scalar>,
+{
+    fn extend_matrix(self) -> M;
+}
+
+/// Truncation of a square matrix into a square matrix of one fewer dimension.
+///
+/// The truncated matrix is the upper-left block of `self`: the last row and
+/// column are discarded. This is the inverse of `ExtendMatrix` and the matrix
+/// analog of `Truncate`.
+pub trait TruncateMatrix<M>: Matrix
+where
+    M: Matrix<Scalar = Self::Scalar>,
+{
+    fn truncate_matrix(self) -> M;
+}
+
+fn minor_indices(indices: &[usize], excluded: usize) -> ArrayVec<[usize; 5]> {
+    indices
+        .iter()
+        .cloned()
+        .enumerate()
+        .filter(|(n, _)| *n != excluded)
+        .map(|(_, index)| index)
+        .collect()
+}
+
+fn minor_determinant<M>(matrix: &M, rows: &[usize], columns: &[usize]) -> M::Scalar
+where
+    M: Matrix,
+{
+    match rows.len() {
+        0 => One::one(),
+        _ => columns
+            .iter()
+            .enumerate()
+            .fold(Zero::zero(), |determinant, (n, column)| {
+                let term = Matrix::scalar_component(matrix, rows[0], *column).unwrap()
+                    * minor_determinant(matrix, &rows[1..], &minor_indices(columns, n));
+                if n.is_multiple_of(2) {
+                    determinant + term
+                }
+                else {
+                    determinant - term
+                }
+            }),
+    }
+}
+
+/// Quaternion over a three-dimensional vector space.
+///
+/// Multiplication of quaternions is the Hamilton product. Unit quaternions
+/// represent rotations in three dimensions.
+pub trait Quaternion: Copy + Mul<Output = Self> {
+    type Vector: DualSpace + FiniteDimensional<N = U3> + InnerSpace;
+    type Matrix: SquareMatrix<Column = Self::Vector, Scalar = <Self::Vector as VectorSpace>::Scalar>;
+
+    /// Creates a unit quaternion that rotates by `angle` radians about `axis`.
+    ///
+    /// Returns `None` if `axis` cannot be normalized.
+    fn from_axis_angle(
+        axis: Self::Vector,
+        angle: <Self::Vector as VectorSpace>::Scalar,
+    ) -> Option<Self>;
+
+    fn conjugate(self) -> Self;
+
+    /// Normalizes the quaternion such that it has unit magnitude.
+    ///
+    /// Returns `None` if the quaternion has zero magnitude.
+    fn normalize(self) -> Option<Self>;
+
+    /// Converts a unit quaternion into a rotation matrix.
+    fn into_rotation_matrix(self) -> Self::Matrix;
+
+    /// Spherically interpolates between unit quaternions.
+    ///
+    /// The interpolation follows the shortest arc between the rotations with
+    /// constant angular velocity. The factor `f` is clamped to $[0, 1]$.
+    fn slerp(self, other: Self, f: R64) -> Self;
+
+    /// Linearly interpolates between unit quaternions and normalizes the
+    /// result.
+    ///
+    /// The interpolation follows the shortest arc between the rotations, but
+    /// unlike `slerp`, its angular velocity is not constant. The factor `f` is
+    /// clamped to $[0, 1]$.
+    fn nlerp(self, other: Self, f: R64) -> Self;
 }
 
 pub trait AffineSpace:
@@ -392,18 +1032,610 @@ pub trait EuclideanSpace:
         self.into_coordinates().extend(One::one())
     }
 
+    /// Transforms the point by a homogeneous matrix.
+    ///
+    /// The point is lifted into its projective space, multiplied by the matrix,
+    /// and truncated with a perspective divide. Returns `None` if the
+    /// transformed point is at infinity.
+    fn transform_homogeneous<M>(self, matrix: M) -> Option<Self>
+    where
+        M: SquareMatrix<Column = Projective<Self>>,
+        Self::CoordinateSpace: Homogeneous + Extend<Projective<Self>>,
+        Projective<Self>:
+            DualSpace + Truncate<Self::CoordinateSpace> + VectorSpace<Scalar = Scalar<Self>>,
+    {
+        Self::from_homogeneous(matrix * self.into_homogeneous())
+    }
+
+    /// Computes the centroid (arithmetic mean) of points.
+    ///
+    /// The centroid is computed incrementally. Returns `None` if there are no
+    /// points.
     fn centroid<I>(points: I) -> Option<Self>
     where
         I: IntoIterator<Item = Self>,
     {
-        VectorSpace::mean(points.into_iter().map(|point| point.into_coordinates()))
-            .map(|mean| Self::origin() + mean)
+        Self::weighted_centroid(points.into_iter().map(|point| (point, One::one())))
+    }
+
+    /// Computes the centroid and covariance of points.
+    ///
+    /// See `VectorSpace::covariance`. Returns `None` if there are no points.
+    fn covariance<I, M>(points: I) -> Option<(Self, M)>
+    where
+        Self::CoordinateSpace: Outer<Output = M>,
+        M: VectorSpace<Scalar = Scalar<Self>>,
+        I: IntoIterator<Item = Self>,
+    {
+        VectorSpace::covariance(points.into_iter().map(|point| point.into_coordinates()))
+            .map(|(mean, covariance)| (Self::from_coordinates(mean), covariance))
+    }
+
+    /// Computes the weighted centroid of points.
+    ///
+    /// Weights should be non-negative. Returns `None` if the sum of the
+    /// weights is zero, including when there are no points.
+    fn weighted_centroid<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Self, Scalar<Self>)>,
+    {
+        VectorSpace::weighted_mean(
+            points
+                .into_iter()
+                .map(|(point, weight)| (point.into_coordinates(), weight)),
+        )
+        .map(Self::from_coordinates)
+    }
+}
+
+/// Space with a distance between its elements.
+///
+/// This trait is implemented for all `EuclideanSpace` types, for which the
+/// distance is the magnitude of the difference between points.
+pub trait MetricSpace: Copy {
+    type Distance: Real;
+
+    fn distance_squared(self, other: Self) -> Self::Distance;
+
+    fn distance(self, other: Self) -> Self::Distance
+    where
+        Self::Distance: Sqrt,
+    {
+        Sqrt::sqrt(self.distance_squared(other))
+    }
+
+    /// Returns `true` if the distance to `other` is no greater than `epsilon`.
+    ///
+    /// This does not compute a square root.
+    fn is_within(self, other: Self, epsilon: Self::Distance) -> bool {
+        self.distance_squared(other) <= epsilon * epsilon
+    }
+}
+
+impl<S> Barycentric for S
+where
+    S: EuclideanSpace,
+{
+    type Weight = Scalar<S>;
+
+    fn from_barycentric(
+        triangle: (Self, Self, Self),
+        weights: (Self::Weight, Self::Weight, Self::Weight),
+    ) -> Self {
+        let (a, b, c) = triangle;
+        let (u, v, w) = weights;
+        S::from_coordinates(
+            (a.into_coordinates() * u) + (b.into_coordinates() * v) + (c.into_coordinates() * w),
+        )
+    }
+
+    fn into_barycentric(
+        self,
+        triangle: (Self, Self, Self),
+    ) -> Option<(Self::Weight, Self::Weight, Self::Weight)> {
+        let (a, b, c) = triangle;
+        let (ab, ac, ap) = (b - a, c - a, self - a);
+        let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+        let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+        let denominator = (d00 * d11) - (d01 * d01);
+        if denominator.is_zero() {
+            None
+        }
+        else {
+            let v = ((d11 * d20) - (d01 * d21)) / denominator;
+            let w = ((d00 * d21) - (d01 * d20)) / denominator;
+            Some((Self::Weight::one() - v - w, v, w))
+        }
+    }
+}
+
+impl<S> MetricSpace for S
+where
+    S: EuclideanSpace,
+{
+    type Distance = Scalar<S>;
+
+    fn distance_squared(self, other: Self) -> Self::Distance {
+        (self - other).square_magnitude()
     }
 }
 
+/// `EuclideanSpace` with the same memory layout as its coordinate space.
+///
+/// This trait is used to determine if types that are composed of both points
+/// and vectors (e.g., `Ray`) are free of padding.
+///
+/// # Safety
+///
+/// `Self` and `Vector<Self>` must have the same size and alignment.
+pub unsafe trait CoordinateLayout: EuclideanSpace {}
+
 // TODO: Constrain the dimensionality of the projective space. This introduces
 //       noisy type bounds, but ensures that the projective space has exactly
 //       one additional dimension (the line at infinity).
+/// Vector space with a projective space of one additional dimension.
+///
+/// Points of a `EuclideanSpace` are lifted into the projective space of their
+/// coordinate space via `EuclideanSpace::into_homogeneous`. For example, a
+/// `nalgebra` `Point3` is lifted into a `Vector4`.
 pub trait Homogeneous: FiniteDimensional + VectorSpace {
     type ProjectiveSpace: FiniteDimensional + VectorSpace;
 }
+
+/// Affine transformation of a `EuclideanSpace`.
+///
+/// Transformations apply to both points and vectors. Vectors are not affected
+/// by translation.
+pub trait Transform<S>: Sized
+where
+    S: EuclideanSpace,
+{
+    fn identity() -> Self;
+
+    fn transform_point(&self, point: S) -> S;
+
+    fn transform_vector(&self, vector: Vector<S>) -> Vector<S>;
+
+    /// Composes transformations such that `other` is applied after `self`.
+    fn compose(self, other: Self) -> Self;
+
+    /// Gets the inverse transformation, if any.
+    fn inverse(self) -> Option<Self>;
+}
+
+/// Rigid motion of a `EuclideanSpace`.
+///
+/// Rigid motions are composed only of a rotation followed by a translation.
+/// They preserve the distances between points and are always invertible.
+///
+/// Interpolation of rigid motions interpolates translation linearly and
+/// rotation spherically.
+pub trait RigidMotion<S>: Interpolate<Output = Self> + Transform<S>
+where
+    S: EuclideanSpace,
+{
+    type Rotation: Rotation<S>;
+
+    fn from_rotation_translation(rotation: Self::Rotation, translation: Vector<S>) -> Self;
+
+    fn rotation(&self) -> Self::Rotation;
+
+    fn translation(&self) -> Vector<S>;
+
+    /// Gets the inverse motion.
+    ///
+    /// Unlike `Transform::inverse`, this does not invert a matrix and cannot
+    /// fail.
+    fn invert(self) -> Self;
+}
+
+/// Rotation of a `EuclideanSpace` about its origin.
+///
+/// Rotations preserve the magnitude of vectors and the distances between
+/// points. Unlike general transformations, rotations are always invertible.
+pub trait Rotation<S>: Sized
+where
+    S: EuclideanSpace,
+{
+    fn identity() -> Self;
+
+    fn rotate_point(&self, point: S) -> S;
+
+    fn rotate_vector(&self, vector: Vector<S>) -> Vector<S>;
+
+    /// Composes rotations such that `other` is applied after `self`.
+    fn compose(self, other: Self) -> Self;
+
+    fn inverse(self) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use decorum::R64;
+    use nalgebra::{
+        DVector, Matrix2, Matrix2x3, Matrix3, Matrix3x2, Matrix4, Point2, Point3, Vector2, Vector3,
+        Vector4,
+    };
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use crate::adjunct::{
+        AsItems, Cast, Compare, FromFn, FromItems, IntoItems, IterItems, MapAssign, Mask, Swizzle,
+        TryFromItems, ZipWith,
+    };
+    use crate::ops::{
+        barycentric_lerp, scalar_triple, vector_triple, Barycentric, Interpolate, MulMN, MulV,
+    };
+    use crate::space::{
+        Basis, DynDimensional, DynVectorSpace, EuclideanSpace, ExtendMatrix, FiniteDimensional,
+        MetricSpace, SquareMatrix, TruncateMatrix, VectorSpace,
+    };
+    use crate::AsPositionMut;
+
+    #[test]
+    fn array_basis() {
+        assert_eq!(3, <[f64; 3] as FiniteDimensional>::dimensions());
+        assert_eq!(
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            <[f64; 3]>::canonical_basis(),
+        );
+        assert_eq!(Some([0, 1]), <[i32; 2]>::canonical_basis_component(1));
+        assert_eq!(None, <[i32; 2]>::canonical_basis_component(2));
+    }
+
+    #[test]
+    fn extend_truncate_matrix_e3() {
+        let matrix = Matrix3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let extended: Matrix4<f64> = matrix.extend_matrix();
+        assert_eq!(
+            Matrix4::new(
+                1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0, 7.0, 8.0, 9.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+            ),
+            extended,
+        );
+        assert_eq!(matrix, extended.truncate_matrix());
+        assert_eq!(
+            Matrix2::new(1.0, 2.0, 4.0, 5.0),
+            TruncateMatrix::<Matrix2<f64>>::truncate_matrix(matrix),
+        );
+    }
+
+    #[test]
+    fn barycentric_round_trip() {
+        let triangle = (
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(2.0, 0.0, 0.0),
+            Point3::new(0.0, 2.0, 0.0),
+        );
+        let point = Point3::new(0.5, 1.0, 3.0);
+        let (u, v, w) = point.into_barycentric(triangle).unwrap();
+        assert_relative_eq!(0.25, u, epsilon = 1e-9);
+        assert_relative_eq!(0.25, v, epsilon = 1e-9);
+        assert_relative_eq!(0.5, w, epsilon = 1e-9);
+        let projection = Point3::from_barycentric(triangle, (u, v, w));
+        for (a, b) in projection.iter().zip([0.5, 1.0, 0.0]) {
+            assert_relative_eq!(*a, b, epsilon = 1e-9);
+        }
+        let (a, b, _) = triangle;
+        assert!(point.into_barycentric((a, b, b)).is_none());
+        assert_eq!(
+            Point2::new(1.0, 1.0),
+            barycentric_lerp(
+                Point2::new(0.0, 0.0),
+                Point2::new(2.0, 0.0),
+                Point2::new(0.0, 2.0),
+                (0.0.into(), 0.5.into(), 0.5.into()),
+            ),
+        );
+    }
+
+    #[test]
+    fn determinant_cofactor_expansion() {
+        let matrix = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+        assert_relative_eq!(
+            nalgebra::Matrix::determinant(&matrix),
+            SquareMatrix::determinant(&matrix),
+            epsilon = 1e-9,
+        );
+        let matrix = Matrix4::new(
+            1.0, 2.0, 0.0, 1.0, 0.0, 1.0, 3.0, 2.0, 4.0, 0.0, 1.0, 0.0, 2.0, 1.0, 0.0, 3.0,
+        );
+        assert_relative_eq!(
+            nalgebra::Matrix::determinant(&matrix),
+            SquareMatrix::determinant(&matrix),
+            epsilon = 1e-9,
+        );
+    }
+
+    #[test]
+    fn inverse_adjugate() {
+        let matrix = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+        let inverse = SquareMatrix::inverse(&matrix).unwrap();
+        let product = matrix * inverse;
+        for (a, b) in product.iter().zip(Matrix3::<f64>::identity().iter()) {
+            assert_relative_eq!(*a, *b, epsilon = 1e-9);
+        }
+
+        let mut matrix = Matrix2::new(1.0, 2.0, 2.0, 4.0);
+        assert!(SquareMatrix::try_invert(&mut matrix).is_none());
+        assert_eq!(Matrix2::new(1.0, 2.0, 2.0, 4.0), matrix);
+    }
+
+    #[test]
+    fn centroid_weighted() {
+        let points = [
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(1.0, 3.0),
+        ];
+        assert_eq!(
+            Point2::new(1.0, 1.0),
+            EuclideanSpace::centroid(points.iter().cloned()).unwrap()
+        );
+        let centroid = EuclideanSpace::weighted_centroid(
+            points.iter().cloned().zip([0.0, 1.0, 3.0].iter().cloned()),
+        )
+        .unwrap();
+        assert_relative_eq!(1.25, centroid.x, epsilon = 1e-9);
+        assert_relative_eq!(2.25, centroid.y, epsilon = 1e-9);
+        assert_eq!(None, EuclideanSpace::centroid(Vec::<Point2<f64>>::new()));
+        assert_eq!(
+            None,
+            EuclideanSpace::weighted_centroid(Some((Point2::new(1.0, 1.0), 0.0)))
+        );
+    }
+
+    #[test]
+    fn iter_as_items_e3() {
+        let point = Point3::new(1.0f64, 2.0, 3.0);
+        assert_eq!(&[1.0, 2.0, 3.0], point.as_items());
+        assert_eq!(6.0, point.iter_items().sum::<f64>());
+        // Matrices are column-major.
+        let matrix = Matrix2::new(1.0f64, 2.0, 3.0, 4.0);
+        assert_eq!(&[1.0, 3.0, 2.0, 4.0], matrix.as_items());
+    }
+
+    #[test]
+    fn from_fn_e3() {
+        let weights = [1.0, 2.0, 3.0];
+        let vector: Vector3<f64> = FromFn::from_fn(|index| weights[index]);
+        assert_eq!(Vector3::new(1.0, 2.0, 3.0), vector);
+    }
+
+    #[test]
+    fn cast_e3() {
+        assert_eq!(
+            Some(Vector3::new(1.0f32, 2.0, 3.0)),
+            Cast::cast::<f32>(Vector3::new(1.0f64, 2.0, 3.0))
+        );
+        assert_eq!(
+            Some(Point2::new(1.0f64, -2.0)),
+            Cast::cast::<f64>(Point2::new(1i32, -2))
+        );
+        assert_eq!(None, Cast::cast::<u32>(Point2::new(1i32, -2)));
+    }
+
+    #[test]
+    fn compare_mask_e3() {
+        let (a, b) = (Vector3::new(1.0, 2.0, 3.0), Vector3::new(3.0, 2.0, 1.0));
+        let mask = a.cmp_lt(b);
+        assert_eq!(Vector3::new(true, false, false), mask);
+        assert_eq!(Vector3::new(1.0, 2.0, 1.0), mask.select(a, b));
+    }
+
+    #[test]
+    fn map_assign_e3() {
+        let mut points = [Point3::new(1.0, 2.0, 3.0), Point3::new(-1.0, 0.0, 1.0)];
+        for point in points.iter_mut() {
+            point.map_position_assign(|x| *x *= 2.0);
+        }
+        assert_eq!(Point3::new(-2.0, 0.0, 2.0), points[1]);
+        let mut vector = Vector3::new(1.0, 2.0, 3.0);
+        vector.zip_map_assign(Vector3::new(1.0, 1.0, 1.0), |a, b| *a += b);
+        assert_eq!(Vector3::new(2.0, 3.0, 4.0), vector);
+    }
+
+    #[test]
+    fn dyn_vector_space() {
+        let a = DVector::from_vec(vec![3.0f64, 0.0, 4.0, 0.0, 0.0]);
+        assert_eq!(5, a.dimensions());
+        assert_eq!(Some(25.0), a.checked_dot(&a));
+        assert_relative_eq!(5.0, a.magnitude());
+        assert_eq!(None, a.clone().checked_add(DVector::zero(4)));
+        assert_eq!(
+            Some(DVector::from_vec(vec![2.0, -1.0, 3.0, -1.0, -1.0])),
+            a.checked_sub(DVector::from_element(5, 1.0))
+        );
+        assert_eq!(None, vec![1.0f64].checked_dot(&vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn into_items_e4() {
+        let homogeneous = Point3::new(1.0f64, 2.0, 3.0).into_homogeneous();
+        assert_eq!(
+            vec![1.0, 2.0, 3.0, 1.0],
+            homogeneous.into_items().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            [4.0, 1.0],
+            Vector4::new(1.0f64, 2.0, 3.0, 4.0)
+                .swizzle::<[f64; 2], 2>([3, 0])
+                .unwrap()
+        );
+        assert_eq!(
+            vec![1.0, 3.0, 2.0, 4.0],
+            Matrix2::new(1.0f64, 2.0, 3.0, 4.0)
+                .into_items()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn from_items_length_e3() {
+        assert_eq!(None, Vector3::<f64>::from_items(vec![1.0, 2.0]));
+        assert_eq!(
+            None,
+            Vector3::<f64>::try_from_items(vec![1.0, 2.0, 3.0, 4.0])
+        );
+        assert_eq!(
+            Some(Point3::new(1.0, 2.0, 3.0)),
+            Point3::<f64>::try_from_items(vec![1.0, 2.0, 3.0])
+        );
+        assert_eq!(
+            Some(Matrix2::new(1.0, 3.0, 2.0, 4.0)),
+            Matrix2::<f64>::from_items(vec![1.0, 2.0, 3.0, 4.0])
+        );
+    }
+
+    #[test]
+    fn zip_with_mask_e3() {
+        let vector = Vector3::new(1.0f32, -2.0, 3.0);
+        let mask = Vector3::new(true, false, true);
+        let masked: Vector3<f32> = vector.zip_with(mask, |x, is_set| {
+            if is_set {
+                x
+            }
+            else {
+                0.0
+            }
+        });
+        assert_eq!(Vector3::new(1.0, 0.0, 3.0), masked);
+    }
+
+    #[test]
+    fn covariance_e2() {
+        // Points far from the origin, where summing outer products loses
+        // precision.
+        let points = [(1.0, 0.0), (-1.0, 0.0), (0.0, 2.0), (0.0, -2.0)]
+            .iter()
+            .map(|(x, y)| Point2::new(1.0e8 + x, 1.0e8 + y));
+        let (centroid, covariance): (_, Matrix2<f64>) = EuclideanSpace::covariance(points).unwrap();
+        assert_relative_eq!(1.0e8, centroid.x);
+        assert_relative_eq!(1.0e8, centroid.y);
+        assert_relative_eq!(0.5, covariance[(0, 0)], epsilon = 1e-6);
+        assert_relative_eq!(2.0, covariance[(1, 1)], epsilon = 1e-6);
+        assert_relative_eq!(0.0, covariance[(0, 1)], epsilon = 1e-6);
+        assert_relative_eq!(0.0, covariance[(1, 0)], epsilon = 1e-6);
+        assert!(<Vector2<f64> as VectorSpace>::covariance::<_, Matrix2<f64>>(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn triple_products() {
+        let (a, b, c) = (
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 2.0, 0.0),
+            Vector3::new(1.0, 1.0, 3.0),
+        );
+        assert_eq!(6.0, scalar_triple(a, b, c));
+        assert_eq!(-6.0, scalar_triple(a, c, b));
+        assert_eq!(0.0, scalar_triple(a, b, a + b));
+        // The BAC-CAB identity: $a \times (b \times c) = b(a \cdot c) - c(a \cdot b)$.
+        assert_eq!((b * a.dot(&c)) - (c * a.dot(&b)), vector_triple(a, b, c));
+    }
+
+    #[test]
+    fn lerp_factor() {
+        let a = Vector3::<f32>::new(0.0, 0.0, 0.0);
+        let b = Vector3::<f32>::new(4.0, 8.0, -4.0);
+        assert_eq!(
+            Vector3::new(1.0, 2.0, -1.0),
+            Interpolate::lerp(a, b, 0.25f32)
+        );
+        assert_eq!(
+            Vector3::new(1.0, 2.0, -1.0),
+            Interpolate::<_, R64>::lerp(a, b, 0.25.into())
+        );
+        assert_eq!(b, Interpolate::lerp(a, b, 2.0f32));
+        assert_eq!(
+            Vector3::new(2.0, 4.0, -2.0),
+            Interpolate::<_, f32>::midpoint(a, b)
+        );
+    }
+
+    #[test]
+    fn mul_mn_rectangular() {
+        let a = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let b = Matrix3x2::new(1.0, 0.0, 0.0, 1.0, 1.0, 1.0);
+        assert_eq!(Matrix2::new(4.0, 5.0, 10.0, 11.0), a.mul_mn(b));
+        assert_eq!(
+            Vector2::new(14.0, 32.0),
+            a.mul_mn(Vector3::new(1.0, 2.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn mul_v() {
+        let m = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(
+            Vector2::new(14.0, 32.0),
+            m.mul_v(Vector3::new(1.0, 2.0, 3.0))
+        );
+        // Arrays are in column-major order.
+        let m = [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]];
+        assert_eq!([14.0, 32.0], m.mul_v([1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn homogeneous_lift() {
+        let point = Point3::new(1.0, 2.0, 3.0);
+        assert_eq!(Vector4::new(1.0, 2.0, 3.0, 1.0), point.into_homogeneous());
+        assert_eq!(
+            Some(point),
+            Point3::from_homogeneous(Vector4::new(2.0, 4.0, 6.0, 2.0))
+        );
+        let vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        let projective = vector.into_homogeneous();
+        assert_eq!(0.0, projective[4]);
+        assert_eq!(Some(vector), Vector4::from_homogeneous(projective));
+    }
+
+    #[test]
+    fn perp_e2() {
+        let a = Vector2::new(2.0, 1.0);
+        let b = Vector2::new(-1.0, 3.0);
+        assert_eq!(Vector2::new(-1.0, 2.0), VectorSpace::perp(a));
+        assert_eq!(0.0, VectorSpace::perp(a).dot(&a));
+        assert_eq!(7.0, VectorSpace::perp_dot(a, b));
+        assert_eq!(-7.0, VectorSpace::perp_dot(b, a));
+        assert_eq!(0.0, VectorSpace::perp_dot(a, a * 2.0));
+    }
+
+    #[test]
+    fn metric_distance() {
+        let a = Point2::new(1.0, 1.0);
+        let b = Point2::new(4.0, 5.0);
+        assert_eq!(25.0, MetricSpace::distance_squared(a, b));
+        assert_relative_eq!(5.0, MetricSpace::distance(a, b), epsilon = 1e-9);
+        assert!(a.is_within(b, 5.0));
+        assert!(!a.is_within(b, 4.9));
+    }
+
+    #[test]
+    fn orthonormalize_gram_schmidt() {
+        let mut vectors = [
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 3.0),
+        ];
+        let basis = super::orthonormalize(&mut vectors).unwrap();
+        for (i, a) in basis.iter().enumerate() {
+            for (j, b) in basis.iter().enumerate() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(expected, a.dot(b), epsilon = 1e-9);
+            }
+        }
+        assert_relative_eq!(FRAC_1_SQRT_2, basis[0].x, epsilon = 1e-9);
+
+        let mut vectors = [Vector3::new(1.0, 1.0, 0.0), Vector3::new(2.0, 2.0, 0.0)];
+        assert!(super::orthonormalize(&mut vectors).is_none());
+    }
+
+    #[test]
+    fn norms() {
+        let vector = Vector3::new(3.0, -4.0, 0.0);
+        assert_eq!(7.0, VectorSpace::l1_norm(vector));
+        assert_eq!(4.0, VectorSpace::linf_norm(vector));
+        assert_relative_eq!(5.0, VectorSpace::p_norm(vector, 2.0), epsilon = 1e-9);
+        assert_relative_eq!(7.0, VectorSpace::p_norm(vector, 1.0), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,95 @@
+//! Constant construction of array vectors and matrices.
+//!
+//! Trait functions like `Basis::canonical_basis_component` cannot be called in
+//! `const` contexts. This module provides `const fn` counterparts for arrays,
+//! so that tables of axes and identity transforms can be stored in `static`s
+//! and `const`s without lazy initialization. Matrices are arrays of columns,
+//! as with the array aliases in `types`.
+//!
+//! These functions require scalars that implement `ConstZero` and `ConstOne`,
+//! such as the primitive numeric types.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use theon::space::consts;
+//!
+//! static AXES: [[f64; 3]; 3] = consts::multiplicative_identity();
+//! const UP: Option<[f32; 3]> = consts::canonical_basis_component(1);
+//!
+//! assert_eq!([0.0, 1.0, 0.0], AXES[1]);
+//! assert_eq!(Some([0.0, 1.0, 0.0]), UP);
+//! ```
+
+use num_traits::{ConstOne, ConstZero};
+
+/// Gets the zero vector (additive identity) of an array.
+pub const fn zero<T, const N: usize>() -> [T; N]
+where
+    T: ConstZero + Copy,
+{
+    [T::ZERO; N]
+}
+
+/// Gets the canonical basis vector of an array with the given index.
+///
+/// Returns `None` if the index is not less than the dimensionality of the
+/// array. See `Basis::canonical_basis_component`.
+pub const fn canonical_basis_component<T, const N: usize>(index: usize) -> Option<[T; N]>
+where
+    T: ConstOne + ConstZero + Copy,
+{
+    if index < N {
+        let mut component = zero::<T, N>();
+        component[index] = T::ONE;
+        Some(component)
+    }
+    else {
+        None
+    }
+}
+
+/// Gets the identity matrix (multiplicative identity) of an array of columns.
+pub const fn multiplicative_identity<T, const N: usize>() -> [[T; N]; N]
+where
+    T: ConstOne + ConstZero + Copy,
+{
+    let mut matrix = [zero::<T, N>(); N];
+    let mut index = 0;
+    while index < N {
+        matrix[index][index] = T::ONE;
+        index += 1;
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::space::consts;
+    use crate::space::Basis;
+
+    const IDENTITY: [[f64; 4]; 4] = consts::multiplicative_identity();
+
+    #[test]
+    fn canonical_basis_e3() {
+        let bases = <[f64; 3]>::canonical_basis();
+        for (index, basis) in bases.iter().enumerate() {
+            assert_eq!(
+                Some(*basis),
+                consts::canonical_basis_component::<f64, 3>(index)
+            );
+        }
+        assert_eq!(None, consts::canonical_basis_component::<f64, 3>(3));
+        assert_eq!([0u32; 3], consts::zero::<u32, 3>());
+    }
+
+    #[test]
+    fn multiplicative_identity_e4() {
+        for (index, column) in IDENTITY.iter().enumerate() {
+            assert_eq!(
+                consts::canonical_basis_component::<f64, 4>(index).unwrap(),
+                *column
+            );
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Cubic splines.
+//!
+//! This module provides cubic Bézier, Hermite, and Catmull-Rom curves. Curves
+//! can be evaluated for any type with a difference that is a `VectorSpace`,
+//! such as points of an `AffineSpace` or `EuclideanSpace` and vectors.
+//! Derivatives (tangents) of curves are vectors.
+//!
+//! Curves are parameterized by $t$, which is typically in $[0, 1]$. The curve
+//! is not clamped, so other values of $t$ extrapolate the curve.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate theon;
+//! #
+//! use nalgebra::Point2;
+//! use theon::spline::CatmullRom;
+//!
+//! type E2 = Point2<f64>;
+//!
+//! let spline = CatmullRom {
+//!     points: [
+//!         E2::new(0.0, 0.0),
+//!         E2::new(1.0, 0.0),
+//!         E2::new(2.0, 1.0),
+//!         E2::new(3.0, 1.0),
+//!     ],
+//! };
+//! let midpoint = spline.evaluate(0.5);
+//! let tangent = spline.derivative(0.5);
+//! ```
+
+use core::ops::{Add, Sub};
+use num::One;
+
+use crate::space::VectorSpace;
+
+/// Cubic Bézier curve.
+///
+/// The curve begins at the first point and ends at the last point. The
+/// intermediate points are control points that the curve does not typically
+/// pass through.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier<S> {
+    pub points: [S; 4],
+}
+
+impl<S> CubicBezier<S>
+where
+    S: Copy,
+{
+    pub fn evaluate<V>(&self, t: V::Scalar) -> S
+    where
+        S: Add<V, Output = S> + Sub<Output = V>,
+        V: VectorSpace,
+    {
+        let [p0, p1, p2, p3] = self.points;
+        let three = three::<V::Scalar>();
+        let s = V::Scalar::one() - t;
+        p0 + ((p1 - p0) * (three * t * s * s))
+            + ((p2 - p0) * (three * t * t * s))
+            + ((p3 - p0) * (t * t * t))
+    }
+
+    pub fn derivative<V>(&self, t: V::Scalar) -> V
+    where
+        S: Sub<Output = V>,
+        V: VectorSpace,
+    {
+        let [p0, p1, p2, p3] = self.points;
+        let three = three::<V::Scalar>();
+        let s = V::Scalar::one() - t;
+        ((p1 - p0) * (three * s * s))
+            + ((p2 - p1) * ((three + three) * s * t))
+            + ((p3 - p2) * (three * t * t))
+    }
+}
+
+impl<S, V> From<Hermite<S, V>> for CubicBezier<S>
+where
+    S: Add<V, Output = S> + Copy,
+    V: VectorSpace,
+{
+    fn from(hermite: Hermite<S, V>) -> Self {
+        let third = V::Scalar::one() / three::<V::Scalar>();
+        CubicBezier {
+            points: [
+                hermite.start,
+                hermite.start + (hermite.start_tangent * third),
+                hermite.end + -(hermite.end_tangent * third),
+                hermite.end,
+            ],
+        }
+    }
+}
+
+/// Cubic Hermite curve.
+///
+/// The curve begins at `start` and ends at `end` with the given tangents at
+/// each endpoint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hermite<S, V> {
+    pub start: S,
+    pub start_tangent: V,
+    pub end: S,
+    pub end_tangent: V,
+}
+
+impl<S, V> Hermite<S, V>
+where
+    S: Add<V, Output = S> + Copy + Sub<Output = V>,
+    V: VectorSpace,
+{
+    pub fn evaluate(&self, t: V::Scalar) -> S {
+        let (two, three) = (two::<V::Scalar>(), three::<V::Scalar>());
+        let (t2, t3) = (t * t, t * t * t);
+        let h10 = t3 - (two * t2) + t;
+        let h01 = (three * t2) - (two * t3);
+        let h11 = t3 - t2;
+        self.start
+            + ((self.end - self.start) * h01)
+            + (self.start_tangent * h10)
+            + (self.end_tangent * h11)
+    }
+
+    pub fn derivative(&self, t: V::Scalar) -> V {
+        let (two, three) = (two::<V::Scalar>(), three::<V::Scalar>());
+        let t2 = t * t;
+        let h10 = (three * t2) - (two * two * t) + V::Scalar::one();
+        let h01 = (two * three) * (t - t2);
+        let h11 = (three * t2) - (two * t);
+        ((self.end - self.start) * h01) + (self.start_tangent * h10) + (self.end_tangent * h11)
+    }
+}
+
+/// Uniform Catmull-Rom curve.
+///
+/// The curve interpolates the second and third points, beginning at the second
+/// point and ending at the third point. The first and last points determine
+/// the tangents at each end of the curve. Consecutive curves that share three
+/// points join with a continuous tangent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CatmullRom<S> {
+    pub points: [S; 4],
+}
+
+impl<S> CatmullRom<S>
+where
+    S: Copy,
+{
+    pub fn evaluate<V>(&self, t: V::Scalar) -> S
+    where
+        S: Add<V, Output = S> + Sub<Output = V>,
+        V: VectorSpace,
+    {
+        self.into_hermite().evaluate(t)
+    }
+
+    pub fn derivative<V>(&self, t: V::Scalar) -> V
+    where
+        S: Add<V, Output = S> + Sub<Output = V>,
+        V: VectorSpace,
+    {
+        self.into_hermite().derivative(t)
+    }
+
+    /// Converts the curve into an equivalent Hermite curve.
+    pub fn into_hermite<V>(self) -> Hermite<S, V>
+    where
+        S: Sub<Output = V>,
+        V: VectorSpace,
+    {
+        let [p0, p1, p2, p3] = self.points;
+        let half = V::Scalar::one() / two::<V::Scalar>();
+        Hermite {
+            start: p1,
+            start_tangent: (p2 - p0) * half,
+            end: p2,
+            end_tangent: (p3 - p1) * half,
+        }
+    }
+}
+
+fn two<T>() -> T
+where
+    T: Add<Output = T> + One,
+{
+    T::one() + T::one()
+}
+
+fn three<T>() -> T
+where
+    T: Add<Output = T> + One,
+{
+    T::one() + T::one() + T::one()
+}
+
+#[cfg(all(test, feature = "geometry-nalgebra"))]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{Point2, Vector2};
+
+    use crate::spline::{CatmullRom, CubicBezier, Hermite};
+
+    type E2 = Point2<f64>;
+
+    fn assert_point_eq(expected: E2, point: E2) {
+        assert_abs_diff_eq!(expected.x, point.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(expected.y, point.y, epsilon = 1e-9);
+    }
+
+    fn assert_vector_eq(expected: Vector2<f64>, vector: Vector2<f64>) {
+        assert_abs_diff_eq!(expected.x, vector.x, epsilon = 1e-9);
+        assert_abs_diff_eq!(expected.y, vector.y, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn bezier_hermite_equivalence() {
+        let hermite = Hermite {
+            start: E2::new(0.0, 0.0),
+            start_tangent: Vector2::new(3.0, 0.0),
+            end: E2::new(2.0, 2.0),
+            end_tangent: Vector2::new(0.0, 3.0),
+        };
+        let bezier = CubicBezier::from(hermite);
+        assert_point_eq(E2::new(1.0, 0.0), bezier.points[1]);
+        for t in [0.0, 0.25, 0.5, 1.0] {
+            assert_point_eq(hermite.evaluate(t), bezier.evaluate(t));
+            assert_vector_eq(hermite.derivative(t), bezier.derivative(t));
+        }
+        assert_point_eq(E2::new(2.0, 2.0), bezier.evaluate(1.0));
+        assert_vector_eq(Vector2::new(3.0, 0.0), bezier.derivative(0.0));
+    }
+
+    #[test]
+    fn catmull_rom_interpolation() {
+        let spline = CatmullRom {
+            points: [
+                E2::new(0.0, 0.0),
+                E2::new(1.0, 0.0),
+                E2::new(2.0, 1.0),
+                E2::new(3.0, 1.0),
+            ],
+        };
+        assert_point_eq(E2::new(1.0, 0.0), spline.evaluate(0.0));
+        assert_point_eq(E2::new(2.0, 1.0), spline.evaluate(1.0));
+        assert_vector_eq(Vector2::new(1.0, 0.5), spline.derivative(0.0));
+        assert_vector_eq(Vector2::new(1.0, 0.5), spline.derivative(1.0));
+        // Splines also interpolate vectors.
+        let spline = CatmullRom {
+            points: spline.points.map(|point| point.coords),
+        };
+        assert_vector_eq(Vector2::new(2.0, 1.0), spline.evaluate(1.0));
+    }
+}
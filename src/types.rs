@@ -0,0 +1,122 @@
+//! Vector, point, and matrix types of the enabled geometry backend.
+//!
+//! This module provides type aliases for commonly used types, such as
+//! `Vector3` and `Point3`, that refer to the types of an enabled geometry
+//! feature. This allows dependent code to name concrete types without
+//! choosing a particular backend.
+//!
+//! If more than one of these features are enabled, then the first of
+//! `geometry-nalgebra`, `geometry-cgmath`, and `geometry-glam` is used. Note
+//! that `geometry-nalgebra` is a default feature. If none of these features
+//! are enabled, then the aliases refer to arrays, which only implement adjunct
+//! traits. Matrices of arrays are arrays of columns.
+//!
+//! Because features are additive, enabling a feature can change these types
+//! for all dependents of Theon in a build. Libraries should prefer generic
+//! code where possible.
+//!
+//! `glam` types are not generic, so the type parameter of aliases is limited to
+//! `f32` and `f64` when using the `geometry-glam` feature. See `GlamScalar`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use theon::space::EuclideanSpace;
+//! use theon::types::Point3;
+//!
+//! let origin = Point3::<f64>::origin();
+//! ```
+
+#[cfg(feature = "geometry-nalgebra")]
+mod backend {
+    pub type Vector2<T> = nalgebra::Vector2<T>;
+    pub type Vector3<T> = nalgebra::Vector3<T>;
+    pub type Vector4<T> = nalgebra::Vector4<T>;
+    pub type Point2<T> = nalgebra::Point2<T>;
+    pub type Point3<T> = nalgebra::Point3<T>;
+    pub type Matrix2<T> = nalgebra::Matrix2<T>;
+    pub type Matrix3<T> = nalgebra::Matrix3<T>;
+    pub type Matrix4<T> = nalgebra::Matrix4<T>;
+}
+
+#[cfg(all(feature = "geometry-cgmath", not(feature = "geometry-nalgebra")))]
+mod backend {
+    pub type Vector2<T> = cgmath::Vector2<T>;
+    pub type Vector3<T> = cgmath::Vector3<T>;
+    pub type Vector4<T> = cgmath::Vector4<T>;
+    pub type Point2<T> = cgmath::Point2<T>;
+    pub type Point3<T> = cgmath::Point3<T>;
+    pub type Matrix2<T> = cgmath::Matrix2<T>;
+    pub type Matrix3<T> = cgmath::Matrix3<T>;
+    pub type Matrix4<T> = cgmath::Matrix4<T>;
+}
+
+#[cfg(all(
+    feature = "geometry-glam",
+    not(any(feature = "geometry-cgmath", feature = "geometry-nalgebra"))
+))]
+mod backend {
+    use super::GlamScalar;
+
+    pub type Vector2<T> = <T as GlamScalar>::Vector2;
+    pub type Vector3<T> = <T as GlamScalar>::Vector3;
+    pub type Vector4<T> = <T as GlamScalar>::Vector4;
+    pub type Point2<T> = <T as GlamScalar>::Vector2;
+    pub type Point3<T> = <T as GlamScalar>::Vector3;
+    pub type Matrix2<T> = <T as GlamScalar>::Matrix2;
+    pub type Matrix3<T> = <T as GlamScalar>::Matrix3;
+    pub type Matrix4<T> = <T as GlamScalar>::Matrix4;
+}
+
+#[cfg(not(any(
+    feature = "geometry-cgmath",
+    feature = "geometry-glam",
+    feature = "geometry-nalgebra"
+)))]
+mod backend {
+    pub type Vector2<T> = [T; 2];
+    pub type Vector3<T> = [T; 3];
+    pub type Vector4<T> = [T; 4];
+    pub type Point2<T> = [T; 2];
+    pub type Point3<T> = [T; 3];
+    pub type Matrix2<T> = [[T; 2]; 2];
+    pub type Matrix3<T> = [[T; 3]; 3];
+    pub type Matrix4<T> = [[T; 4]; 4];
+}
+
+pub use backend::{Matrix2, Matrix3, Matrix4, Point2, Point3, Vector2, Vector3, Vector4};
+
+/// Scalar type of `glam` types.
+///
+/// `glam` provides distinct types for `f32` and `f64` scalars, such as `Vec3`
+/// and `DVec3`. This trait maps these scalars to their types. `glam` types
+/// represent both vectors and points.
+#[cfg(feature = "geometry-glam")]
+pub trait GlamScalar {
+    type Vector2;
+    type Vector3;
+    type Vector4;
+    type Matrix2;
+    type Matrix3;
+    type Matrix4;
+}
+
+#[cfg(feature = "geometry-glam")]
+impl GlamScalar for f32 {
+    type Vector2 = glam::Vec2;
+    type Vector3 = glam::Vec3;
+    type Vector4 = glam::Vec4;
+    type Matrix2 = glam::Mat2;
+    type Matrix3 = glam::Mat3;
+    type Matrix4 = glam::Mat4;
+}
+
+#[cfg(feature = "geometry-glam")]
+impl GlamScalar for f64 {
+    type Vector2 = glam::DVec2;
+    type Vector3 = glam::DVec3;
+    type Vector4 = glam::DVec4;
+    type Matrix2 = glam::DMat2;
+    type Matrix3 = glam::DMat3;
+    type Matrix4 = glam::DMat4;
+}
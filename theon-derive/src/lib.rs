@@ -0,0 +1,127 @@
+//! Derive macros for Theon.
+//!
+//! This crate is re-exported by `theon` when its `derive` feature is enabled
+//! and should not be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Member, Type};
+
+const POSITION: &str = "position";
+
+/// Derives `AsPosition` for a struct with a position field.
+///
+/// The position field is the field with a `#[position]` attribute or, if no
+/// field has that attribute, the field named `position`.
+#[proc_macro_derive(AsPosition, attributes(position))]
+pub fn derive_as_position(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_as_position(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `AsPositionMut` for a struct with a position field.
+///
+/// The position field is chosen in the same way as `AsPosition`, which must
+/// also be implemented.
+#[proc_macro_derive(AsPositionMut, attributes(position))]
+pub fn derive_as_position_mut(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_as_position_mut(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_as_position(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, ty) = position_field(input)?;
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#ty: ::theon::space::EuclideanSpace));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::theon::AsPosition for #name #type_generics #where_clause {
+            type Position = #ty;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.#member
+            }
+        }
+    })
+}
+
+fn expand_as_position_mut(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, ty) = position_field(input)?;
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#ty: ::theon::space::EuclideanSpace));
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::theon::AsPositionMut for #name #type_generics #where_clause {
+            fn as_position_mut(&mut self) -> &mut Self::Position {
+                &mut self.#member
+            }
+        }
+    })
+}
+
+// Gets the member and type of the position field of a struct.
+fn position_field(input: &DeriveInput) -> syn::Result<(Member, Type)> {
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                input.ident.span(),
+                "positions can only be derived for structs",
+            ));
+        }
+    };
+    let members = || -> Box<dyn Iterator<Item = (Member, &syn::Field)>> {
+        match fields {
+            Fields::Named(ref fields) => Box::new(
+                fields
+                    .named
+                    .iter()
+                    .map(|field| (Member::Named(field.ident.clone().unwrap()), field)),
+            ),
+            Fields::Unnamed(ref fields) => Box::new(
+                fields
+                    .unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| (Member::from(index), field)),
+            ),
+            Fields::Unit => Box::new(None.into_iter()),
+        }
+    };
+    let mut tagged = members().filter(|(_, field)| {
+        field
+            .attrs
+            .iter()
+            .any(|attribute| attribute.path().is_ident(POSITION))
+    });
+    match (tagged.next(), tagged.next()) {
+        (Some((member, field)), None) => Ok((member, field.ty.clone())),
+        (Some(_), Some((_, field))) => Err(Error::new(
+            field.span(),
+            "more than one field has a `#[position]` attribute",
+        )),
+        (None, _) => members()
+            .find(|(member, _)| matches!(member, Member::Named(ident) if ident == POSITION))
+            .map(|(member, field)| (member, field.ty.clone()))
+            .ok_or_else(|| {
+                Error::new(
+                    input.ident.span(),
+                    "no field has a `#[position]` attribute nor is named `position`",
+                )
+            }),
+    }
+}